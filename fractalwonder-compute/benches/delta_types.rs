@@ -0,0 +1,97 @@
+//! Compares the FloatExp and HDRFloat tile renderers on the CPU.
+//!
+//! Run with `cargo bench -p fractalwonder-compute --bench delta_types`.
+//! Both paths render the same tile past the f64 exponent range, with and
+//! without BLA, and report the best of several runs.
+
+use fractalwonder_compute::{
+    render_tile_floatexp, render_tile_hdr, BlaTable, ReferenceOrbit, TileConfig,
+};
+use fractalwonder_core::{BigFloat, DeltaStep, FloatExp, HDRFloat};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const TILE_SIZE: u32 = 32;
+const MAX_ITERATIONS: u32 = 2000;
+const RUNS: u32 = 5;
+
+fn best_of<F: FnMut() -> u64>(mut render: F) -> (Duration, u64) {
+    let mut best = Duration::MAX;
+    let mut iterations = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        iterations = black_box(render());
+        best = best.min(start.elapsed());
+    }
+    (best, iterations)
+}
+
+fn main() {
+    // A point near the main cardioid cusp keeps most pixels iterating for a while
+    let precision = 1400;
+    let c_ref = (
+        BigFloat::from_string("0.2501", precision).unwrap(),
+        BigFloat::zero(precision),
+    );
+    let orbit = ReferenceOrbit::compute(&c_ref, MAX_ITERATIONS);
+
+    let origin = BigFloat::from_string("-1e-400", precision).unwrap();
+    let step = BigFloat::from_string("6e-402", precision).unwrap();
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_bigfloat(&origin).mul_f64(-2.0));
+
+    println!(
+        "{}x{} tile, {} max iterations, deltas ~1e-400",
+        TILE_SIZE, TILE_SIZE, MAX_ITERATIONS
+    );
+
+    for bla_enabled in [false, true] {
+        let config = TileConfig {
+            size: (TILE_SIZE, TILE_SIZE),
+            max_iterations: MAX_ITERATIONS,
+            tau_sq: 1e-6,
+            bla_enabled,
+            force_hdr_float: false,
+            scaled_f64: false,
+            collect_orbit_stats: false,
+            probe_interior: true,
+            iteration_limit: None,
+        };
+
+        let (fe_time, fe_iterations) = best_of(|| {
+            let origin = FloatExp::from_bigfloat(&origin);
+            let step = FloatExp::from_bigfloat(&step);
+            render_tile_floatexp(
+                &orbit,
+                Some(&bla_table),
+                (origin, origin),
+                DeltaStep::axis_aligned(step, step),
+                &config,
+            )
+            .stats
+            .total_iterations
+        });
+        let (hdr_time, hdr_iterations) = best_of(|| {
+            let origin = HDRFloat::from_bigfloat(&origin);
+            let step = HDRFloat::from_bigfloat(&step);
+            render_tile_hdr(
+                &orbit,
+                Some(&bla_table),
+                (origin, origin),
+                DeltaStep::axis_aligned(step, step),
+                &config,
+            )
+            .stats
+            .total_iterations
+        });
+
+        let label = if bla_enabled { "BLA" } else { "no BLA" };
+        println!(
+            "{label:>6}: FloatExp {:>9.2?} ({} iters)  HDRFloat {:>9.2?} ({} iters)  speedup {:.2}x",
+            fe_time,
+            fe_iterations,
+            hdr_time,
+            hdr_iterations,
+            hdr_time.as_secs_f64() / fe_time.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Compares the lane-parallel f64 BLA kernel with the per-pixel kernel.
+//!
+//! Run with `cargo bench -p fractalwonder-compute --bench f64_lanes`.
+//! Both render the same 64x64 grid of deltas at a moderate zoom and report
+//! the best of several runs.
+
+use fractalwonder_compute::{
+    compute_pixel_perturbation_f64_bla, compute_pixels_perturbation_f64_bla, BlaTable,
+    ReferenceOrbit,
+};
+use fractalwonder_core::{BigFloat, HDRFloat};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const GRID: usize = 64;
+const MAX_ITERATIONS: u32 = 5000;
+const RUNS: u32 = 5;
+
+fn best_of<F: FnMut() -> u64>(mut render: F) -> (Duration, u64) {
+    let mut best = Duration::MAX;
+    let mut iterations = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        iterations = black_box(render());
+        best = best.min(start.elapsed());
+    }
+    (best, iterations)
+}
+
+fn main() {
+    // Seahorse valley: a mix of slow escapes and interior pixels
+    let c_ref = (
+        BigFloat::with_precision(-0.743_643_887, 128),
+        BigFloat::with_precision(0.131_825_904, 128),
+    );
+    let orbit = ReferenceOrbit::compute(&c_ref, MAX_ITERATIONS);
+
+    let half_width = 1e-7;
+    let step = 2.0 * half_width / GRID as f64;
+    let deltas: Vec<(f64, f64)> = (0..GRID * GRID)
+        .map(|i| {
+            let (x, y) = ((i % GRID) as f64, (i / GRID) as f64);
+            (-half_width + x * step, -half_width + y * step)
+        })
+        .collect();
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(half_width * 1.5));
+
+    println!("{GRID}x{GRID} pixels, {MAX_ITERATIONS} max iterations, deltas ~{half_width:e}");
+
+    let (scalar_time, scalar_iterations) = best_of(|| {
+        deltas
+            .iter()
+            .map(|&dc| {
+                compute_pixel_perturbation_f64_bla(&orbit, &bla_table, dc, MAX_ITERATIONS, 1e-6)
+                    .1
+                    .total_iterations as u64
+            })
+            .sum()
+    });
+    let (lanes_time, lanes_iterations) = best_of(|| {
+        compute_pixels_perturbation_f64_bla(&orbit, &bla_table, &deltas, MAX_ITERATIONS, 1e-6)
+            .iter()
+            .map(|(_, stats)| stats.total_iterations as u64)
+            .sum()
+    });
+    assert_eq!(scalar_iterations, lanes_iterations);
+
+    println!("  per-pixel: {scalar_time:>10.2?}  ({scalar_iterations} iterations)");
+    println!(
+        "  lanes:     {lanes_time:>10.2?}  ({:.2}x)",
+        scalar_time.as_secs_f64() / lanes_time.as_secs_f64()
+    );
+}
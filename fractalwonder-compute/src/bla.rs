@@ -3,7 +3,8 @@
 //! Uses HDRFloat for coefficients to prevent overflow at deep zoom levels
 //! where BLA coefficients can exceed f64 range (10^308).
 
-use crate::ReferenceOrbit;
+use crate::block_window::BlockWindow;
+use crate::{CompressedOrbit, ReferenceOrbit};
 use fractalwonder_core::{HDRComplex, HDRFloat};
 
 /// Single BLA entry: skips `l` iterations.
@@ -11,7 +12,7 @@ use fractalwonder_core::{HDRComplex, HDRFloat};
 ///
 /// Uses HDRFloat for A, B, and r_sq to prevent overflow during deep zoom
 /// where coefficients multiply together across many iterations.
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct BlaEntry {
     /// Complex coefficient A (multiplies δz)
     pub a: HDRComplex,
@@ -67,6 +68,42 @@ impl BlaEntryF64 {
 }
 
 impl BlaEntry {
+    /// Size of the fixed-width encoding used in shared orbit buffers.
+    pub const WORDS: usize = 16;
+
+    /// Encode as `WORDS` 32-bit words: A, B and r² as (head, tail, exponent)
+    /// triples, then `l`.
+    pub fn to_words(&self) -> [u32; Self::WORDS] {
+        let mut words = [0; Self::WORDS];
+        let values = [self.a.re, self.a.im, self.b.re, self.b.im, self.r_sq];
+        for (out, value) in words.chunks_exact_mut(3).zip(values) {
+            out.copy_from_slice(&[value.head.to_bits(), value.tail.to_bits(), value.exp as u32]);
+        }
+        words[15] = self.l;
+        words
+    }
+
+    /// Decode from the first `WORDS` words written by `to_words`.
+    pub fn from_words(words: &[u32]) -> Self {
+        let value = |i: usize| HDRFloat {
+            head: f32::from_bits(words[3 * i]),
+            tail: f32::from_bits(words[3 * i + 1]),
+            exp: words[3 * i + 2] as i32,
+        };
+        Self {
+            a: HDRComplex {
+                re: value(0),
+                im: value(1),
+            },
+            b: HDRComplex {
+                re: value(2),
+                im: value(3),
+            },
+            l: words[15],
+            r_sq: value(4),
+        }
+    }
+
     /// Create a single-iteration BLA from a reference orbit point Z = (z_re, z_im).
     pub fn from_orbit_point(z_re: f64, z_im: f64) -> Self {
         let epsilon = 2.0_f64.powi(-53);
@@ -145,12 +182,49 @@ pub struct BlaTable {
 }
 
 impl BlaTable {
+    /// Whether a table is worth building for pixels up to `dc_max` away from
+    /// the reference.
+    ///
+    /// BLA helps at deep zoom where iteration counts are high. Phil Thompson
+    /// enables it at scale > 1e25 (dc_max below roughly 2^-80).
+    /// Reference: https://philthompson.me/2023/Faster-Mandelbrot-Set-Rendering-with-BLA-Bivariate-Linear-Approximation.html
+    pub fn pays_off(dc_max: &HDRFloat) -> bool {
+        dc_max.log2() < -80.0
+    }
+
+    /// Reassemble a table from its entries, e.g. after reading them back
+    /// from a shared buffer.
+    pub fn from_parts(
+        entries: Vec<BlaEntry>,
+        level_offsets: Vec<usize>,
+        dc_max: &HDRFloat,
+    ) -> Self {
+        Self {
+            num_levels: level_offsets.len(),
+            entries,
+            level_offsets,
+            dc_max: *dc_max,
+        }
+    }
+
     /// Compute BLA table from a reference orbit.
     ///
     /// dc_max must be HDRFloat to prevent underflow at deep zoom levels
     /// where the viewport width (10^-270) underflows in f64.
     pub fn compute(orbit: &ReferenceOrbit, dc_max: &HDRFloat) -> Self {
-        let m = orbit.orbit.len();
+        let points = &orbit.orbit[..steppable_len(orbit.orbit.len(), orbit.period)];
+        Self::from_points(points.iter().copied(), dc_max)
+    }
+
+    /// Compute BLA table from a compressed orbit, regenerating its points
+    /// one at a time instead of expanding the whole orbit first.
+    pub fn from_compressed(orbit: &CompressedOrbit, dc_max: &HDRFloat) -> Self {
+        let len = steppable_len(orbit.len(), orbit.period());
+        Self::from_points(orbit.iter().take(len).map(|p| p.z), dc_max)
+    }
+
+    fn from_points(points: impl ExactSizeIterator<Item = (f64, f64)>, dc_max: &HDRFloat) -> Self {
+        let m = points.len();
         if m == 0 {
             return Self {
                 entries: vec![],
@@ -166,7 +240,7 @@ impl BlaTable {
 
         // Level 0: single-iteration BLAs
         level_offsets.push(0);
-        for &(z_re, z_im) in &orbit.orbit {
+        for (z_re, z_im) in points {
             entries.push(BlaEntry::from_orbit_point(z_re, z_im));
         }
 
@@ -184,7 +258,7 @@ impl BlaTable {
 
                 if y_idx >= entries.len() {
                     // Odd number: copy last entry unchanged
-                    entries.push(entries[x_idx].clone());
+                    entries.push(entries[x_idx]);
                 } else {
                     let merged = BlaEntry::merge(&entries[x_idx], &entries[y_idx], dc_max);
                     entries.push(merged);
@@ -205,10 +279,43 @@ impl BlaTable {
             dc_max: *dc_max,
         }
     }
+}
 
-    /// Get dc_max for validity checks during find_valid.
-    pub fn dc_max(&self) -> &HDRFloat {
-        &self.dc_max
+/// BLA table as the perturbation kernels read it.
+///
+/// Implementors give access to the entries by index; the level search is
+/// shared.
+pub trait BlaSource {
+    /// Maximum |delta_c| for BLA validity checks.
+    fn dc_max(&self) -> &HDRFloat;
+
+    /// Index of the first entry of each level.
+    fn level_offsets(&self) -> &[usize];
+
+    /// Number of entries over all levels.
+    fn entry_count(&self) -> usize;
+
+    /// Entry at `index`, below `entry_count()`.
+    fn entry(&self, index: usize) -> BlaEntry;
+
+    /// Index of the entry of `level` that starts at reference index `m`, if
+    /// the level has one.
+    fn entry_index(&self, level: usize, m: usize) -> Option<usize> {
+        // Only use higher-level BLA when m is aligned to the skip size.
+        // Level n entry i was built from orbit points Z_{i*2^n} to Z_{i*2^n + 2^n - 1}.
+        // These coefficients are only valid when applied starting at m = i * 2^n.
+        // If m is not aligned, the BLA would use wrong orbit points.
+        let skip_size = 1usize << level;
+        if !m.is_multiple_of(skip_size) {
+            return None;
+        }
+        let offsets = self.level_offsets();
+        let entry_idx = offsets[level] + m / skip_size;
+        let level_end = offsets
+            .get(level + 1)
+            .copied()
+            .unwrap_or_else(|| self.entry_count());
+        (entry_idx < level_end).then_some(entry_idx)
     }
 
     /// Find the largest valid BLA at reference index `m` for current |δz|².
@@ -216,13 +323,8 @@ impl BlaTable {
     ///
     /// Uses HDRFloat for dz_mag_sq to prevent f64 underflow at deep zoom levels
     /// where |δz|² can be as small as 10^-1800.
-    pub fn find_valid(
-        &self,
-        m: usize,
-        dz_mag_sq: &HDRFloat,
-        dc_max: &HDRFloat,
-    ) -> Option<&BlaEntry> {
-        if self.entries.is_empty() {
+    fn find_valid(&self, m: usize, dz_mag_sq: &HDRFloat, dc_max: &HDRFloat) -> Option<BlaEntry> {
+        if self.entry_count() == 0 {
             return None;
         }
 
@@ -233,34 +335,11 @@ impl BlaTable {
         let max_b_dc_exp = 0;
 
         // Search from highest level (largest skips) down to level 0
-        for level in (0..=self.num_levels.saturating_sub(1)).rev() {
-            let level_start = self.level_offsets[level];
-            let skip_size = 1usize << level; // 2^level iterations per entry at this level
-
-            // BUG FIX: Only use higher-level BLA when m is aligned to skip_size.
-            // Level n entry i was built from orbit points Z_{i*2^n} to Z_{i*2^n + 2^n - 1}.
-            // These coefficients are only valid when applied starting at m = i * 2^n.
-            // If m is not aligned, the BLA would use wrong orbit points.
-            if !m.is_multiple_of(skip_size) {
+        for level in (0..self.level_offsets().len()).rev() {
+            let Some(entry_idx) = self.entry_index(level, m) else {
                 continue;
-            }
-
-            // Index within this level for reference index m
-            let idx_in_level = m / skip_size;
-            let entry_idx = level_start + idx_in_level;
-
-            // Check bounds
-            let level_end = if level + 1 < self.level_offsets.len() {
-                self.level_offsets[level + 1]
-            } else {
-                self.entries.len()
             };
-
-            if entry_idx >= level_end {
-                continue;
-            }
-
-            let entry = &self.entries[entry_idx];
+            let entry = self.entry(entry_idx);
 
             // Check validity: |δz|² < r²
             // Using HDRFloat comparison: a < b iff (a - b).is_negative()
@@ -286,35 +365,18 @@ impl BlaTable {
     ///
     /// This is the f64-optimized version for moderate zoom levels where BLA coefficients
     /// fit in f64 range. Falls back gracefully when coefficients overflow.
-    pub fn find_valid_f64(&self, m: usize, dz_mag_sq: f64, dc_max: f64) -> Option<BlaEntryF64> {
-        if self.entries.is_empty() {
+    fn find_valid_f64(&self, m: usize, dz_mag_sq: f64, dc_max: f64) -> Option<BlaEntryF64> {
+        if self.entry_count() == 0 {
             return None;
         }
 
         let max_b_dc_exp = 0;
 
-        for level in (0..=self.num_levels.saturating_sub(1)).rev() {
-            let level_start = self.level_offsets[level];
-            let skip_size = 1usize << level;
-
-            if !m.is_multiple_of(skip_size) {
+        for level in (0..self.level_offsets().len()).rev() {
+            let Some(entry_idx) = self.entry_index(level, m) else {
                 continue;
-            }
-
-            let idx_in_level = m / skip_size;
-            let entry_idx = level_start + idx_in_level;
-
-            let level_end = if level + 1 < self.level_offsets.len() {
-                self.level_offsets[level + 1]
-            } else {
-                self.entries.len()
             };
-
-            if entry_idx >= level_end {
-                continue;
-            }
-
-            let entry = &self.entries[entry_idx];
+            let entry = self.entry(entry_idx);
 
             // Convert r_sq to f64 for comparison
             let r_sq_f64 = entry.r_sq.to_f64();
@@ -336,7 +398,7 @@ impl BlaTable {
             }
 
             // Try to convert to f64 - returns None if overflow
-            if let Some(f64_entry) = BlaEntryF64::try_from_hdr(entry) {
+            if let Some(f64_entry) = BlaEntryF64::try_from_hdr(&entry) {
                 return Some(f64_entry);
             }
             // If conversion failed, try lower level
@@ -346,6 +408,94 @@ impl BlaTable {
     }
 }
 
+impl BlaSource for BlaTable {
+    fn dc_max(&self) -> &HDRFloat {
+        &self.dc_max
+    }
+
+    fn level_offsets(&self) -> &[usize] {
+        &self.level_offsets[..self.num_levels]
+    }
+
+    #[inline]
+    fn entry_count(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    fn entry(&self, index: usize) -> BlaEntry {
+        self.entries[index]
+    }
+}
+
+/// Entries a `BlaWindow` reads from storage outside plain memory, one block
+/// at a time.
+pub trait BlaBlocks {
+    /// Maximum |delta_c| the table was built for.
+    fn dc_max(&self) -> &HDRFloat;
+
+    /// Index of the first entry of each level.
+    fn level_offsets(&self) -> &[usize];
+
+    /// Number of entries over all levels.
+    fn entry_count(&self) -> usize;
+
+    /// Fill `out` with the entries from `start` on. The entries asked for
+    /// are always within the table.
+    fn read_entries(&self, start: usize, out: &mut [BlaEntry]);
+}
+
+/// Entries per block read by a `BlaWindow`.
+const BLA_BLOCK_LEN: usize = 256;
+
+/// Blocks a `BlaWindow` keeps. A lookup touches one block per level below
+/// the few top levels that share a block, for each SIMD lane.
+const BLA_WINDOW_BLOCKS: usize = 128;
+
+/// `BlaSource` over `BlaBlocks` storage that keeps the `BLA_WINDOW_BLOCKS`
+/// most recently read blocks of entries.
+pub struct BlaWindow<'a> {
+    blocks: &'a dyn BlaBlocks,
+    window: BlockWindow<BlaEntry>,
+}
+
+impl<'a> BlaWindow<'a> {
+    pub fn new(blocks: &'a dyn BlaBlocks) -> Self {
+        Self {
+            blocks,
+            window: BlockWindow::new(blocks.entry_count(), BLA_BLOCK_LEN, BLA_WINDOW_BLOCKS),
+        }
+    }
+}
+
+impl BlaSource for BlaWindow<'_> {
+    fn dc_max(&self) -> &HDRFloat {
+        self.blocks.dc_max()
+    }
+
+    fn level_offsets(&self) -> &[usize] {
+        self.blocks.level_offsets()
+    }
+
+    #[inline]
+    fn entry_count(&self) -> usize {
+        self.window.len()
+    }
+
+    #[inline]
+    fn entry(&self, index: usize) -> BlaEntry {
+        self.window.get(index, |start, entries| {
+            self.blocks.read_entries(start, entries)
+        })
+    }
+}
+
+/// Number of orbit points a BLA may step from. A periodic orbit rebases at
+/// its closing point, so no approximation may start there or skip past it.
+fn steppable_len(len: usize, period: Option<u32>) -> usize {
+    period.map_or(len, |p| (p as usize).min(len))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +522,19 @@ mod tests {
         assert!((entry.r_sq.to_f64() - expected_r_sq).abs() < 1e-40);
     }
 
+    #[test]
+    fn bla_entry_word_encoding_roundtrip() {
+        let x = BlaEntry::from_orbit_point(1e-200, -3.5);
+        let y = BlaEntry::from_orbit_point(-0.25, 0.75);
+        let merged = BlaEntry::merge(&x, &y, &HDRFloat::from_f64(1e-30));
+
+        let decoded = BlaEntry::from_words(&merged.to_words());
+        assert_eq!(decoded.l, merged.l);
+        assert_eq!(decoded.a, merged.a);
+        assert_eq!(decoded.b, merged.b);
+        assert_eq!(decoded.r_sq, merged.r_sq);
+    }
+
     #[test]
     fn bla_entry_merge_two_single_iterations() {
         // Two single-iteration BLAs should merge into one that skips 2
@@ -0,0 +1,71 @@
+//! Cache of the most recently read blocks of a large array.
+//!
+//! Shared by the readers that let the kernels index orbits and BLA tables
+//! held outside plain memory, such as compressed or in a SharedArrayBuffer.
+
+use std::cell::RefCell;
+
+/// The `capacity` most recently used blocks of `block_len` consecutive items
+/// of an array `len` items long.
+pub(crate) struct BlockWindow<T> {
+    len: usize,
+    block_len: usize,
+    capacity: usize,
+    /// Blocks as (first index, items), most recently used first
+    blocks: RefCell<Vec<(usize, Vec<T>)>>,
+}
+
+impl<T: Copy + Default> BlockWindow<T> {
+    pub(crate) fn new(len: usize, block_len: usize, capacity: usize) -> Self {
+        Self {
+            len,
+            block_len,
+            capacity,
+            blocks: RefCell::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Length of the whole array.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Item `n`, filling its block with `read(first index, block)` if the
+    /// window does not hold it.
+    ///
+    /// # Panics
+    /// If `n` is not below the array length.
+    #[inline]
+    pub(crate) fn get(&self, n: usize, read: impl FnOnce(usize, &mut [T])) -> T {
+        if let Some((start, items)) = self.blocks.borrow().first() {
+            if let Some(item) = items.get(n.wrapping_sub(*start)) {
+                return *item;
+            }
+        }
+        self.get_slow(n, read)
+    }
+
+    /// Look `n` up in the blocks behind the most recent one, reading its
+    /// block in place of the least recently used if none holds it.
+    #[cold]
+    fn get_slow(&self, n: usize, read: impl FnOnce(usize, &mut [T])) -> T {
+        assert!(n < self.len, "index {} out of range", n);
+        let mut blocks = self.blocks.borrow_mut();
+        let start = n - n % self.block_len;
+        match blocks.iter().position(|(first, _)| *first == start) {
+            Some(index) => blocks[..=index].rotate_right(1),
+            None => {
+                let mut items = if blocks.len() < self.capacity {
+                    Vec::new()
+                } else {
+                    blocks.pop().map(|(_, items)| items).unwrap_or_default()
+                };
+                items.resize(self.block_len.min(self.len - start), T::default());
+                read(start, &mut items);
+                blocks.insert(0, (start, items));
+            }
+        }
+        blocks[0].1[n - start]
+    }
+}
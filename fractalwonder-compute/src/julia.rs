@@ -0,0 +1,178 @@
+//! Direct f64 iteration of Julia sets z ↦ z² + c for a fixed c.
+//!
+//! A Julia set lies within |z| ≤ 2 whatever the zoom depth of the c it was
+//! picked at, so showing the whole set needs no reference orbit: plain f64
+//! per pixel is exact enough and fast enough to run on the main thread.
+
+use crate::perturbation::{compute_surface_normal_direction, distance_estimate_log2, OrbitStats};
+use fractalwonder_core::{ComputeData, MandelbrotData};
+use std::ops::Range;
+
+/// Width of the Julia plane shown by `JuliaView::whole_set`.
+pub const JULIA_VIEW_WIDTH: f64 = 4.0;
+
+/// Region of the Julia plane mapped onto a pixel grid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JuliaView {
+    pub center: (f64, f64),
+    /// Width in the Julia plane; the height follows the aspect ratio
+    pub width: f64,
+    pub size: (u32, u32),
+}
+
+impl JuliaView {
+    /// The whole set, fitted so the shorter side spans `JULIA_VIEW_WIDTH`.
+    pub fn whole_set(size: (u32, u32)) -> Self {
+        let aspect = size.0.max(1) as f64 / size.1.max(1) as f64;
+        Self {
+            center: (0.0, 0.0),
+            width: JULIA_VIEW_WIDTH * aspect.max(1.0),
+            size,
+        }
+    }
+
+    pub fn height(&self) -> f64 {
+        self.width * self.size.1.max(1) as f64 / self.size.0.max(1) as f64
+    }
+
+    /// Starting point z_0 of a pixel, laid out like `pixel_to_fractal`.
+    pub fn pixel_to_z(&self, x: f64, y: f64) -> (f64, f64) {
+        let norm_x = x / self.size.0.max(1) as f64 - 0.5;
+        let norm_y = y / self.size.1.max(1) as f64 - 0.5;
+        (
+            self.center.0 + norm_x * self.width,
+            self.center.1 + norm_y * self.height(),
+        )
+    }
+}
+
+/// Iterate z ↦ z² + c from `z0`.
+///
+/// The derivative is taken with respect to z_0, which plays the part dz/dc
+/// plays for the Mandelbrot set in the distance estimate and surface normal.
+pub fn compute_julia_pixel(z0: (f64, f64), c: (f64, f64), max_iterations: u32) -> MandelbrotData {
+    let (mut z_re, mut z_im) = z0;
+    let (mut der_re, mut der_im) = (1.0, 0.0);
+    let mut stats = OrbitStats::new();
+
+    for n in 0..max_iterations {
+        let z_norm_sq = z_re * z_re + z_im * z_im;
+        if z_norm_sq > 65536.0 {
+            let (sn_re, sn_im) = compute_surface_normal_direction(z_re, z_im, der_re, der_im);
+            let der_norm_log2 = 0.5 * (der_re * der_re + der_im * der_im).log2();
+            return stats.apply(
+                MandelbrotData::new(
+                    n,
+                    max_iterations,
+                    true,
+                    false,
+                    z_norm_sq as f32,
+                    sn_re,
+                    sn_im,
+                ),
+                distance_estimate_log2(z_norm_sq, der_norm_log2),
+            );
+        }
+        if n > 0 {
+            stats.record(n, z_re, z_im, z_norm_sq);
+        }
+
+        // der' = 2·z·der, z' = z² + c
+        let new_der_re = 2.0 * (z_re * der_re - z_im * der_im);
+        der_im = 2.0 * (z_re * der_im + z_im * der_re);
+        der_re = new_der_re;
+        let new_z_re = z_re * z_re - z_im * z_im + c.0;
+        z_im = 2.0 * z_re * z_im + c.1;
+        z_re = new_z_re;
+    }
+
+    let final_z_norm_sq = (z_re * z_re + z_im * z_im) as f32;
+    stats
+        .apply(
+            MandelbrotData {
+                iterations: max_iterations,
+                max_iterations,
+                ..MandelbrotData::default()
+            },
+            0.0,
+        )
+        .with_interior(final_z_norm_sq, 0, 0.0)
+}
+
+/// Compute rows `rows` of the Julia set for `c` in `view`, row-major.
+pub fn render_julia_rows(
+    c: (f64, f64),
+    view: &JuliaView,
+    rows: Range<u32>,
+    max_iterations: u32,
+) -> Vec<ComputeData> {
+    let width = view.size.0;
+    let mut data = Vec::with_capacity(rows.len() * width as usize);
+    for y in rows {
+        for x in 0..width {
+            let z0 = view.pixel_to_z(x as f64, y as f64);
+            data.push(ComputeData::Mandelbrot(compute_julia_pixel(
+                z0,
+                c,
+                max_iterations,
+            )));
+        }
+    }
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_zero_gives_unit_disk() {
+        let inside = compute_julia_pixel((0.5, 0.5), (0.0, 0.0), 200);
+        assert!(!inside.escaped);
+        assert_eq!(inside.iterations, 200);
+
+        let outside = compute_julia_pixel((1.1, 0.0), (0.0, 0.0), 200);
+        assert!(outside.escaped);
+        // 1.1^(2^n) > 256 first at n = 6
+        assert_eq!(outside.iterations, 6);
+    }
+
+    #[test]
+    fn escape_matches_mandelbrot_at_the_critical_point() {
+        // z_0 = 0 runs the Mandelbrot orbit of c
+        for c in [(0.3, 0.5), (-0.75, 0.1), (0.26, 0.0)] {
+            let julia = compute_julia_pixel((0.0, 0.0), c, 1000);
+            let (mut re, mut im) = (0.0f64, 0.0f64);
+            let mut n = 0;
+            while n < 1000 && re * re + im * im <= 65536.0 {
+                (re, im) = (re * re - im * im + c.0, 2.0 * re * im + c.1);
+                n += 1;
+            }
+            assert_eq!(julia.iterations, n, "c = {c:?}");
+        }
+    }
+
+    #[test]
+    fn whole_set_covers_the_escape_disk() {
+        let view = JuliaView::whole_set((200, 100));
+        assert_eq!(view.height(), 4.0);
+        assert_eq!(view.pixel_to_z(0.0, 0.0), (-4.0, -2.0));
+        assert_eq!(view.pixel_to_z(100.0, 50.0), (0.0, 0.0));
+
+        let tall = JuliaView::whole_set((100, 200));
+        assert_eq!((tall.width, tall.height()), (4.0, 8.0));
+    }
+
+    #[test]
+    fn rows_are_row_major() {
+        let view = JuliaView::whole_set((8, 6));
+        let c = (-0.4, 0.6);
+        let data = render_julia_rows(c, &view, 2..4, 100);
+        assert_eq!(data.len(), 16);
+        let ComputeData::Mandelbrot(pixel) = &data[8 + 5];
+        assert_eq!(
+            *pixel,
+            compute_julia_pixel(view.pixel_to_z(5.0, 3.0), c, 100)
+        );
+    }
+}
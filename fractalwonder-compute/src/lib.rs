@@ -1,10 +1,18 @@
 mod bla;
+mod block_window;
+mod julia;
 mod perturbation;
+pub mod shared_buffers;
 pub mod worker;
 
-pub use bla::{BlaEntry, BlaTable};
+pub use bla::{BlaBlocks, BlaEntry, BlaSource, BlaTable, BlaWindow};
+pub use julia::{compute_julia_pixel, render_julia_rows, JuliaView, JULIA_VIEW_WIDTH};
 pub use perturbation::{
     compute_pixel_perturbation, compute_pixel_perturbation_f64_bla,
-    compute_pixel_perturbation_hdr_bla, render_tile_f64, render_tile_hdr, BlaStats, ReferenceOrbit,
-    TileConfig, TileRenderResult, TileStats,
+    compute_pixel_perturbation_floatexp_bla, compute_pixel_perturbation_hdr_bla,
+    compute_pixel_perturbation_scaled_bla, compute_pixels_perturbation_f64_bla, render_tile,
+    render_tile_f64, render_tile_floatexp, render_tile_hdr, render_tile_scaled, resume_tile,
+    trace_orbit_path, BlaStats, CompressedOrbit, OrbitBlocks, OrbitChunk, OrbitPoint, OrbitPoints,
+    OrbitSource, OrbitWindow, ReferenceOrbit, ReferenceOrbitStream, TileConfig, TileInterrupt,
+    TileRenderResult, TileStats, Uninterrupted, ORBIT_COMPRESSION_TOLERANCE, SCALED_F64_MIN_LOG2,
 };
@@ -0,0 +1,264 @@
+//! Compressed storage for long reference orbits.
+//!
+//! A plain `ReferenceOrbit` costs 32 bytes per iteration, which adds up to
+//! gigabytes for orbits of tens of millions of iterations once every worker
+//! holds its own copy. `CompressedOrbit` uses two observations:
+//!
+//! - Between two points, iterating `Z² + c` and `2·Z·Der + 1` in f64 from the
+//!   earlier one reproduces the later one until rounding errors, doubling
+//!   with every step, reach the tolerance. Only the points where that
+//!   happens are kept as full-precision waypoints.
+//! - A reference sitting on a periodic point returns to (almost exactly) zero
+//!   after one period. Rebasing onto the start of the orbit there is exact, so
+//!   one period plus its closing point is all the kernels ever need.
+//!
+//! Points are regenerated on access, either sequentially through `iter` or
+//! individually through `get`, at a cost bounded by the waypoint spacing.
+
+use super::ReferenceOrbit;
+use fractalwonder_core::HDRFloat;
+
+/// Relative error allowed in regenerated points, ten bits above f64 rounding.
+pub const ORBIT_COMPRESSION_TOLERANCE: f64 = f64::EPSILON * 1024.0;
+
+/// How far, in bits, the closing point of a cycle must lie below the delta of
+/// the smallest pixel that is rebased onto it (roughly `dc_max · |Der_p|`
+/// scaled down to one pixel).
+const CYCLE_MARGIN_LOG2: f64 = 24.0;
+
+/// A reference orbit value Z_n with its derivative Der_n = dZ_n/dC.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct OrbitPoint {
+    pub z: (f64, f64),
+    pub der: (f64, f64),
+}
+
+impl OrbitPoint {
+    /// Advance one iteration in f64: Z' = Z² + c, Der' = 2·Z·Der + 1.
+    #[inline]
+    fn step(self, c: (f64, f64)) -> Self {
+        let (x, y) = self.z;
+        let (dx, dy) = self.der;
+        Self {
+            z: (x * x - y * y + c.0, 2.0 * x * y + c.1),
+            der: (2.0 * (x * dx - y * dy) + 1.0, 2.0 * (x * dy + y * dx)),
+        }
+    }
+
+    /// True if both values are within `tolerance` of `exact`, relative to its magnitude.
+    fn within(self, exact: Self, tolerance: f64) -> bool {
+        let close = |a: (f64, f64), b: (f64, f64)| {
+            let (err_re, err_im) = (a.0 - b.0, a.1 - b.1);
+            let err_sq = err_re * err_re + err_im * err_im;
+            err_sq <= tolerance * tolerance * (b.0 * b.0 + b.1 * b.1)
+        };
+        close(self.z, exact.z) && close(self.der, exact.der)
+    }
+}
+
+/// Full-precision point at which regeneration restarts.
+#[derive(Clone, Copy, Debug)]
+struct Waypoint {
+    iteration: u32,
+    point: OrbitPoint,
+}
+
+/// Reference orbit stored as sparse waypoints, and as a single period for
+/// periodic references.
+#[derive(Clone, Debug)]
+pub struct CompressedOrbit {
+    c_ref: (f64, f64),
+    len: u32,
+    period: Option<u32>,
+    escaped_at: Option<u32>,
+    /// Sorted by iteration; the first is always iteration 0
+    waypoints: Vec<Waypoint>,
+}
+
+impl CompressedOrbit {
+    /// Compress with `ORBIT_COMPRESSION_TOLERANCE`.
+    ///
+    /// `dc_max` is the largest |δc| that will be iterated against the orbit;
+    /// it decides whether a near-zero return is close enough to close a cycle.
+    pub fn compress(orbit: &ReferenceOrbit, dc_max: &HDRFloat) -> Self {
+        Self::with_tolerance(orbit, dc_max, ORBIT_COMPRESSION_TOLERANCE)
+    }
+
+    /// Compress, keeping every regenerated point within `tolerance` (relative)
+    /// of the original. A tolerance of zero is lossless.
+    pub fn with_tolerance(orbit: &ReferenceOrbit, dc_max: &HDRFloat, tolerance: f64) -> Self {
+        let point = |n: usize| OrbitPoint {
+            z: orbit.orbit[n],
+            der: orbit.derivative[n],
+        };
+
+        let period = match (orbit.period, orbit.escaped_at) {
+            (Some(period), _) => Some(period),
+            (None, None) => {
+                let dc_max_log2 = dc_max.log2();
+                (1..orbit.orbit.len())
+                    .find(|&n| closes_cycle(point(n), dc_max_log2))
+                    .map(|n| n as u32)
+            }
+            (None, Some(_)) => None,
+        };
+        let len = period.map_or(orbit.orbit.len(), |p| p as usize + 1);
+
+        let mut waypoints = Vec::new();
+        let mut current = OrbitPoint::default();
+        for n in 0..len {
+            let exact = point(n);
+            let predicted = current.step(orbit.c_ref);
+            if n > 0 && predicted.within(exact, tolerance) {
+                current = predicted;
+            } else {
+                waypoints.push(Waypoint {
+                    iteration: n as u32,
+                    point: exact,
+                });
+                current = exact;
+            }
+        }
+
+        Self {
+            c_ref: orbit.c_ref,
+            len: len as u32,
+            period,
+            escaped_at: orbit.escaped_at,
+            waypoints,
+        }
+    }
+
+    /// Reference point C as f64.
+    pub fn c_ref(&self) -> (f64, f64) {
+        self.c_ref
+    }
+
+    /// Number of stored orbit points (one period plus its closing point for
+    /// periodic orbits).
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Check if the orbit holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Period of the reference, if the orbit was folded onto one cycle.
+    pub fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    /// Iteration at which the reference escaped (None if never escaped).
+    pub fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    /// Number of full-precision points kept.
+    pub fn waypoint_count(&self) -> usize {
+        self.waypoints.len()
+    }
+
+    /// Approximate heap size, for comparing with the 32 bytes per point of
+    /// an uncompressed orbit.
+    pub fn memory_bytes(&self) -> usize {
+        self.waypoints.len() * std::mem::size_of::<Waypoint>()
+    }
+
+    /// Regenerate the point at iteration `n`.
+    ///
+    /// # Panics
+    /// If `n` is not below `len()`.
+    pub fn get(&self, n: usize) -> OrbitPoint {
+        assert!(n < self.len(), "orbit index {} out of range", n);
+        self.iter_from(n).next().unwrap_or_default()
+    }
+
+    /// Regenerate all points in order.
+    pub fn iter(&self) -> OrbitPoints<'_> {
+        OrbitPoints {
+            orbit: self,
+            n: 0,
+            next_waypoint: 0,
+            current: OrbitPoint::default(),
+        }
+    }
+
+    /// Regenerate points from iteration `start` on.
+    pub fn iter_from(&self, start: usize) -> OrbitPoints<'_> {
+        let next_waypoint = self
+            .waypoints
+            .partition_point(|w| w.iteration as usize <= start)
+            .saturating_sub(1);
+        let mut points = OrbitPoints {
+            orbit: self,
+            n: self
+                .waypoints
+                .get(next_waypoint)
+                .map_or(0, |w| w.iteration as usize),
+            next_waypoint,
+            current: OrbitPoint::default(),
+        };
+        let skip = start.min(self.len()) - points.n;
+        if skip > 0 {
+            points.nth(skip - 1);
+        }
+        points
+    }
+
+    /// Expand into a plain `ReferenceOrbit` for the perturbation kernels.
+    pub fn decompress(&self) -> ReferenceOrbit {
+        let (orbit, derivative) = self.iter().map(|p| (p.z, p.der)).unzip();
+        ReferenceOrbit {
+            c_ref: self.c_ref,
+            orbit,
+            derivative,
+            escaped_at: self.escaped_at,
+            period: self.period,
+        }
+    }
+}
+
+/// True if Z_n is negligible next to the smallest pixel delta at iteration n,
+/// so the orbit can restart from Z_0 = 0 without losing precision.
+fn closes_cycle(point: OrbitPoint, dc_max_log2: f64) -> bool {
+    let magnitude_log2 = |(re, im): (f64, f64)| re.abs().max(im.abs()).log2();
+    magnitude_log2(point.z) <= dc_max_log2 + magnitude_log2(point.der) - CYCLE_MARGIN_LOG2
+}
+
+/// Iterator regenerating a `CompressedOrbit` point by point.
+pub struct OrbitPoints<'a> {
+    orbit: &'a CompressedOrbit,
+    /// Iteration of the next point to yield
+    n: usize,
+    next_waypoint: usize,
+    current: OrbitPoint,
+}
+
+impl Iterator for OrbitPoints<'_> {
+    type Item = OrbitPoint;
+
+    #[inline]
+    fn next(&mut self) -> Option<OrbitPoint> {
+        if self.n >= self.orbit.len() {
+            return None;
+        }
+        match self.orbit.waypoints.get(self.next_waypoint) {
+            Some(waypoint) if waypoint.iteration as usize == self.n => {
+                self.current = waypoint.point;
+                self.next_waypoint += 1;
+            }
+            _ => self.current = self.current.step(self.orbit.c_ref),
+        }
+        self.n += 1;
+        Some(self.current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.orbit.len().saturating_sub(self.n);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for OrbitPoints<'_> {}
@@ -3,18 +3,42 @@
 //! Computes reference orbits at high precision, then uses fast f64
 //! delta iterations for individual pixels.
 
+mod compressed_orbit;
+mod orbit_path;
+mod orbit_window;
 mod pixel;
 mod pixel_f64_bla;
+mod pixel_f64_bla_simd;
+mod pixel_floatexp_bla;
 mod pixel_hdr_bla;
+mod pixel_scaled_bla;
 mod reference_orbit;
+mod simd;
 mod tile;
 
-pub use tile::{render_tile_f64, render_tile_hdr, TileConfig, TileRenderResult, TileStats};
+pub use tile::{
+    render_tile, render_tile_f64, render_tile_floatexp, render_tile_hdr, render_tile_scaled,
+    resume_tile, TileConfig, TileInterrupt, TileRenderResult, TileStats, Uninterrupted,
+    SCALED_F64_MIN_LOG2,
+};
 
+pub use compressed_orbit::{CompressedOrbit, OrbitPoint, OrbitPoints, ORBIT_COMPRESSION_TOLERANCE};
+pub use orbit_path::trace_orbit_path;
+pub use orbit_window::{OrbitBlocks, OrbitSource, OrbitWindow};
 pub use pixel::compute_pixel_perturbation;
+pub(crate) use pixel::continue_pixel;
 pub use pixel_f64_bla::compute_pixel_perturbation_f64_bla;
+pub use pixel_f64_bla_simd::compute_pixels_perturbation_f64_bla;
+pub(crate) use pixel_f64_bla_simd::{continue_pixels_f64_bla, LanePixel, LaneSink};
+pub use pixel_floatexp_bla::compute_pixel_perturbation_floatexp_bla;
+pub(crate) use pixel_floatexp_bla::continue_pixel_floatexp_bla;
+pub(crate) use pixel_hdr_bla::continue_pixel_hdr_bla;
 pub use pixel_hdr_bla::{compute_pixel_perturbation_hdr_bla, BlaStats};
-pub use reference_orbit::ReferenceOrbit;
+pub use pixel_scaled_bla::compute_pixel_perturbation_scaled_bla;
+pub(crate) use pixel_scaled_bla::{continue_pixel_scaled_bla, ScaledPixelState};
+pub use reference_orbit::{OrbitChunk, ReferenceOrbit, ReferenceOrbitStream};
+
+use fractalwonder_core::{ComplexDelta, MandelbrotData, STRIPE_DENSITY};
 
 /// Compute normalized z/ρ direction for 3D lighting.
 /// Returns (re, im) of the unit vector, or (0, 0) if degenerate.
@@ -45,5 +69,297 @@ pub(crate) fn compute_surface_normal_direction(
     ((u_re / u_norm) as f32, (u_im / u_norm) as f32)
 }
 
+/// Running orbit statistics recorded for the layered colorizers.
+///
+/// Sampled once per iteration after z_0 (after the rebase check). Only the
+/// generic kernel records them: BLA would skip iterations, so tiles collecting
+/// stats render without it (see `TileConfig::collect_orbit_stats`).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct OrbitStats {
+    trap_min_sq: f64,
+    trap_iteration: u32,
+    stripe_sum: f64,
+    samples: u32,
+}
+
+impl OrbitStats {
+    pub(crate) fn new() -> Self {
+        Self {
+            trap_min_sq: f64::INFINITY,
+            trap_iteration: 0,
+            stripe_sum: 0.0,
+            samples: 0,
+        }
+    }
+
+    /// Record the full orbit value z at iteration n.
+    #[inline]
+    pub(crate) fn record(&mut self, n: u32, z_re: f64, z_im: f64, z_norm_sq: f64) {
+        if z_norm_sq < self.trap_min_sq {
+            self.trap_min_sq = z_norm_sq;
+            self.trap_iteration = n;
+        }
+        let angle = z_im.atan2(z_re);
+        self.stripe_sum += 0.5 * (STRIPE_DENSITY * angle).sin() + 0.5;
+        self.samples += 1;
+    }
+
+    /// Attach the recorded statistics to pixel data.
+    pub(crate) fn apply(&self, data: MandelbrotData, distance_log2: f32) -> MandelbrotData {
+        let orbit_trap = if self.trap_min_sq.is_finite() {
+            self.trap_min_sq.sqrt() as f32
+        } else {
+            0.0
+        };
+        let stripe_avg = if self.samples > 0 {
+            (self.stripe_sum / self.samples as f64) as f32
+        } else {
+            0.0
+        };
+        MandelbrotData {
+            atom_domain: self.trap_iteration,
+            ..data.with_orbit_stats(orbit_trap, stripe_avg, distance_log2)
+        }
+    }
+}
+
+/// Where the iteration of one pixel stands, so that a kernel can stop short
+/// of max_iterations and a later call continue bit for bit where it left off.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PixelState<Z> {
+    /// Perturbation δz of the current iteration
+    pub dz: Z,
+    /// Derivative perturbation δρ
+    pub drho: Z,
+    /// Index into the reference orbit; differs from `n` after a rebase
+    pub m: usize,
+    /// Iterations done
+    pub n: u32,
+    pub glitched: bool,
+    /// Iterations and rebases so far, including any interior probe
+    pub stats: BlaStats,
+    pub orbit_stats: OrbitStats,
+}
+
+impl<Z: Clone> PixelState<Z> {
+    /// A pixel that has not started iterating.
+    pub(crate) fn new(zero: Z) -> Self {
+        Self {
+            dz: zero.clone(),
+            drho: zero,
+            m: 0,
+            n: 0,
+            glitched: false,
+            stats: BlaStats::default(),
+            orbit_stats: OrbitStats::new(),
+        }
+    }
+}
+
+impl<Z> PixelState<Z> {
+    /// Stand-in data for a pixel that has not finished: not escaped so far.
+    pub(crate) fn unfinished(&self, max_iterations: u32) -> MandelbrotData {
+        MandelbrotData {
+            iterations: self.n.min(max_iterations),
+            max_iterations,
+            escaped: false,
+            glitched: self.glitched,
+            ..MandelbrotData::default()
+        }
+    }
+}
+
+/// How far one call of a pixel kernel iterates.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PixelRun {
+    pub max_iterations: u32,
+    /// The kernel returns without a result once the pixel has done this many
+    /// iterations, keeping its state. At most `max_iterations`.
+    pub stop_at: u32,
+    /// Glitch detection threshold squared (τ²)
+    pub tau_sq: f64,
+    pub options: PixelOptions,
+}
+
+impl PixelRun {
+    /// Iterate until the pixel escapes or reaches max_iterations.
+    pub(crate) fn to_end(max_iterations: u32, tau_sq: f64, options: PixelOptions) -> Self {
+        Self {
+            max_iterations,
+            stop_at: max_iterations,
+            tau_sq,
+            options,
+        }
+    }
+
+    /// The same run, stopping at `stop_at` iterations or max_iterations.
+    pub(crate) fn until(self, stop_at: u32) -> Self {
+        Self {
+            stop_at: stop_at.min(self.max_iterations),
+            ..self
+        }
+    }
+}
+
+/// Data for every pixel when the reference orbit is empty.
+pub(crate) fn degenerate_orbit_data(max_iterations: u32) -> MandelbrotData {
+    MandelbrotData {
+        iterations: 0,
+        max_iterations,
+        escaped: false,
+        glitched: true,
+        ..MandelbrotData::default()
+    }
+}
+
+/// log₂ of the exterior distance estimate |z|·ln|z| / |ρ| at escape.
+///
+/// Takes log₂|ρ| rather than ρ so the estimate stays finite when the
+/// derivative exceeds f64 range at deep zoom.
+#[inline]
+pub(crate) fn distance_estimate_log2(z_norm_sq: f64, rho_norm_log2: f64) -> f32 {
+    let ln_z = 0.5 * z_norm_sq.ln();
+    if ln_z <= 0.0 || !rho_norm_log2.is_finite() {
+        return 0.0;
+    }
+    (0.5 * z_norm_sq.log2() + ln_z.log2() - rho_norm_log2) as f32
+}
+
+/// Longest cycle the interior probe searches for, as a fraction of max_iterations.
+/// A cycle needs several turns within the budget to converge, so longer periods
+/// would rarely be found and only cost time.
+const PERIOD_PROBE_DIVISOR: u32 = 4;
+
+/// Relative tolerance (squared) for the orbit returning to its starting point.
+const PERIOD_TOLERANCE_SQ: f64 = 1e-16;
+
+/// Optional per-pixel work a tile asks of the kernels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct PixelOptions {
+    /// Record orbit trap and stripe statistics (generic kernel only).
+    pub collect_orbit_stats: bool,
+    /// Probe pixels that reach max_iterations for their attracting cycle.
+    pub probe_interior: bool,
+}
+
+impl PixelOptions {
+    /// Everything on, as used by the public per-pixel kernels.
+    pub(crate) const ALL: Self = Self {
+        collect_orbit_stats: true,
+        probe_interior: true,
+    };
+
+    /// Finish a pixel that reached max_iterations without escaping, running
+    /// the interior probe from (m, δz) if asked to. The probe's iterations
+    /// are added to `stats`.
+    pub(crate) fn finish_interior<D: ComplexDelta>(
+        self,
+        orbit: &impl OrbitSource,
+        delta_c: &D,
+        dz: D,
+        m: usize,
+        data: MandelbrotData,
+        stats: &mut BlaStats,
+    ) -> MandelbrotData {
+        if !self.probe_interior {
+            return data;
+        }
+        let interior = probe_interior(orbit, delta_c, dz, m, data.max_iterations);
+        stats.total_iterations += interior.iterations;
+        interior.apply(data)
+    }
+}
+
+/// Interior data for a non-escaped pixel: |z|² after max_iterations, the period
+/// of its attracting cycle and the interior distance estimate.
+pub(crate) struct InteriorProbe {
+    pub final_z_norm_sq: f64,
+    pub period: u32,
+    pub distance_log2: f32,
+    /// Iterations the probe ran
+    pub iterations: u32,
+}
+
+impl InteriorProbe {
+    pub(crate) fn apply(&self, data: MandelbrotData) -> MandelbrotData {
+        data.with_interior(self.final_z_norm_sq as f32, self.period, self.distance_log2)
+    }
+}
+
+/// Continue a non-escaped orbit from (m, δz) without BLA until it returns to its
+/// starting point, giving the period of the attracting cycle.
+///
+/// Derivatives over one turn of the cycle give the interior distance estimate
+/// (1 - |∂z|²) / |∂c∂z + ∂z∂z·∂c / (1 - ∂z)|, evaluated as a logarithm because
+/// ∂c grows with the zoom depth.
+pub(crate) fn probe_interior<D: ComplexDelta>(
+    orbit: &impl OrbitSource,
+    delta_c: &D,
+    mut dz: D,
+    mut m: usize,
+    max_iterations: u32,
+) -> InteriorProbe {
+    let orbit_len = orbit.len();
+    let full_z = |m: usize, dz: &D| {
+        let (re, im) = orbit.point(m % orbit_len).z;
+        D::from_f64_pair(re, im).add(dz)
+    };
+
+    let start = full_z(m, &dz);
+    let start_norm_sq = start.norm_sq();
+    let mut probe = InteriorProbe {
+        final_z_norm_sq: start_norm_sq,
+        period: 0,
+        distance_log2: 0.0,
+        iterations: 0,
+    };
+    let tolerance_sq = PERIOD_TOLERANCE_SQ * start_norm_sq;
+
+    let one = delta_c.zero().add(&D::from_f64_pair(1.0, 0.0));
+    let mut d_z = one.clone();
+    let mut d_c = delta_c.zero();
+    let mut d_zz = delta_c.zero();
+    let mut d_cz = delta_c.zero();
+    let mut z = start.clone();
+
+    for k in 1..=(max_iterations / PERIOD_PROBE_DIVISOR) {
+        probe.iterations = k;
+        // Derivatives of z ↦ z² + c, using the values from before this step
+        let two_z = z.scale(2.0);
+        d_cz = d_z.mul(&d_c).add(&z.mul(&d_cz)).scale(2.0);
+        d_zz = d_z.square().add(&z.mul(&d_zz)).scale(2.0);
+        d_c = two_z.mul(&d_c).add(&one);
+        d_z = two_z.mul(&d_z);
+
+        // Perturbation step with rebasing, as in the main loop
+        if z.norm_sq() < dz.norm_sq() || orbit.closes_cycle_at(m) {
+            dz = z.clone();
+            m = 0;
+        }
+        if orbit.escaped_at().is_some() && m + 1 >= orbit_len {
+            break;
+        }
+        let (z_m_re, z_m_im) = orbit.point(m % orbit_len).z;
+        let z_m = D::from_f64_pair(z_m_re, z_m_im);
+        dz = z_m.mul(&dz).scale(2.0).add(&dz.square()).add(delta_c);
+        m += 1;
+        z = full_z(m, &dz);
+
+        if z.sub(&start).norm_sq() <= tolerance_sq {
+            probe.period = k;
+            let multiplier_sq = d_z.norm_sq();
+            if multiplier_sq < 1.0 {
+                let one_minus_d_z = one.sub(&d_z);
+                let denominator = d_cz.mul(&one_minus_d_z).add(&d_zz.mul(&d_c));
+                probe.distance_log2 = ((1.0 - multiplier_sq).log2() + one_minus_d_z.norm_log2()
+                    - denominator.norm_log2()) as f32;
+            }
+            break;
+        }
+    }
+
+    probe
+}
+
 #[cfg(test)]
 mod tests;
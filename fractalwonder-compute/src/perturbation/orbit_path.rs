@@ -0,0 +1,75 @@
+//! Iteration path of one pixel for the orbit overlay.
+
+use super::{compute_pixel_perturbation, OrbitSource};
+use fractalwonder_core::{ComplexDelta, HDRComplex, HDRFloat, OrbitPath, OrbitPathPoint};
+
+/// Trace z_0, z_1, … of the pixel at `delta_c` from the reference point.
+///
+/// Steps exactly like `compute_pixel_perturbation`, without BLA, including
+/// rebasing, so the path shows what the tile kernels see. Records at most
+/// `max_points` iterations; the result data covers the whole run.
+pub fn trace_orbit_path(
+    orbit: &impl OrbitSource,
+    delta_c: HDRComplex,
+    max_iterations: u32,
+    tau_sq: f64,
+    max_points: usize,
+) -> OrbitPath {
+    let mut path = OrbitPath {
+        c_ref: orbit.c_ref(),
+        data: compute_pixel_perturbation(orbit, delta_c, max_iterations, tau_sq),
+        ..OrbitPath::default()
+    };
+    let orbit_len = orbit.len();
+    if orbit_len == 0 {
+        return path;
+    }
+
+    let mut dz = HDRComplex::ZERO;
+    let mut m: usize = 0;
+    let mut n: u32 = 0;
+
+    while n < max_iterations && path.points.len() < max_points {
+        let z_m = orbit.point(m % orbit_len).z;
+        let z_m_complex = HDRComplex::from_f64_pair(z_m.0, z_m.1);
+        let z = z_m_complex.add(&dz);
+        let z_norm_sq = z.norm_sq();
+        let escaped = z_norm_sq > 65536.0;
+
+        // Rebase check, forced at the point closing a periodic orbit
+        if !escaped && (z_norm_sq < dz.norm_sq() || orbit.closes_cycle_at(m)) {
+            path.rebases += 1;
+            dz = z;
+            m = 0;
+            continue;
+        }
+
+        // z_n − C_ref, keeping the part of δz that rounds away next to Z_m
+        let offset = (
+            HDRFloat::from_f64(z_m.0 - orbit.c_ref().0).add(&dz.re),
+            HDRFloat::from_f64(z_m.1 - orbit.c_ref().1).add(&dz.im),
+        );
+        path.points.push(OrbitPathPoint {
+            z: z.to_f64_pair(),
+            offset,
+            reference_index: m as u32,
+        });
+        if escaped {
+            break;
+        }
+
+        // Delta iteration: δz' = 2·Z_m·δz + δz² + δc
+        dz = z_m_complex
+            .mul(&dz)
+            .scale(2.0)
+            .add(&dz.square())
+            .add(&delta_c);
+        m += 1;
+        n += 1;
+    }
+
+    path.reference = (0..path.points.len().min(orbit_len))
+        .map(|n| orbit.point(n).z)
+        .collect();
+    path
+}
@@ -0,0 +1,198 @@
+//! Read access to a reference orbit for the perturbation kernels.
+//!
+//! The kernels read Z_m and Der_m one iteration at a time through
+//! `OrbitSource`. A plain `ReferenceOrbit` answers from its vectors.
+//! `OrbitWindow` reads any `OrbitBlocks` storage, such as a
+//! `CompressedOrbit`, a block of consecutive points at a time and keeps only
+//! the last few blocks, so a worker never holds the whole orbit in plain form.
+
+use super::{CompressedOrbit, OrbitPoint, ReferenceOrbit};
+use crate::block_window::BlockWindow;
+
+/// Points per block read by an `OrbitWindow`.
+const BLOCK_LEN: usize = 4096;
+
+/// Blocks an `OrbitWindow` keeps: one per SIMD lane, the start of the orbit
+/// that every rebase returns to, and a spare.
+const WINDOW_BLOCKS: usize = 4;
+
+/// Reference orbit as the perturbation kernels read it.
+pub trait OrbitSource {
+    /// Reference point C as f64.
+    fn c_ref(&self) -> (f64, f64);
+
+    /// Number of points.
+    fn len(&self) -> usize;
+
+    /// Check if the orbit holds no points.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iteration at which the reference escaped (None if never escaped).
+    fn escaped_at(&self) -> Option<u32>;
+
+    /// Period of the cycle the orbit is folded onto, if any.
+    fn period(&self) -> Option<u32>;
+
+    /// Z_n and Der_n.
+    ///
+    /// # Panics
+    /// If `n` is not below `len()`.
+    fn point(&self, n: usize) -> OrbitPoint;
+
+    /// True at the closing point of a periodic orbit, where iteration has to
+    /// continue from Z_0 by rebasing.
+    #[inline]
+    fn closes_cycle_at(&self, m: usize) -> bool {
+        self.period() == Some(m as u32)
+    }
+}
+
+impl OrbitSource for ReferenceOrbit {
+    fn c_ref(&self) -> (f64, f64) {
+        self.c_ref
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.orbit.len()
+    }
+
+    fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    #[inline]
+    fn point(&self, n: usize) -> OrbitPoint {
+        OrbitPoint {
+            z: self.orbit[n],
+            der: self.derivative[n],
+        }
+    }
+}
+
+/// Orbit storage an `OrbitWindow` reads a block of points at a time.
+pub trait OrbitBlocks {
+    /// Reference point C as f64.
+    fn c_ref(&self) -> (f64, f64);
+
+    /// Number of points.
+    fn len(&self) -> usize;
+
+    /// Check if the orbit holds no points.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iteration at which the reference escaped (None if never escaped).
+    fn escaped_at(&self) -> Option<u32>;
+
+    /// Period of the cycle the orbit is folded onto, if any.
+    fn period(&self) -> Option<u32>;
+
+    /// Fill `out` with the points from iteration `start` on. The points
+    /// asked for are always within the orbit.
+    fn read_points(&self, start: usize, out: &mut [OrbitPoint]);
+}
+
+impl OrbitBlocks for ReferenceOrbit {
+    fn c_ref(&self) -> (f64, f64) {
+        self.c_ref
+    }
+
+    fn len(&self) -> usize {
+        self.orbit.len()
+    }
+
+    fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    fn read_points(&self, start: usize, out: &mut [OrbitPoint]) {
+        for (n, point) in (start..).zip(out) {
+            *point = OrbitSource::point(self, n);
+        }
+    }
+}
+
+impl OrbitBlocks for CompressedOrbit {
+    fn c_ref(&self) -> (f64, f64) {
+        CompressedOrbit::c_ref(self)
+    }
+
+    fn len(&self) -> usize {
+        CompressedOrbit::len(self)
+    }
+
+    fn escaped_at(&self) -> Option<u32> {
+        CompressedOrbit::escaped_at(self)
+    }
+
+    fn period(&self) -> Option<u32> {
+        CompressedOrbit::period(self)
+    }
+
+    /// Regenerates the block from the waypoint before it, at a cost bounded
+    /// by the block length plus the waypoint spacing.
+    fn read_points(&self, start: usize, out: &mut [OrbitPoint]) {
+        for (point, regenerated) in out.iter_mut().zip(self.iter_from(start)) {
+            *point = regenerated;
+        }
+    }
+}
+
+/// Cursor over `OrbitBlocks` storage that keeps the `WINDOW_BLOCKS` most
+/// recently read blocks of plain points.
+pub struct OrbitWindow<'a> {
+    blocks: &'a dyn OrbitBlocks,
+    c_ref: (f64, f64),
+    escaped_at: Option<u32>,
+    period: Option<u32>,
+    window: BlockWindow<OrbitPoint>,
+}
+
+impl<'a> OrbitWindow<'a> {
+    pub fn new(blocks: &'a dyn OrbitBlocks) -> Self {
+        Self {
+            blocks,
+            c_ref: blocks.c_ref(),
+            escaped_at: blocks.escaped_at(),
+            period: blocks.period(),
+            window: BlockWindow::new(blocks.len(), BLOCK_LEN, WINDOW_BLOCKS),
+        }
+    }
+}
+
+impl OrbitSource for OrbitWindow<'_> {
+    fn c_ref(&self) -> (f64, f64) {
+        self.c_ref
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    #[inline]
+    fn point(&self, n: usize) -> OrbitPoint {
+        self.window
+            .get(n, |start, points| self.blocks.read_points(start, points))
+    }
+}
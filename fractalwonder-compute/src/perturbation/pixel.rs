@@ -3,104 +3,126 @@
 //! Provides a single generic implementation for f64, HDRFloat, and BigFloat
 //! delta types via the `ComplexDelta` trait.
 
-use super::{compute_surface_normal_direction, ReferenceOrbit};
+use super::{
+    compute_surface_normal_direction, degenerate_orbit_data, distance_estimate_log2, OrbitPoint,
+    OrbitSource, PixelOptions, PixelRun, PixelState,
+};
 use fractalwonder_core::{ComplexDelta, MandelbrotData};
 
 /// Generic perturbation iteration for any ComplexDelta type.
 pub fn compute_pixel_perturbation<D: ComplexDelta>(
-    orbit: &ReferenceOrbit,
+    orbit: &impl OrbitSource,
     delta_c: D,
     max_iterations: u32,
     tau_sq: f64,
 ) -> MandelbrotData {
-    let orbit_len = orbit.orbit.len();
+    let mut state = PixelState::new(delta_c.zero());
+    let run = PixelRun::to_end(max_iterations, tau_sq, PixelOptions::ALL);
+    continue_pixel(orbit, &delta_c, &mut state, &run)
+        .unwrap_or_else(|| state.unfinished(max_iterations))
+}
+
+/// Continue `compute_pixel_perturbation` from `state` as far as `run` allows.
+///
+/// Returns the pixel data once it escapes or reaches max_iterations, or None
+/// with `state` holding the pixel where it stopped. Its statistics never
+/// include any BLA iterations.
+pub(crate) fn continue_pixel<D: ComplexDelta>(
+    orbit: &impl OrbitSource,
+    delta_c: &D,
+    state: &mut PixelState<D>,
+    run: &PixelRun,
+) -> Option<MandelbrotData> {
+    let orbit_len = orbit.len();
     if orbit_len == 0 {
-        return MandelbrotData {
-            iterations: 0,
-            max_iterations,
-            escaped: false,
-            glitched: true,
-            final_z_norm_sq: 0.0,
-            surface_normal_re: 0.0,
-            surface_normal_im: 0.0,
-        };
+        return Some(degenerate_orbit_data(run.max_iterations));
     }
 
-    let reference_escaped = orbit.escaped_at.is_some();
-    let mut dz = delta_c.zero();
-    let mut drho = delta_c.zero();
-    let mut m: usize = 0;
-    let mut n: u32 = 0;
-    let mut glitched = false;
+    let reference_escaped = orbit.escaped_at().is_some();
 
-    while n < max_iterations {
-        if reference_escaped && m >= orbit_len {
-            glitched = true;
+    while state.n < run.stop_at {
+        if reference_escaped && state.m >= orbit_len {
+            state.glitched = true;
         }
 
-        let z_m = orbit.orbit[m % orbit_len];
-        let der_m = orbit.derivative[m % orbit_len];
+        let OrbitPoint { z: z_m, der: der_m } = orbit.point(state.m % orbit_len);
         let z_m_complex = D::from_f64_pair(z_m.0, z_m.1);
         let der_m_complex = D::from_f64_pair(der_m.0, der_m.1);
 
-        let z = z_m_complex.add(&dz);
+        let z = z_m_complex.add(&state.dz);
         let z_norm_sq = z.norm_sq();
-        let rho = der_m_complex.add(&drho);
+        let rho = der_m_complex.add(&state.drho);
 
         // Escape check
         if z_norm_sq > 65536.0 {
             let (z_re, z_im) = z.to_f64_pair();
             let (rho_re, rho_im) = rho.to_f64_pair();
             let (sn_re, sn_im) = compute_surface_normal_direction(z_re, z_im, rho_re, rho_im);
-            return MandelbrotData::new(
-                n,
-                max_iterations,
-                true,
-                glitched,
-                z_norm_sq as f32,
-                sn_re,
-                sn_im,
-            );
+            let distance_log2 = distance_estimate_log2(z_norm_sq, rho.norm_log2());
+            return Some(state.orbit_stats.apply(
+                MandelbrotData::new(
+                    state.n,
+                    run.max_iterations,
+                    true,
+                    state.glitched,
+                    z_norm_sq as f32,
+                    sn_re,
+                    sn_im,
+                ),
+                distance_log2,
+            ));
         }
 
         // Pauldelbrot glitch detection
         let z_m_norm_sq = z_m.0 * z_m.0 + z_m.1 * z_m.1;
-        if z_m_norm_sq > 1e-20 && z_norm_sq < tau_sq * z_m_norm_sq {
-            glitched = true;
+        if z_m_norm_sq > 1e-20 && z_norm_sq < run.tau_sq * z_m_norm_sq {
+            state.glitched = true;
         }
 
-        // Rebase check
-        let dz_norm_sq = dz.norm_sq();
-        if z_norm_sq < dz_norm_sq {
-            dz = z;
-            drho = rho;
-            m = 0;
+        // Rebase check, forced at the point closing a periodic orbit
+        let dz_norm_sq = state.dz.norm_sq();
+        if z_norm_sq < dz_norm_sq || orbit.closes_cycle_at(state.m) {
+            state.dz = z;
+            state.drho = rho;
+            state.m = 0;
+            state.stats.rebase_count += 1;
             continue;
         }
 
+        if run.options.collect_orbit_stats && state.n > 0 {
+            let (z_re, z_im) = z.to_f64_pair();
+            state.orbit_stats.record(state.n, z_re, z_im, z_norm_sq);
+        }
+
         // Delta iteration: δz' = 2·Z_m·δz + δz² + δc
-        let old_dz = dz.clone();
-        let two_z_dz = z_m_complex.mul(&dz).scale(2.0);
-        let dz_sq = dz.square();
-        dz = two_z_dz.add(&dz_sq).add(&delta_c);
+        let old_dz = state.dz.clone();
+        let two_z_dz = z_m_complex.mul(&state.dz).scale(2.0);
+        let dz_sq = state.dz.square();
+        state.dz = two_z_dz.add(&dz_sq).add(delta_c);
 
         // Derivative iteration: δρ' = 2·Z_m·δρ + 2·δz·Der_m + 2·δz·δρ
-        let term1 = z_m_complex.mul(&drho).scale(2.0);
+        let term1 = z_m_complex.mul(&state.drho).scale(2.0);
         let term2 = old_dz.mul(&der_m_complex).scale(2.0);
-        let term3 = old_dz.mul(&drho).scale(2.0);
-        drho = term1.add(&term2).add(&term3);
+        let term3 = old_dz.mul(&state.drho).scale(2.0);
+        state.drho = term1.add(&term2).add(&term3);
 
-        m += 1;
-        n += 1;
+        state.m += 1;
+        state.n += 1;
+        state.stats.total_iterations += 1;
     }
 
-    MandelbrotData {
-        iterations: max_iterations,
-        max_iterations,
-        escaped: false,
-        glitched,
-        final_z_norm_sq: 0.0,
-        surface_normal_re: 0.0,
-        surface_normal_im: 0.0,
+    if state.n < run.max_iterations {
+        return None;
     }
+    let data = state
+        .orbit_stats
+        .apply(state.unfinished(run.max_iterations), 0.0);
+    Some(run.options.finish_interior(
+        orbit,
+        delta_c,
+        state.dz.clone(),
+        state.m,
+        data,
+        &mut state.stats,
+    ))
 }
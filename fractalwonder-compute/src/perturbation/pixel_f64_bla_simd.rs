@@ -0,0 +1,459 @@
+//! Lane-parallel f64 perturbation with BLA acceleration.
+//!
+//! Iterates `LANES` pixels at once: the delta arithmetic runs on SIMD vectors
+//! while escape, glitch, rebase and BLA decisions stay per lane. A lane whose
+//! pixel finishes is refilled with the next pixel straight away, so lanes
+//! never idle while their neighbour runs a long orbit.
+//!
+//! Every vector expression mirrors the scalar kernel in `pixel_f64_bla`
+//! operation for operation, so results match it bit for bit.
+//!
+//! The caller is asked every `STOP_CHECK_ROUNDS` steps whether to stop, so a
+//! batch of long interior pixels can be interrupted mid-orbit and continued
+//! later from the state it hands back.
+
+use super::simd::{F64Lanes, NativeF64x2, LANES};
+use super::{
+    compute_surface_normal_direction, degenerate_orbit_data, distance_estimate_log2, BlaStats,
+    OrbitSource, PixelOptions, PixelRun, PixelState,
+};
+use crate::bla::BlaSource;
+use fractalwonder_core::{F64Complex, MandelbrotData};
+
+/// A pixel handed to the lane kernel: its index in the caller's order, its
+/// δc and where its iteration stands.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LanePixel {
+    pub index: usize,
+    pub delta_c: (f64, f64),
+    pub state: PixelState<F64Complex>,
+}
+
+impl LanePixel {
+    /// A pixel that has not started iterating.
+    pub(crate) fn new(index: usize, delta_c: (f64, f64)) -> Self {
+        Self {
+            index,
+            delta_c,
+            state: PixelState::new(F64Complex::default()),
+        }
+    }
+}
+
+/// Lane steps between asking the sink whether to stop.
+const STOP_CHECK_ROUNDS: u32 = 1024;
+
+/// Receives the pixels leaving the lane kernel and decides when it stops.
+pub(crate) trait LaneSink {
+    /// A pixel left its lane, with its data if it finished or None if it
+    /// stopped early; its state is current either way.
+    fn emit(&mut self, pixel: LanePixel, data: Option<MandelbrotData>);
+
+    /// Whether to stop now, given the work each lane's pixel has done since
+    /// it entered. Stopping emits every unfinished pixel with None.
+    fn should_stop(&mut self, in_flight: &[BlaStats; LANES]) -> bool;
+}
+
+/// A closure takes the pixels and never stops.
+impl<F: FnMut(LanePixel, Option<MandelbrotData>)> LaneSink for F {
+    fn emit(&mut self, pixel: LanePixel, data: Option<MandelbrotData>) {
+        self(pixel, data)
+    }
+
+    fn should_stop(&mut self, _in_flight: &[BlaStats; LANES]) -> bool {
+        false
+    }
+}
+
+/// Bookkeeping for the pixel held by one lane. Its deltas live in the
+/// kernel's vectors so the hot loop never moves them out of registers;
+/// `pixel.state.dz` and `pixel.state.drho` are only current once the
+/// pixel leaves its lane.
+struct Lane {
+    pixel: LanePixel,
+    dc_max: f64,
+    /// Statistics of the pixel when it entered the lane
+    entry: BlaStats,
+}
+
+impl Lane {
+    fn new(pixel: LanePixel) -> Self {
+        let (re, im) = pixel.delta_c;
+        Self {
+            pixel,
+            dc_max: (re * re + im * im).sqrt(),
+            entry: pixel.state.stats,
+        }
+    }
+
+    /// Result for a pixel that reached max_iterations without escaping.
+    fn finish_interior(&mut self, orbit: &impl OrbitSource, run: &PixelRun) -> MandelbrotData {
+        let state = &mut self.pixel.state;
+        run.options.finish_interior(
+            orbit,
+            &F64Complex {
+                re: self.pixel.delta_c.0,
+                im: self.pixel.delta_c.1,
+            },
+            state.dz,
+            state.m,
+            state.unfinished(run.max_iterations),
+            &mut state.stats,
+        )
+    }
+}
+
+/// Delta state of all lanes, one vector per component.
+#[derive(Clone, Copy)]
+struct Deltas<V> {
+    dz_re: V,
+    dz_im: V,
+    drho_re: V,
+    drho_im: V,
+    dc_re: V,
+    dc_im: V,
+}
+
+impl<V: F64Lanes> Deltas<V> {
+    fn dz(&self, i: usize) -> (f64, f64) {
+        (self.dz_re.to_array()[i], self.dz_im.to_array()[i])
+    }
+
+    fn drho(&self, i: usize) -> (f64, f64) {
+        (self.drho_re.to_array()[i], self.drho_im.to_array()[i])
+    }
+
+    fn set_dz(&mut self, i: usize, dz: (f64, f64)) {
+        self.dz_re = with_lane(self.dz_re, i, dz.0);
+        self.dz_im = with_lane(self.dz_im, i, dz.1);
+    }
+
+    fn set_drho(&mut self, i: usize, drho: (f64, f64)) {
+        self.drho_re = with_lane(self.drho_re, i, drho.0);
+        self.drho_im = with_lane(self.drho_im, i, drho.1);
+    }
+
+    /// Load a pixel into lane i, or park the lane on zeros.
+    fn load(&mut self, i: usize, pixel: Option<&LanePixel>) {
+        let zero = F64Complex::default();
+        let (dz, drho, dc) = pixel.map_or((zero, zero, (0.0, 0.0)), |p| {
+            (p.state.dz, p.state.drho, p.delta_c)
+        });
+        self.set_dz(i, (dz.re, dz.im));
+        self.set_drho(i, (drho.re, drho.im));
+        self.dc_re = with_lane(self.dc_re, i, dc.0);
+        self.dc_im = with_lane(self.dc_im, i, dc.1);
+    }
+
+    /// Copy the deltas of lane i back into the pixel leaving it.
+    fn store(&self, i: usize, pixel: &mut LanePixel) {
+        let (dz, drho) = (self.dz(i), self.drho(i));
+        pixel.state.dz = F64Complex { re: dz.0, im: dz.1 };
+        pixel.state.drho = F64Complex {
+            re: drho.0,
+            im: drho.1,
+        };
+    }
+}
+
+#[inline]
+fn with_lane<V: F64Lanes>(v: V, i: usize, x: f64) -> V {
+    let mut a = v.to_array();
+    a[i] = x;
+    V::from_array(a)
+}
+
+/// Lanes from `new` where `mask` is set, from `old` elsewhere.
+#[inline]
+fn select<V: F64Lanes>(mask: [bool; LANES], new: V, old: V) -> V {
+    let (new, old) = (new.to_array(), old.to_array());
+    V::from_array(std::array::from_fn(
+        |i| if mask[i] { new[i] } else { old[i] },
+    ))
+}
+
+/// Compute a batch of pixels with the lane-parallel f64 BLA kernel.
+/// Returns pixel data and BLA statistics in the order of `delta_cs`.
+///
+/// Uses the native SIMD backend for the target; results are identical to
+/// calling `compute_pixel_perturbation_f64_bla` on each pixel.
+pub fn compute_pixels_perturbation_f64_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_cs: &[(f64, f64)],
+    max_iterations: u32,
+    tau_sq: f64,
+) -> Vec<(MandelbrotData, BlaStats)> {
+    compute_pixels_lanes::<NativeF64x2>(
+        orbit,
+        bla_table,
+        delta_cs,
+        max_iterations,
+        tau_sq,
+        PixelOptions::ALL,
+    )
+}
+
+/// The above over any `F64Lanes` backend, with the optional per-pixel work
+/// chosen by `options`.
+pub(crate) fn compute_pixels_lanes<V: F64Lanes>(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_cs: &[(f64, f64)],
+    max_iterations: u32,
+    tau_sq: f64,
+    options: PixelOptions,
+) -> Vec<(MandelbrotData, BlaStats)> {
+    let mut results = vec![(MandelbrotData::default(), BlaStats::default()); delta_cs.len()];
+    let pixels = delta_cs
+        .iter()
+        .enumerate()
+        .map(|(index, &dc)| LanePixel::new(index, dc));
+    let run = PixelRun::to_end(max_iterations, tau_sq, options);
+    let mut collect = |pixel: LanePixel, data: Option<MandelbrotData>| {
+        let data = data.unwrap_or_else(|| pixel.state.unfinished(max_iterations));
+        results[pixel.index] = (data, pixel.state.stats);
+    };
+    continue_pixels_lanes::<V>(orbit, bla_table, pixels, &run, &mut collect);
+    results
+}
+
+/// Continue a batch of pixels with the native lane-parallel kernel; see
+/// `continue_pixels_lanes`.
+pub(crate) fn continue_pixels_f64_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    pixels: impl IntoIterator<Item = LanePixel>,
+    run: &PixelRun,
+    sink: &mut impl LaneSink,
+) {
+    continue_pixels_lanes::<NativeF64x2>(orbit, bla_table, pixels, run, sink)
+}
+
+/// Lane-parallel `continue_pixel_f64_bla` over any `F64Lanes` backend.
+///
+/// Every pixel is handed to `sink` once it leaves its lane, with its data
+/// if it finished or None if it stopped at `run.stop_at` or the sink asked
+/// to stop; its state is then current either way. Pixels are emitted in
+/// the order they leave.
+pub(crate) fn continue_pixels_lanes<V: F64Lanes>(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    pixels: impl IntoIterator<Item = LanePixel>,
+    run: &PixelRun,
+    sink: &mut impl LaneSink,
+) {
+    let mut pending = pixels.into_iter();
+    let orbit_len = orbit.len();
+    if orbit_len == 0 {
+        // Degenerate orbit: every pixel is reported as glitched
+        for pixel in pending {
+            sink.emit(pixel, Some(degenerate_orbit_data(run.max_iterations)));
+        }
+        return;
+    }
+
+    let mut lanes: [Option<Lane>; LANES] = std::array::from_fn(|_| None);
+    let zero = V::splat(0.0);
+    let mut d = Deltas {
+        dz_re: zero,
+        dz_im: zero,
+        drho_re: zero,
+        drho_im: zero,
+        dc_re: zero,
+        dc_im: zero,
+    };
+    for (i, slot) in lanes.iter_mut().enumerate() {
+        *slot = pending.next().map(Lane::new);
+        d.load(i, slot.as_ref().map(|l| &l.pixel));
+    }
+
+    let reference_escaped = orbit.escaped_at().is_some();
+    let two = V::splat(2.0);
+
+    for round in 0u32.. {
+        // Retire lanes that ran out of iterations and refill them
+        for (i, slot) in lanes.iter_mut().enumerate() {
+            while let Some(mut lane) = slot.take_if(|l| l.pixel.state.n >= run.stop_at) {
+                d.store(i, &mut lane.pixel);
+                let data = (lane.pixel.state.n >= run.max_iterations)
+                    .then(|| lane.finish_interior(orbit, run));
+                sink.emit(lane.pixel, data);
+                *slot = pending.next().map(Lane::new);
+                d.load(i, slot.as_ref().map(|l| &l.pixel));
+            }
+        }
+        if lanes.iter().all(Option::is_none) {
+            break;
+        }
+
+        if round % STOP_CHECK_ROUNDS == 0 {
+            let in_flight = std::array::from_fn(|i| {
+                lanes[i].as_ref().map_or(BlaStats::default(), |lane| {
+                    lane.pixel.state.stats.since(&lane.entry)
+                })
+            });
+            if sink.should_stop(&in_flight) {
+                for (i, slot) in lanes.iter_mut().enumerate() {
+                    if let Some(mut lane) = slot.take() {
+                        d.store(i, &mut lane.pixel);
+                        sink.emit(lane.pixel, None);
+                    }
+                }
+                for pixel in pending {
+                    sink.emit(pixel, None);
+                }
+                return;
+            }
+        }
+
+        // Gather reference values; idle lanes read index 0
+        let mut z_m = [[0.0; LANES]; 2];
+        let mut der_m = [[0.0; LANES]; 2];
+        for (i, lane) in lanes.iter_mut().enumerate() {
+            let Some(lane) = lane else { continue };
+            let state = &mut lane.pixel.state;
+            if reference_escaped && state.m >= orbit_len {
+                state.glitched = true;
+            }
+            let idx = if state.m < orbit_len {
+                state.m
+            } else {
+                state.m % orbit_len
+            };
+            let point = orbit.point(idx);
+            (z_m[0][i], z_m[1][i]) = point.z;
+            (der_m[0][i], der_m[1][i]) = point.der;
+        }
+        let (z_m_re, z_m_im) = (V::from_array(z_m[0]), V::from_array(z_m[1]));
+        let (der_m_re, der_m_im) = (V::from_array(der_m[0]), V::from_array(der_m[1]));
+        let Deltas {
+            dz_re,
+            dz_im,
+            drho_re,
+            drho_im,
+            dc_re,
+            dc_im,
+        } = d;
+
+        // Full values: z = Z_m + dz, rho = Der_m + drho
+        let z_re = z_m_re.add(dz_re);
+        let z_im = z_m_im.add(dz_im);
+        let rho_re = der_m_re.add(drho_re);
+        let rho_im = der_m_im.add(drho_im);
+
+        let z_mag_sq = z_re.mul(z_re).add(z_im.mul(z_im)).to_array();
+        let z_m_mag_sq = z_m_re.mul(z_m_re).add(z_m_im.mul(z_m_im)).to_array();
+        let dz_mag_sq = dz_re.mul(dz_re).add(dz_im.mul(dz_im)).to_array();
+        let (z_re_a, z_im_a) = (z_re.to_array(), z_im.to_array());
+
+        // Per-lane control flow; lanes left in `step` take a standard iteration
+        let mut step = [false; LANES];
+        for (i, slot) in lanes.iter_mut().enumerate() {
+            let Some(lane) = slot else { continue };
+            let state = &mut lane.pixel.state;
+
+            // 1. Escape check
+            if z_mag_sq[i] > 65536.0 {
+                let (rho_re, rho_im) = (rho_re.to_array()[i], rho_im.to_array()[i]);
+                let (sn_re, sn_im) =
+                    compute_surface_normal_direction(z_re_a[i], z_im_a[i], rho_re, rho_im);
+                let distance_log2 =
+                    distance_estimate_log2(z_mag_sq[i], rho_re.hypot(rho_im).log2());
+                let data = MandelbrotData::new(
+                    state.n,
+                    run.max_iterations,
+                    true,
+                    state.glitched,
+                    z_mag_sq[i] as f32,
+                    sn_re,
+                    sn_im,
+                )
+                .with_orbit_stats(0.0, 0.0, distance_log2);
+                let Some(mut lane) = slot.take() else {
+                    continue;
+                };
+                d.store(i, &mut lane.pixel);
+                sink.emit(lane.pixel, Some(data));
+                *slot = pending.next().map(Lane::new);
+                d.load(i, slot.as_ref().map(|l| &l.pixel));
+                continue;
+            }
+
+            // 2. Pauldelbrot glitch detection
+            if z_m_mag_sq[i] > 1e-20 && z_mag_sq[i] < run.tau_sq * z_m_mag_sq[i] {
+                state.glitched = true;
+            }
+
+            // 3. Rebase check: if |z| < |dz|, the perturbation dominates the full value.
+            // A periodic orbit always rebases at the point closing its cycle.
+            if z_mag_sq[i] < dz_mag_sq[i] || orbit.closes_cycle_at(state.m) {
+                d.set_dz(i, (z_re_a[i], z_im_a[i]));
+                d.set_drho(i, (rho_re.to_array()[i], rho_im.to_array()[i]));
+                state.m = 0;
+                state.stats.rebase_count += 1;
+                continue;
+            }
+
+            // 4. Try BLA acceleration (with f64 coefficients)
+            if let Some(bla) = bla_table.find_valid_f64(state.m, dz_mag_sq[i], lane.dc_max) {
+                let (a, b, dz, dc) = (bla.a, bla.b, d.dz(i), lane.pixel.delta_c);
+                let a_dz = (a.0 * dz.0 - a.1 * dz.1, a.0 * dz.1 + a.1 * dz.0);
+                let b_dc = (b.0 * dc.0 - b.1 * dc.1, b.0 * dc.1 + b.1 * dc.0);
+                d.set_dz(i, (a_dz.0 + b_dc.0, a_dz.1 + b_dc.1));
+
+                let state = &mut lane.pixel.state;
+                state.stats.bla_iterations += bla.l;
+                state.stats.total_iterations += bla.l;
+                state.m += bla.l as usize;
+                state.n += bla.l;
+            } else {
+                step[i] = true;
+                let state = &mut lane.pixel.state;
+                state.stats.total_iterations += 1;
+                state.m += 1;
+                state.n += 1;
+            }
+        }
+
+        if !step.contains(&true) {
+            continue;
+        }
+
+        // 5. Standard delta iteration for all lanes: dz' = 2*Z_m*dz + dz^2 + dc
+        let two_z_dz_re = two.mul(z_m_re.mul(dz_re).sub(z_m_im.mul(dz_im)));
+        let two_z_dz_im = two.mul(z_m_re.mul(dz_im).add(z_m_im.mul(dz_re)));
+
+        let dz_sq_re = dz_re.mul(dz_re).sub(dz_im.mul(dz_im));
+        let dz_sq_im = two.mul(dz_re).mul(dz_im);
+
+        let new_dz_re = two_z_dz_re.add(dz_sq_re).add(dc_re);
+        let new_dz_im = two_z_dz_im.add(dz_sq_im).add(dc_im);
+
+        // Derivative delta iteration: drho' = 2*Z_m*drho + 2*dz*Der_m + 2*dz*drho
+        let two_z_drho_re = two.mul(z_m_re.mul(drho_re).sub(z_m_im.mul(drho_im)));
+        let two_z_drho_im = two.mul(z_m_re.mul(drho_im).add(z_m_im.mul(drho_re)));
+
+        let two_dz_der_re = two.mul(dz_re.mul(der_m_re).sub(dz_im.mul(der_m_im)));
+        let two_dz_der_im = two.mul(dz_re.mul(der_m_im).add(dz_im.mul(der_m_re)));
+
+        let two_dz_drho_re = two.mul(dz_re.mul(drho_re).sub(dz_im.mul(drho_im)));
+        let two_dz_drho_im = two.mul(dz_re.mul(drho_im).add(dz_im.mul(drho_re)));
+
+        let new_drho_re = two_z_drho_re.add(two_dz_der_re).add(two_dz_drho_re);
+        let new_drho_im = two_z_drho_im.add(two_dz_der_im).add(two_dz_drho_im);
+
+        // Lanes that escaped, rebased or took a BLA step keep their new state
+        if step == [true; LANES] {
+            d.dz_re = new_dz_re;
+            d.dz_im = new_dz_im;
+            d.drho_re = new_drho_re;
+            d.drho_im = new_drho_im;
+        } else {
+            d.dz_re = select(step, new_dz_re, d.dz_re);
+            d.dz_im = select(step, new_dz_im, d.dz_im);
+            d.drho_re = select(step, new_drho_re, d.drho_re);
+            d.drho_im = select(step, new_drho_im, d.drho_im);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! FloatExp perturbation with BLA (Bivariate Linear Approximation) acceleration.
+//!
+//! Mid-depth path for zooms past the f64 exponent floor: the f64 mantissa and
+//! i64 exponent avoid underflow while keeping each operation a single native
+//! multiply, which is cheaper than HDRFloat's double-single arithmetic.
+
+use super::{
+    compute_surface_normal_direction, degenerate_orbit_data, distance_estimate_log2, BlaStats,
+    OrbitPoint, OrbitSource, PixelOptions, PixelRun, PixelState,
+};
+use crate::bla::BlaSource;
+use fractalwonder_core::{FloatExp, FloatExpComplex, MandelbrotData};
+
+/// Compute pixel using perturbation with FloatExp deltas and BLA acceleration.
+/// Returns pixel data and BLA statistics for performance monitoring.
+///
+/// BLA coefficients are stored as HDRFloat and converted on use; the
+/// conversion is exact since HDRFloat carries fewer mantissa bits.
+pub fn compute_pixel_perturbation_floatexp_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_c: FloatExpComplex,
+    max_iterations: u32,
+    tau_sq: f64,
+) -> (MandelbrotData, BlaStats) {
+    let mut state = PixelState::new(FloatExpComplex::ZERO);
+    let run = PixelRun::to_end(max_iterations, tau_sq, PixelOptions::ALL);
+    let data = continue_pixel_floatexp_bla(orbit, bla_table, delta_c, &mut state, &run)
+        .unwrap_or_else(|| state.unfinished(max_iterations));
+    (data, state.stats)
+}
+
+/// Continue `compute_pixel_perturbation_floatexp_bla` from `state` as far as `run` allows.
+///
+/// Returns the pixel data once it escapes or reaches max_iterations, or None
+/// with `state` holding the pixel where it stopped.
+pub(crate) fn continue_pixel_floatexp_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_c: FloatExpComplex,
+    state: &mut PixelState<FloatExpComplex>,
+    run: &PixelRun,
+) -> Option<MandelbrotData> {
+    let orbit_len = orbit.len();
+    if orbit_len == 0 {
+        return Some(degenerate_orbit_data(run.max_iterations));
+    }
+
+    // The loop runs on copies, written back to `state` when it stops
+    let PixelState {
+        mut dz,
+        mut drho,
+        mut m,
+        mut n,
+        mut glitched,
+        mut stats,
+        ..
+    } = *state;
+    let reference_escaped = orbit.escaped_at().is_some();
+
+    let escaped = 'iterate: {
+        while n < run.stop_at {
+            if reference_escaped && m >= orbit_len {
+                glitched = true;
+            }
+
+            let OrbitPoint {
+                z: (z_m_re, z_m_im),
+                der: (der_m_re, der_m_im),
+            } = orbit.point(m % orbit_len);
+
+            // Full values: z = Z_m + δz, ρ = Der_m + δρ
+            let z_re = FloatExp::from_f64(z_m_re).add(&dz.re);
+            let z_im = FloatExp::from_f64(z_m_im).add(&dz.im);
+            let rho_re = FloatExp::from_f64(der_m_re).add(&drho.re);
+            let rho_im = FloatExp::from_f64(der_m_im).add(&drho.im);
+
+            let z_mag_sq_fe = z_re.square().add(&z_im.square());
+            let z_mag_sq = z_mag_sq_fe.to_f64();
+            let z_m_mag_sq = z_m_re * z_m_re + z_m_im * z_m_im;
+            let dz_mag_sq = dz.norm_sq_fe();
+
+            // 1. Escape check
+            if z_mag_sq > 65536.0 {
+                let (sn_re, sn_im) = compute_surface_normal_direction(
+                    z_re.to_f64(),
+                    z_im.to_f64(),
+                    rho_re.to_f64(),
+                    rho_im.to_f64(),
+                );
+                let rho_norm_log2 = rho_re.square().add(&rho_im.square()).log2() / 2.0;
+                let distance_log2 = distance_estimate_log2(z_mag_sq, rho_norm_log2);
+
+                break 'iterate Some(
+                    MandelbrotData::new(
+                        n,
+                        run.max_iterations,
+                        true,
+                        glitched,
+                        z_mag_sq as f32,
+                        sn_re,
+                        sn_im,
+                    )
+                    .with_orbit_stats(0.0, 0.0, distance_log2),
+                );
+            }
+
+            // 2. Pauldelbrot glitch detection
+            if z_m_mag_sq > 1e-20 && z_mag_sq < run.tau_sq * z_m_mag_sq {
+                glitched = true;
+            }
+
+            // 3. Rebase check: if |z| < |δz|, the perturbation dominates the full value.
+            // A periodic orbit always rebases at the point closing its cycle.
+            if z_mag_sq_fe.sub(&dz_mag_sq).is_negative() || orbit.closes_cycle_at(m) {
+                dz = FloatExpComplex { re: z_re, im: z_im };
+                drho = FloatExpComplex {
+                    re: rho_re,
+                    im: rho_im,
+                };
+                m = 0;
+                stats.rebase_count += 1;
+                continue;
+            }
+
+            // 4. Try BLA acceleration
+            let bla_entry = bla_table.find_valid(m, &dz_mag_sq.to_hdr(), bla_table.dc_max());
+
+            if let Some(bla) = bla_entry {
+                // Apply BLA: δz_new = A·δz + B·δc
+                let a_dz = FloatExpComplex::from_hdr(&bla.a).mul(&dz);
+                let b_dc = FloatExpComplex::from_hdr(&bla.b).mul(&delta_c);
+                dz = a_dz.add(&b_dc);
+
+                stats.bla_iterations += bla.l;
+                stats.total_iterations += bla.l;
+                m += bla.l as usize;
+                n += bla.l;
+            } else {
+                // 5. Standard delta iteration
+                let old_dz = dz;
+
+                let two_z_dz_re = dz
+                    .re
+                    .mul_f64(z_m_re)
+                    .sub(&dz.im.mul_f64(z_m_im))
+                    .mul_pow2(1);
+                let two_z_dz_im = dz
+                    .re
+                    .mul_f64(z_m_im)
+                    .add(&dz.im.mul_f64(z_m_re))
+                    .mul_pow2(1);
+
+                let dz_sq = dz.square();
+
+                dz = FloatExpComplex {
+                    re: two_z_dz_re.add(&dz_sq.re).add(&delta_c.re),
+                    im: two_z_dz_im.add(&dz_sq.im).add(&delta_c.im),
+                };
+
+                // Derivative delta iteration: δρ' = 2·Z_m·δρ + 2·δz·Der_m + 2·δz·δρ
+                let two_z_drho_re = drho
+                    .re
+                    .mul_f64(z_m_re)
+                    .sub(&drho.im.mul_f64(z_m_im))
+                    .mul_pow2(1);
+                let two_z_drho_im = drho
+                    .re
+                    .mul_f64(z_m_im)
+                    .add(&drho.im.mul_f64(z_m_re))
+                    .mul_pow2(1);
+
+                let two_dz_der_re = old_dz
+                    .re
+                    .mul_f64(der_m_re)
+                    .sub(&old_dz.im.mul_f64(der_m_im))
+                    .mul_pow2(1);
+                let two_dz_der_im = old_dz
+                    .re
+                    .mul_f64(der_m_im)
+                    .add(&old_dz.im.mul_f64(der_m_re))
+                    .mul_pow2(1);
+
+                let two_dz_drho_re = old_dz
+                    .re
+                    .mul(&drho.re)
+                    .sub(&old_dz.im.mul(&drho.im))
+                    .mul_pow2(1);
+                let two_dz_drho_im = old_dz
+                    .re
+                    .mul(&drho.im)
+                    .add(&old_dz.im.mul(&drho.re))
+                    .mul_pow2(1);
+
+                drho = FloatExpComplex {
+                    re: two_z_drho_re.add(&two_dz_der_re).add(&two_dz_drho_re),
+                    im: two_z_drho_im.add(&two_dz_der_im).add(&two_dz_drho_im),
+                };
+
+                stats.total_iterations += 1;
+                m += 1;
+                n += 1;
+            }
+        }
+        None
+    };
+    *state = PixelState {
+        dz,
+        drho,
+        m,
+        n,
+        glitched,
+        stats,
+        ..*state
+    };
+    if escaped.is_some() || n < run.max_iterations {
+        return escaped;
+    }
+
+    Some(run.options.finish_interior(
+        orbit,
+        &delta_c,
+        dz,
+        m,
+        state.unfinished(run.max_iterations),
+        &mut state.stats,
+    ))
+}
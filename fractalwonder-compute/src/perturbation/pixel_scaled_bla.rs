@@ -0,0 +1,426 @@
+//! Scaled-f64 perturbation with BLA (Bivariate Linear Approximation) acceleration.
+//!
+//! Past ~10^-300 the deltas underflow f64, but their ratios do not. This path
+//! stores δz and δc as plain f64 values in units of a per-pixel power of two
+//! 2^s, and δρ in units of its own 2^r, so the inner loop is ordinary f64
+//! arithmetic. The scales are only renormalized when a value drifts far from
+//! 1, and the quadratic δz² term picks up one factor of 2^s (it underflows to
+//! zero while δz is negligible).
+//!
+//! Once δz grows into the f64 range its scale reaches 2^0 and the loop is
+//! the plain f64 iteration. If the derivative overflows f64 even at 2^0 the
+//! pixel is recomputed on the HDRFloat path.
+
+use super::{
+    compute_surface_normal_direction, continue_pixel_hdr_bla, degenerate_orbit_data,
+    distance_estimate_log2, BlaStats, OrbitPoint, OrbitSource, PixelOptions, PixelRun, PixelState,
+};
+use crate::bla::BlaSource;
+use fractalwonder_core::{HDRComplex, HDRFloat, MandelbrotData};
+
+/// Renormalize once a scaled value leaves [2^-128, 2^128].
+const RESCALE_BITS: i32 = 128;
+const RESCALE_HIGH: f64 = 3.402_823_669_209_385e38; // 2^128
+const RESCALE_LOW: f64 = 2.938_735_877_055_719e-39; // 2^-128
+
+/// Power-of-two unit 2^exp for a scaled f64 value.
+#[derive(Clone, Copy, Debug)]
+struct Scale {
+    /// Exponent of the unit; never positive.
+    exp: i32,
+    /// 2^exp as f64, zero once it underflows.
+    factor: f64,
+}
+
+impl Scale {
+    fn new(exp: i32) -> Self {
+        let exp = exp.min(0);
+        Self {
+            exp,
+            factor: libm::ldexp(1.0, exp),
+        }
+    }
+
+    /// Scale re-centered on a scaled value of magnitude `mag`, if it drifted.
+    fn drifted(&self, mag: f64) -> Option<Self> {
+        let drifted = (mag > RESCALE_HIGH && self.exp < 0) || (mag < RESCALE_LOW && mag != 0.0);
+        if !drifted {
+            return None;
+        }
+        let next = Self::new(self.exp.saturating_add(libm::frexp(mag).1));
+        (next.exp != self.exp).then_some(next)
+    }
+
+    /// Express an HDRFloat in units of this scale.
+    fn scale(&self, v: &HDRFloat) -> f64 {
+        if v.is_zero() {
+            return 0.0;
+        }
+        let shift = (v.exp as i64 - self.exp as i64).clamp(-2200, 2200) as i32;
+        libm::ldexp(v.head as f64 + v.tail as f64, shift)
+    }
+
+    /// Convert a scaled value back to HDRFloat.
+    fn unscale(&self, v: f64) -> HDRFloat {
+        let mut h = HDRFloat::from_f64(v);
+        if !h.is_zero() {
+            h.exp = h.exp.saturating_add(self.exp);
+        }
+        h
+    }
+
+    fn scale_complex(&self, v: &HDRComplex) -> (f64, f64) {
+        (self.scale(&v.re), self.scale(&v.im))
+    }
+
+    fn unscale_complex(&self, v: (f64, f64)) -> HDRComplex {
+        HDRComplex {
+            re: self.unscale(v.0),
+            im: self.unscale(v.1),
+        }
+    }
+
+    /// Move a scaled value from this unit to `to`.
+    fn convert(&self, v: (f64, f64), to: &Self) -> (f64, f64) {
+        let shift = self.exp.saturating_sub(to.exp);
+        (libm::ldexp(v.0, shift), libm::ldexp(v.1, shift))
+    }
+}
+
+/// Complex value stored as f64 components in units of `unit`.
+#[derive(Clone, Copy, Debug)]
+struct ScaledDelta {
+    value: (f64, f64),
+    unit: Scale,
+}
+
+/// Where a pixel of the scaled kernel stands; see `PixelState`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScaledPixelState(ScaledProgress);
+
+#[derive(Clone, Copy, Debug)]
+enum ScaledProgress {
+    Scaled(PixelState<ScaledDelta>),
+    /// The derivative outgrew f64 and the pixel moved to the HDRFloat kernel
+    Hdr(PixelState<HDRComplex>),
+}
+
+impl ScaledPixelState {
+    /// A pixel at `delta_c` that has not started iterating.
+    pub(crate) fn new(delta_c: &HDRComplex) -> Self {
+        let zero = ScaledDelta {
+            value: (0.0, 0.0),
+            unit: Scale::new(leading_exp_hdr(delta_c).unwrap_or(0)),
+        };
+        Self(ScaledProgress::Scaled(PixelState::new(zero)))
+    }
+
+    /// Iterations done.
+    pub(crate) fn n(&self) -> u32 {
+        match &self.0 {
+            ScaledProgress::Scaled(state) => state.n,
+            ScaledProgress::Hdr(state) => state.n,
+        }
+    }
+
+    pub(crate) fn stats(&self) -> BlaStats {
+        match &self.0 {
+            ScaledProgress::Scaled(state) => state.stats,
+            ScaledProgress::Hdr(state) => state.stats,
+        }
+    }
+
+    pub(crate) fn unfinished(&self, max_iterations: u32) -> MandelbrotData {
+        match &self.0 {
+            ScaledProgress::Scaled(state) => state.unfinished(max_iterations),
+            ScaledProgress::Hdr(state) => state.unfinished(max_iterations),
+        }
+    }
+}
+
+/// Binary exponent e with max(|re|, |im|) < 2^e, or None for zero.
+fn leading_exp_hdr(v: &HDRComplex) -> Option<i32> {
+    match (v.re.is_zero(), v.im.is_zero()) {
+        (true, true) => None,
+        (false, true) => Some(v.re.exp),
+        (true, false) => Some(v.im.exp),
+        (false, false) => Some(v.re.exp.max(v.im.exp)),
+    }
+}
+
+#[inline]
+fn max_abs(v: (f64, f64)) -> f64 {
+    v.0.abs().max(v.1.abs())
+}
+
+#[inline]
+fn is_finite(v: (f64, f64)) -> bool {
+    v.0.is_finite() && v.1.is_finite()
+}
+
+/// Factor taking δz units (2^s) to δρ units (2^r): 2^(s - r).
+#[inline]
+fn cross_factor(dz_scale: &Scale, rho_scale: &Scale) -> f64 {
+    libm::ldexp(1.0, dz_scale.exp.saturating_sub(rho_scale.exp))
+}
+
+/// Reference values below this may trigger a rebase: between
+/// renormalizations |δz| < 2^(s + 129), and a rebase needs |Z| < 2·|δz|.
+#[inline]
+fn rebase_gate(dz_scale: &Scale) -> f64 {
+    libm::ldexp(1.0, dz_scale.exp.saturating_add(RESCALE_BITS + 2))
+}
+
+/// Compute pixel using scaled-f64 perturbation with BLA acceleration.
+/// Returns pixel data and BLA statistics for performance monitoring.
+///
+/// Takes the same HDRFloat delta as `compute_pixel_perturbation_hdr_bla`
+/// and produces matching results for deltas down to ~10^-4900.
+pub fn compute_pixel_perturbation_scaled_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_c: HDRComplex,
+    max_iterations: u32,
+    tau_sq: f64,
+) -> (MandelbrotData, BlaStats) {
+    let mut state = ScaledPixelState::new(&delta_c);
+    let run = PixelRun::to_end(max_iterations, tau_sq, PixelOptions::ALL);
+    let data = continue_pixel_scaled_bla(orbit, bla_table, delta_c, &mut state, &run)
+        .unwrap_or_else(|| state.unfinished(max_iterations));
+    (data, state.stats())
+}
+
+/// Continue `compute_pixel_perturbation_scaled_bla` from `state` as far as `run` allows.
+///
+/// Returns the pixel data once it escapes or reaches max_iterations, or None
+/// with `state` holding the pixel where it stopped.
+pub(crate) fn continue_pixel_scaled_bla(
+    orbit: &impl OrbitSource,
+    bla_table: &dyn BlaSource,
+    delta_c: HDRComplex,
+    state: &mut ScaledPixelState,
+    run: &PixelRun,
+) -> Option<MandelbrotData> {
+    let scaled = match &mut state.0 {
+        ScaledProgress::Scaled(scaled) => scaled,
+        ScaledProgress::Hdr(hdr) => {
+            return continue_pixel_hdr_bla(orbit, bla_table, delta_c, hdr, run);
+        }
+    };
+    let orbit_len = orbit.len();
+    if orbit_len == 0 {
+        return Some(degenerate_orbit_data(run.max_iterations));
+    }
+
+    // The loop runs on copies, written back to `state` when it stops. The
+    // factors derived from the units are recomputed, as on every rescale.
+    let PixelState {
+        dz: ScaledDelta {
+            value: mut dz,
+            unit: mut dz_scale,
+        },
+        drho: ScaledDelta {
+            value: mut drho,
+            unit: mut rho_scale,
+        },
+        mut m,
+        mut n,
+        mut glitched,
+        mut stats,
+        ..
+    } = *scaled;
+    let mut cross = cross_factor(&dz_scale, &rho_scale);
+    let mut gate = rebase_gate(&dz_scale);
+    let mut dc = dz_scale.scale_complex(&delta_c);
+    let mut overflowed = false;
+
+    let reference_escaped = orbit.escaped_at().is_some();
+
+    let escaped = 'iterate: {
+        while n < run.stop_at {
+            if reference_escaped && m >= orbit_len {
+                glitched = true;
+            }
+
+            let OrbitPoint {
+                z: (z_m_re, z_m_im),
+                der: (der_m_re, der_m_im),
+            } = orbit.point(m % orbit_len);
+
+            // Full values: z = Z_m + 2^s·dz, rho = Der_m + 2^r·drho
+            let z_re = z_m_re + dz.0 * dz_scale.factor;
+            let z_im = z_m_im + dz.1 * dz_scale.factor;
+            let rho_re = der_m_re + drho.0 * rho_scale.factor;
+            let rho_im = der_m_im + drho.1 * rho_scale.factor;
+
+            let z_mag_sq = z_re * z_re + z_im * z_im;
+            let z_m_mag_sq = z_m_re * z_m_re + z_m_im * z_m_im;
+            let dz_mag_sq = dz.0 * dz.0 + dz.1 * dz.1;
+
+            // 1. Escape check
+            if z_mag_sq > 65536.0 {
+                let (sn_re, sn_im) = compute_surface_normal_direction(z_re, z_im, rho_re, rho_im);
+                let distance_log2 = distance_estimate_log2(z_mag_sq, rho_re.hypot(rho_im).log2());
+
+                break 'iterate Some(
+                    MandelbrotData::new(
+                        n,
+                        run.max_iterations,
+                        true,
+                        glitched,
+                        z_mag_sq as f32,
+                        sn_re,
+                        sn_im,
+                    )
+                    .with_orbit_stats(0.0, 0.0, distance_log2),
+                );
+            }
+
+            // 2. Pauldelbrot glitch detection
+            if z_m_mag_sq > 1e-20 && z_mag_sq < run.tau_sq * z_m_mag_sq {
+                glitched = true;
+            }
+
+            // 3. Rebase check: if |z| < |δz|, the perturbation dominates the full value.
+            // Only a reference value comparable to δz can trigger it, so the
+            // comparison in scaled units is skipped for everything else. A periodic
+            // orbit always rebases at the point closing its cycle.
+            let closes_cycle = orbit.closes_cycle_at(m);
+            if closes_cycle || max_abs((z_m_re, z_m_im)) <= gate {
+                let z_scaled = (
+                    libm::ldexp(z_m_re, -dz_scale.exp) + dz.0,
+                    libm::ldexp(z_m_im, -dz_scale.exp) + dz.1,
+                );
+                if closes_cycle || z_scaled.0 * z_scaled.0 + z_scaled.1 * z_scaled.1 < dz_mag_sq {
+                    if !rho_re.is_finite() || !rho_im.is_finite() {
+                        overflowed = true;
+                        break 'iterate None;
+                    }
+                    // δz keeps its unit; δρ becomes the full ρ, which is not small
+                    dz = z_scaled;
+                    rho_scale = Scale::new(libm::frexp(max_abs((rho_re, rho_im))).1);
+                    drho = (
+                        libm::ldexp(rho_re, -rho_scale.exp),
+                        libm::ldexp(rho_im, -rho_scale.exp),
+                    );
+                    cross = cross_factor(&dz_scale, &rho_scale);
+                    m = 0;
+                    stats.rebase_count += 1;
+                    continue;
+                }
+            }
+
+            // 4. Try BLA acceleration. Validity and the step itself run in HDRFloat,
+            // since the coefficients can exceed f64 range.
+            let dz_mag_sq_hdr = {
+                let mut h = HDRFloat::from_f64(dz_mag_sq);
+                if !h.is_zero() {
+                    h.exp = h.exp.saturating_add(dz_scale.exp.saturating_mul(2));
+                }
+                h
+            };
+            if let Some(bla) = bla_table.find_valid(m, &dz_mag_sq_hdr, bla_table.dc_max()) {
+                // Apply BLA: δz_new = A·δz + B·δc
+                let dz_hdr = dz_scale.unscale_complex(dz);
+                let new_dz = bla.a.mul(&dz_hdr).add(&bla.b.mul(&delta_c));
+
+                // Re-center the δz unit on the result
+                if let Some(exp) = leading_exp_hdr(&new_dz) {
+                    dz_scale = Scale::new(exp);
+                    dc = dz_scale.scale_complex(&delta_c);
+                    cross = cross_factor(&dz_scale, &rho_scale);
+                    gate = rebase_gate(&dz_scale);
+                }
+                dz = dz_scale.scale_complex(&new_dz);
+
+                stats.bla_iterations += bla.l;
+                stats.total_iterations += bla.l;
+                m += bla.l as usize;
+                n += bla.l;
+            } else {
+                // 5. Standard delta iteration, in units of 2^s:
+                // dz' = 2·Z·dz + 2^s·dz² + dc
+                let old_dz = dz;
+                let new_dz_re = 2.0 * (z_m_re * dz.0 - z_m_im * dz.1)
+                    + dz_scale.factor * (dz.0 * dz.0 - dz.1 * dz.1)
+                    + dc.0;
+                let new_dz_im = 2.0 * (z_m_re * dz.1 + z_m_im * dz.0)
+                    + dz_scale.factor * 2.0 * dz.0 * dz.1
+                    + dc.1;
+                dz = (new_dz_re, new_dz_im);
+
+                // Derivative delta, in units of 2^r:
+                // drho' = 2·Z·drho + 2^(s-r)·2·dz·Der + 2^s·2·dz·drho
+                let new_drho_re = 2.0 * (z_m_re * drho.0 - z_m_im * drho.1)
+                    + cross * 2.0 * (old_dz.0 * der_m_re - old_dz.1 * der_m_im)
+                    + dz_scale.factor * 2.0 * (old_dz.0 * drho.0 - old_dz.1 * drho.1);
+                let new_drho_im = 2.0 * (z_m_re * drho.1 + z_m_im * drho.0)
+                    + cross * 2.0 * (old_dz.0 * der_m_im + old_dz.1 * der_m_re)
+                    + dz_scale.factor * 2.0 * (old_dz.0 * drho.1 + old_dz.1 * drho.0);
+                drho = (new_drho_re, new_drho_im);
+
+                // Renormalize either unit once its value drifts
+                if let Some(next) = dz_scale.drifted(max_abs(dz)) {
+                    dz = dz_scale.convert(dz, &next);
+                    dz_scale = next;
+                    dc = dz_scale.scale_complex(&delta_c);
+                    cross = cross_factor(&dz_scale, &rho_scale);
+                    gate = rebase_gate(&dz_scale);
+                }
+                if let Some(next) = rho_scale.drifted(max_abs(drho)) {
+                    drho = rho_scale.convert(drho, &next);
+                    rho_scale = next;
+                    cross = cross_factor(&dz_scale, &rho_scale);
+                }
+                if !is_finite(dz) || !is_finite(drho) {
+                    overflowed = true;
+                    break 'iterate None;
+                }
+
+                stats.total_iterations += 1;
+                m += 1;
+                n += 1;
+            }
+        }
+        None
+    };
+    *scaled = PixelState {
+        dz: ScaledDelta {
+            value: dz,
+            unit: dz_scale,
+        },
+        drho: ScaledDelta {
+            value: drho,
+            unit: rho_scale,
+        },
+        m,
+        n,
+        glitched,
+        stats,
+        ..*scaled
+    };
+
+    if overflowed {
+        // The derivative outgrew f64: redo the pixel in HDRFloat, counting
+        // the iterations spent so far
+        let mut hdr = PixelState {
+            stats,
+            ..PixelState::new(HDRComplex::ZERO)
+        };
+        let data = continue_pixel_hdr_bla(orbit, bla_table, delta_c, &mut hdr, run);
+        state.0 = ScaledProgress::Hdr(hdr);
+        return data;
+    }
+    if escaped.is_some() || n < run.max_iterations {
+        return escaped;
+    }
+
+    Some(run.options.finish_interior(
+        orbit,
+        &delta_c,
+        dz_scale.unscale_complex(dz),
+        m,
+        scaled.unfinished(run.max_iterations),
+        &mut scaled.stats,
+    ))
+}
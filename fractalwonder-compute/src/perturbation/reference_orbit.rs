@@ -16,6 +16,9 @@ pub struct ReferenceOrbit {
     pub derivative: Vec<(f64, f64)>,
     /// Iteration at which reference escaped (None if never escaped)
     pub escaped_at: Option<u32>,
+    /// Set when the orbit holds one period of a cycle plus the point closing
+    /// it, which is (almost) zero; kernels rebase there instead of stepping on.
+    pub period: Option<u32>,
 }
 
 impl ReferenceOrbit {
@@ -24,23 +27,141 @@ impl ReferenceOrbit {
     /// The orbit is computed at full precision but stored as f64
     /// since orbit values are bounded by escape radius (256).
     pub fn compute(c_ref: &(BigFloat, BigFloat), max_iterations: u32) -> Self {
+        let mut stream = ReferenceOrbitStream::new(c_ref, max_iterations);
+        let chunk = stream.next_chunk(max_iterations);
+        Self {
+            c_ref: stream.c_ref(),
+            orbit: chunk.orbit,
+            derivative: chunk.derivative,
+            escaped_at: stream.escaped_at(),
+            period: None,
+        }
+    }
+
+    /// True at the closing point of a periodic orbit, where iteration has to
+    /// continue from Z_0 by rebasing.
+    #[inline]
+    pub fn closes_cycle_at(&self, m: usize) -> bool {
+        self.period == Some(m as u32)
+    }
+}
+
+/// A run of consecutive orbit points produced by `ReferenceOrbitStream`.
+#[derive(Clone, Debug, Default)]
+pub struct OrbitChunk {
+    /// Iteration index of the first point in the chunk
+    pub start: u32,
+    /// Orbit values X_n for n in start..start + len
+    pub orbit: Vec<(f64, f64)>,
+    /// Derivative values Der_n for the same iterations
+    pub derivative: Vec<(f64, f64)>,
+}
+
+impl OrbitChunk {
+    /// Number of points in the chunk.
+    pub fn len(&self) -> usize {
+        self.orbit.len()
+    }
+
+    /// Check if the chunk holds no points.
+    pub fn is_empty(&self) -> bool {
+        self.orbit.is_empty()
+    }
+
+    /// Iteration index one past the last point.
+    pub fn end(&self) -> u32 {
+        self.start + self.orbit.len() as u32
+    }
+}
+
+/// Incremental reference orbit computation.
+///
+/// Yields the orbit in chunks so the caller can report progress and hand
+/// the finished prefix to other workers while the tail is still running.
+/// Concatenating every chunk gives exactly the `ReferenceOrbit::compute`
+/// result for the same inputs.
+pub struct ReferenceOrbitStream {
+    c_ref: (BigFloat, BigFloat),
+    max_iterations: u32,
+    /// Index of the next point to produce
+    n: u32,
+    x: BigFloat,
+    y: BigFloat,
+    der_x: BigFloat,
+    der_y: BigFloat,
+    escaped_at: Option<u32>,
+    finished: bool,
+}
+
+impl ReferenceOrbitStream {
+    /// Start a reference orbit at c_ref; nothing is computed until `next_chunk`.
+    pub fn new(c_ref: &(BigFloat, BigFloat), max_iterations: u32) -> Self {
         let precision = c_ref.0.precision_bits();
-        let mut orbit = Vec::with_capacity(max_iterations as usize);
-        let mut derivative = Vec::with_capacity(max_iterations as usize);
+        Self {
+            c_ref: c_ref.clone(),
+            max_iterations,
+            n: 0,
+            x: BigFloat::zero(precision),
+            y: BigFloat::zero(precision),
+            // Derivative: Der_0 = 0
+            der_x: BigFloat::zero(precision),
+            der_y: BigFloat::zero(precision),
+            escaped_at: None,
+            finished: max_iterations == 0,
+        }
+    }
+
+    /// Reference point C as f64.
+    pub fn c_ref(&self) -> (f64, f64) {
+        (self.c_ref.0.to_f64(), self.c_ref.1.to_f64())
+    }
+
+    /// Iteration limit the stream was started with.
+    pub fn max_iterations(&self) -> u32 {
+        self.max_iterations
+    }
+
+    /// Number of orbit points produced so far.
+    pub fn computed(&self) -> u32 {
+        self.n
+    }
 
-        let mut x = BigFloat::zero(precision);
-        let mut y = BigFloat::zero(precision);
-        // Derivative: Der_0 = 0
-        let mut der_x = BigFloat::zero(precision);
-        let mut der_y = BigFloat::zero(precision);
+    /// True once the orbit escaped, overflowed or hit `max_iterations`.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
 
+    /// Iteration at which the reference escaped, once known.
+    pub fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    /// Compute up to `max_len` further points.
+    ///
+    /// Returns an empty chunk once the stream is finished.
+    pub fn next_chunk(&mut self, max_len: u32) -> OrbitChunk {
+        let start = self.n;
+        let len = max_len.min(self.max_iterations - start) as usize;
+        let mut chunk = OrbitChunk {
+            start,
+            orbit: Vec::with_capacity(len),
+            derivative: Vec::with_capacity(len),
+        };
+        if self.finished {
+            return chunk;
+        }
+
+        let precision = self.c_ref.0.precision_bits();
         let escape_radius_sq = BigFloat::with_precision(65536.0, precision);
         let one = BigFloat::with_precision(1.0, precision);
         let two = BigFloat::with_precision(2.0, precision);
+        let end = start + len as u32;
 
-        let mut escaped_at = None;
+        while self.n < end {
+            let n = self.n;
+            let (x, y) = (&self.x, &self.y);
+            let (der_x, der_y) = (&self.der_x, &self.der_y);
 
-        for n in 0..max_iterations {
             // Convert to f64 for storage
             let orbit_val = (x.to_f64(), y.to_f64());
             let der_val = (der_x.to_f64(), der_y.to_f64());
@@ -48,44 +169,41 @@ impl ReferenceOrbit {
             // Check for f64 overflow in derivative (can exceed f64 range at deep zooms
             // where derivative grows as ~512^n after n iterations)
             if !der_val.0.is_finite() || !der_val.1.is_finite() {
-                escaped_at = Some(n);
-                break;
+                self.escaped_at = Some(n);
+                self.finished = true;
+                return chunk;
             }
 
             // Store current Z_n and Der_n
-            orbit.push(orbit_val);
-            derivative.push(der_val);
+            chunk.orbit.push(orbit_val);
+            chunk.derivative.push(der_val);
+            self.n += 1;
 
             // Check escape: |z|^2 > 65536
-            let x_sq = x.mul(&x);
-            let y_sq = y.mul(&y);
-            if x_sq.add(&y_sq).gt(&escape_radius_sq) {
-                escaped_at = Some(n);
-                break;
+            let z_sq = BigFloat::complex_square(x, y);
+            if z_sq.norm_sq.gt(&escape_radius_sq) {
+                self.escaped_at = Some(n);
+                self.finished = true;
+                return chunk;
             }
 
             // Derivative update: Der' = 2*Z*Der + 1
             // (der_x + i*der_y)' = 2*(x + i*y)*(der_x + i*der_y) + 1
             // Real: 2*(x*der_x - y*der_y) + 1
             // Imag: 2*(x*der_y + y*der_x)
-            let new_der_x = two.mul(&x.mul(&der_x).sub(&y.mul(&der_y))).add(&one);
-            let new_der_y = two.mul(&x.mul(&der_y).add(&y.mul(&der_x)));
+            let new_der_x = two.mul(&x.mul(der_x).sub(&y.mul(der_y))).add(&one);
+            let new_der_y = two.mul(&x.mul(der_y).add(&y.mul(der_x)));
 
             // z = z^2 + c
-            let new_x = x_sq.sub(&y_sq).add(&c_ref.0);
-            let new_y = two.mul(&x).mul(&y).add(&c_ref.1);
-
-            x = new_x;
-            y = new_y;
-            der_x = new_der_x;
-            der_y = new_der_y;
+            self.x = z_sq.re.add(&self.c_ref.0);
+            self.y = z_sq.im.add(&self.c_ref.1);
+            self.der_x = new_der_x;
+            self.der_y = new_der_y;
         }
 
-        Self {
-            c_ref: (c_ref.0.to_f64(), c_ref.1.to_f64()),
-            orbit,
-            derivative,
-            escaped_at,
+        if self.n == self.max_iterations {
+            self.finished = true;
         }
+        chunk
     }
 }
@@ -0,0 +1,220 @@
+//! Two-lane f64 vectors for the lane-parallel perturbation kernel.
+//!
+//! Backed by SSE2 on x86_64, NEON on aarch64 and simd128 on wasm32 when the
+//! target enables it; other targets use `ScalarF64x2`. Only add, sub and mul
+//! are provided, each a single IEEE operation per lane, so a kernel written
+//! against `F64Lanes` rounds exactly like the same expression on scalars.
+
+/// Pixels iterated together by one vector.
+pub(crate) const LANES: usize = 2;
+
+/// A vector of `LANES` f64 values with lane-wise arithmetic.
+pub(crate) trait F64Lanes: Copy {
+    fn splat(v: f64) -> Self;
+    fn from_array(v: [f64; LANES]) -> Self;
+    fn to_array(self) -> [f64; LANES];
+    fn add(self, other: Self) -> Self;
+    fn sub(self, other: Self) -> Self;
+    fn mul(self, other: Self) -> Self;
+}
+
+/// Portable fallback, also used by tests to check the native backend.
+#[cfg_attr(not(test), allow(dead_code))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScalarF64x2([f64; LANES]);
+
+impl F64Lanes for ScalarF64x2 {
+    #[inline]
+    fn splat(v: f64) -> Self {
+        Self([v, v])
+    }
+
+    #[inline]
+    fn from_array(v: [f64; LANES]) -> Self {
+        Self(v)
+    }
+
+    #[inline]
+    fn to_array(self) -> [f64; LANES] {
+        self.0
+    }
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self([self.0[0] + other.0[0], self.0[1] + other.0[1]])
+    }
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self([self.0[0] - other.0[0], self.0[1] - other.0[1]])
+    }
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self([self.0[0] * other.0[0], self.0[1] * other.0[1]])
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod native {
+    use super::{F64Lanes, LANES};
+    use core::arch::x86_64::{
+        __m128d, _mm_add_pd, _mm_cvtsd_f64, _mm_mul_pd, _mm_set1_pd, _mm_set_pd, _mm_sub_pd,
+        _mm_unpackhi_pd,
+    };
+
+    /// SSE2 vector.
+    #[derive(Clone, Copy, Debug)]
+    pub(crate) struct NativeF64x2(__m128d);
+
+    impl F64Lanes for NativeF64x2 {
+        #[inline]
+        fn splat(v: f64) -> Self {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe { Self(_mm_set1_pd(v)) }
+        }
+
+        #[inline]
+        fn from_array(v: [f64; LANES]) -> Self {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe { Self(_mm_set_pd(v[1], v[0])) }
+        }
+
+        #[inline]
+        fn to_array(self) -> [f64; LANES] {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe {
+                [
+                    _mm_cvtsd_f64(self.0),
+                    _mm_cvtsd_f64(_mm_unpackhi_pd(self.0, self.0)),
+                ]
+            }
+        }
+
+        #[inline]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe { Self(_mm_add_pd(self.0, other.0)) }
+        }
+
+        #[inline]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe { Self(_mm_sub_pd(self.0, other.0)) }
+        }
+
+        #[inline]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: SSE2 is part of the x86_64 baseline.
+            unsafe { Self(_mm_mul_pd(self.0, other.0)) }
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod native {
+    use super::{F64Lanes, LANES};
+    use core::arch::aarch64::{
+        float64x2_t, vaddq_f64, vdupq_n_f64, vgetq_lane_f64, vmulq_f64, vsetq_lane_f64, vsubq_f64,
+    };
+
+    /// NEON vector.
+    #[derive(Clone, Copy, Debug)]
+    pub(crate) struct NativeF64x2(float64x2_t);
+
+    impl F64Lanes for NativeF64x2 {
+        #[inline]
+        fn splat(v: f64) -> Self {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { Self(vdupq_n_f64(v)) }
+        }
+
+        #[inline]
+        fn from_array(v: [f64; LANES]) -> Self {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { Self(vsetq_lane_f64::<1>(v[1], vdupq_n_f64(v[0]))) }
+        }
+
+        #[inline]
+        fn to_array(self) -> [f64; LANES] {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { [vgetq_lane_f64::<0>(self.0), vgetq_lane_f64::<1>(self.0)] }
+        }
+
+        #[inline]
+        fn add(self, other: Self) -> Self {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { Self(vaddq_f64(self.0, other.0)) }
+        }
+
+        #[inline]
+        fn sub(self, other: Self) -> Self {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { Self(vsubq_f64(self.0, other.0)) }
+        }
+
+        #[inline]
+        fn mul(self, other: Self) -> Self {
+            // SAFETY: NEON is part of the aarch64 baseline.
+            unsafe { Self(vmulq_f64(self.0, other.0)) }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod native {
+    use super::{F64Lanes, LANES};
+    use core::arch::wasm32::{
+        f64x2, f64x2_add, f64x2_extract_lane, f64x2_mul, f64x2_splat, f64x2_sub, v128,
+    };
+
+    /// simd128 vector, enabled through `.cargo/config.toml`.
+    #[derive(Clone, Copy, Debug)]
+    pub(crate) struct NativeF64x2(v128);
+
+    impl F64Lanes for NativeF64x2 {
+        #[inline]
+        fn splat(v: f64) -> Self {
+            Self(f64x2_splat(v))
+        }
+
+        #[inline]
+        fn from_array(v: [f64; LANES]) -> Self {
+            Self(f64x2(v[0], v[1]))
+        }
+
+        #[inline]
+        fn to_array(self) -> [f64; LANES] {
+            [
+                f64x2_extract_lane::<0>(self.0),
+                f64x2_extract_lane::<1>(self.0),
+            ]
+        }
+
+        #[inline]
+        fn add(self, other: Self) -> Self {
+            Self(f64x2_add(self.0, other.0))
+        }
+
+        #[inline]
+        fn sub(self, other: Self) -> Self {
+            Self(f64x2_sub(self.0, other.0))
+        }
+
+        #[inline]
+        fn mul(self, other: Self) -> Self {
+            Self(f64x2_mul(self.0, other.0))
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+mod native {
+    pub(crate) type NativeF64x2 = super::ScalarF64x2;
+}
+
+pub(crate) use native::NativeF64x2;
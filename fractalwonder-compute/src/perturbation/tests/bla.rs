@@ -1,8 +1,10 @@
 use super::helpers::TEST_TAU_SQ;
 use crate::{
-    compute_pixel_perturbation, compute_pixel_perturbation_hdr_bla, BlaTable, ReferenceOrbit,
+    compute_pixel_perturbation, compute_pixel_perturbation_floatexp_bla,
+    compute_pixel_perturbation_hdr_bla, compute_pixel_perturbation_scaled_bla, BlaTable,
+    ReferenceOrbit,
 };
-use fractalwonder_core::{BigFloat, HDRComplex, HDRFloat};
+use fractalwonder_core::{BigFloat, ComplexDelta, FloatExpComplex, HDRComplex, HDRFloat};
 
 #[test]
 fn bla_version_matches_non_bla_for_escaping_point() {
@@ -112,3 +114,109 @@ fn bla_handles_rebasing() {
     );
     assert_eq!(result_no_bla.iterations, result_bla.iterations);
 }
+
+#[test]
+fn floatexp_bla_matches_hdr_bla_for_many_deltas() {
+    let c_ref = (BigFloat::with_precision(-0.5, 128), BigFloat::zero(128));
+    let orbit = ReferenceOrbit::compute(&c_ref, 1000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.2));
+
+    for (re, im) in [(0.01, 0.01), (-0.005, 0.002), (0.1, -0.05), (0.0, 0.001)] {
+        let (hdr, hdr_stats) = compute_pixel_perturbation_hdr_bla(
+            &orbit,
+            &bla_table,
+            HDRComplex::from_f64_pair(re, im),
+            1000,
+            TEST_TAU_SQ,
+        );
+        let (fe, fe_stats) = compute_pixel_perturbation_floatexp_bla(
+            &orbit,
+            &bla_table,
+            FloatExpComplex::from_f64_pair(re, im),
+            1000,
+            TEST_TAU_SQ,
+        );
+
+        assert_eq!(hdr.escaped, fe.escaped, "delta ({re}, {im})");
+        assert_eq!(hdr.iterations, fe.iterations, "delta ({re}, {im})");
+        assert_eq!(hdr_stats.bla_iterations, fe_stats.bla_iterations);
+    }
+}
+
+#[test]
+fn scaled_bla_matches_hdr_bla_for_many_deltas() {
+    let c_ref = (BigFloat::with_precision(-0.5, 128), BigFloat::zero(128));
+    let orbit = ReferenceOrbit::compute(&c_ref, 1000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.2));
+
+    for (re, im) in [(0.01, 0.01), (-0.005, 0.002), (0.1, -0.05), (0.0, 0.001)] {
+        let delta_c = HDRComplex::from_f64_pair(re, im);
+        let (hdr, hdr_stats) =
+            compute_pixel_perturbation_hdr_bla(&orbit, &bla_table, delta_c, 1000, TEST_TAU_SQ);
+        let (scaled, scaled_stats) =
+            compute_pixel_perturbation_scaled_bla(&orbit, &bla_table, delta_c, 1000, TEST_TAU_SQ);
+
+        assert_eq!(hdr.escaped, scaled.escaped, "delta ({re}, {im})");
+        assert_eq!(hdr.iterations, scaled.iterations, "delta ({re}, {im})");
+        assert_eq!(hdr_stats.bla_iterations, scaled_stats.bla_iterations);
+    }
+}
+
+#[test]
+fn scaled_bla_matches_hdr_bla_far_below_f64_range() {
+    // Z = 2 is a repelling fixed point of c = -2: δz grows 4× per iteration,
+    // so the δz unit is renormalized many times on the way up.
+    let c_ref = (BigFloat::with_precision(-2.0, 128), BigFloat::zero(128));
+    let orbit = ReferenceOrbit::compute(&c_ref, 3000);
+
+    for exponent in [400, 2000, 4800] {
+        let precision = exponent * 4;
+        let tiny = BigFloat::from_string(&format!("1e-{exponent}"), precision).unwrap();
+        let tiny = HDRFloat::from_bigfloat(&tiny);
+        let bla_table = BlaTable::compute(&orbit, &tiny);
+
+        for delta_c in [
+            HDRComplex {
+                re: tiny.neg(),
+                im: HDRFloat::ZERO,
+            },
+            HDRComplex {
+                re: HDRFloat::ZERO,
+                im: tiny.mul_f64(3.0),
+            },
+        ] {
+            let (hdr, _) =
+                compute_pixel_perturbation_hdr_bla(&orbit, &bla_table, delta_c, 3000, TEST_TAU_SQ);
+            let (scaled, _) = compute_pixel_perturbation_scaled_bla(
+                &orbit,
+                &bla_table,
+                delta_c,
+                3000,
+                TEST_TAU_SQ,
+            );
+
+            assert_eq!(hdr.escaped, scaled.escaped, "1e-{exponent}");
+            assert_eq!(hdr.iterations, scaled.iterations, "1e-{exponent}");
+            assert_eq!(hdr.glitched, scaled.glitched, "1e-{exponent}");
+        }
+    }
+}
+
+#[test]
+fn scaled_bla_keeps_interior_points_interior() {
+    // Deep inside the period-2 disk, δz stays tiny for the whole orbit
+    let c_ref = (BigFloat::with_precision(-1.0, 128), BigFloat::zero(128));
+    let orbit = ReferenceOrbit::compute(&c_ref, 500);
+    let tiny = HDRFloat::from_bigfloat(&BigFloat::from_string("1e-1000", 4000).unwrap());
+    let bla_table = BlaTable::compute(&orbit, &tiny);
+    let delta_c = HDRComplex { re: tiny, im: tiny };
+
+    let (hdr, _) =
+        compute_pixel_perturbation_hdr_bla(&orbit, &bla_table, delta_c, 500, TEST_TAU_SQ);
+    let (scaled, _) =
+        compute_pixel_perturbation_scaled_bla(&orbit, &bla_table, delta_c, 500, TEST_TAU_SQ);
+
+    assert!(!scaled.escaped);
+    assert_eq!(hdr.iterations, scaled.iterations);
+    assert_eq!(hdr.period, scaled.period);
+}
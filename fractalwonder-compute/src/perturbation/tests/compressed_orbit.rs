@@ -0,0 +1,200 @@
+use super::helpers::TEST_TAU_SQ;
+use crate::{
+    compute_pixel_perturbation, compute_pixel_perturbation_hdr_bla, BlaTable, CompressedOrbit,
+    OrbitSource, OrbitWindow, ReferenceOrbit, ORBIT_COMPRESSION_TOLERANCE,
+};
+use fractalwonder_core::{BigFloat, ComplexDelta, F64Complex, HDRComplex, HDRFloat};
+
+fn orbit_at(c: (f64, f64), max_iter: u32) -> ReferenceOrbit {
+    let c_ref = (
+        BigFloat::with_precision(c.0, 128),
+        BigFloat::with_precision(c.1, 128),
+    );
+    ReferenceOrbit::compute(&c_ref, max_iter)
+}
+
+fn within_tolerance(a: (f64, f64), b: (f64, f64)) -> bool {
+    (a.0 - b.0).hypot(a.1 - b.1) <= ORBIT_COMPRESSION_TOLERANCE * b.0.hypot(b.1)
+}
+
+#[test]
+fn zero_tolerance_is_lossless() {
+    let orbit = orbit_at((-1.401155, 0.0), 5000);
+    let compressed = CompressedOrbit::with_tolerance(&orbit, &HDRFloat::from_f64(1e-3), 0.0);
+    let restored = compressed.decompress();
+
+    assert_eq!(compressed.period(), None);
+    assert_eq!(restored.orbit, orbit.orbit);
+    assert_eq!(restored.derivative, orbit.derivative);
+    assert_eq!(restored.escaped_at, orbit.escaped_at);
+}
+
+#[test]
+fn regenerated_points_stay_within_tolerance() {
+    let orbit = orbit_at((-1.401155, 0.0), 20_000);
+    let compressed = CompressedOrbit::compress(&orbit, &HDRFloat::from_f64(1e-3));
+
+    assert_eq!(compressed.len(), orbit.orbit.len());
+    assert!(
+        compressed.waypoint_count() * 4 < compressed.len(),
+        "{} waypoints for {} points",
+        compressed.waypoint_count(),
+        compressed.len()
+    );
+    for (n, point) in compressed.iter().enumerate() {
+        assert!(within_tolerance(point.z, orbit.orbit[n]), "Z_{n}");
+        assert!(within_tolerance(point.der, orbit.derivative[n]), "Der_{n}");
+    }
+}
+
+#[test]
+fn random_access_matches_sequential_regeneration() {
+    let orbit = orbit_at((-1.401155, 0.0), 3000);
+    let compressed = CompressedOrbit::compress(&orbit, &HDRFloat::from_f64(1e-3));
+    let sequential: Vec<_> = compressed.iter().collect();
+
+    for n in [0, 1, 17, 500, 1234, 2999] {
+        assert_eq!(compressed.get(n), sequential[n], "point {n}");
+        assert_eq!(compressed.iter_from(n).next(), Some(sequential[n]));
+    }
+    assert_eq!(compressed.iter_from(3000).next(), None);
+}
+
+#[test]
+fn escaped_reference_keeps_every_iteration() {
+    let orbit = orbit_at((0.5, 0.5), 1000);
+    assert!(orbit.escaped_at.is_some());
+
+    let compressed = CompressedOrbit::compress(&orbit, &HDRFloat::from_f64(1e-3));
+    assert_eq!(compressed.period(), None);
+    assert_eq!(compressed.len(), orbit.orbit.len());
+    assert_eq!(compressed.escaped_at(), orbit.escaped_at);
+}
+
+#[test]
+fn periodic_reference_is_stored_once() {
+    // c = -1 cycles 0 → -1 → 0
+    let orbit = orbit_at((-1.0, 0.0), 100_000);
+    let compressed = CompressedOrbit::compress(&orbit, &HDRFloat::from_f64(0.5));
+
+    assert_eq!(compressed.period(), Some(2));
+    assert_eq!(compressed.len(), 3);
+    assert!(compressed.memory_bytes() < 1024);
+
+    let restored = compressed.decompress();
+    assert_eq!(restored.orbit, orbit.orbit[..3]);
+    assert_eq!(restored.period, Some(2));
+    assert!(restored.closes_cycle_at(2));
+}
+
+#[test]
+fn folded_orbit_gives_same_pixels_as_full_orbit() {
+    let max_iter = 2000;
+    let orbit = orbit_at((-1.0, 0.0), max_iter);
+    let dc_max = HDRFloat::from_f64(0.6);
+    let folded = CompressedOrbit::compress(&orbit, &dc_max);
+    let folded_orbit = folded.decompress();
+    assert_eq!(folded.period(), Some(2));
+
+    // Period-2 disk, its period-4 and period-6 bulbs, and points outside
+    for dc in [
+        (0.05, 0.05),
+        (-0.31, 0.0),
+        (0.12, 0.23),
+        (0.3, 0.5),
+        (-1.0, 0.2),
+    ] {
+        let full = compute_pixel_perturbation(
+            &orbit,
+            F64Complex::from_f64_pair(dc.0, dc.1),
+            max_iter,
+            TEST_TAU_SQ,
+        );
+        let result = compute_pixel_perturbation(
+            &folded_orbit,
+            F64Complex::from_f64_pair(dc.0, dc.1),
+            max_iter,
+            TEST_TAU_SQ,
+        );
+        assert_eq!(result.escaped, full.escaped, "escape at {dc:?}");
+        assert_eq!(result.iterations, full.iterations, "iterations at {dc:?}");
+        assert_eq!(result.period, full.period, "period at {dc:?}");
+        assert!(
+            (result.distance_log2 - full.distance_log2).abs() < 1e-3,
+            "distance at {dc:?}: {} vs {}",
+            result.distance_log2,
+            full.distance_log2
+        );
+
+        let bla = BlaTable::from_compressed(&folded, &dc_max);
+        let delta_c = HDRComplex {
+            re: HDRFloat::from_f64(dc.0),
+            im: HDRFloat::from_f64(dc.1),
+        };
+        let (with_bla, _) =
+            compute_pixel_perturbation_hdr_bla(&folded_orbit, &bla, delta_c, max_iter, TEST_TAU_SQ);
+        assert_eq!(with_bla.escaped, full.escaped, "BLA escape at {dc:?}");
+        assert_eq!(
+            with_bla.iterations, full.iterations,
+            "BLA iterations at {dc:?}"
+        );
+    }
+}
+
+#[test]
+fn bla_from_compressed_matches_full_orbit() {
+    let orbit = orbit_at((-1.401155, 0.0), 4000);
+    let dc_max = HDRFloat::from_f64(1e-6);
+    let compressed = CompressedOrbit::with_tolerance(&orbit, &dc_max, 0.0);
+
+    let expected = BlaTable::compute(&orbit, &dc_max);
+    let table = BlaTable::from_compressed(&compressed, &dc_max);
+    assert_eq!(table.num_levels, expected.num_levels);
+    assert_eq!(table.level_offsets, expected.level_offsets);
+    for (entry, expected) in table.entries.iter().zip(&expected.entries) {
+        assert_eq!(entry.l, expected.l);
+        assert_eq!(entry.a.re.to_f64(), expected.a.re.to_f64());
+        assert_eq!(entry.r_sq.to_f64(), expected.r_sq.to_f64());
+    }
+}
+
+#[test]
+fn window_reads_match_decompressed_orbit() {
+    let orbit = orbit_at((-1.401155, 0.0), 30_000);
+    let compressed = CompressedOrbit::compress(&orbit, &HDRFloat::from_f64(1e-3));
+    let restored = compressed.decompress();
+    let window = OrbitWindow::new(&compressed);
+
+    assert_eq!(window.len(), restored.orbit.len());
+    // Jump between blocks so some are evicted and read again
+    for n in [
+        0, 29_999, 1, 4095, 4096, 12_000, 25_000, 20_000, 0, 8191, 29_000,
+    ] {
+        let point = window.point(n);
+        assert_eq!(point.z, restored.orbit[n], "Z_{n}");
+        assert_eq!(point.der, restored.derivative[n], "Der_{n}");
+    }
+}
+
+#[test]
+fn window_gives_same_pixels_as_decompressed_orbit() {
+    let max_iter = 20_000;
+    let orbit = orbit_at((-1.401155, 0.0), max_iter);
+    let dc_max = HDRFloat::from_f64(1e-6);
+    let compressed = CompressedOrbit::compress(&orbit, &dc_max);
+    let restored = compressed.decompress();
+    let window = OrbitWindow::new(&compressed);
+    let bla = BlaTable::from_compressed(&compressed, &dc_max);
+
+    for dc in [(1e-7, 0.0), (-3e-7, 2e-7), (5e-7, -5e-7)] {
+        let delta_c = HDRComplex {
+            re: HDRFloat::from_f64(dc.0),
+            im: HDRFloat::from_f64(dc.1),
+        };
+        let (expected, _) =
+            compute_pixel_perturbation_hdr_bla(&restored, &bla, delta_c, max_iter, TEST_TAU_SQ);
+        let (result, _) =
+            compute_pixel_perturbation_hdr_bla(&window, &bla, delta_c, max_iter, TEST_TAU_SQ);
+        assert_eq!(result, expected, "pixel at {dc:?}");
+    }
+}
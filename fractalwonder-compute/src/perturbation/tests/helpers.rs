@@ -26,6 +26,11 @@ pub fn compute_direct(c: &(BigFloat, BigFloat), max_iter: u32) -> MandelbrotData
                 final_z_norm_sq: z_mag_sq as f32,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             };
         }
         let two = BigFloat::with_precision(2.0, precision);
@@ -43,5 +48,10 @@ pub fn compute_direct(c: &(BigFloat, BigFloat), max_iter: u32) -> MandelbrotData
         final_z_norm_sq: 0.0,
         surface_normal_re: 0.0,
         surface_normal_im: 0.0,
+        orbit_trap: 0.0,
+        stripe_avg: 0.0,
+        distance_log2: 0.0,
+        atom_domain: 0,
+        period: 0,
     }
 }
@@ -0,0 +1,169 @@
+use super::helpers::TEST_TAU_SQ;
+use crate::perturbation::tile::{render_tile_f64, TileConfig, TileStats};
+use crate::{
+    compute_pixel_perturbation, compute_pixel_perturbation_f64_bla,
+    compute_pixel_perturbation_hdr_bla, BlaTable, ReferenceOrbit,
+};
+use fractalwonder_core::{
+    BigFloat, ComplexDelta, ComputeData, DeltaStep, F64Complex, HDRComplex, HDRFloat,
+    MandelbrotData,
+};
+
+fn origin_orbit(max_iter: u32) -> ReferenceOrbit {
+    let c_ref = (BigFloat::zero(128), BigFloat::zero(128));
+    ReferenceOrbit::compute(&c_ref, max_iter)
+}
+
+fn compute(c: (f64, f64), max_iter: u32) -> MandelbrotData {
+    let orbit = origin_orbit(max_iter);
+    compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(c.0, c.1),
+        max_iter,
+        TEST_TAU_SQ,
+    )
+}
+
+#[test]
+fn detects_period_of_hyperbolic_components() {
+    // Main cardioid, period-2 disk, upper period-3 bulb
+    for (c, period) in [((-0.1, 0.1), 1), ((-1.05, 0.05), 2), ((-0.1226, 0.7449), 3)] {
+        let result = compute(c, 2000);
+        assert!(!result.escaped, "{c:?} should be interior");
+        assert_eq!(result.period, period, "period at {c:?}");
+    }
+}
+
+#[test]
+fn escaped_points_have_no_period() {
+    let result = compute((0.5, 0.5), 1000);
+    assert!(result.escaped);
+    assert_eq!(result.period, 0);
+}
+
+#[test]
+fn interior_distance_bounds_true_distance() {
+    // c = -1 is the center of the period-2 disk of radius 1/4.
+    // The interior estimate is at least the true distance and at most 4x it.
+    let result = compute((-1.0, 0.0), 1000);
+    assert_eq!(result.period, 2);
+    let distance = 2f64.powf(result.distance_log2 as f64);
+    assert!(
+        (0.25..=1.0).contains(&distance),
+        "interior distance = {distance}"
+    );
+}
+
+#[test]
+fn interior_distance_shrinks_towards_the_boundary() {
+    let center = compute((-1.0, 0.0), 2000);
+    let edge = compute((-1.2, 0.0), 2000);
+    assert_eq!(center.period, 2);
+    assert_eq!(edge.period, 2);
+    assert!(
+        edge.distance_log2 < center.distance_log2,
+        "edge = {}, center = {}",
+        edge.distance_log2,
+        center.distance_log2
+    );
+}
+
+#[test]
+fn atom_domain_is_iteration_of_minimal_z() {
+    // c = -1: orbit is 0, -1, 0, -1, ... so |z| is first smallest at n = 2
+    let result = compute((-1.0, 0.0), 100);
+    assert_eq!(result.atom_domain, 2);
+}
+
+#[test]
+fn interior_records_final_z() {
+    // c = -0.1 converges to the fixed point z* = (1 - √1.4) / 2
+    let result = compute((-0.1, 0.0), 1000);
+    let expected = (1.0 - 1.4f64.sqrt()) / 2.0;
+    assert!(
+        ((result.final_z_norm_sq as f64).sqrt() - expected.abs()).abs() < 1e-6,
+        "final |z|² = {}",
+        result.final_z_norm_sq
+    );
+}
+
+#[test]
+fn interior_data_matches_across_precision_paths() {
+    let orbit = origin_orbit(2000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-3));
+    let delta_c = (-1.05, 0.05);
+
+    let generic = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(delta_c.0, delta_c.1),
+        2000,
+        TEST_TAU_SQ,
+    );
+    let (f64_bla, _) =
+        compute_pixel_perturbation_f64_bla(&orbit, &bla_table, delta_c, 2000, TEST_TAU_SQ);
+    let (hdr_bla, _) = compute_pixel_perturbation_hdr_bla(
+        &orbit,
+        &bla_table,
+        HDRComplex::from_f64_pair(delta_c.0, delta_c.1),
+        2000,
+        TEST_TAU_SQ,
+    );
+
+    assert_eq!(generic.period, 2);
+    for other in [&f64_bla, &hdr_bla] {
+        assert_eq!(other.period, generic.period);
+        assert!((generic.distance_log2 - other.distance_log2).abs() < 1e-3);
+    }
+}
+
+/// A 4x4 tile inside the period-2 bulb, rendered from the origin orbit.
+fn render_bulb_tile(bla_enabled: bool, probe_interior: bool) -> (Vec<MandelbrotData>, TileStats) {
+    let orbit = origin_orbit(2000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-3));
+    let config = TileConfig {
+        size: (4, 4),
+        max_iterations: 2000,
+        tau_sq: TEST_TAU_SQ,
+        bla_enabled,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior,
+        iteration_limit: None,
+    };
+    let result = render_tile_f64(
+        &orbit,
+        Some(&bla_table),
+        (-1.05, 0.05),
+        DeltaStep::axis_aligned(1e-3, 1e-3),
+        &config,
+    );
+    let data = result
+        .data
+        .into_iter()
+        .map(|ComputeData::Mandelbrot(m)| m)
+        .collect();
+    (data, result.stats)
+}
+
+#[test]
+fn tiles_probe_interior_only_when_requested() {
+    for bla_enabled in [false, true] {
+        let (plain, _) = render_bulb_tile(bla_enabled, false);
+        let (probed, _) = render_bulb_tile(bla_enabled, true);
+
+        assert!(plain
+            .iter()
+            .all(|pixel| !pixel.escaped && pixel.period == 0));
+        assert!(probed.iter().all(|pixel| pixel.period == 2));
+    }
+}
+
+#[test]
+fn probe_iterations_are_counted() {
+    let (_, plain) = render_bulb_tile(false, false);
+    let (_, probed) = render_bulb_tile(false, true);
+
+    assert_eq!(plain.total_iterations, 16 * 2000);
+    assert!(probed.total_iterations > plain.total_iterations);
+}
@@ -3,8 +3,13 @@ mod helpers;
 mod arbitrary_precision;
 mod basic_perturbation;
 mod bla;
+mod compressed_orbit;
 mod generic_types;
 mod glitch_detection;
 mod grid;
+mod interior;
+mod orbit_path;
+mod orbit_stats;
 mod reference_orbit;
+mod simd;
 mod tile;
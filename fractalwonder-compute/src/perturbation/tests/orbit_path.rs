@@ -0,0 +1,70 @@
+use super::helpers::TEST_TAU_SQ;
+use crate::{compute_pixel_perturbation, trace_orbit_path, ReferenceOrbit};
+use fractalwonder_core::{BigFloat, ComplexDelta, HDRComplex, HDRFloat};
+
+fn origin_orbit(max_iter: u32) -> ReferenceOrbit {
+    let c_ref = (BigFloat::zero(128), BigFloat::zero(128));
+    ReferenceOrbit::compute(&c_ref, max_iter)
+}
+
+/// Direct f64 iteration of z² + c.
+fn direct_orbit(c: (f64, f64), steps: usize) -> Vec<(f64, f64)> {
+    let mut z = (0.0, 0.0);
+    let mut points = vec![z];
+    for _ in 1..steps {
+        z = (z.0 * z.0 - z.1 * z.1 + c.0, 2.0 * z.0 * z.1 + c.1);
+        points.push(z);
+    }
+    points
+}
+
+#[test]
+fn path_matches_direct_iteration_until_escape() {
+    let c = (0.5, 0.5);
+    let orbit = origin_orbit(1000);
+    let delta_c = HDRComplex::from_f64_pair(c.0, c.1);
+    let path = trace_orbit_path(&orbit, delta_c, 1000, TEST_TAU_SQ, 10_000);
+
+    assert_eq!(
+        path.data,
+        compute_pixel_perturbation(&orbit, delta_c, 1000, TEST_TAU_SQ)
+    );
+    let escaped_at = path.escaped_at().expect("c = 0.5+0.5i escapes");
+    // z_0 through the first point outside the escape radius
+    assert_eq!(path.points.len(), escaped_at as usize + 1);
+
+    let direct = direct_orbit(c, path.points.len());
+    for (point, expected) in path.points.iter().zip(&direct).take(escaped_at as usize) {
+        assert!((point.z.0 - expected.0).abs() < 1e-9);
+        assert!((point.z.1 - expected.1).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn offsets_stay_exact_below_f64_resolution() {
+    // C_ref = -0.75 with a pixel 1e-30 away: z_1 = C_ref + δc rounds to C_ref
+    let c_ref = (BigFloat::with_precision(-0.75, 256), BigFloat::zero(256));
+    let orbit = ReferenceOrbit::compute(&c_ref, 100);
+    let delta_c = HDRComplex {
+        re: HDRFloat::from_f64(1e-30),
+        im: HDRFloat::ZERO,
+    };
+    let path = trace_orbit_path(&orbit, delta_c, 100, TEST_TAU_SQ, 10_000);
+
+    assert_eq!(path.points[1].z, (-0.75, 0.0));
+    let offset_re = path.points[1].offset.0.to_f64();
+    assert!((offset_re / 1e-30 - 1.0).abs() < 1e-6, "{offset_re}");
+}
+
+#[test]
+fn interior_path_is_capped_and_reports_period() {
+    // Period-2 disk
+    let orbit = origin_orbit(5000);
+    let delta_c = HDRComplex::from_f64_pair(-1.05, 0.05);
+    let path = trace_orbit_path(&orbit, delta_c, 5000, TEST_TAU_SQ, 500);
+
+    assert_eq!(path.points.len(), 500);
+    assert_eq!(path.reference.len(), 500);
+    assert_eq!(path.escaped_at(), None);
+    assert_eq!(path.period(), Some(2));
+}
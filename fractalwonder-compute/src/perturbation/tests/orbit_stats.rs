@@ -0,0 +1,152 @@
+use super::helpers::TEST_TAU_SQ;
+use crate::perturbation::tile::{render_tile_f64, TileConfig, TileStats};
+use crate::{compute_pixel_perturbation, BlaTable, ReferenceOrbit};
+use fractalwonder_core::{
+    BigFloat, ComplexDelta, ComputeData, DeltaStep, F64Complex, HDRFloat, MandelbrotData,
+};
+
+fn origin_orbit(max_iter: u32) -> ReferenceOrbit {
+    let c_ref = (BigFloat::zero(128), BigFloat::zero(128));
+    ReferenceOrbit::compute(&c_ref, max_iter)
+}
+
+#[test]
+fn orbit_trap_is_zero_for_origin() {
+    // c = 0: z stays at the origin forever
+    let orbit = origin_orbit(100);
+    let result = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(0.0, 0.0),
+        100,
+        TEST_TAU_SQ,
+    );
+
+    assert!(!result.escaped);
+    assert!(result.orbit_trap < 1e-6, "trap = {}", result.orbit_trap);
+    assert_eq!(result.period, 1, "the origin is a fixed point");
+}
+
+#[test]
+fn orbit_trap_skips_initial_zero() {
+    // c = 2: orbit is 0, 2, 6, 38, ... so the trap is |z_1| = 2, not |z_0| = 0
+    let orbit = origin_orbit(100);
+    let result = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(2.0, 0.0),
+        100,
+        TEST_TAU_SQ,
+    );
+
+    assert!(result.escaped);
+    assert!(
+        (result.orbit_trap - 2.0).abs() < 1e-6,
+        "trap = {}",
+        result.orbit_trap
+    );
+}
+
+#[test]
+fn stripe_average_is_in_unit_range() {
+    let orbit = origin_orbit(1000);
+    let result = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(0.5, 0.5),
+        1000,
+        TEST_TAU_SQ,
+    );
+
+    assert!(result.escaped);
+    assert!(
+        (0.0..=1.0).contains(&result.stripe_avg),
+        "stripe = {}",
+        result.stripe_avg
+    );
+}
+
+#[test]
+fn distance_estimate_decreases_towards_the_set() {
+    let orbit = origin_orbit(1000);
+    let near = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(0.26, 0.0),
+        1000,
+        TEST_TAU_SQ,
+    );
+    let far = compute_pixel_perturbation(
+        &orbit,
+        F64Complex::from_f64_pair(1.5, 0.0),
+        1000,
+        TEST_TAU_SQ,
+    );
+
+    assert!(near.escaped && far.escaped);
+    assert!(
+        near.distance_log2 < far.distance_log2,
+        "near = {}, far = {}",
+        near.distance_log2,
+        far.distance_log2
+    );
+    // c = 1.5 is 1.25 from the cusp at 0.25; the estimate is within a factor of 4
+    let far_distance = 2f64.powf(far.distance_log2 as f64);
+    assert!(
+        far_distance > 1.25 / 8.0 && far_distance < 1.25 * 4.0,
+        "far distance = {far_distance}"
+    );
+}
+
+fn stats_tile_config(bla_enabled: bool, collect_orbit_stats: bool) -> TileConfig {
+    TileConfig {
+        size: (4, 4),
+        max_iterations: 1000,
+        tau_sq: TEST_TAU_SQ,
+        bla_enabled,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats,
+        probe_interior: false,
+        iteration_limit: None,
+    }
+}
+
+fn render_stats_tile(config: &TileConfig) -> (Vec<MandelbrotData>, TileStats) {
+    // Small deltas around c = -0.5, where the BLA table skips iterations
+    let c_ref = (BigFloat::with_precision(-0.5, 128), BigFloat::zero(128));
+    let orbit = ReferenceOrbit::compute(&c_ref, 1000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-10));
+    let result = render_tile_f64(
+        &orbit,
+        Some(&bla_table),
+        (1e-12, 1e-12),
+        DeltaStep::axis_aligned(1e-14, 1e-14),
+        config,
+    );
+    let data = result
+        .data
+        .into_iter()
+        .map(|ComputeData::Mandelbrot(m)| m)
+        .collect();
+    (data, result.stats)
+}
+
+#[test]
+fn tiles_skip_orbit_stats_unless_requested() {
+    let (data, stats) = render_stats_tile(&stats_tile_config(true, false));
+
+    assert!(stats.bla_iterations > 0, "BLA should still be used");
+    for pixel in &data {
+        assert_eq!(pixel.orbit_trap, 0.0);
+        assert_eq!(pixel.stripe_avg, 0.0);
+        assert_eq!(pixel.atom_domain, 0);
+    }
+}
+
+#[test]
+fn collecting_orbit_stats_turns_bla_off() {
+    let (with_bla, stats) = render_stats_tile(&stats_tile_config(true, true));
+    let (without_bla, _) = render_stats_tile(&stats_tile_config(false, true));
+
+    assert_eq!(stats.bla_iterations, 0);
+    assert!(with_bla.iter().all(|pixel| pixel.orbit_trap > 0.0));
+    // Same statistics whether or not BLA is enabled
+    assert_eq!(with_bla, without_bla);
+}
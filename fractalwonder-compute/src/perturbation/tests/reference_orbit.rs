@@ -1,4 +1,4 @@
-use crate::ReferenceOrbit;
+use crate::{ReferenceOrbit, ReferenceOrbitStream};
 use fractalwonder_core::BigFloat;
 
 #[test]
@@ -197,3 +197,62 @@ fn high_precision_orbit_differs_from_low_precision() {
         "Low precision orbit should compute"
     );
 }
+
+/// Concatenate every chunk of a stream, checking that chunks are contiguous.
+fn collect_stream(stream: &mut ReferenceOrbitStream, chunk_len: u32) -> ReferenceOrbit {
+    let mut orbit = Vec::new();
+    let mut derivative = Vec::new();
+    while !stream.is_finished() {
+        let chunk = stream.next_chunk(chunk_len);
+        assert_eq!(chunk.start as usize, orbit.len());
+        assert!(chunk.len() <= chunk_len as usize);
+        assert_eq!(chunk.end(), stream.computed());
+        orbit.extend(chunk.orbit);
+        derivative.extend(chunk.derivative);
+    }
+    assert!(stream.next_chunk(chunk_len).is_empty());
+    ReferenceOrbit {
+        c_ref: stream.c_ref(),
+        orbit,
+        derivative,
+        escaped_at: stream.escaped_at(),
+        period: None,
+    }
+}
+
+#[test]
+fn streamed_chunks_concatenate_to_full_orbit() {
+    // Near the boundary, so the orbit is long and chunk ends fall everywhere
+    let c_ref = (
+        BigFloat::from_string("-0.7436438870371587", 256).unwrap(),
+        BigFloat::from_string("0.1318259042053119", 256).unwrap(),
+    );
+    let full = ReferenceOrbit::compute(&c_ref, 5000);
+
+    for chunk_len in [1, 7, 64, 5000] {
+        let mut stream = ReferenceOrbitStream::new(&c_ref, 5000);
+        let streamed = collect_stream(&mut stream, chunk_len);
+        assert_eq!(streamed.orbit, full.orbit, "chunk_len={chunk_len}");
+        assert_eq!(streamed.derivative, full.derivative);
+        assert_eq!(streamed.escaped_at, full.escaped_at);
+    }
+}
+
+#[test]
+fn stream_stops_at_max_iterations_without_escape() {
+    let c_ref = (BigFloat::with_precision(-1.0, 128), BigFloat::zero(128));
+    let mut stream = ReferenceOrbitStream::new(&c_ref, 100);
+    let streamed = collect_stream(&mut stream, 30);
+
+    assert_eq!(stream.computed(), 100);
+    assert_eq!(streamed.orbit.len(), 100);
+    assert_eq!(streamed.escaped_at, None);
+}
+
+#[test]
+fn stream_with_zero_iterations_is_finished() {
+    let c_ref = (BigFloat::zero(128), BigFloat::zero(128));
+    let mut stream = ReferenceOrbitStream::new(&c_ref, 0);
+    assert!(stream.is_finished());
+    assert!(stream.next_chunk(10).is_empty());
+}
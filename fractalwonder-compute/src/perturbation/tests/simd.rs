@@ -0,0 +1,156 @@
+use super::helpers::TEST_TAU_SQ;
+use crate::perturbation::pixel_f64_bla_simd::compute_pixels_lanes;
+use crate::perturbation::simd::{F64Lanes, NativeF64x2, ScalarF64x2};
+use crate::perturbation::PixelOptions;
+use crate::{
+    compute_pixel_perturbation_f64_bla, compute_pixels_perturbation_f64_bla, render_tile_f64,
+    BlaStats, BlaTable, ReferenceOrbit, TileConfig,
+};
+use fractalwonder_core::{BigFloat, ComputeData, DeltaStep, HDRFloat, MandelbrotData};
+
+/// Bitwise comparison; Debug output distinguishes NaN and signed zeros.
+fn assert_bit_identical(a: &(MandelbrotData, BlaStats), b: &(MandelbrotData, BlaStats), ctx: &str) {
+    assert_eq!(format!("{:?}", a.0), format!("{:?}", b.0), "{ctx}");
+    assert_eq!(a.1.bla_iterations, b.1.bla_iterations, "{ctx}");
+    assert_eq!(a.1.total_iterations, b.1.total_iterations, "{ctx}");
+    assert_eq!(a.1.rebase_count, b.1.rebase_count, "{ctx}");
+}
+
+/// Deltas around c = -0.75 + 0.1i: a mix of escaping, interior and rebasing pixels.
+fn seahorse_deltas(count: usize) -> Vec<(f64, f64)> {
+    (0..count)
+        .map(|i| {
+            let t = i as f64 / count as f64;
+            (0.02 * (t * 37.0).sin(), 0.02 * (t * 23.0).cos() - 0.01)
+        })
+        .collect()
+}
+
+fn seahorse_orbit(max_iter: u32) -> (ReferenceOrbit, BlaTable) {
+    let c_ref = (
+        BigFloat::with_precision(-0.75, 128),
+        BigFloat::with_precision(0.1, 128),
+    );
+    let orbit = ReferenceOrbit::compute(&c_ref, max_iter);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.03));
+    (orbit, bla_table)
+}
+
+#[test]
+fn lane_arithmetic_matches_scalar() {
+    let a = [0.1, -3.5e200];
+    let b = [7.25, 1e-310];
+    for (va, vb) in [
+        (NativeF64x2::from_array(a), NativeF64x2::from_array(b)),
+        (NativeF64x2::splat(a[0]), NativeF64x2::splat(b[1])),
+    ] {
+        let (la, lb) = (va.to_array(), vb.to_array());
+        for i in 0..2 {
+            assert_eq!(
+                va.add(vb).to_array()[i].to_bits(),
+                (la[i] + lb[i]).to_bits()
+            );
+            assert_eq!(
+                va.sub(vb).to_array()[i].to_bits(),
+                (la[i] - lb[i]).to_bits()
+            );
+            assert_eq!(
+                va.mul(vb).to_array()[i].to_bits(),
+                (la[i] * lb[i]).to_bits()
+            );
+        }
+    }
+}
+
+#[test]
+fn simd_kernel_matches_scalar_kernel_bit_for_bit() {
+    let max_iter = 500;
+    let (orbit, bla_table) = seahorse_orbit(max_iter);
+    // Odd count leaves one lane idle at the end
+    let deltas = seahorse_deltas(201);
+
+    let native =
+        compute_pixels_perturbation_f64_bla(&orbit, &bla_table, &deltas, max_iter, TEST_TAU_SQ);
+    let fallback = compute_pixels_lanes::<ScalarF64x2>(
+        &orbit,
+        &bla_table,
+        &deltas,
+        max_iter,
+        TEST_TAU_SQ,
+        PixelOptions::ALL,
+    );
+
+    assert_eq!(native.len(), deltas.len());
+    let mut escaped = 0;
+    let mut rebased = 0;
+    for (i, &dc) in deltas.iter().enumerate() {
+        let scalar =
+            compute_pixel_perturbation_f64_bla(&orbit, &bla_table, dc, max_iter, TEST_TAU_SQ);
+        assert_bit_identical(&native[i], &scalar, &format!("native, pixel {i}"));
+        assert_bit_identical(&fallback[i], &scalar, &format!("fallback, pixel {i}"));
+        escaped += scalar.0.escaped as u32;
+        rebased += (scalar.1.rebase_count > 0) as u32;
+    }
+
+    // The batch should exercise both exits and the rebase branch
+    assert!(
+        escaped > 0 && escaped < deltas.len() as u32,
+        "escaped {escaped}"
+    );
+    assert!(rebased > 0, "no pixel rebased");
+}
+
+#[test]
+fn simd_kernel_handles_tiny_batches() {
+    let (orbit, bla_table) = seahorse_orbit(100);
+    assert!(
+        compute_pixels_perturbation_f64_bla(&orbit, &bla_table, &[], 100, TEST_TAU_SQ).is_empty()
+    );
+
+    let one =
+        compute_pixels_perturbation_f64_bla(&orbit, &bla_table, &[(0.3, 0.0)], 100, TEST_TAU_SQ);
+    let scalar =
+        compute_pixel_perturbation_f64_bla(&orbit, &bla_table, (0.3, 0.0), 100, TEST_TAU_SQ);
+    assert_bit_identical(&one[0], &scalar, "single pixel");
+}
+
+#[test]
+fn render_tile_f64_matches_per_pixel_kernel() {
+    let max_iter = 300;
+    let (orbit, bla_table) = seahorse_orbit(max_iter);
+    let config = TileConfig {
+        size: (7, 5),
+        max_iterations: max_iter,
+        tau_sq: TEST_TAU_SQ,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+    let origin = (-0.02, -0.015);
+    let step = DeltaStep::axis_aligned(0.006, 0.007);
+
+    let tile = render_tile_f64(&orbit, Some(&bla_table), origin, step, &config);
+
+    let mut row = origin;
+    let mut pixels = tile.data.iter();
+    for _ in 0..5 {
+        let mut dc = row;
+        for _ in 0..7 {
+            let (expected, _) =
+                compute_pixel_perturbation_f64_bla(&orbit, &bla_table, dc, max_iter, TEST_TAU_SQ);
+            let ComputeData::Mandelbrot(actual) = pixels.next().unwrap();
+            assert_eq!(
+                format!("{actual:?}"),
+                format!("{expected:?}"),
+                "delta {dc:?}"
+            );
+            dc.0 += step.x.0;
+            dc.1 += step.x.1;
+        }
+        row.0 += step.y.0;
+        row.1 += step.y.1;
+    }
+}
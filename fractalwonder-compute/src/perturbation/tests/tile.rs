@@ -2,7 +2,7 @@
 
 use crate::perturbation::tile::{render_tile_f64, TileConfig};
 use crate::ReferenceOrbit;
-use fractalwonder_core::{BigFloat, ComputeData};
+use fractalwonder_core::{BigFloat, ComputeData, DeltaStep};
 
 #[test]
 fn render_tile_f64_produces_correct_pixel_count() {
@@ -15,11 +15,16 @@ fn render_tile_f64_produces_correct_pixel_count() {
         max_iterations: 100,
         tau_sq: 1e-6,
         bla_enabled: false,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
     };
 
     // Delta origin and step for a 4x4 tile
     let delta_origin = (0.1, 0.1);
-    let delta_step = (0.01, 0.01);
+    let delta_step = DeltaStep::axis_aligned(0.01, 0.01);
 
     let result = render_tile_f64(&orbit, None, delta_origin, delta_step, &config);
 
@@ -44,11 +49,16 @@ fn render_tile_f64_escapes_outside_set() {
         max_iterations: 100,
         tau_sq: 1e-6,
         bla_enabled: false,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
     };
 
     // Delta puts pixels outside the set (|c| > 2)
     let delta_origin = (2.5, 2.5);
-    let delta_step = (0.1, 0.1);
+    let delta_step = DeltaStep::axis_aligned(0.1, 0.1);
 
     let result = render_tile_f64(&orbit, None, delta_origin, delta_step, &config);
 
@@ -65,7 +75,7 @@ fn render_tile_f64_escapes_outside_set() {
 // ============================================================================
 
 use crate::perturbation::tile::render_tile_hdr;
-use crate::BlaTable;
+use crate::{BlaSource, BlaTable};
 use fractalwonder_core::HDRFloat;
 
 #[test]
@@ -81,11 +91,16 @@ fn render_tile_hdr_produces_correct_pixel_count() {
         max_iterations: 100,
         tau_sq: 1e-6,
         bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
     };
 
     // Use HDRFloat deltas
     let delta_origin = (HDRFloat::from_f64(0.1), HDRFloat::from_f64(0.1));
-    let delta_step = (HDRFloat::from_f64(0.01), HDRFloat::from_f64(0.01));
+    let delta_step = DeltaStep::axis_aligned(HDRFloat::from_f64(0.01), HDRFloat::from_f64(0.01));
 
     let result = render_tile_hdr(&orbit, Some(&bla_table), delta_origin, delta_step, &config);
 
@@ -102,10 +117,15 @@ fn render_tile_hdr_without_bla_table() {
         max_iterations: 100,
         tau_sq: 1e-6,
         bla_enabled: true, // Enabled but no table provided
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
     };
 
     let delta_origin = (HDRFloat::from_f64(0.1), HDRFloat::from_f64(0.1));
-    let delta_step = (HDRFloat::from_f64(0.01), HDRFloat::from_f64(0.01));
+    let delta_step = DeltaStep::axis_aligned(HDRFloat::from_f64(0.01), HDRFloat::from_f64(0.01));
 
     // Should work without BLA table (falls back to standard iteration)
     let result = render_tile_hdr(&orbit, None, delta_origin, delta_step, &config);
@@ -129,11 +149,16 @@ fn render_tile_hdr_tracks_bla_iterations() {
         max_iterations: 1000,
         tau_sq: 1e-6,
         bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
     };
 
     // Very small deltas so BLA validity checks pass
     let delta_origin = (HDRFloat::from_f64(1e-12), HDRFloat::from_f64(1e-12));
-    let delta_step = (HDRFloat::from_f64(1e-14), HDRFloat::from_f64(1e-14));
+    let delta_step = DeltaStep::axis_aligned(HDRFloat::from_f64(1e-14), HDRFloat::from_f64(1e-14));
 
     let result = render_tile_hdr(&orbit, Some(&bla_table), delta_origin, delta_step, &config);
 
@@ -143,3 +168,550 @@ fn render_tile_hdr_tracks_bla_iterations() {
         "Should have computed iterations"
     );
 }
+
+#[test]
+fn rotated_tile_steps_along_both_axes() {
+    use crate::compute_pixel_perturbation;
+    use fractalwonder_core::F64Complex;
+
+    let c_ref = (BigFloat::with_precision(-0.5, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 200);
+
+    let config = TileConfig {
+        size: (3, 3),
+        max_iterations: 200,
+        tau_sq: 1e-6,
+        bla_enabled: false,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+
+    // A 30 degree turn: each step moves along both re and im
+    let (sin, cos) = std::f64::consts::FRAC_PI_6.sin_cos();
+    let s = 0.15;
+    let delta_origin = (-0.2, -0.2);
+    let delta_step = DeltaStep {
+        x: (s * cos, s * sin),
+        y: (-s * sin, s * cos),
+    };
+
+    let f64_result = render_tile_f64(&orbit, None, delta_origin, delta_step, &config);
+    let hdr_result = render_tile_hdr(
+        &orbit,
+        None,
+        (
+            HDRFloat::from_f64(delta_origin.0),
+            HDRFloat::from_f64(delta_origin.1),
+        ),
+        delta_step.map(|v| HDRFloat::from_f64(*v)),
+        &config,
+    );
+
+    for py in 0..3 {
+        for px in 0..3 {
+            let (fx, fy) = (px as f64, py as f64);
+            let delta_c = F64Complex {
+                re: delta_origin.0 + fx * delta_step.x.0 + fy * delta_step.y.0,
+                im: delta_origin.1 + fx * delta_step.x.1 + fy * delta_step.y.1,
+            };
+            let expected = compute_pixel_perturbation(&orbit, delta_c, 200, 1e-6);
+
+            let index = py * 3 + px;
+            let ComputeData::Mandelbrot(f64_pixel) = &f64_result.data[index];
+            let ComputeData::Mandelbrot(hdr_pixel) = &hdr_result.data[index];
+            assert_eq!(
+                f64_pixel.iterations, expected.iterations,
+                "f64 ({px}, {py})"
+            );
+            assert_eq!(
+                hdr_pixel.iterations, expected.iterations,
+                "hdr ({px}, {py})"
+            );
+        }
+    }
+}
+
+// ============================================================================
+// FloatExp tile rendering tests
+// ============================================================================
+
+use crate::perturbation::tile::render_tile_floatexp;
+use fractalwonder_core::FloatExp;
+
+#[test]
+fn render_tile_floatexp_matches_f64_at_shallow_zoom() {
+    let c_ref = (BigFloat::with_precision(-0.5, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 500);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.2));
+
+    let config = TileConfig {
+        size: (4, 4),
+        max_iterations: 500,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+
+    let delta_origin = (-0.1, 0.05);
+    let delta_step = DeltaStep::axis_aligned(0.03, 0.03);
+
+    let f64_result = render_tile_f64(&orbit, Some(&bla_table), delta_origin, delta_step, &config);
+    let fe_result = render_tile_floatexp(
+        &orbit,
+        Some(&bla_table),
+        (
+            FloatExp::from_f64(delta_origin.0),
+            FloatExp::from_f64(delta_origin.1),
+        ),
+        delta_step.map(|v| FloatExp::from_f64(*v)),
+        &config,
+    );
+
+    assert_eq!(fe_result.data.len(), 16);
+    for (f64_pixel, fe_pixel) in f64_result.data.iter().zip(&fe_result.data) {
+        let (ComputeData::Mandelbrot(a), ComputeData::Mandelbrot(b)) = (f64_pixel, fe_pixel);
+        assert_eq!(a.iterations, b.iterations);
+        assert_eq!(a.escaped, b.escaped);
+    }
+}
+
+#[test]
+fn render_tile_floatexp_matches_hdr_beyond_f64_range() {
+    // c = 0.3 escapes slowly; deltas around 1e-400 cannot be held in f64
+    let c_ref = (BigFloat::with_precision(0.3, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 200);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-300));
+
+    let config = TileConfig {
+        size: (3, 3),
+        max_iterations: 200,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: false,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+
+    let origin = BigFloat::from_string("1e-400", 1400).unwrap();
+    let step = BigFloat::from_string("3e-401", 1400).unwrap();
+
+    let fe_result = render_tile_floatexp(
+        &orbit,
+        Some(&bla_table),
+        (
+            FloatExp::from_bigfloat(&origin),
+            FloatExp::from_bigfloat(&origin),
+        ),
+        DeltaStep::axis_aligned(
+            FloatExp::from_bigfloat(&step),
+            FloatExp::from_bigfloat(&step),
+        ),
+        &config,
+    );
+    let hdr_result = render_tile_hdr(
+        &orbit,
+        Some(&bla_table),
+        (
+            HDRFloat::from_bigfloat(&origin),
+            HDRFloat::from_bigfloat(&origin),
+        ),
+        DeltaStep::axis_aligned(
+            HDRFloat::from_bigfloat(&step),
+            HDRFloat::from_bigfloat(&step),
+        ),
+        &config,
+    );
+
+    for (fe_pixel, hdr_pixel) in fe_result.data.iter().zip(&hdr_result.data) {
+        let (ComputeData::Mandelbrot(a), ComputeData::Mandelbrot(b)) = (fe_pixel, hdr_pixel);
+        assert!(a.escaped, "c ≈ 0.3 lies outside the set");
+        assert_eq!(a.iterations, b.iterations);
+        assert_eq!(a.glitched, b.glitched);
+    }
+    assert!(
+        fe_result.stats.bla_iterations > 0,
+        "BLA should skip iterations"
+    );
+}
+
+// ============================================================================
+// Scaled-f64 tile rendering and precision selection tests
+// ============================================================================
+
+use crate::perturbation::tile::{render_tile_scaled, SCALED_F64_MIN_LOG2};
+use fractalwonder_core::DeltaPrecision;
+
+#[test]
+fn render_tile_scaled_matches_hdr_beyond_f64_range() {
+    let c_ref = (BigFloat::with_precision(0.3, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 200);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-300));
+
+    let config = TileConfig {
+        size: (3, 3),
+        max_iterations: 200,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: true,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+
+    let origin = HDRFloat::from_bigfloat(&BigFloat::from_string("1e-400", 1400).unwrap());
+    let step = HDRFloat::from_bigfloat(&BigFloat::from_string("3e-401", 1400).unwrap());
+
+    let scaled_result = render_tile_scaled(
+        &orbit,
+        Some(&bla_table),
+        (origin, origin),
+        DeltaStep::axis_aligned(step, step),
+        &config,
+    );
+    let hdr_result = render_tile_hdr(
+        &orbit,
+        Some(&bla_table),
+        (origin, origin),
+        DeltaStep::axis_aligned(step, step),
+        &config,
+    );
+
+    assert_eq!(scaled_result.data.len(), 9);
+    for (s_pixel, hdr_pixel) in scaled_result.data.iter().zip(&hdr_result.data) {
+        let (ComputeData::Mandelbrot(a), ComputeData::Mandelbrot(b)) = (s_pixel, hdr_pixel);
+        assert_eq!(a.escaped, b.escaped);
+        assert_eq!(a.iterations, b.iterations);
+        assert_eq!(a.glitched, b.glitched);
+    }
+}
+
+#[test]
+fn precision_for_picks_cheapest_sufficient_type() {
+    let mut config = TileConfig {
+        size: (1, 1),
+        max_iterations: 100,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: true,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+
+    assert_eq!(config.precision_for(-20.0), DeltaPrecision::F64);
+    assert_eq!(config.precision_for(-2000.0), DeltaPrecision::ScaledF64);
+    assert_eq!(
+        config.precision_for(SCALED_F64_MIN_LOG2 - 1.0),
+        DeltaPrecision::FloatExp
+    );
+
+    config.scaled_f64 = false;
+    assert_eq!(config.precision_for(-2000.0), DeltaPrecision::FloatExp);
+
+    config.force_hdr_float = true;
+    assert_eq!(config.precision_for(-20.0), DeltaPrecision::HdrFloat);
+}
+
+// ============================================================================
+// Resumable tile rendering tests
+// ============================================================================
+
+use crate::perturbation::tile::{
+    render_tile, resume_tile, TileInterrupt, TileRenderResult, TileStats,
+};
+
+/// Stops whenever another `slice` iterations have been computed.
+struct IterationSlices {
+    slice: u64,
+    next_stop: u64,
+    stops: u32,
+}
+
+impl TileInterrupt for IterationSlices {
+    fn should_stop(&mut self, stats: &TileStats) -> bool {
+        if stats.total_iterations < self.next_stop {
+            return false;
+        }
+        self.next_stop = stats.total_iterations + self.slice;
+        self.stops += 1;
+        true
+    }
+}
+
+/// Render a tile in slices and check it matches rendering it in one go.
+fn assert_resumes_identically(
+    orbit: &ReferenceOrbit,
+    bla_table: Option<&dyn BlaSource>,
+    origin: &str,
+    step: &str,
+    config: &TileConfig,
+    expected: DeltaPrecision,
+) {
+    let origin = BigFloat::from_string(origin, 1400).unwrap();
+    let step = BigFloat::from_string(step, 1400).unwrap();
+    let delta_origin = (origin.clone(), origin);
+    let delta_step = DeltaStep {
+        x: (step.clone(), BigFloat::zero(1400)),
+        y: (BigFloat::zero(1400), step),
+    };
+
+    let (whole, precision) = render_tile(orbit, bla_table, &delta_origin, &delta_step, config);
+    assert_eq!(precision, expected);
+
+    let mut sliced = TileRenderResult::default();
+    let mut interrupt = IterationSlices {
+        slice: 50,
+        next_stop: 50,
+        stops: 0,
+    };
+    let mut calls = 0;
+    while !sliced.is_complete(config) {
+        let precision = resume_tile(
+            orbit,
+            bla_table,
+            &delta_origin,
+            &delta_step,
+            config,
+            &mut sliced,
+            &mut interrupt,
+        );
+        assert_eq!(precision, expected);
+        calls += 1;
+        assert!(calls < 10_000, "resuming makes no progress");
+    }
+    assert!(interrupt.stops > 1, "tile should have been interrupted");
+
+    for (a, b) in sliced.data.iter().zip(&whole.data) {
+        let (ComputeData::Mandelbrot(a), ComputeData::Mandelbrot(b)) = (a, b);
+        assert_eq!(a, b);
+    }
+    assert_eq!(sliced.data.len(), whole.data.len());
+    assert_eq!(sliced.stats.total_iterations, whole.stats.total_iterations);
+    assert_eq!(sliced.stats.bla_iterations, whole.stats.bla_iterations);
+    assert_eq!(sliced.stats.rebase_count, whole.stats.rebase_count);
+}
+
+#[test]
+fn resumed_f64_tile_matches_uninterrupted_render() {
+    let c_ref = (BigFloat::with_precision(-0.5, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 300);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.05));
+
+    for bla_enabled in [false, true] {
+        let config = TileConfig {
+            size: (8, 5),
+            max_iterations: 300,
+            tau_sq: 1e-6,
+            bla_enabled,
+            force_hdr_float: false,
+            scaled_f64: true,
+            collect_orbit_stats: false,
+            probe_interior: true,
+            iteration_limit: None,
+        };
+        assert_resumes_identically(
+            &orbit,
+            Some(&bla_table),
+            "0.21",
+            "0.015",
+            &config,
+            DeltaPrecision::F64,
+        );
+    }
+}
+
+#[test]
+fn resumed_deep_tiles_match_uninterrupted_render() {
+    let c_ref = (BigFloat::with_precision(0.3, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 200);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-300));
+
+    let mut config = TileConfig {
+        size: (4, 3),
+        max_iterations: 200,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: true,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+    let (origin, step) = ("1e-400", "3e-401");
+
+    assert_resumes_identically(
+        &orbit,
+        Some(&bla_table),
+        origin,
+        step,
+        &config,
+        DeltaPrecision::ScaledF64,
+    );
+
+    config.scaled_f64 = false;
+    assert_resumes_identically(
+        &orbit,
+        Some(&bla_table),
+        origin,
+        step,
+        &config,
+        DeltaPrecision::FloatExp,
+    );
+
+    config.force_hdr_float = true;
+    assert_resumes_identically(
+        &orbit,
+        Some(&bla_table),
+        origin,
+        step,
+        &config,
+        DeltaPrecision::HdrFloat,
+    );
+}
+
+/// Render a tile with the iteration limit raised a step at a time, as while
+/// the orbit streams in, and check it matches rendering it in one go
+/// without redoing any pixel's iterations.
+fn assert_continues_identically(
+    orbit: &ReferenceOrbit,
+    bla_table: Option<&dyn BlaSource>,
+    origin: &str,
+    step: &str,
+    config: &TileConfig,
+    limit_step: u32,
+) {
+    let origin = BigFloat::from_string(origin, 1400).unwrap();
+    let step = BigFloat::from_string(step, 1400).unwrap();
+    let delta_origin = (origin.clone(), origin);
+    let delta_step = DeltaStep {
+        x: (step.clone(), BigFloat::zero(1400)),
+        y: (BigFloat::zero(1400), step),
+    };
+
+    let (whole, _) = render_tile(orbit, bla_table, &delta_origin, &delta_step, config);
+
+    let mut limited = config.clone();
+    limited.iteration_limit = Some(limit_step);
+    let mut continued = TileRenderResult::default();
+    let mut interrupt = IterationSlices {
+        slice: 50,
+        next_stop: 50,
+        stops: 0,
+    };
+    let mut calls = 0;
+    let mut waits = 0;
+    while !continued.is_complete(&limited) {
+        if continued.needs_longer_orbit(&limited) {
+            waits += 1;
+            let limit = limited.iteration_limit.unwrap() + limit_step;
+            limited.iteration_limit = (limit < config.max_iterations).then_some(limit);
+        }
+        resume_tile(
+            orbit,
+            bla_table,
+            &delta_origin,
+            &delta_step,
+            &limited,
+            &mut continued,
+            &mut interrupt,
+        );
+        calls += 1;
+        assert!(calls < 10_000, "continuing makes no progress");
+    }
+    assert!(waits > 0, "no pixel waited at the limit");
+
+    assert_eq!(continued.data.len(), whole.data.len());
+    for (a, b) in continued.data.iter().zip(&whole.data) {
+        let (ComputeData::Mandelbrot(a), ComputeData::Mandelbrot(b)) = (a, b);
+        assert_eq!(a, b);
+    }
+    assert_eq!(
+        continued.stats.total_iterations,
+        whole.stats.total_iterations
+    );
+    assert_eq!(continued.stats.bla_iterations, whole.stats.bla_iterations);
+    assert_eq!(continued.stats.rebase_count, whole.stats.rebase_count);
+}
+
+#[test]
+fn tiles_continue_unfinished_pixels_when_the_limit_rises() {
+    let c_ref = (BigFloat::with_precision(-0.5, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 300);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(0.05));
+
+    for bla_enabled in [false, true] {
+        let config = TileConfig {
+            size: (8, 5),
+            max_iterations: 300,
+            tau_sq: 1e-6,
+            bla_enabled,
+            force_hdr_float: false,
+            scaled_f64: true,
+            collect_orbit_stats: true,
+            probe_interior: true,
+            iteration_limit: None,
+        };
+        assert_continues_identically(&orbit, Some(&bla_table), "0.21", "0.015", &config, 40);
+    }
+
+    let c_ref = (BigFloat::with_precision(0.3, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 200);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-300));
+    let mut config = TileConfig {
+        size: (4, 3),
+        max_iterations: 200,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: true,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+    for _ in 0..3 {
+        assert_continues_identically(&orbit, Some(&bla_table), "1e-400", "3e-401", &config, 30);
+        if config.scaled_f64 {
+            config.scaled_f64 = false;
+        } else {
+            config.force_hdr_float = true;
+        }
+    }
+}
+
+#[test]
+fn lane_kernel_stops_inside_long_pixels() {
+    // One row of interior pixels: the interrupt can only land mid-orbit
+    let c_ref = (BigFloat::with_precision(-0.5, 64), BigFloat::zero(64));
+    let orbit = ReferenceOrbit::compute(&c_ref, 20_000);
+    let bla_table = BlaTable::compute(&orbit, &HDRFloat::from_f64(1e-3));
+    let config = TileConfig {
+        size: (3, 1),
+        max_iterations: 20_000,
+        tau_sq: 1e-6,
+        bla_enabled: true,
+        force_hdr_float: false,
+        scaled_f64: true,
+        collect_orbit_stats: false,
+        probe_interior: true,
+        iteration_limit: None,
+    };
+    assert_resumes_identically(
+        &orbit,
+        Some(&bla_table),
+        "0.001",
+        "0.0003",
+        &config,
+        DeltaPrecision::F64,
+    );
+}
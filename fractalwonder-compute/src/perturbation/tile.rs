@@ -1,14 +1,35 @@
 //! Tile rendering for perturbation-based Mandelbrot computation.
 //!
 //! Provides pure functions for rendering tiles using pre-computed reference orbits.
-//! Supports both f64 (fast path) and HDRFloat (deep zoom) precision.
+//! Supports f64 (fast path), scaled f64 (the 10^-300 to 10^-4900 band),
+//! FloatExp (any depth) and HDRFloat (matching the GPU representation)
+//! precision. `render_tile` picks one from the delta magnitude and `TileConfig`.
+//!
+//! `resume_tile` renders the same pixels but checks a `TileInterrupt` before
+//! each one, and the lane kernel also every so many steps of its pixels, so
+//! a long tile can stop early and later continue where it left off with
+//! bit-identical results. With `TileConfig::iteration_limit` set,
+//! pixels still running at the limit are set aside with their state and
+//! continued by a later call once the limit is raised.
 
+use super::simd::LANES;
 use super::{
-    compute_pixel_perturbation, compute_pixel_perturbation_f64_bla,
-    compute_pixel_perturbation_hdr_bla, ReferenceOrbit,
+    continue_pixel, continue_pixel_floatexp_bla, continue_pixel_hdr_bla, continue_pixel_scaled_bla,
+    continue_pixels_f64_bla, BlaStats, LanePixel, LaneSink, OrbitSource, PixelOptions, PixelRun,
+    PixelState, ScaledPixelState,
+};
+use crate::BlaSource;
+use fractalwonder_core::{
+    BigFloat, ComplexDelta, ComputeData, DeltaPrecision, DeltaStep, F64Complex, FloatExp,
+    FloatExpComplex, HDRComplex, HDRFloat, MandelbrotData,
 };
-use crate::BlaTable;
-use fractalwonder_core::{ComplexDelta, ComputeData, F64Complex, HDRComplex, HDRFloat};
+
+/// Deltas with log2 magnitude inside (-F64_MAX_LOG2, F64_MAX_LOG2) fit in f64.
+const F64_MAX_LOG2: f64 = 900.0;
+
+/// Smallest delta magnitude (log2) rendered with scaled f64, ~10^-4900.
+/// Deeper tiles use FloatExp.
+pub const SCALED_F64_MIN_LOG2: f64 = -16_280.0;
 
 /// Statistics from rendering a tile.
 #[derive(Clone, Debug, Default)]
@@ -22,14 +43,203 @@ pub struct TileStats {
     pub rebase_count: u64,
 }
 
+impl TileStats {
+    /// Add the statistics of one pixel.
+    fn add(&mut self, pixel: &BlaStats) {
+        self.bla_iterations += pixel.bla_iterations as u64;
+        self.total_iterations += pixel.total_iterations as u64;
+        self.rebase_count += pixel.rebase_count as u64;
+    }
+}
+
 /// Result of rendering a tile.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct TileRenderResult {
-    /// Computed data for each pixel in row-major order.
+    /// Computed data for each pixel started so far, in row-major order.
+    /// Pixels set aside at the iteration limit hold stand-in data that
+    /// reports them as not escaped.
     pub data: Vec<ComputeData>,
     /// Rendering statistics.
     #[allow(dead_code)] // Used by worker integration (Task 6)
     pub stats: TileStats,
+    /// Unfinished pixels by index into `data`, with the state to continue them
+    suspended: Vec<(usize, PixelProgress)>,
+}
+
+impl TileRenderResult {
+    /// Whether every pixel of a tile rendered with `config` is done.
+    pub fn is_complete(&self, config: &TileConfig) -> bool {
+        self.data.len() == config.pixel_count() && self.suspended.is_empty()
+    }
+
+    /// Whether every pixel is done apart from those waiting at
+    /// `config.iteration_limit` for a longer reference orbit.
+    pub fn needs_longer_orbit(&self, config: &TileConfig) -> bool {
+        let stop_at = config.stop_at();
+        stop_at < config.max_iterations
+            && self.data.len() == config.pixel_count()
+            && !self.suspended.is_empty()
+            && self.suspended.iter().all(|(_, p)| p.n() >= stop_at)
+    }
+
+    /// Remove the suspended pixels that can continue before `stop_at`.
+    fn take_resumable(&mut self, stop_at: u32) -> Vec<(usize, PixelProgress)> {
+        let (resumable, waiting) = std::mem::take(&mut self.suspended)
+            .into_iter()
+            .partition(|(_, p)| p.n() < stop_at);
+        self.suspended = waiting;
+        resumable
+    }
+
+    /// Start pixel `index`, the next one in row-major order.
+    fn start_pixel(&mut self, index: usize, max_iterations: u32) {
+        debug_assert_eq!(index, self.data.len());
+        self.data.push(ComputeData::Mandelbrot(MandelbrotData {
+            max_iterations,
+            ..MandelbrotData::default()
+        }));
+    }
+
+    /// Record a pixel leaving its kernel: its data if it finished, or its
+    /// state to continue from. `before` is its statistics on entering.
+    fn end_pixel<S: TilePixel>(
+        &mut self,
+        index: usize,
+        state: S,
+        before: &BlaStats,
+        data: Option<MandelbrotData>,
+        max_iterations: u32,
+    ) {
+        self.stats.add(&state.stats().since(before));
+        let data = data.unwrap_or_else(|| {
+            let progress = state.suspend();
+            let unfinished = progress.unfinished(max_iterations);
+            self.suspended.push((index, progress));
+            unfinished
+        });
+        self.data[index] = ComputeData::Mandelbrot(data);
+    }
+}
+
+/// State of an unfinished pixel, from whichever kernel ran it.
+#[derive(Clone, Debug)]
+enum PixelProgress {
+    F64(PixelState<F64Complex>),
+    Scaled(ScaledPixelState),
+    FloatExp(PixelState<FloatExpComplex>),
+    Hdr(PixelState<HDRComplex>),
+}
+
+impl PixelProgress {
+    /// Iterations done.
+    fn n(&self) -> u32 {
+        match self {
+            Self::F64(state) => state.n,
+            Self::Scaled(state) => state.n(),
+            Self::FloatExp(state) => state.n,
+            Self::Hdr(state) => state.n,
+        }
+    }
+
+    fn unfinished(&self, max_iterations: u32) -> MandelbrotData {
+        match self {
+            Self::F64(state) => state.unfinished(max_iterations),
+            Self::Scaled(state) => state.unfinished(max_iterations),
+            Self::FloatExp(state) => state.unfinished(max_iterations),
+            Self::Hdr(state) => state.unfinished(max_iterations),
+        }
+    }
+}
+
+/// Kernel state a tile can set aside and continue later.
+trait TilePixel: Copy {
+    fn stats(&self) -> BlaStats;
+    fn suspend(self) -> PixelProgress;
+    /// The state in `progress`, if the same kernel ran it.
+    fn resume(progress: &PixelProgress) -> Option<Self>;
+}
+
+impl TilePixel for PixelState<F64Complex> {
+    fn stats(&self) -> BlaStats {
+        self.stats
+    }
+
+    fn suspend(self) -> PixelProgress {
+        PixelProgress::F64(self)
+    }
+
+    fn resume(progress: &PixelProgress) -> Option<Self> {
+        match progress {
+            PixelProgress::F64(state) => Some(*state),
+            _ => None,
+        }
+    }
+}
+
+impl TilePixel for ScaledPixelState {
+    fn stats(&self) -> BlaStats {
+        ScaledPixelState::stats(self)
+    }
+
+    fn suspend(self) -> PixelProgress {
+        PixelProgress::Scaled(self)
+    }
+
+    fn resume(progress: &PixelProgress) -> Option<Self> {
+        match progress {
+            PixelProgress::Scaled(state) => Some(*state),
+            _ => None,
+        }
+    }
+}
+
+impl TilePixel for PixelState<FloatExpComplex> {
+    fn stats(&self) -> BlaStats {
+        self.stats
+    }
+
+    fn suspend(self) -> PixelProgress {
+        PixelProgress::FloatExp(self)
+    }
+
+    fn resume(progress: &PixelProgress) -> Option<Self> {
+        match progress {
+            PixelProgress::FloatExp(state) => Some(*state),
+            _ => None,
+        }
+    }
+}
+
+impl TilePixel for PixelState<HDRComplex> {
+    fn stats(&self) -> BlaStats {
+        self.stats
+    }
+
+    fn suspend(self) -> PixelProgress {
+        PixelProgress::Hdr(self)
+    }
+
+    fn resume(progress: &PixelProgress) -> Option<Self> {
+        match progress {
+            PixelProgress::Hdr(state) => Some(*state),
+            _ => None,
+        }
+    }
+}
+
+/// Asked before each pixel whether a resumable tile render should stop.
+pub trait TileInterrupt {
+    /// `stats` covers the pixels finished so far, across all resumptions.
+    fn should_stop(&mut self, stats: &TileStats) -> bool;
+}
+
+/// Never stops; the tile renders in one go.
+pub struct Uninterrupted;
+
+impl TileInterrupt for Uninterrupted {
+    fn should_stop(&mut self, _stats: &TileStats) -> bool {
+        false
+    }
 }
 
 /// Configuration for tile rendering.
@@ -43,82 +253,509 @@ pub struct TileConfig {
     pub tau_sq: f64,
     /// Enable BLA acceleration.
     pub bla_enabled: bool,
+    /// Render every tile beyond f64 range with HDRFloat (debug option).
+    pub force_hdr_float: bool,
+    /// Use scaled f64 for deltas between ~10^-300 and ~10^-4900.
+    pub scaled_f64: bool,
+    /// Record orbit trap and stripe statistics for the layered colorizers.
+    /// They need every iteration, so BLA is off while collecting them.
+    pub collect_orbit_stats: bool,
+    /// Probe pixels that reach max_iterations for the period and interior
+    /// distance of their attracting cycle, for the interior coloring modes.
+    pub probe_interior: bool,
+    /// Set pixels still running after this many iterations aside, for a
+    /// later `resume_tile` with a higher limit to continue. Used while the
+    /// reference orbit is streaming in and ends sooner than max_iterations.
+    pub iteration_limit: Option<u32>,
+}
+
+impl TileConfig {
+    /// Whether a tile skips iterations with the BLA table, if one is given.
+    pub fn uses_bla(&self) -> bool {
+        self.bla_enabled && !self.collect_orbit_stats
+    }
+
+    /// The optional per-pixel work this tile asks of the kernels.
+    pub(crate) fn pixel_options(&self) -> PixelOptions {
+        PixelOptions {
+            collect_orbit_stats: self.collect_orbit_stats,
+            probe_interior: self.probe_interior,
+        }
+    }
+
+    /// Iteration count at which pixels stop for now.
+    fn stop_at(&self) -> u32 {
+        self.iteration_limit
+            .map_or(self.max_iterations, |limit| limit.min(self.max_iterations))
+    }
+
+    /// How far the kernels take each pixel in one call.
+    fn pixel_run(&self) -> PixelRun {
+        PixelRun::to_end(self.max_iterations, self.tau_sq, self.pixel_options())
+            .until(self.stop_at())
+    }
+
+    fn pixel_count(&self) -> usize {
+        (self.size.0 * self.size.1) as usize
+    }
+
+    /// Delta arithmetic for a tile whose largest delta component is 2^delta_log2.
+    pub fn precision_for(&self, delta_log2: f64) -> DeltaPrecision {
+        if self.force_hdr_float {
+            DeltaPrecision::HdrFloat
+        } else if delta_log2 > -F64_MAX_LOG2 && delta_log2 < F64_MAX_LOG2 {
+            DeltaPrecision::F64
+        } else if self.scaled_f64 && (SCALED_F64_MIN_LOG2..=-F64_MAX_LOG2).contains(&delta_log2) {
+            DeltaPrecision::ScaledF64
+        } else {
+            DeltaPrecision::FloatExp
+        }
+    }
+}
+
+/// Render a tile from BigFloat deltas, choosing the delta arithmetic with
+/// `TileConfig::precision_for`.
+///
+/// Returns the render result together with the precision that was used.
+pub fn render_tile(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: &(BigFloat, BigFloat),
+    delta_step: &DeltaStep<BigFloat>,
+    config: &TileConfig,
+) -> (TileRenderResult, DeltaPrecision) {
+    let mut result = TileRenderResult::default();
+    let precision = resume_tile(
+        orbit,
+        bla_table,
+        delta_origin,
+        delta_step,
+        config,
+        &mut result,
+        &mut Uninterrupted,
+    );
+    (result, precision)
+}
+
+/// Continue rendering a tile into `result`, which holds the pixels finished
+/// by earlier calls, until it is complete or `interrupt` asks to stop.
+/// Pixels set aside at an earlier, lower `config.iteration_limit` continue
+/// from where they stopped.
+///
+/// Returns the precision used, which is the same on every call for a tile.
+pub fn resume_tile(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: &(BigFloat, BigFloat),
+    delta_step: &DeltaStep<BigFloat>,
+    config: &TileConfig,
+    result: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+) -> DeltaPrecision {
+    let delta_log2 = delta_origin
+        .0
+        .log2_approx()
+        .max(delta_origin.1.log2_approx());
+    let precision = config.precision_for(delta_log2);
+
+    match precision {
+        DeltaPrecision::F64 => fill_tile_f64(
+            orbit,
+            bla_table,
+            (delta_origin.0.to_f64(), delta_origin.1.to_f64()),
+            delta_step.map(BigFloat::to_f64),
+            config,
+            result,
+            interrupt,
+        ),
+        DeltaPrecision::ScaledF64 => fill_tile_scaled(
+            orbit,
+            bla_table,
+            (
+                HDRFloat::from_bigfloat(&delta_origin.0),
+                HDRFloat::from_bigfloat(&delta_origin.1),
+            ),
+            delta_step.map(HDRFloat::from_bigfloat),
+            config,
+            result,
+            interrupt,
+        ),
+        DeltaPrecision::FloatExp => fill_tile_floatexp(
+            orbit,
+            bla_table,
+            (
+                FloatExp::from_bigfloat(&delta_origin.0),
+                FloatExp::from_bigfloat(&delta_origin.1),
+            ),
+            delta_step.map(FloatExp::from_bigfloat),
+            config,
+            result,
+            interrupt,
+        ),
+        DeltaPrecision::HdrFloat => fill_tile_hdr(
+            orbit,
+            bla_table,
+            (
+                HDRFloat::from_bigfloat(&delta_origin.0),
+                HDRFloat::from_bigfloat(&delta_origin.1),
+            ),
+            delta_step.map(HDRFloat::from_bigfloat),
+            config,
+            result,
+            interrupt,
+        ),
+    }
+
+    precision
+}
+
+/// Deltas of a tile's pixels in row-major order.
+///
+/// Steps from pixel to pixel and row to row by repeated addition, always in
+/// the same order, so a resumed tile sees exactly the deltas it would have
+/// seen in one go.
+fn pixel_deltas<T: Copy>(
+    origin: T,
+    (width, height): (u32, u32),
+    step_x: impl Fn(&T) -> T + Copy,
+    step_y: impl Fn(&T) -> T,
+) -> impl Iterator<Item = T> {
+    std::iter::successors(Some(origin), move |row| Some(step_y(row)))
+        .take(height as usize)
+        .flat_map(move |row| {
+            std::iter::successors(Some(row), move |delta| Some(step_x(delta))).take(width as usize)
+        })
+}
+
+/// Run a tile through a per-pixel kernel: first the pixels set aside by
+/// earlier calls that can go further now, then those not started yet.
+/// `fresh` gives the state of a pixel that has not started iterating.
+fn fill_pixels<T, S: TilePixel>(
+    deltas: &[T],
+    config: &TileConfig,
+    out: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+    fresh: impl Fn(&T) -> S,
+    mut kernel: impl FnMut(&T, &mut S, &PixelRun) -> Option<MandelbrotData>,
+) {
+    let run = config.pixel_run();
+    let mut resumable = out.take_resumable(run.stop_at).into_iter();
+    while let Some((index, progress)) = resumable.next() {
+        if interrupt.should_stop(&out.stats) {
+            out.suspended.push((index, progress));
+            out.suspended.extend(resumable);
+            return;
+        }
+        // A pixel run by another kernel before starts over
+        let mut state = S::resume(&progress).unwrap_or_else(|| fresh(&deltas[index]));
+        let before = state.stats();
+        let data = kernel(&deltas[index], &mut state, &run);
+        out.end_pixel(index, state, &before, data, run.max_iterations);
+    }
+
+    for (index, delta_c) in deltas.iter().enumerate().skip(out.data.len()) {
+        if interrupt.should_stop(&out.stats) {
+            return;
+        }
+        out.start_pixel(index, run.max_iterations);
+        let mut state = fresh(delta_c);
+        let data = kernel(delta_c, &mut state, &run);
+        out.end_pixel(index, state, &BlaStats::default(), data, run.max_iterations);
+    }
 }
 
 /// Render a tile using f64 precision with optional BLA acceleration.
 ///
 /// This path is used when delta values fit comfortably in f64 range (~10^±300).
-/// BLA acceleration is applied when available and enabled.
+/// BLA acceleration is applied when available and enabled; those pixels go
+/// through the lane-parallel SIMD kernel.
 ///
 /// # Arguments
 /// * `orbit` - Pre-computed reference orbit
 /// * `bla_table` - Optional BLA table for iteration skipping
 /// * `delta_origin` - Delta from reference point to top-left pixel (re, im)
-/// * `delta_step` - Delta step between pixels (re, im)
+/// * `delta_step` - Delta change per pixel along a row and down a column
 /// * `config` - Tile rendering configuration
 ///
 /// # Returns
 /// Computed pixel data and rendering statistics including BLA metrics
 pub fn render_tile_f64(
-    orbit: &ReferenceOrbit,
-    bla_table: Option<&BlaTable>,
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
     delta_origin: (f64, f64),
-    delta_step: (f64, f64),
+    delta_step: DeltaStep<f64>,
     config: &TileConfig,
 ) -> TileRenderResult {
-    let capacity = (config.size.0 * config.size.1) as usize;
-    let mut data = Vec::with_capacity(capacity);
-    let mut stats = TileStats::default();
-
-    let mut delta_c_row = delta_origin;
-
-    for _py in 0..config.size.1 {
-        let mut delta_c = delta_c_row;
-
-        for _px in 0..config.size.0 {
-            if config.bla_enabled {
-                if let Some(bla) = bla_table {
-                    let (result, pixel_stats) = compute_pixel_perturbation_f64_bla(
-                        orbit,
-                        bla,
-                        delta_c,
-                        config.max_iterations,
-                        config.tau_sq,
-                    );
-                    stats.bla_iterations += pixel_stats.bla_iterations as u64;
-                    stats.total_iterations += pixel_stats.total_iterations as u64;
-                    stats.rebase_count += pixel_stats.rebase_count as u64;
-                    data.push(ComputeData::Mandelbrot(result));
-                } else {
-                    // BLA enabled but no table - fall back to generic f64 path
-                    let result = compute_pixel_perturbation(
-                        orbit,
-                        F64Complex::from_f64_pair(delta_c.0, delta_c.1),
-                        config.max_iterations,
-                        config.tau_sq,
-                    );
-                    stats.total_iterations += result.iterations as u64;
-                    data.push(ComputeData::Mandelbrot(result));
-                }
-            } else {
-                // BLA disabled - use generic f64 path
-                let result = compute_pixel_perturbation(
-                    orbit,
-                    F64Complex::from_f64_pair(delta_c.0, delta_c.1),
-                    config.max_iterations,
-                    config.tau_sq,
-                );
-                stats.total_iterations += result.iterations as u64;
-                data.push(ComputeData::Mandelbrot(result));
+    let mut result = TileRenderResult::default();
+    fill_tile_f64(
+        orbit,
+        bla_table,
+        delta_origin,
+        delta_step,
+        config,
+        &mut result,
+        &mut Uninterrupted,
+    );
+    result
+}
+
+fn fill_tile_f64(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (f64, f64),
+    delta_step: DeltaStep<f64>,
+    config: &TileConfig,
+    out: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+) {
+    let delta_cs: Vec<(f64, f64)> = pixel_deltas(
+        delta_origin,
+        config.size,
+        |d| (d.0 + delta_step.x.0, d.1 + delta_step.x.1),
+        |d| (d.0 + delta_step.y.0, d.1 + delta_step.y.1),
+    )
+    .collect();
+    let fresh = |_: &(f64, f64)| PixelState::new(F64Complex::default());
+
+    let Some(bla) = bla_table.filter(|_| config.uses_bla()) else {
+        // BLA disabled or no table - use generic f64 path
+        fill_pixels(
+            &delta_cs,
+            config,
+            out,
+            interrupt,
+            fresh,
+            |dc, state, run| {
+                continue_pixel(orbit, &F64Complex::from_f64_pair(dc.0, dc.1), state, run)
+            },
+        );
+        return;
+    };
+
+    // Lane-parallel kernel, bit-identical to compute_pixel_perturbation_f64_bla.
+    // Set-aside pixels that can go further come first, then new ones.
+    let run = config.pixel_run();
+    let mut before = vec![BlaStats::default(); delta_cs.len()];
+    let resumed: Vec<LanePixel> = out
+        .take_resumable(run.stop_at)
+        .into_iter()
+        .map(|(index, progress)| {
+            let state = TilePixel::resume(&progress).unwrap_or_else(|| fresh(&delta_cs[index]));
+            before[index] = state.stats;
+            LanePixel {
+                index,
+                delta_c: delta_cs[index],
+                state,
             }
+        })
+        .collect();
+    let new: Vec<LanePixel> = (out.data.len()..delta_cs.len())
+        .map(|index| LanePixel::new(index, delta_cs[index]))
+        .collect();
+    for pixel in &new {
+        out.start_pixel(pixel.index, run.max_iterations);
+    }
+
+    let mut sink = TileLanes {
+        out,
+        interrupt,
+        before: &before,
+        max_iterations: run.max_iterations,
+    };
+    continue_pixels_f64_bla(orbit, bla, resumed.into_iter().chain(new), &run, &mut sink);
+}
+
+/// Takes the pixels leaving the lane kernel into a tile, and stops the
+/// kernel when the tile's interrupt asks to.
+struct TileLanes<'a> {
+    out: &'a mut TileRenderResult,
+    interrupt: &'a mut dyn TileInterrupt,
+    /// Statistics of each pixel before this call, by index
+    before: &'a [BlaStats],
+    max_iterations: u32,
+}
+
+impl LaneSink for TileLanes<'_> {
+    fn emit(&mut self, pixel: LanePixel, data: Option<MandelbrotData>) {
+        self.out.end_pixel(
+            pixel.index,
+            pixel.state,
+            &self.before[pixel.index],
+            data,
+            self.max_iterations,
+        );
+    }
 
-            delta_c.0 += delta_step.0;
+    fn should_stop(&mut self, in_flight: &[BlaStats; LANES]) -> bool {
+        let mut stats = self.out.stats.clone();
+        for pixel in in_flight {
+            stats.add(pixel);
         }
+        self.interrupt.should_stop(&stats)
+    }
+}
 
-        delta_c_row.1 += delta_step.1;
+/// Render a tile using scaled f64 with optional BLA acceleration.
+///
+/// Each pixel keeps its deltas as f64 values in units of a per-pixel power
+/// of two, so the inner loop runs on plain f64 arithmetic well below the
+/// f64 exponent floor. Takes HDRFloat deltas, like `render_tile_hdr`;
+/// without a BLA table it falls back to the generic FloatExp path.
+///
+/// # Arguments
+/// * `orbit` - Pre-computed reference orbit
+/// * `bla_table` - Optional BLA table for iteration skipping
+/// * `delta_origin` - Delta from reference point to top-left pixel (re, im)
+/// * `delta_step` - Delta change per pixel along a row and down a column
+/// * `config` - Tile rendering configuration
+///
+/// # Returns
+/// Computed pixel data and rendering statistics including BLA metrics
+pub fn render_tile_scaled(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (HDRFloat, HDRFloat),
+    delta_step: DeltaStep<HDRFloat>,
+    config: &TileConfig,
+) -> TileRenderResult {
+    let mut result = TileRenderResult::default();
+    fill_tile_scaled(
+        orbit,
+        bla_table,
+        delta_origin,
+        delta_step,
+        config,
+        &mut result,
+        &mut Uninterrupted,
+    );
+    result
+}
+
+fn fill_tile_scaled(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (HDRFloat, HDRFloat),
+    delta_step: DeltaStep<HDRFloat>,
+    config: &TileConfig,
+    out: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+) {
+    let step_x = HDRComplex {
+        re: delta_step.x.0,
+        im: delta_step.x.1,
+    };
+    let step_y = HDRComplex {
+        re: delta_step.y.0,
+        im: delta_step.y.1,
+    };
+    let origin = HDRComplex {
+        re: delta_origin.0,
+        im: delta_origin.1,
+    };
+
+    let deltas: Vec<HDRComplex> =
+        pixel_deltas(origin, config.size, |d| d.add(&step_x), |d| d.add(&step_y)).collect();
+    match bla_table.filter(|_| config.uses_bla()) {
+        Some(bla) => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            ScaledPixelState::new,
+            |delta_c, state, run| continue_pixel_scaled_bla(orbit, bla, *delta_c, state, run),
+        ),
+        None => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            |_| PixelState::new(FloatExpComplex::ZERO),
+            |delta_c, state, run| {
+                continue_pixel(orbit, &FloatExpComplex::from_hdr(delta_c), state, run)
+            },
+        ),
     }
+}
 
-    TileRenderResult { data, stats }
+/// Render a tile using FloatExp precision with optional BLA acceleration.
+///
+/// Used once deltas leave the f64 exponent range. The f64 mantissa keeps
+/// each operation a single native multiply, so this is faster than the
+/// HDRFloat path on the CPU while covering the same depths.
+///
+/// # Arguments
+/// * `orbit` - Pre-computed reference orbit
+/// * `bla_table` - Optional BLA table for iteration skipping
+/// * `delta_origin` - Delta from reference point to top-left pixel (re, im)
+/// * `delta_step` - Delta change per pixel along a row and down a column
+/// * `config` - Tile rendering configuration
+///
+/// # Returns
+/// Computed pixel data and rendering statistics including BLA metrics
+pub fn render_tile_floatexp(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (FloatExp, FloatExp),
+    delta_step: DeltaStep<FloatExp>,
+    config: &TileConfig,
+) -> TileRenderResult {
+    let mut result = TileRenderResult::default();
+    fill_tile_floatexp(
+        orbit,
+        bla_table,
+        delta_origin,
+        delta_step,
+        config,
+        &mut result,
+        &mut Uninterrupted,
+    );
+    result
+}
+
+fn fill_tile_floatexp(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (FloatExp, FloatExp),
+    delta_step: DeltaStep<FloatExp>,
+    config: &TileConfig,
+    out: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+) {
+    let step_x = FloatExpComplex {
+        re: delta_step.x.0,
+        im: delta_step.x.1,
+    };
+    let step_y = FloatExpComplex {
+        re: delta_step.y.0,
+        im: delta_step.y.1,
+    };
+    let origin = FloatExpComplex {
+        re: delta_origin.0,
+        im: delta_origin.1,
+    };
+
+    let deltas: Vec<FloatExpComplex> =
+        pixel_deltas(origin, config.size, |d| d.add(&step_x), |d| d.add(&step_y)).collect();
+    let fresh = |_: &FloatExpComplex| PixelState::new(FloatExpComplex::ZERO);
+    match bla_table.filter(|_| config.uses_bla()) {
+        Some(bla) => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            fresh,
+            |delta_c, state, run| continue_pixel_floatexp_bla(orbit, bla, *delta_c, state, run),
+        ),
+        // BLA disabled or no table - use generic FloatExpComplex path
+        None => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            fresh,
+            |delta_c, state, run| continue_pixel(orbit, delta_c, state, run),
+        ),
+    }
 }
 
 /// Render a tile using HDRFloat precision with optional BLA acceleration.
@@ -130,86 +767,80 @@ pub fn render_tile_f64(
 /// * `orbit` - Pre-computed reference orbit
 /// * `bla_table` - Optional BLA table for iteration skipping
 /// * `delta_origin` - Delta from reference point to top-left pixel (re, im)
-/// * `delta_step` - Delta step between pixels (re, im)
+/// * `delta_step` - Delta change per pixel along a row and down a column
 /// * `config` - Tile rendering configuration
 ///
 /// # Returns
 /// Computed pixel data and rendering statistics including BLA metrics
 pub fn render_tile_hdr(
-    orbit: &ReferenceOrbit,
-    bla_table: Option<&BlaTable>,
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
     delta_origin: (HDRFloat, HDRFloat),
-    delta_step: (HDRFloat, HDRFloat),
+    delta_step: DeltaStep<HDRFloat>,
     config: &TileConfig,
 ) -> TileRenderResult {
-    let capacity = (config.size.0 * config.size.1) as usize;
-    let mut data = Vec::with_capacity(capacity);
-    let mut stats = TileStats::default();
+    let mut result = TileRenderResult::default();
+    fill_tile_hdr(
+        orbit,
+        bla_table,
+        delta_origin,
+        delta_step,
+        config,
+        &mut result,
+        &mut Uninterrupted,
+    );
+    result
+}
 
+fn fill_tile_hdr(
+    orbit: &impl OrbitSource,
+    bla_table: Option<&dyn BlaSource>,
+    delta_origin: (HDRFloat, HDRFloat),
+    delta_step: DeltaStep<HDRFloat>,
+    config: &TileConfig,
+    out: &mut TileRenderResult,
+    interrupt: &mut dyn TileInterrupt,
+) {
     let delta_origin_complex = HDRComplex {
         re: delta_origin.0,
         im: delta_origin.1,
     };
-    let delta_step_complex = HDRComplex {
-        re: delta_step.0,
-        im: delta_step.1,
+    let step_x = HDRComplex {
+        re: delta_step.x.0,
+        im: delta_step.x.1,
+    };
+    let step_y = HDRComplex {
+        re: delta_step.y.0,
+        im: delta_step.y.1,
     };
 
-    let mut delta_c_row = delta_origin_complex;
-
-    for _py in 0..config.size.1 {
-        let mut delta_c = delta_c_row;
-
-        for _px in 0..config.size.0 {
-            if config.bla_enabled {
-                if let Some(bla) = bla_table {
-                    let (result, pixel_stats) = compute_pixel_perturbation_hdr_bla(
-                        orbit,
-                        bla,
-                        delta_c,
-                        config.max_iterations,
-                        config.tau_sq,
-                    );
-                    stats.bla_iterations += pixel_stats.bla_iterations as u64;
-                    stats.total_iterations += pixel_stats.total_iterations as u64;
-                    stats.rebase_count += pixel_stats.rebase_count as u64;
-                    data.push(ComputeData::Mandelbrot(result));
-                } else {
-                    // BLA enabled but no table - fall back to generic HDRComplex path
-                    let result = compute_pixel_perturbation(
-                        orbit,
-                        delta_c,
-                        config.max_iterations,
-                        config.tau_sq,
-                    );
-                    stats.total_iterations += result.iterations as u64;
-                    data.push(ComputeData::Mandelbrot(result));
-                }
-            } else {
-                // BLA disabled - use generic HDRComplex path
-                let result = compute_pixel_perturbation(
-                    orbit,
-                    delta_c,
-                    config.max_iterations,
-                    config.tau_sq,
-                );
-                stats.total_iterations += result.iterations as u64;
-                data.push(ComputeData::Mandelbrot(result));
-            }
-
-            delta_c = HDRComplex {
-                re: delta_c.re.add(&delta_step_complex.re),
-                im: delta_c.im,
-            };
-        }
-
-        delta_c_row = HDRComplex {
-            re: delta_c_row.re,
-            im: delta_c_row.im.add(&delta_step_complex.im),
-        };
+    let deltas: Vec<HDRComplex> = pixel_deltas(
+        delta_origin_complex,
+        config.size,
+        |d| d.add(&step_x),
+        |d| d.add(&step_y),
+    )
+    .collect();
+    let fresh = |_: &HDRComplex| PixelState::new(HDRComplex::ZERO);
+    match bla_table.filter(|_| config.uses_bla()) {
+        Some(bla) => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            fresh,
+            |delta_c, state, run| continue_pixel_hdr_bla(orbit, bla, *delta_c, state, run),
+        ),
+        // BLA disabled or no table - use generic HDRComplex path
+        None => fill_pixels(
+            &deltas,
+            config,
+            out,
+            interrupt,
+            fresh,
+            |delta_c, state, run| continue_pixel(orbit, delta_c, state, run),
+        ),
     }
-
-    TileRenderResult { data, stats }
 }
 
 #[cfg(test)]
@@ -230,11 +861,16 @@ mod tests {
             max_iterations: 1000,
             tau_sq: 1e-6,
             bla_enabled: true,
+            force_hdr_float: false,
+            scaled_f64: false,
+            collect_orbit_stats: false,
+            probe_interior: true,
+            iteration_limit: None,
         };
 
         // Small deltas to trigger BLA
         let delta_origin = (1e-12, 1e-12);
-        let delta_step = (1e-14, 1e-14);
+        let delta_step = DeltaStep::axis_aligned(1e-14, 1e-14);
 
         let result = render_tile_f64(&orbit, Some(&bla_table), delta_origin, delta_step, &config);
 
@@ -0,0 +1,235 @@
+//! Layouts of the SharedArrayBuffers the coordinator shares with workers.
+//!
+//! With cross-origin isolation the coordinator allocates one buffer for the
+//! finished reference orbit and its BLA table, and one for the results of a
+//! whole frame, instead of copying both through JSON messages. These helpers
+//! only describe and fill plain slices; copying between the slices and the
+//! JavaScript buffers is left to the two sides of the message channel.
+
+use crate::{BlaEntry, OrbitPoint, ReferenceOrbit};
+use fractalwonder_core::{MandelbrotData, PixelRect};
+
+/// f64 values per orbit point: Z and Der, real and imaginary parts.
+pub const ORBIT_POINT_WORDS: usize = 4;
+
+/// 32-bit words ahead of the pixels in a result buffer. Word 0 holds the
+/// current render: results are accepted for it only, and tiles and orbits
+/// of earlier renders stop at their next check. The rest is reserved.
+pub const RESULT_HEADER_WORDS: usize = 4;
+
+/// Placement of the orbit points and BLA entries in a shared orbit buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedOrbitLayout {
+    pub len: usize,
+    pub bla_entries: usize,
+}
+
+impl SharedOrbitLayout {
+    /// Number of f64 values holding the orbit points, from byte 0.
+    pub fn point_words(&self) -> usize {
+        self.len * ORBIT_POINT_WORDS
+    }
+
+    /// Byte offset of the BLA entries, right after the points.
+    pub fn bla_byte_offset(&self) -> usize {
+        self.point_words() * std::mem::size_of::<f64>()
+    }
+
+    /// Number of u32 words holding the BLA entries.
+    pub fn bla_words(&self) -> usize {
+        self.bla_entries * BlaEntry::WORDS
+    }
+
+    /// Total size of the buffer in bytes.
+    pub fn byte_len(&self) -> usize {
+        self.bla_byte_offset() + self.bla_words() * std::mem::size_of::<u32>()
+    }
+}
+
+/// Write the orbit points as interleaved (Z, Der) quadruples.
+pub fn write_orbit_points(orbit: &ReferenceOrbit, out: &mut [f64]) {
+    let points = orbit.orbit.iter().zip(&orbit.derivative);
+    for (words, (z, der)) in out.chunks_exact_mut(ORBIT_POINT_WORDS).zip(points) {
+        words.copy_from_slice(&[z.0, z.1, der.0, der.1]);
+    }
+}
+
+/// Read consecutive orbit points from values written by
+/// `write_orbit_points`, as many as fit in `out`.
+pub fn read_orbit_block(words: &[f64], out: &mut [OrbitPoint]) {
+    for (point, w) in out.iter_mut().zip(words.chunks_exact(ORBIT_POINT_WORDS)) {
+        *point = OrbitPoint {
+            z: (w[0], w[1]),
+            der: (w[2], w[3]),
+        };
+    }
+}
+
+/// Write BLA entries back to back.
+pub fn write_bla_entries(entries: &[BlaEntry], out: &mut [u32]) {
+    for (words, entry) in out.chunks_exact_mut(BlaEntry::WORDS).zip(entries) {
+        words.copy_from_slice(&entry.to_words());
+    }
+}
+
+/// Read consecutive BLA entries from words written by `write_bla_entries`,
+/// as many as fit in `out`.
+pub fn read_bla_entries(words: &[u32], out: &mut [BlaEntry]) {
+    for (entry, w) in out.iter_mut().zip(words.chunks_exact(BlaEntry::WORDS)) {
+        *entry = BlaEntry::from_words(w);
+    }
+}
+
+/// Number of u32 words in the result buffer of a `width × height` frame.
+pub fn result_buffer_words(width: u32, height: u32) -> usize {
+    RESULT_HEADER_WORDS + width as usize * height as usize * MandelbrotData::WORDS
+}
+
+/// Word offset of the first pixel of row `row` of `tile` in a frame
+/// `frame_width` pixels wide.
+pub fn result_row_offset(frame_width: u32, tile: &PixelRect, row: u32) -> usize {
+    let pixel = (tile.y + row) as usize * frame_width as usize + tile.x as usize;
+    RESULT_HEADER_WORDS + pixel * MandelbrotData::WORDS
+}
+
+/// Whether `tile` lies inside a `width × height` frame.
+pub fn tile_fits_frame(tile: &PixelRect, width: u32, height: u32) -> bool {
+    tile.x + tile.width <= width && tile.y + tile.height <= height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BlaBlocks, BlaSource, BlaTable, BlaWindow};
+    use fractalwonder_core::{BigFloat, HDRFloat};
+
+    fn orbit() -> ReferenceOrbit {
+        let c_ref = (
+            BigFloat::with_precision(-1.401155, 128),
+            BigFloat::zero(128),
+        );
+        ReferenceOrbit::compute(&c_ref, 500)
+    }
+
+    #[test]
+    fn orbit_points_roundtrip() {
+        let orbit = orbit();
+        let layout = SharedOrbitLayout {
+            len: orbit.orbit.len(),
+            bla_entries: 0,
+        };
+        let mut words = vec![0.0; layout.point_words()];
+        write_orbit_points(&orbit, &mut words);
+
+        let mut restored = vec![OrbitPoint::default(); orbit.orbit.len()];
+        read_orbit_block(&words, &mut restored);
+        assert!(restored.iter().map(|p| p.z).eq(orbit.orbit.iter().copied()));
+        assert!(restored
+            .iter()
+            .map(|p| p.der)
+            .eq(orbit.derivative.iter().copied()));
+        assert_eq!(layout.byte_len(), orbit.orbit.len() * 32);
+    }
+
+    #[test]
+    fn bla_table_roundtrip() {
+        let orbit = orbit();
+        let dc_max = HDRFloat::from_f64(1e-30);
+        let table = BlaTable::compute(&orbit, &dc_max);
+        let layout = SharedOrbitLayout {
+            len: orbit.orbit.len(),
+            bla_entries: table.entries.len(),
+        };
+        assert_eq!(layout.bla_byte_offset() % std::mem::size_of::<f64>(), 0);
+
+        let mut words = vec![0; layout.bla_words()];
+        write_bla_entries(&table.entries, &mut words);
+        let mut entries = vec![BlaEntry::default(); table.entries.len()];
+        read_bla_entries(&words, &mut entries);
+        let restored = BlaTable::from_parts(entries, table.level_offsets.clone(), &dc_max);
+
+        assert_eq!(restored.num_levels, table.num_levels);
+        for m in [0, 17, 256] {
+            let dz = HDRFloat::from_f64(1e-40);
+            let found = restored.find_valid(m, &dz, &dc_max).map(|e| e.l);
+            let expected = table.find_valid(m, &dz, &dc_max).map(|e| e.l);
+            assert_eq!(found, expected, "skip at {m}");
+        }
+    }
+
+    /// BLA entries in a word buffer, read the way a worker reads them
+    /// from a shared orbit buffer.
+    struct WordBla {
+        words: Vec<u32>,
+        level_offsets: Vec<usize>,
+        dc_max: HDRFloat,
+    }
+
+    impl BlaBlocks for WordBla {
+        fn dc_max(&self) -> &HDRFloat {
+            &self.dc_max
+        }
+
+        fn level_offsets(&self) -> &[usize] {
+            &self.level_offsets
+        }
+
+        fn entry_count(&self) -> usize {
+            self.words.len() / BlaEntry::WORDS
+        }
+
+        fn read_entries(&self, start: usize, out: &mut [BlaEntry]) {
+            read_bla_entries(&self.words[start * BlaEntry::WORDS..], out);
+        }
+    }
+
+    #[test]
+    fn windowed_bla_lookups_match_table() {
+        let c_ref = (
+            BigFloat::with_precision(-1.401155, 128),
+            BigFloat::zero(128),
+        );
+        let orbit = ReferenceOrbit::compute(&c_ref, 50_000);
+        let dc_max = HDRFloat::from_f64(1e-12);
+        let table = BlaTable::compute(&orbit, &dc_max);
+        let mut words = vec![0; table.entries.len() * BlaEntry::WORDS];
+        write_bla_entries(&table.entries, &mut words);
+        let blocks = WordBla {
+            words,
+            level_offsets: table.level_offsets.clone(),
+            dc_max,
+        };
+        let window = BlaWindow::new(&blocks);
+
+        assert_eq!(window.entry_count(), table.entries.len());
+        // Enough lookups at scattered points to evict blocks and read them again
+        for m in (0..orbit.orbit.len())
+            .step_by(97)
+            .chain([0, 49_152, 1024, 32_768])
+        {
+            for dz in [1e-40, 1e-24, 1e-16] {
+                let dz = HDRFloat::from_f64(dz);
+                let found = window.find_valid(m, &dz, &dc_max).map(|e| (e.l, e.r_sq));
+                let expected = table.find_valid(m, &dz, &dc_max).map(|e| (e.l, e.r_sq));
+                assert_eq!(found, expected, "skip at {m}");
+            }
+        }
+    }
+
+    #[test]
+    fn result_rows_are_placed_in_frame_order() {
+        let tile = PixelRect::new(32, 16, 8, 8);
+        let start = result_row_offset(100, &tile, 0);
+        assert_eq!(
+            start,
+            RESULT_HEADER_WORDS + (16 * 100 + 32) * MandelbrotData::WORDS
+        );
+        assert_eq!(
+            result_row_offset(100, &tile, 1) - start,
+            100 * MandelbrotData::WORDS
+        );
+        assert!(result_row_offset(100, &tile, 7) < result_buffer_words(100, 24));
+        assert!(tile_fits_frame(&tile, 40, 24));
+        assert!(!tile_fits_frame(&tile, 39, 24));
+    }
+}
@@ -1,42 +1,465 @@
 // fractalwonder-compute/src/worker.rs
-use crate::{render_tile_f64, render_tile_hdr, BlaTable, ReferenceOrbit, TileConfig};
-use fractalwonder_core::{BigFloat, HDRFloat, MainToWorker, WorkerToMain};
-use js_sys::Date;
+use crate::shared_buffers::{
+    read_bla_entries, read_orbit_block, result_row_offset, tile_fits_frame, SharedOrbitLayout,
+    ORBIT_POINT_WORDS, RESULT_HEADER_WORDS,
+};
+use crate::{
+    resume_tile, trace_orbit_path, BlaBlocks, BlaEntry, BlaSource, BlaTable, BlaWindow,
+    CompressedOrbit, OrbitBlocks, OrbitChunk, OrbitPoint, OrbitWindow, ReferenceOrbit,
+    ReferenceOrbitStream, TileConfig, TileInterrupt, TileRenderResult, TileStats,
+};
+use fractalwonder_core::{
+    BigFloat, ComputeData, DeltaPrecision, DeltaStep, HDRComplex, HDRFloat, MainToWorker,
+    MandelbrotData, PixelRect, SharedBlaLayout, WorkerToMain,
+};
+use js_sys::{Atomics, Date, Float64Array, Int32Array, Uint32Array};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
+use web_sys::{MessageChannel, MessagePort};
 
-/// Cached reference orbit for perturbation rendering.
-struct CachedOrbit {
+/// Orbit points computed between checks for progress and chunk messages.
+const ORBIT_STEP: u32 = 1024;
+
+/// Minimum time between `ReferenceOrbitProgress` messages.
+const ORBIT_PROGRESS_INTERVAL_MS: f64 = 100.0;
+
+/// Length of the first streamed orbit chunk. Each chunk makes the other
+/// workers rebuild their prefix BLA table, so chunks double in length up to
+/// `ORBIT_CHUNK_MAX` to keep the rebuilds few.
+const ORBIT_CHUNK_MIN: usize = 1 << 16;
+const ORBIT_CHUNK_MAX: usize = 1 << 20;
+
+/// Iterations a tile computes before the worker looks at its messages, when
+/// no shared result buffer can tell it about cancellation directly.
+const TILE_SLICE_ITERATIONS: u64 = 4_000_000;
+
+/// Time the reference orbit computes before the worker looks at its messages.
+const ORBIT_SLICE_MS: f64 = 20.0;
+
+/// Time a tile computes before the worker yields, even when a shared result
+/// buffer tells it about cancellation, so that heartbeats get out.
+const TILE_SLICE_MS: f64 = 250.0;
+
+/// Minimum time between `TileHeartbeat` messages.
+const TILE_HEARTBEAT_INTERVAL_MS: f64 = 1_000.0;
+
+/// Iterations recorded by `TraceOrbitPath`; the overlay cannot show more.
+const MAX_PATH_POINTS: usize = 10_000;
+
+/// Storage behind a cached reference orbit.
+enum OrbitStore {
+    /// Chunks are still arriving; tiles iterate over the prefix received so far
+    Streaming(ReferenceOrbit),
+    /// Complete; the kernels regenerate it a block at a time
+    Compressed(CompressedOrbit),
+    /// Complete and held by the coordinator in a SharedArrayBuffer; the
+    /// kernels read it a block at a time
+    Shared(SharedOrbit),
+}
+
+/// Reference orbit living in a SharedArrayBuffer, with the BLA table the
+/// coordinator built for it, if any.
+struct SharedOrbit {
+    points: Float64Array,
+    len: usize,
     c_ref: (f64, f64),
-    orbit: Vec<(f64, f64)>,
-    derivative: Vec<(f64, f64)>,
     escaped_at: Option<u32>,
+    period: Option<u32>,
+    bla: Option<SharedBla>,
+}
+
+impl OrbitBlocks for SharedOrbit {
+    fn c_ref(&self) -> (f64, f64) {
+        self.c_ref
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn escaped_at(&self) -> Option<u32> {
+        self.escaped_at
+    }
+
+    fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    /// Copies only the block's values out of the shared buffer.
+    fn read_points(&self, start: usize, out: &mut [OrbitPoint]) {
+        let mut words = vec![0.0; out.len() * ORBIT_POINT_WORDS];
+        let first = (start * ORBIT_POINT_WORDS) as u32;
+        self.points
+            .subarray(first, first + words.len() as u32)
+            .copy_to(&mut words);
+        read_orbit_block(&words, out);
+    }
+}
+
+/// BLA table living in a SharedArrayBuffer, after the orbit points.
+struct SharedBla {
+    words: Uint32Array,
+    level_offsets: Vec<usize>,
+    entries: usize,
+    dc_max: HDRFloat,
+}
+
+impl BlaBlocks for SharedBla {
+    fn dc_max(&self) -> &HDRFloat {
+        &self.dc_max
+    }
+
+    fn level_offsets(&self) -> &[usize] {
+        &self.level_offsets
+    }
+
+    fn entry_count(&self) -> usize {
+        self.entries
+    }
+
+    /// Copies only the block's words out of the shared buffer.
+    fn read_entries(&self, start: usize, out: &mut [BlaEntry]) {
+        let mut words = vec![0; out.len() * BlaEntry::WORDS];
+        let first = (start * BlaEntry::WORDS) as u32;
+        self.words
+            .subarray(first, first + words.len() as u32)
+            .copy_to(&mut words);
+        read_bla_entries(&words, out);
+    }
+}
+
+/// BLA lookups for a tile: the worker's own table, or a window on the one
+/// in a shared orbit buffer.
+enum TileBla<'a> {
+    Table(&'a BlaTable),
+    Shared(BlaWindow<'a>),
+}
+
+impl TileBla<'_> {
+    fn source(&self) -> &dyn BlaSource {
+        match self {
+            TileBla::Table(table) => *table,
+            TileBla::Shared(window) => window,
+        }
+    }
+}
+
+/// Full-frame result buffer shared with the coordinator.
+struct SharedResults {
+    width: u32,
+    height: u32,
+    header: Int32Array,
+    words: Uint32Array,
+}
+
+impl SharedResults {
+    fn new(buffer: &JsValue, width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            header: Int32Array::new_with_byte_offset_and_length(
+                buffer,
+                0,
+                RESULT_HEADER_WORDS as u32,
+            ),
+            words: Uint32Array::new(buffer),
+        }
+    }
+
+    /// Whether the coordinator has moved past `render_id`.
+    fn cancelled(&self, render_id: u32) -> bool {
+        Atomics::load(&self.header, 0).is_ok_and(|current| render_id < current as u32)
+    }
+
+    /// Write a finished tile into the frame.
+    ///
+    /// Returns false, leaving the buffer untouched, if the coordinator has
+    /// moved on from `render_id` or the tile is outside the frame; the
+    /// results then travel in the message instead.
+    fn write_tile(&self, render_id: u32, tile: &PixelRect, data: &[ComputeData]) -> bool {
+        let current = Atomics::load(&self.header, 0).unwrap_or(-1);
+        if current != render_id as i32 || !tile_fits_frame(tile, self.width, self.height) {
+            return false;
+        }
+        let mut row = Vec::with_capacity(tile.width as usize * MandelbrotData::WORDS);
+        for (y, pixels) in data.chunks(tile.width as usize).enumerate() {
+            row.clear();
+            for ComputeData::Mandelbrot(pixel) in pixels {
+                row.extend_from_slice(&pixel.to_words());
+            }
+            let offset = result_row_offset(self.width, tile, y as u32) as u32;
+            self.words
+                .subarray(offset, offset + row.len() as u32)
+                .copy_from(&row);
+        }
+        true
+    }
+}
+
+/// Cached reference orbit for perturbation rendering.
+struct CachedOrbit {
+    store: OrbitStore,
+    /// Table built by this worker; a shared orbit carries its own
     bla_table: Option<BlaTable>,
+    dc_max: HDRFloat,
+    bla_enabled: bool,
+    /// Orbit length `bla_table` was built for
+    bla_len: usize,
 }
 
 impl CachedOrbit {
-    fn to_reference_orbit(&self) -> ReferenceOrbit {
-        ReferenceOrbit {
-            c_ref: self.c_ref,
-            orbit: self.orbit.clone(),
-            derivative: self.derivative.clone(),
-            escaped_at: self.escaped_at,
+    /// An empty orbit waiting for its first chunk.
+    fn streaming(c_ref: (f64, f64), dc_max: HDRFloat, bla_enabled: bool) -> Self {
+        Self {
+            store: OrbitStore::Streaming(ReferenceOrbit {
+                c_ref,
+                orbit: Vec::new(),
+                derivative: Vec::new(),
+                escaped_at: None,
+                period: None,
+            }),
+            bla_table: None,
+            dc_max,
+            bla_enabled,
+            bla_len: 0,
         }
     }
+
+    /// A finished orbit, compressed and with its BLA table built.
+    fn complete(orbit: &ReferenceOrbit, dc_max: HDRFloat, bla_enabled: bool) -> Self {
+        let compressed = compress_orbit(orbit, &dc_max);
+        let bla_table = build_bla_table(&dc_max, bla_enabled, || {
+            BlaTable::from_compressed(&compressed, &dc_max)
+        });
+        Self {
+            store: OrbitStore::Compressed(compressed),
+            bla_table,
+            dc_max,
+            bla_enabled,
+            bla_len: orbit.orbit.len(),
+        }
+    }
+
+    /// A finished orbit in a shared buffer laid out as described by the
+    /// `AttachSharedOrbit` message, together with the BLA table if the
+    /// coordinator built one. Neither is copied out of the buffer.
+    fn shared(
+        buffer: &JsValue,
+        orbit: SharedOrbitDescription,
+        dc_max: HDRFloat,
+        bla: Option<SharedBlaLayout>,
+    ) -> Self {
+        let layout = SharedOrbitLayout {
+            len: orbit.len as usize,
+            bla_entries: bla.as_ref().map_or(0, |b| b.entries as usize),
+        };
+        let points =
+            Float64Array::new_with_byte_offset_and_length(buffer, 0, layout.point_words() as u32);
+        let bla = bla.map(|bla| SharedBla {
+            words: Uint32Array::new_with_byte_offset_and_length(
+                buffer,
+                layout.bla_byte_offset() as u32,
+                layout.bla_words() as u32,
+            ),
+            level_offsets: bla.level_offsets,
+            entries: layout.bla_entries,
+            dc_max,
+        });
+        Self {
+            bla_enabled: bla.is_some(),
+            store: OrbitStore::Shared(SharedOrbit {
+                points,
+                len: layout.len,
+                c_ref: orbit.c_ref,
+                escaped_at: orbit.escaped_at,
+                period: orbit.period,
+                bla,
+            }),
+            bla_table: None,
+            dc_max,
+            bla_len: layout.len,
+        }
+    }
+
+    /// Build the BLA table for the points received so far, if it is stale.
+    fn refresh_bla_table(&mut self) {
+        let OrbitStore::Streaming(orbit) = &self.store else {
+            return;
+        };
+        if self.bla_len == orbit.orbit.len() {
+            return;
+        }
+        self.bla_len = orbit.orbit.len();
+        self.bla_table = build_bla_table(&self.dc_max, self.bla_enabled, || {
+            BlaTable::compute(orbit, &self.dc_max)
+        });
+    }
+}
+
+/// Orbit fields of an `AttachSharedOrbit` message.
+struct SharedOrbitDescription {
+    c_ref: (f64, f64),
+    len: u32,
+    escaped_at: Option<u32>,
+    period: Option<u32>,
+}
+
+/// Tile or orbit computed a slice at a time, so that messages, and with
+/// them cancellation, get in between.
+enum Job {
+    Tile(TileJob),
+    Orbit(OrbitJob),
+}
+
+/// A perturbation tile and the pixels finished so far.
+struct TileJob {
+    render_id: u32,
+    tile: PixelRect,
+    orbit_id: u32,
+    delta_c_origin: (BigFloat, BigFloat),
+    delta_c_step: DeltaStep<BigFloat>,
+    config: TileConfig,
+    result: TileRenderResult,
+    precision: DeltaPrecision,
+    /// Time spent in slices, without the gaps between them
+    compute_time_ms: f64,
+    /// When the main thread last heard about the tile
+    last_heartbeat: f64,
+}
+
+/// A reference orbit being computed and streamed to the main thread.
+struct OrbitJob {
+    render_id: u32,
+    orbit_id: u32,
+    stream: ReferenceOrbitStream,
+    c_ref: (f64, f64),
+    /// Points computed since the last chunk was sent
+    pending: OrbitChunk,
+    chunk_len: usize,
+    start_time: f64,
+    last_progress: f64,
+}
+
+/// Stops a tile slice when its render is cancelled, when its time is up or,
+/// without a shared result buffer to check, when the slice has used its
+/// iteration budget.
+struct SliceInterrupt<'a> {
+    render_id: u32,
+    results: Option<&'a SharedResults>,
+    /// Iteration count at which the slice ends
+    budget_end: Option<u64>,
+    /// Time at which the slice ends
+    deadline: f64,
+    cancelled: bool,
+}
+
+impl TileInterrupt for SliceInterrupt<'_> {
+    fn should_stop(&mut self, stats: &TileStats) -> bool {
+        if self.results.is_some_and(|r| r.cancelled(self.render_id)) {
+            self.cancelled = true;
+            return true;
+        }
+        self.budget_end
+            .is_some_and(|end| stats.total_iterations >= end)
+            || Date::now() >= self.deadline
+    }
 }
 
 /// Worker state for orbit cache.
 struct WorkerState {
     orbit_cache: HashMap<u32, CachedOrbit>,
+    /// Frame buffer tile results are written to, when shared memory is on
+    results: Option<SharedResults>,
+    /// Tile or orbit in progress
+    job: Option<Job>,
+    /// Messages that arrived during `job`, handled in order once it ends
+    queued: VecDeque<(MainToWorker, Option<JsValue>)>,
+    /// Tiles waiting at the end of a streamed orbit prefix, by render and
+    /// tile corner, continued when the tile is sent again
+    parked: HashMap<(u32, u32, u32), TileJob>,
+    /// Work of renders before this one is dropped
+    oldest_wanted_render: u32,
+    /// Posting to this port schedules the next slice of `job`
+    wake: Option<MessagePort>,
+    wake_pending: bool,
 }
 
 impl WorkerState {
     fn new() -> Self {
         Self {
             orbit_cache: HashMap::new(),
+            results: None,
+            job: None,
+            queued: VecDeque::new(),
+            parked: HashMap::new(),
+            oldest_wanted_render: 0,
+            wake: None,
+            wake_pending: false,
+        }
+    }
+
+    fn is_cancelled(&self, render_id: u32) -> bool {
+        render_id < self.oldest_wanted_render
+            || self
+                .results
+                .as_ref()
+                .is_some_and(|r| r.cancelled(render_id))
+    }
+
+    /// Drop parked tiles of cancelled renders.
+    fn drop_cancelled_parked(&mut self) {
+        let oldest = self.oldest_wanted_render;
+        self.parked.retain(|_, job| job.render_id >= oldest);
+    }
+
+    /// Run the next slice of `job` after pending messages.
+    fn schedule_wake(&mut self) {
+        if self.wake_pending {
+            return;
         }
+        if let Some(port) = &self.wake {
+            self.wake_pending = port.post_message(&JsValue::NULL).is_ok();
+        }
+    }
+
+    /// Bring the BLA table of `orbit_id` up to date for `tile_orbit`.
+    /// Returns false if the orbit is not cached.
+    fn prepare_orbit(&mut self, orbit_id: u32) -> bool {
+        let Some(cached) = self.orbit_cache.get_mut(&orbit_id) else {
+            return false;
+        };
+        cached.refresh_bla_table();
+        true
+    }
+
+    /// Orbit, BLA table and iteration limit for a tile of `orbit_id`,
+    /// once `prepare_orbit` has run.
+    ///
+    /// While the orbit is still streaming in, iterate only as far as the
+    /// received prefix; pixels still running at its end wait for more.
+    fn tile_orbit(
+        &self,
+        orbit_id: u32,
+    ) -> Option<(OrbitWindow<'_>, Option<TileBla<'_>>, Option<u32>)> {
+        let cached = self.orbit_cache.get(&orbit_id)?;
+        let table = cached.bla_table.as_ref().map(TileBla::Table);
+        Some(match &cached.store {
+            OrbitStore::Streaming(orbit) => (
+                OrbitWindow::new(orbit),
+                table,
+                Some(orbit.orbit.len() as u32),
+            ),
+            OrbitStore::Compressed(orbit) => (OrbitWindow::new(orbit), table, None),
+            OrbitStore::Shared(orbit) => {
+                let bla = orbit
+                    .bla
+                    .as_ref()
+                    .map(|bla| TileBla::Shared(BlaWindow::new(bla)));
+                (OrbitWindow::new(orbit), bla, None)
+            }
+        })
     }
 }
 
@@ -55,29 +478,354 @@ fn post_message(msg: &WorkerToMain) {
     }
 }
 
+/// Compress a finished orbit for the cache.
+fn compress_orbit(orbit: &ReferenceOrbit, dc_max: &HDRFloat) -> CompressedOrbit {
+    let start_time = Date::now();
+    let compressed = CompressedOrbit::compress(orbit, dc_max);
+    let megabytes = |bytes: usize| bytes as f64 / (1024.0 * 1024.0);
+    web_sys::console::log_1(
+        &format!(
+            "[Worker] Compressed orbit: {} points to {} waypoints (period {:?}), {:.1} MB -> {:.1} MB in {:.0}ms",
+            orbit.orbit.len(),
+            compressed.waypoint_count(),
+            compressed.period(),
+            megabytes(orbit.orbit.len() * 2 * std::mem::size_of::<(f64, f64)>()),
+            megabytes(compressed.memory_bytes()),
+            Date::now() - start_time
+        )
+        .into(),
+    );
+    compressed
+}
+
+/// Build a BLA table with `build` when enabled and deep enough to pay off.
+fn build_bla_table(
+    dc_max: &HDRFloat,
+    bla_enabled: bool,
+    build: impl FnOnce() -> BlaTable,
+) -> Option<BlaTable> {
+    let bla_useful = BlaTable::pays_off(dc_max);
+
+    if bla_enabled && bla_useful {
+        let table = build();
+        web_sys::console::log_1(
+            &format!(
+                "[Worker] Built BLA table: {} entries, {} levels (dc_max: head={:.2e}, exp={})",
+                table.entries.len(),
+                table.num_levels,
+                dc_max.head,
+                dc_max.exp
+            )
+            .into(),
+        );
+        Some(table)
+    } else {
+        if bla_enabled && !bla_useful {
+            web_sys::console::log_1(
+                &format!(
+                    "[Worker] Skipping BLA table: dc_max (head={:.2e}, exp={}) too large (log2={:.0})",
+                    dc_max.head,
+                    dc_max.exp,
+                    dc_max.log2()
+                )
+                .into(),
+            );
+        }
+        None
+    }
+}
+
+impl OrbitJob {
+    fn new(
+        render_id: u32,
+        orbit_id: u32,
+        c_ref: &(BigFloat, BigFloat),
+        max_iterations: u32,
+    ) -> Self {
+        let stream = ReferenceOrbitStream::new(c_ref, max_iterations);
+        let start_time = Date::now();
+        Self {
+            render_id,
+            orbit_id,
+            c_ref: stream.c_ref(),
+            stream,
+            pending: OrbitChunk::default(),
+            chunk_len: ORBIT_CHUNK_MIN,
+            start_time,
+            last_progress: start_time,
+        }
+    }
+
+    /// Compute the orbit for one slice, streaming finished chunks and
+    /// progress to the main thread. Returns the job if it is unfinished;
+    /// the final `ReferenceOrbitComplete` carries the remainder.
+    fn advance(mut self, state: &WorkerState) -> Option<Self> {
+        let slice_start = Date::now();
+        while !self.stream.is_finished() {
+            if state.is_cancelled(self.render_id) {
+                abandon(self.render_id);
+                return None;
+            }
+            if Date::now() - slice_start >= ORBIT_SLICE_MS {
+                return Some(self);
+            }
+
+            let step = self.stream.next_chunk(ORBIT_STEP);
+            self.pending.orbit.extend(step.orbit);
+            self.pending.derivative.extend(step.derivative);
+
+            let now = Date::now();
+            if now - self.last_progress >= ORBIT_PROGRESS_INTERVAL_MS {
+                self.last_progress = now;
+                post_message(&WorkerToMain::ReferenceOrbitProgress {
+                    render_id: self.render_id,
+                    orbit_id: self.orbit_id,
+                    computed: self.stream.computed(),
+                    max_iterations: self.stream.max_iterations(),
+                });
+            }
+
+            if self.pending.len() >= self.chunk_len && !self.stream.is_finished() {
+                let next_start = self.pending.end();
+                let chunk = std::mem::replace(
+                    &mut self.pending,
+                    OrbitChunk {
+                        start: next_start,
+                        ..OrbitChunk::default()
+                    },
+                );
+                post_message(&WorkerToMain::ReferenceOrbitChunk {
+                    render_id: self.render_id,
+                    orbit_id: self.orbit_id,
+                    c_ref: self.c_ref,
+                    start: chunk.start,
+                    orbit: chunk.orbit,
+                    derivative: chunk.derivative,
+                });
+                self.chunk_len = (self.chunk_len * 2).min(ORBIT_CHUNK_MAX);
+            }
+        }
+
+        web_sys::console::log_1(
+            &format!(
+                "[Worker] Reference orbit computed: {} iterations in {:.0}ms, escaped_at={:?}",
+                self.stream.computed(),
+                Date::now() - self.start_time,
+                self.stream.escaped_at()
+            )
+            .into(),
+        );
+
+        post_message(&WorkerToMain::ReferenceOrbitComplete {
+            render_id: self.render_id,
+            orbit_id: self.orbit_id,
+            c_ref: self.c_ref,
+            start: self.pending.start,
+            orbit: self.pending.orbit,
+            derivative: self.pending.derivative,
+            escaped_at: self.stream.escaped_at(),
+        });
+        None
+    }
+}
+
+impl TileJob {
+    /// Render the tile for one slice. Returns the job if it is unfinished.
+    fn advance(mut self, state: &mut WorkerState) -> Option<Self> {
+        if state.is_cancelled(self.render_id) {
+            abandon(self.render_id);
+            return None;
+        }
+        let budget_end = state
+            .results
+            .is_none()
+            .then(|| self.result.stats.total_iterations + TILE_SLICE_ITERATIONS);
+
+        let slice_start = Date::now();
+        state.prepare_orbit(self.orbit_id);
+        let Some((orbit, bla_table, iteration_limit)) = state.tile_orbit(self.orbit_id) else {
+            post_message(&WorkerToMain::Error {
+                message: format!("Orbit {} not found in cache", self.orbit_id),
+            });
+            return None;
+        };
+        self.config.iteration_limit = iteration_limit;
+        let mut interrupt = SliceInterrupt {
+            render_id: self.render_id,
+            results: state.results.as_ref(),
+            budget_end,
+            deadline: slice_start + TILE_SLICE_MS,
+            cancelled: false,
+        };
+        self.precision = resume_tile(
+            &orbit,
+            bla_table.as_ref().map(TileBla::source),
+            &self.delta_c_origin,
+            &self.delta_c_step,
+            &self.config,
+            &mut self.result,
+            &mut interrupt,
+        );
+        self.compute_time_ms += Date::now() - slice_start;
+
+        if interrupt.cancelled {
+            abandon(self.render_id);
+            return None;
+        }
+        if self.result.needs_longer_orbit(&self.config) {
+            // Keep the unfinished pixels for when the tile comes back
+            self.report(state, true);
+            state
+                .parked
+                .insert((self.render_id, self.tile.x, self.tile.y), self);
+            return None;
+        }
+        if !self.result.is_complete(&self.config) {
+            let now = Date::now();
+            if now - self.last_heartbeat >= TILE_HEARTBEAT_INTERVAL_MS {
+                self.last_heartbeat = now;
+                post_message(&WorkerToMain::TileHeartbeat {
+                    render_id: self.render_id,
+                    tile: self.tile,
+                });
+            }
+            return Some(self);
+        }
+        self.report(state, false);
+        None
+    }
+
+    /// Hand the tile to the main thread and ask for the next one. The
+    /// statistics are the tile's totals so far.
+    fn report(&self, state: &WorkerState, needs_longer_orbit: bool) {
+        let Self {
+            render_id,
+            tile,
+            result,
+            precision,
+            compute_time_ms,
+            ..
+        } = self;
+
+        let in_shared_buffer = state
+            .results
+            .as_ref()
+            .is_some_and(|results| results.write_tile(*render_id, tile, &result.data));
+        let data = if in_shared_buffer {
+            Vec::new()
+        } else {
+            result.data.clone()
+        };
+
+        post_message(&WorkerToMain::TileComplete {
+            render_id: *render_id,
+            tile: *tile,
+            data,
+            compute_time_ms: *compute_time_ms,
+            bla_iterations: result.stats.bla_iterations,
+            total_iterations: result.stats.total_iterations,
+            rebase_count: result.stats.rebase_count,
+            precision: *precision,
+            needs_longer_orbit,
+            in_shared_buffer,
+        });
+
+        post_message(&WorkerToMain::RequestWork {
+            render_id: Some(*render_id),
+        });
+    }
+}
+
+/// Drop the work of a cancelled render and ask for more.
+fn abandon(render_id: u32) {
+    post_message(&WorkerToMain::RequestWork {
+        render_id: Some(render_id),
+    });
+}
+
+/// Run the current job for a slice. Once it ends, handle queued messages
+/// until one of them starts the next job; an unfinished job continues after
+/// the messages that arrive in the meantime.
+fn advance_job(state: &mut WorkerState) {
+    while let Some(job) = state.job.take() {
+        state.job = match job {
+            Job::Tile(job) => job.advance(state).map(Job::Tile),
+            Job::Orbit(job) => job.advance(state).map(Job::Orbit),
+        };
+        if state.job.is_some() {
+            state.schedule_wake();
+            return;
+        }
+        while state.job.is_none() {
+            let Some((msg, buffer)) = state.queued.pop_front() else {
+                return;
+            };
+            dispatch_message(state, msg, buffer);
+        }
+    }
+}
+
 fn handle_message(state: &mut WorkerState, data: JsValue) {
+    let Some((msg, buffer)) = parse_message(data) else {
+        return;
+    };
+
+    if let MainToWorker::CancelRender { render_id } = msg {
+        state.oldest_wanted_render = state.oldest_wanted_render.max(render_id);
+        state.drop_cancelled_parked();
+        // A job waiting for its next slice is dropped there
+        if state.job.is_some() {
+            state.schedule_wake();
+        }
+        return;
+    }
+    if state.job.is_some() {
+        state.queued.push_back((msg, buffer));
+        return;
+    }
+
+    dispatch_message(state, msg, buffer);
+    advance_job(state);
+}
+
+fn parse_message(data: JsValue) -> Option<(MainToWorker, Option<JsValue>)> {
+    // Messages that hand over a SharedArrayBuffer arrive as [json, buffer]
+    let (data, buffer) = if js_sys::Array::is_array(&data) {
+        let parts = js_sys::Array::from(&data);
+        (parts.get(0), Some(parts.get(1)))
+    } else {
+        (data, None)
+    };
     let Some(msg_str) = data.as_string() else {
         post_message(&WorkerToMain::Error {
             message: "Message is not a string".to_string(),
         });
-        return;
+        return None;
     };
 
-    let msg: MainToWorker = match serde_json::from_str(&msg_str) {
-        Ok(m) => m,
+    match serde_json::from_str(&msg_str) {
+        Ok(msg) => Some((msg, buffer)),
         Err(e) => {
             post_message(&WorkerToMain::Error {
                 message: format!("Failed to parse message: {}", e),
             });
-            return;
+            None
         }
-    };
+    }
+}
 
+/// Handle a message; tiles and orbits become `state.job`.
+fn dispatch_message(state: &mut WorkerState, msg: MainToWorker, buffer: Option<JsValue>) {
     match msg {
         MainToWorker::NoWork => {
             // Idle - wait for next message
         }
 
+        MainToWorker::CancelRender { render_id } => {
+            state.oldest_wanted_render = state.oldest_wanted_render.max(render_id);
+            state.drop_cancelled_parked();
+        }
+
         MainToWorker::Terminate => {
             web_sys::console::log_1(&"[Worker] Terminating".into());
             let global: web_sys::DedicatedWorkerGlobalScope =
@@ -102,31 +850,12 @@ fn handle_message(state: &mut WorkerState, data: JsValue) {
                 }
             };
 
-            let start_time = Date::now();
-
-            // Compute reference orbit
-            let orbit = ReferenceOrbit::compute(&c_ref, max_iterations);
-
-            let compute_time = Date::now() - start_time;
-            web_sys::console::log_1(
-                &format!(
-                    "[Worker] Reference orbit computed: {} iterations in {:.0}ms, escaped_at={:?}",
-                    orbit.orbit.len(),
-                    compute_time,
-                    orbit.escaped_at
-                )
-                .into(),
-            );
-
-            // Send result back
-            post_message(&WorkerToMain::ReferenceOrbitComplete {
+            state.job = Some(Job::Orbit(OrbitJob::new(
                 render_id,
                 orbit_id,
-                c_ref: orbit.c_ref,
-                orbit: orbit.orbit,
-                derivative: orbit.derivative,
-                escaped_at: orbit.escaped_at,
-            });
+                &c_ref,
+                max_iterations,
+            )));
         }
 
         MainToWorker::StoreReferenceOrbit {
@@ -138,63 +867,65 @@ fn handle_message(state: &mut WorkerState, data: JsValue) {
             dc_max,
             bla_enabled,
         } => {
-            // BLA helps at deep zoom where iteration counts are high.
-            // Phil Thompson enables BLA at scale > 1e25 (dc_max < ~1e-25).
-            // Reference: https://philthompson.me/2023/Faster-Mandelbrot-Set-Rendering-with-BLA-Bivariate-Linear-Approximation.html
-            // dc_max is now HDRFloat to prevent underflow at deep zoom
-            let dc_max_log2 = if dc_max.is_zero() {
-                f64::NEG_INFINITY
-            } else {
-                // log2(head * 2^exp) = log2(head) + exp
-                (dc_max.head as f64).log2() + dc_max.exp as f64
+            let ref_orbit = ReferenceOrbit {
+                c_ref,
+                orbit,
+                derivative,
+                escaped_at,
+                period: None,
             };
-            let bla_useful = dc_max_log2 < -80.0; // Roughly 10^-25 (scale > 1e25)
-
-            let bla_table = if bla_enabled && bla_useful {
-                let ref_orbit = ReferenceOrbit {
-                    c_ref,
-                    orbit: orbit.clone(),
-                    derivative: derivative.clone(),
-                    escaped_at,
-                };
-                let table = BlaTable::compute(&ref_orbit, &dc_max);
-                web_sys::console::log_1(
-                    &format!(
-                        "[Worker] Built BLA table: {} entries, {} levels (dc_max: head={:.2e}, exp={})",
-                        table.entries.len(),
-                        table.num_levels,
-                        dc_max.head,
-                        dc_max.exp
-                    )
-                    .into(),
-                );
-                Some(table)
-            } else {
-                if bla_enabled && !bla_useful {
-                    web_sys::console::log_1(
-                        &format!(
-                            "[Worker] Skipping BLA table: dc_max (head={:.2e}, exp={}) too large (log2={:.0})",
-                            dc_max.head, dc_max.exp, dc_max_log2
-                        )
-                        .into(),
-                    );
-                }
-                None
-            };
-
             state.orbit_cache.insert(
                 orbit_id,
-                CachedOrbit {
-                    c_ref,
-                    orbit,
-                    derivative,
-                    escaped_at,
-                    bla_table,
-                },
+                CachedOrbit::complete(&ref_orbit, dc_max, bla_enabled),
             );
             post_message(&WorkerToMain::OrbitStored { orbit_id });
         }
 
+        MainToWorker::AppendReferenceOrbit {
+            orbit_id,
+            c_ref,
+            start,
+            orbit,
+            derivative,
+            complete,
+            escaped_at,
+            dc_max,
+            bla_enabled,
+        } => {
+            let cached = state
+                .orbit_cache
+                .entry(orbit_id)
+                .or_insert_with(|| CachedOrbit::streaming(c_ref, dc_max, bla_enabled));
+
+            let OrbitStore::Streaming(stored) = &mut cached.store else {
+                post_message(&WorkerToMain::Error {
+                    message: format!("Orbit {} is already complete", orbit_id),
+                });
+                return;
+            };
+            if stored.orbit.len() != start as usize {
+                post_message(&WorkerToMain::Error {
+                    message: format!(
+                        "Orbit {} chunk starts at {} but {} points are stored",
+                        orbit_id,
+                        start,
+                        stored.orbit.len()
+                    ),
+                });
+                return;
+            }
+
+            stored.orbit.extend(orbit);
+            stored.derivative.extend(derivative);
+
+            if complete {
+                stored.escaped_at = escaped_at;
+                let finished = CachedOrbit::complete(stored, dc_max, bla_enabled);
+                state.orbit_cache.insert(orbit_id, finished);
+                post_message(&WorkerToMain::OrbitStored { orbit_id });
+            }
+        }
+
         MainToWorker::RenderTilePerturbation {
             render_id,
             tile,
@@ -206,7 +937,19 @@ fn handle_message(state: &mut WorkerState, data: JsValue) {
             bigfloat_threshold_bits: _,
             bla_enabled,
             force_hdr_float,
+            collect_orbit_stats,
+            probe_interior,
         } => {
+            // A tile parked at the end of the orbit prefix continues
+            if let Some(job) = state
+                .parked
+                .remove(&(render_id, tile.x, tile.y))
+                .filter(|job| job.orbit_id == orbit_id)
+            {
+                state.job = Some(Job::Tile(job));
+                return;
+            }
+
             // Parse BigFloat deltas from JSON
             let delta_c_origin: (BigFloat, BigFloat) =
                 match serde_json::from_str(&delta_c_origin_json) {
@@ -219,8 +962,7 @@ fn handle_message(state: &mut WorkerState, data: JsValue) {
                     }
                 };
 
-            let delta_c_step: (BigFloat, BigFloat) = match serde_json::from_str(&delta_c_step_json)
-            {
+            let delta_c_step: DeltaStep<BigFloat> = match serde_json::from_str(&delta_c_step_json) {
                 Ok(d) => d,
                 Err(e) => {
                     post_message(&WorkerToMain::Error {
@@ -230,82 +972,128 @@ fn handle_message(state: &mut WorkerState, data: JsValue) {
                 }
             };
 
-            // Get cached orbit
-            let cached = match state.orbit_cache.get(&orbit_id) {
-                Some(c) => c,
-                None => {
+            state.prepare_orbit(orbit_id);
+            let Some((_, _, iteration_limit)) = state.tile_orbit(orbit_id) else {
+                post_message(&WorkerToMain::Error {
+                    message: format!("Orbit {} not found in cache", orbit_id),
+                });
+                return;
+            };
+
+            state.job = Some(Job::Tile(TileJob {
+                render_id,
+                tile,
+                orbit_id,
+                delta_c_origin,
+                delta_c_step,
+                config: TileConfig {
+                    size: (tile.width, tile.height),
+                    max_iterations,
+                    tau_sq,
+                    bla_enabled,
+                    force_hdr_float,
+                    scaled_f64: true,
+                    collect_orbit_stats,
+                    probe_interior,
+                    iteration_limit,
+                },
+                result: TileRenderResult::default(),
+                precision: DeltaPrecision::F64,
+                compute_time_ms: 0.0,
+                last_heartbeat: Date::now(),
+            }));
+        }
+
+        MainToWorker::DiscardOrbit { orbit_id } => {
+            state.orbit_cache.remove(&orbit_id);
+            state.parked.retain(|_, job| job.orbit_id != orbit_id);
+        }
+
+        MainToWorker::TraceOrbitPath {
+            request_id,
+            orbit_id,
+            c_ref_json,
+            delta_c_json,
+            max_iterations,
+            tau_sq,
+        } => {
+            let delta_c: (BigFloat, BigFloat) = match serde_json::from_str(&delta_c_json) {
+                Ok(d) => d,
+                Err(e) => {
                     post_message(&WorkerToMain::Error {
-                        message: format!("Orbit {} not found in cache", orbit_id),
+                        message: format!("Failed to parse delta_c: {}", e),
                     });
                     return;
                 }
             };
-
-            let orbit = cached.to_reference_orbit();
-            let start_time = Date::now();
-
-            let config = TileConfig {
-                size: (tile.width, tile.height),
-                max_iterations,
-                tau_sq,
-                bla_enabled,
+            let delta_c = HDRComplex {
+                re: HDRFloat::from_bigfloat(&delta_c.0),
+                im: HDRFloat::from_bigfloat(&delta_c.1),
             };
 
-            // Dispatch based on delta magnitude: use f64 when deltas fit, HDRFloat otherwise
-            let delta_log2 = delta_c_origin
-                .0
-                .log2_approx()
-                .max(delta_c_origin.1.log2_approx());
-            let use_f64 = !force_hdr_float && delta_log2 > -900.0 && delta_log2 < 900.0;
-
-            let result = if use_f64 {
-                let delta_origin = (delta_c_origin.0.to_f64(), delta_c_origin.1.to_f64());
-                let delta_step = (delta_c_step.0.to_f64(), delta_c_step.1.to_f64());
-                render_tile_f64(
-                    &orbit,
-                    cached.bla_table.as_ref(),
-                    delta_origin,
-                    delta_step,
-                    &config,
-                )
-            } else {
-                let delta_origin = (
-                    HDRFloat::from_bigfloat(&delta_c_origin.0),
-                    HDRFloat::from_bigfloat(&delta_c_origin.1),
+            // A streaming prefix may stop short of the pixel, so only a
+            // complete cached orbit is reused
+            let cached = state.prepare_orbit(orbit_id)
+                && !matches!(
+                    state.orbit_cache.get(&orbit_id).map(|c| &c.store),
+                    Some(OrbitStore::Streaming(_))
                 );
-                let delta_step = (
-                    HDRFloat::from_bigfloat(&delta_c_step.0),
-                    HDRFloat::from_bigfloat(&delta_c_step.1),
-                );
-                render_tile_hdr(
-                    &orbit,
-                    cached.bla_table.as_ref(),
-                    delta_origin,
-                    delta_step,
-                    &config,
-                )
+            let path = match state.tile_orbit(orbit_id) {
+                Some((orbit, _, _)) if cached => {
+                    trace_orbit_path(&orbit, delta_c, max_iterations, tau_sq, MAX_PATH_POINTS)
+                }
+                _ => {
+                    let c_ref: (BigFloat, BigFloat) = match serde_json::from_str(&c_ref_json) {
+                        Ok(c) => c,
+                        Err(e) => {
+                            post_message(&WorkerToMain::Error {
+                                message: format!("Failed to parse c_ref: {}", e),
+                            });
+                            return;
+                        }
+                    };
+                    let orbit = ReferenceOrbit::compute(&c_ref, max_iterations);
+                    trace_orbit_path(&orbit, delta_c, max_iterations, tau_sq, MAX_PATH_POINTS)
+                }
             };
+            post_message(&WorkerToMain::OrbitPath { request_id, path });
+        }
 
-            let compute_time_ms = Date::now() - start_time;
-
-            post_message(&WorkerToMain::TileComplete {
-                render_id,
-                tile,
-                data: result.data,
-                compute_time_ms,
-                bla_iterations: result.stats.bla_iterations,
-                total_iterations: result.stats.total_iterations,
-                rebase_count: result.stats.rebase_count,
-                used_f64: use_f64,
-            });
-
-            post_message(&WorkerToMain::RequestWork {
-                render_id: Some(render_id),
-            });
+        MainToWorker::AttachSharedOrbit {
+            orbit_id,
+            c_ref,
+            len,
+            escaped_at,
+            period,
+            dc_max,
+            bla,
+        } => {
+            let Some(buffer) = buffer else {
+                post_message(&WorkerToMain::Error {
+                    message: format!("Shared orbit {} arrived without its buffer", orbit_id),
+                });
+                return;
+            };
+            let orbit = SharedOrbitDescription {
+                c_ref,
+                len,
+                escaped_at,
+                period,
+            };
+            state
+                .orbit_cache
+                .insert(orbit_id, CachedOrbit::shared(&buffer, orbit, dc_max, bla));
+            post_message(&WorkerToMain::OrbitStored { orbit_id });
         }
 
-        MainToWorker::DiscardOrbit { orbit_id } => {
-            state.orbit_cache.remove(&orbit_id);
+        MainToWorker::AttachResultBuffer { width, height } => {
+            let Some(buffer) = buffer else {
+                post_message(&WorkerToMain::Error {
+                    message: "Result buffer message arrived without its buffer".to_string(),
+                });
+                return;
+            };
+            state.results = Some(SharedResults::new(&buffer, width, height));
         }
     }
 }
@@ -324,6 +1112,21 @@ pub fn init_message_worker() {
         handle_message(&mut state_clone.borrow_mut(), e.data());
     }) as Box<dyn FnMut(_)>);
 
+    // Slices of long jobs are scheduled through a channel to ourselves,
+    // which unlike setTimeout is not throttled
+    let channel = MessageChannel::new().expect("MessageChannel not available");
+    let state_clone = Rc::clone(&state);
+    let onwake = Closure::wrap(Box::new(move |_: web_sys::MessageEvent| {
+        let mut state = state_clone.borrow_mut();
+        state.wake_pending = false;
+        advance_job(&mut state);
+    }) as Box<dyn FnMut(_)>);
+    channel
+        .port1()
+        .set_onmessage(Some(onwake.as_ref().unchecked_ref()));
+    onwake.forget();
+    state.borrow_mut().wake = Some(channel.port2());
+
     let global: web_sys::DedicatedWorkerGlobalScope =
         js_sys::global().dyn_into().expect("Not in worker context");
 
@@ -1,8 +1,15 @@
-use dashu_base::{Abs, Approximation, Sign};
+use dashu_base::{Abs, Approximation, BitTest, Sign};
 use dashu_float::ops::SquareRoot;
 use dashu_float::{DBig, FBig};
 use serde::{Deserialize, Serialize};
 
+pub mod compact;
+mod complex_square;
+mod transcendental;
+
+pub use compact::CompactBigFloat;
+pub use complex_square::ComplexSquare;
+
 /// Extract value from Approximation, accepting both Exact and Inexact results.
 ///
 /// The dashu library's `with_precision()` returns `Approximation<FBig, _>` which
@@ -36,97 +43,6 @@ fn exceeds_f64_exponent_range(s: &str) -> bool {
     false
 }
 
-/// Estimate log2 from BINARY (base-2) string representation from FBig::to_string().
-/// FBig outputs in base-2 format like "0.00000...001..." where zeros are binary zeros.
-///
-/// This function now parses the significant bits after leading zeros to provide
-/// accurate mantissa precision, not just integer approximation.
-fn estimate_log2_from_binary_string(s: &str) -> f64 {
-    // Strip leading sign if present (log2 of absolute value)
-    let unsigned_str = s.strip_prefix('-').unwrap_or(s);
-
-    // Handle small values: "0.000...001bbb..."
-    // The value is 0.1bbb... × 2^-(leading_zeros+1) where 0.1bbb... ∈ [0.5, 1.0)
-    if let Some(after_decimal) = unsigned_str.strip_prefix("0.") {
-        let leading_zeros = after_decimal.chars().take_while(|&c| c == '0').count();
-
-        // Parse significant bits after leading zeros to compute mantissa
-        // The mantissa starts with implicit "0.1" (value 0.5), then adds
-        // subsequent bits: 0.1b₁b₂b₃... = 0.5 + b₁×0.25 + b₂×0.125 + ...
-        let sig_bits_start = leading_zeros;
-        let sig_chars: Vec<char> = after_decimal
-            .chars()
-            .skip(sig_bits_start)
-            .take(24) // Parse up to 24 bits for f64 precision
-            .collect();
-
-        let mantissa = if sig_chars.is_empty() || sig_chars[0] != '1' {
-            // Shouldn't happen for non-zero values, but fallback to 0.5
-            0.5
-        } else {
-            // Build mantissa: 0.1b₁b₂... = 0.5 + Σ(bᵢ × 2^-(i+1))
-            let mut m = 0.5_f64;
-            let mut weight = 0.25_f64;
-            for &c in sig_chars.iter().skip(1) {
-                if c == '1' {
-                    m += weight;
-                }
-                weight *= 0.5;
-            }
-            m
-        };
-
-        // log2(value) = log2(mantissa) + exponent
-        // where exponent = -(leading_zeros + 1)
-        let exponent = -(leading_zeros as f64 + 1.0);
-        return mantissa.log2() + exponent;
-    }
-
-    // Handle large values: count digits before decimal point
-    // In binary, n digits before decimal = 2^(n-1) magnitude
-    if let Some(dot_pos) = unsigned_str.find('.') {
-        let integer_part = &unsigned_str[..dot_pos];
-        let digits: String = integer_part
-            .chars()
-            .filter(|c| *c == '0' || *c == '1')
-            .collect();
-        if !digits.is_empty() {
-            // Parse significant bits for mantissa
-            let mut m = 0.0_f64;
-            let mut weight = 0.5_f64;
-            for c in digits.chars() {
-                if c == '1' {
-                    m += weight;
-                }
-                weight *= 0.5;
-            }
-            let exponent = digits.len() as f64;
-            return m.log2() + exponent;
-        }
-    } else {
-        // No decimal point - count all binary digits
-        let digits: String = unsigned_str
-            .chars()
-            .filter(|c| *c == '0' || *c == '1')
-            .collect();
-        if !digits.is_empty() {
-            let mut m = 0.0_f64;
-            let mut weight = 0.5_f64;
-            for c in digits.chars() {
-                if c == '1' {
-                    m += weight;
-                }
-                weight *= 0.5;
-            }
-            let exponent = digits.len() as f64;
-            return m.log2() + exponent;
-        }
-    }
-
-    // Last resort
-    0.0
-}
-
 /// Arbitrary precision floating point with explicit precision enforcement
 ///
 /// Uses f64 internally when precision_bits <= 64, FBig otherwise.
@@ -200,12 +116,20 @@ impl BigFloat {
                 precision_bits,
             }
         } else {
-            // Convert to arbitrary precision
-            let fbig = approx_value(self.to_fbig().with_precision(precision_bits));
-            Self {
-                value: BigFloatValue::Arbitrary(fbig),
-                precision_bits,
-            }
+            Self::from_fbig(self.to_fbig(), precision_bits)
+        }
+    }
+
+    /// Store an FBig at `precision_bits`, as f64 when that is enough.
+    fn from_fbig(value: FBig, precision_bits: usize) -> Self {
+        let value = if precision_bits <= 64 {
+            BigFloatValue::F64(value.to_f64().value())
+        } else {
+            BigFloatValue::Arbitrary(approx_value(value.with_precision(precision_bits)))
+        };
+        Self {
+            value,
+            precision_bits,
         }
     }
 
@@ -218,6 +142,74 @@ impl BigFloat {
         }
     }
 
+    /// Decimal digits with as much precision as the value carries.
+    ///
+    /// Magnitudes from 1e-5 up to 1e15 are written positionally, others in
+    /// scientific notation ("-1.25e-300"). `from_string` parses both forms.
+    pub fn to_decimal_string(&self) -> String {
+        let v = match &self.value {
+            BigFloatValue::F64(v) => {
+                let exponent = if *v == 0.0 {
+                    0
+                } else {
+                    v.abs().log10().floor() as i64
+                };
+                return if (-5..15).contains(&exponent) {
+                    format!("{}", v)
+                } else {
+                    format!("{:e}", v)
+                };
+            }
+            BigFloatValue::Arbitrary(v) => v,
+        };
+
+        // Round to the decimal digits the binary precision can resolve
+        let digits = ((self.precision_bits as f64 * std::f64::consts::LOG10_2) as usize).max(17);
+        let decimal = match v
+            .clone()
+            .with_rounding::<dashu_float::round::mode::HalfAway>()
+            .with_base_and_precision::<10>(digits)
+        {
+            Approximation::Exact(d) => d,
+            Approximation::Inexact(d, _) => d,
+        };
+        let repr = decimal.repr();
+        if repr.is_zero() {
+            return "0".to_string();
+        }
+
+        let (sign, magnitude) = repr.significand().clone().into_parts();
+        let sign = if sign == Sign::Negative { "-" } else { "" };
+        let all_digits = magnitude.to_string();
+        let mantissa = all_digits.trim_end_matches('0');
+        let trailing_zeros = all_digits.len() - mantissa.len();
+        let last_digit_exponent = repr.exponent() as i64 + trailing_zeros as i64;
+        let exponent = last_digit_exponent + mantissa.len() as i64 - 1;
+
+        if !(-5..15).contains(&exponent) {
+            let (lead, rest) = mantissa.split_at(1);
+            return if rest.is_empty() {
+                format!("{sign}{lead}e{exponent}")
+            } else {
+                format!("{sign}{lead}.{rest}e{exponent}")
+            };
+        }
+        if last_digit_exponent >= 0 {
+            return format!(
+                "{sign}{mantissa}{}",
+                "0".repeat(last_digit_exponent as usize)
+            );
+        }
+        let fraction_digits = (-last_digit_exponent) as usize;
+        if fraction_digits >= mantissa.len() {
+            let zeros = "0".repeat(fraction_digits - mantissa.len());
+            format!("{sign}0.{zeros}{mantissa}")
+        } else {
+            let (whole, fraction) = mantissa.split_at(mantissa.len() - fraction_digits);
+            format!("{sign}{whole}.{fraction}")
+        }
+    }
+
     /// Create BigFloat from string with explicit precision
     ///
     /// Allows creating values beyond f64 range (e.g., "1e1000").
@@ -394,44 +386,60 @@ impl BigFloat {
         }
     }
 
-    /// Approximate log2 using exponent extraction.
-    /// Accurate to ~1 bit, sufficient for precision calculation.
-    /// Returns f64::NEG_INFINITY for zero values.
-    pub fn log2_approx(&self) -> f64 {
+    /// Split into a signed mantissa and binary exponent.
+    ///
+    /// The value equals `mantissa × 2^exponent` with `|mantissa|` in [0.5, 1),
+    /// truncated to 53 bits. The exponent is exact for every representable
+    /// value, so zoom depth can be measured without going through f64.
+    /// Returns `(0.0, 0)` for zero.
+    pub fn to_hdr_parts(&self) -> (f64, i64) {
         match &self.value {
             BigFloatValue::F64(v) => {
-                if *v == 0.0 {
-                    f64::NEG_INFINITY
-                } else {
-                    v.abs().log2()
-                }
+                let (mantissa, exponent) = libm::frexp(*v);
+                (mantissa, exponent as i64)
             }
             BigFloatValue::Arbitrary(v) => {
-                // FBig uses base-2 representation internally
-                // log2(value) ≈ exponent (crude but sufficient for precision calc)
-                // For more accuracy, we convert to f64 if possible, else estimate from exponent
-                let f64_val = v.to_f64().value();
-                if f64_val == 0.0 {
-                    // Either actually zero, or underflowed to zero
-                    // FBig::to_string() is base-2, so we need to count BINARY zeros
-                    // and NOT multiply by log2(10)
-                    let s = v.to_string();
-                    if s == "0" || s == "0.0" {
-                        return f64::NEG_INFINITY;
-                    }
-                    // Very small but not zero - estimate from binary string
-                    estimate_log2_from_binary_string(&s)
-                } else if f64_val.is_finite() {
-                    f64_val.abs().log2()
-                } else {
-                    // Value too extreme for f64, estimate from binary string representation
-                    let s = v.to_string();
-                    estimate_log2_from_binary_string(&s)
+                let repr = v.repr();
+                if repr.is_zero() {
+                    return (0.0, 0);
                 }
+                let (sign, magnitude) = repr.significand().clone().into_parts();
+                let bits = magnitude.bit_len();
+                let shift = bits.saturating_sub(f64::MANTISSA_DIGITS as usize);
+                let top = (magnitude >> shift).to_f64().value();
+                let mantissa = top / libm::exp2((bits - shift) as f64);
+                let exponent = repr.exponent() as i64 + bits as i64;
+                (sign * mantissa, exponent)
             }
         }
     }
 
+    /// Base-2 logarithm of the absolute value.
+    ///
+    /// Built on `to_hdr_parts`, so it stays accurate at any exponent.
+    /// Returns f64::NEG_INFINITY for zero values.
+    pub fn log2_approx(&self) -> f64 {
+        let (mantissa, exponent) = self.to_hdr_parts();
+        if mantissa == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        mantissa.abs().log2() + exponent as f64
+    }
+
+    /// log2(|self| / |other|), subtracting the binary exponents as integers
+    /// before anything is rounded to f64.
+    ///
+    /// Zero operands give the same infinities or NaN as subtracting
+    /// `log2_approx` values.
+    pub fn log2_ratio(&self, other: &Self) -> f64 {
+        let (m_self, e_self) = self.to_hdr_parts();
+        let (m_other, e_other) = other.to_hdr_parts();
+        if m_self == 0.0 || m_other == 0.0 {
+            return self.log2_approx() - other.log2_approx();
+        }
+        e_self.saturating_sub(e_other) as f64 + (m_self.abs().log2() - m_other.abs().log2())
+    }
+
     /// Convert to FBig for arbitrary precision operations
     fn to_fbig(&self) -> FBig {
         match &self.value {
@@ -487,17 +495,46 @@ impl std::fmt::Display for BigFloat {
     }
 }
 
+/// Legacy human-readable form, still written to text formats
 #[derive(Serialize, Deserialize)]
 struct BigFloatSerde {
     value: String,
     precision_bits: usize,
 }
 
+impl BigFloatSerde {
+    fn into_bigfloat(self) -> Result<BigFloat, String> {
+        let value = if self.precision_bits <= 64 {
+            let f = self
+                .value
+                .parse::<f64>()
+                .map_err(|e| format!("Failed to parse f64: {}", e))?;
+            BigFloatValue::F64(f)
+        } else {
+            let fbig = self
+                .value
+                .parse::<FBig>()
+                .map_err(|e| format!("Failed to parse FBig: {}", e))?;
+            BigFloatValue::Arbitrary(fbig)
+        };
+
+        Ok(BigFloat {
+            value,
+            precision_bits: self.precision_bits,
+        })
+    }
+}
+
 impl Serialize for BigFloat {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
+        // Binary formats always get the compact encoding
+        if !serializer.is_human_readable() {
+            return self.compact().serialize(serializer);
+        }
+
         let value_str = match &self.value {
             BigFloatValue::F64(v) => v.to_string(),
             BigFloatValue::Arbitrary(v) => v.to_string(),
@@ -517,26 +554,11 @@ impl<'de> Deserialize<'de> for BigFloat {
     where
         D: serde::Deserializer<'de>,
     {
-        let serde = BigFloatSerde::deserialize(deserializer)?;
-
-        let value = if serde.precision_bits <= 64 {
-            let f = serde
-                .value
-                .parse::<f64>()
-                .map_err(|e| serde::de::Error::custom(format!("Failed to parse f64: {}", e)))?;
-            BigFloatValue::F64(f)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(compact::BigFloatVisitor)
         } else {
-            let fbig = serde
-                .value
-                .parse::<FBig>()
-                .map_err(|e| serde::de::Error::custom(format!("Failed to parse FBig: {}", e)))?;
-            BigFloatValue::Arbitrary(fbig)
-        };
-
-        Ok(BigFloat {
-            value,
-            precision_bits: serde.precision_bits,
-        })
+            deserializer.deserialize_bytes(compact::BigFloatVisitor)
+        }
     }
 }
 
@@ -544,6 +566,37 @@ impl<'de> Deserialize<'de> for BigFloat {
 mod tests {
     use super::*;
 
+    #[test]
+    fn to_decimal_string_writes_f64_values_shortest() {
+        assert_eq!(
+            BigFloat::with_precision(-0.75, 64).to_decimal_string(),
+            "-0.75"
+        );
+        assert_eq!(BigFloat::with_precision(0.0, 64).to_decimal_string(), "0");
+        assert_eq!(
+            BigFloat::with_precision(2.5e-20, 64).to_decimal_string(),
+            "2.5e-20"
+        );
+    }
+
+    #[test]
+    fn to_decimal_string_keeps_arbitrary_precision_digits() {
+        let digits = "-0.74364388703715870475219150611477";
+        let value = BigFloat::from_string(digits, 256).unwrap();
+        assert_eq!(value.to_decimal_string(), digits);
+
+        let tiny = BigFloat::from_string("1.5e-400", 256).unwrap();
+        assert_eq!(tiny.to_decimal_string(), "1.5e-400");
+        assert_eq!(BigFloat::zero(256).to_decimal_string(), "0");
+    }
+
+    #[test]
+    fn to_decimal_string_round_trips_through_from_string() {
+        let value = BigFloat::from_string("3.25e-400", 1400).unwrap();
+        let parsed = BigFloat::from_string(&value.to_decimal_string(), 1400).unwrap();
+        assert_eq!(parsed, value);
+    }
+
     #[test]
     fn abs_returns_positive_for_negative_value() {
         let neg = BigFloat::with_precision(-5.0, 64);
@@ -596,6 +649,16 @@ mod tests {
         assert!(log2 > -1700.0);
     }
 
+    #[test]
+    fn log2_approx_is_exact_for_small_powers_of_two_beyond_f64() {
+        let half = BigFloat::with_precision(0.5, 256);
+        let mut val = BigFloat::one(256);
+        for _ in 0..1100 {
+            val = val.mul(&half);
+        }
+        assert_eq!(val.log2_approx(), -1100.0);
+    }
+
     #[test]
     fn log2_approx_handles_values_near_one() {
         let val = BigFloat::with_precision(1.0, 64);
@@ -625,6 +688,28 @@ mod tests {
         assert!(log2 > -1700.0);
     }
 
+    #[test]
+    fn to_hdr_parts_matches_frexp_on_both_paths() {
+        for v in [1.0, -0.75, 3.5e-7, 1234.5] {
+            let expected = libm::frexp(v);
+            let expected = (expected.0, expected.1 as i64);
+            assert_eq!(BigFloat::with_precision(v, 64).to_hdr_parts(), expected);
+            assert_eq!(BigFloat::with_precision(v, 256).to_hdr_parts(), expected);
+        }
+        assert_eq!(BigFloat::zero(256).to_hdr_parts(), (0.0, 0));
+    }
+
+    #[test]
+    fn to_hdr_parts_exponent_is_exact_at_extreme_depth() {
+        let val = BigFloat::with_precision(-0.75, 4_000_128).mul(
+            &BigFloat::with_precision(0.5, 4_000_128)
+                .powi(4_000_000)
+                .unwrap(),
+        );
+        assert_eq!(val.to_hdr_parts(), (-0.75, -4_000_000));
+        assert_eq!(val.log2_approx(), 0.75f64.log2() - 4_000_000.0);
+    }
+
     #[test]
     fn from_string_with_extreme_exponent_auto_upgrades_precision() {
         // When parsing "4.0e-1000" with 64 bits, it should auto-upgrade
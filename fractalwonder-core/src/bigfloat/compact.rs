@@ -0,0 +1,261 @@
+//! Compact binary encoding for BigFloat.
+//!
+//! Layout, all integers little-endian base-128 varints:
+//! - precision_bits
+//! - tag: 0 = f64 path, 1 = zero, 2 = positive, 3 = negative
+//! - f64 path: the 8 bytes of `f64::to_bits`
+//! - non-zero arbitrary: zigzag exponent, then the significand magnitude as
+//!   packed little-endian bytes filling the rest of the buffer
+//!
+//! In text formats the bytes travel as a URL-safe base64 string. The legacy
+//! `{ "value", "precision_bits" }` map is still accepted everywhere a BigFloat
+//! is deserialized.
+
+use super::{BigFloat, BigFloatSerde, BigFloatValue};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use dashu::integer::{IBig, Sign, UBig};
+use dashu_float::FBig;
+use serde::de::{self, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+const TAG_F64: u8 = 0;
+const TAG_ZERO: u8 = 1;
+const TAG_POSITIVE: u8 = 2;
+const TAG_NEGATIVE: u8 = 3;
+
+impl BigFloat {
+    /// Encode in the compact binary format
+    pub fn to_compact_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_varint(&mut bytes, self.precision_bits as u64);
+        match &self.value {
+            BigFloatValue::F64(v) => {
+                bytes.push(TAG_F64);
+                bytes.extend_from_slice(&v.to_bits().to_le_bytes());
+            }
+            BigFloatValue::Arbitrary(v) => {
+                let repr = v.repr();
+                if repr.is_zero() {
+                    bytes.push(TAG_ZERO);
+                    return bytes;
+                }
+                let (sign, magnitude) = repr.significand().clone().into_parts();
+                bytes.push(match sign {
+                    Sign::Positive => TAG_POSITIVE,
+                    Sign::Negative => TAG_NEGATIVE,
+                });
+                write_varint(&mut bytes, zigzag(repr.exponent() as i64));
+                bytes.extend_from_slice(&magnitude.to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    /// Decode from the compact binary format
+    ///
+    /// The value is stored for the decoded precision the same way
+    /// `with_precision` and `to_precision` would, whatever tag it came with.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut cursor = bytes;
+        let precision_bits = usize::try_from(read_varint(&mut cursor)?)
+            .map_err(|_| "Precision out of range".to_string())?;
+        let (&tag, rest) = cursor
+            .split_first()
+            .ok_or_else(|| "Missing value tag".to_string())?;
+        cursor = rest;
+
+        match tag {
+            TAG_F64 => {
+                let raw: [u8; 8] = cursor
+                    .try_into()
+                    .map_err(|_| format!("Expected 8 f64 bytes, found {}", cursor.len()))?;
+                let value = f64::from_bits(u64::from_le_bytes(raw));
+                Ok(Self::with_precision(value, precision_bits))
+            }
+            TAG_ZERO => Ok(Self::zero(precision_bits)),
+            TAG_POSITIVE | TAG_NEGATIVE => {
+                let exponent = unzigzag(read_varint(&mut cursor)?);
+                let exponent =
+                    isize::try_from(exponent).map_err(|_| "Exponent out of range".to_string())?;
+                let magnitude = UBig::from_le_bytes(cursor);
+                let sign = if tag == TAG_NEGATIVE {
+                    Sign::Negative
+                } else {
+                    Sign::Positive
+                };
+                let significand = IBig::from_parts(sign, magnitude);
+                Ok(Self::from_fbig(
+                    FBig::from_parts(significand, exponent),
+                    precision_bits,
+                ))
+            }
+            other => Err(format!("Unknown value tag {}", other)),
+        }
+    }
+
+    /// Encode as URL-safe base64 of the compact bytes
+    pub fn to_compact_string(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.to_compact_bytes())
+    }
+
+    /// Decode from URL-safe base64 of the compact bytes
+    pub fn from_compact_string(encoded: &str) -> Result<Self, String> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded)
+            .map_err(|e| format!("Invalid base64: {}", e))?;
+        Self::from_compact_bytes(&bytes)
+    }
+
+    /// Borrow as a value that serializes in the compact encoding
+    pub fn compact(&self) -> CompactBigFloat<'_> {
+        CompactBigFloat(self)
+    }
+}
+
+/// BigFloat that serializes in the compact encoding.
+///
+/// Deserialize the plain `BigFloat`, which accepts either encoding.
+#[derive(Clone, Copy, Debug)]
+pub struct CompactBigFloat<'a>(pub &'a BigFloat);
+
+impl Serialize for CompactBigFloat<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.0.to_compact_string())
+        } else {
+            serializer.serialize_bytes(&self.0.to_compact_bytes())
+        }
+    }
+}
+
+/// Serde adapter for `#[serde(with = "...")]` on BigFloat fields.
+pub fn serialize<S>(value: &BigFloat, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    value.compact().serialize(serializer)
+}
+
+/// Serde adapter for `#[serde(with = "...")]` on BigFloat fields.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<BigFloat, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    BigFloat::deserialize(deserializer)
+}
+
+/// Accepts the compact string, compact bytes, or the legacy map
+pub(super) struct BigFloatVisitor;
+
+impl<'de> Visitor<'de> for BigFloatVisitor {
+    type Value = BigFloat;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a compact BigFloat string or bytes, or a value/precision_bits map")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<BigFloat, E> {
+        BigFloat::from_compact_string(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<BigFloat, E> {
+        BigFloat::from_compact_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<BigFloat, A::Error> {
+        let legacy = BigFloatSerde::deserialize(de::value::MapAccessDeserializer::new(map))?;
+        legacy.into_bigfloat().map_err(de::Error::custom)
+    }
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn read_varint(cursor: &mut &[u8]) -> Result<u64, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = cursor
+            .split_first()
+            .ok_or_else(|| "Truncated varint".to_string())?;
+        *cursor = rest;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err("Varint too long".to_string())
+}
+
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_and_zigzag_roundtrip() {
+        for value in [0i64, 1, -1, 63, -64, 1 << 40, i64::MIN, i64::MAX] {
+            let mut bytes = Vec::new();
+            write_varint(&mut bytes, zigzag(value));
+            let mut cursor = &bytes[..];
+            assert_eq!(unzigzag(read_varint(&mut cursor).unwrap()), value);
+            assert!(cursor.is_empty());
+        }
+    }
+
+    #[test]
+    fn f64_payload_is_widened_to_its_precision() {
+        let mut bytes = Vec::new();
+        write_varint(&mut bytes, 256);
+        bytes.push(TAG_F64);
+        bytes.extend_from_slice(&(-1.25f64).to_bits().to_le_bytes());
+
+        let decoded = BigFloat::from_compact_bytes(&bytes).unwrap();
+        let expected = BigFloat::with_precision(-1.25, 256);
+        assert_eq!(decoded.precision_bits(), 256);
+        assert!(matches!(decoded.value, BigFloatValue::Arbitrary(_)));
+        assert_eq!(decoded, expected);
+
+        let three = BigFloat::with_precision(3.0, 256);
+        assert_eq!(
+            decoded.div(&three).to_compact_bytes(),
+            expected.div(&three).to_compact_bytes()
+        );
+    }
+
+    #[test]
+    fn arbitrary_payload_at_f64_precision_is_stored_as_f64() {
+        let third = BigFloat::with_precision(1.0, 256).div(&BigFloat::with_precision(3.0, 256));
+        let mut bytes = Vec::new();
+        write_varint(&mut bytes, 64);
+        // Skip the two-byte varint of 256
+        bytes.extend_from_slice(&third.to_compact_bytes()[2..]);
+
+        let decoded = BigFloat::from_compact_bytes(&bytes).unwrap();
+        assert_eq!(decoded.precision_bits(), 64);
+        assert!(matches!(decoded.value, BigFloatValue::F64(v) if v == 1.0 / 3.0));
+    }
+
+    #[test]
+    fn truncated_input_is_an_error() {
+        let bytes = BigFloat::with_precision(-1.25, 256).to_compact_bytes();
+        assert!(BigFloat::from_compact_bytes(&bytes[..1]).is_err());
+        assert!(BigFloat::from_compact_bytes(&[]).is_err());
+        assert!(BigFloat::from_compact_bytes(&[64, 9]).is_err());
+    }
+}
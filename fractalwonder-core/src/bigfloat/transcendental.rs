@@ -0,0 +1,355 @@
+//! Transcendental functions for BigFloat.
+//!
+//! Values of 64 bits or less use the f64 implementations. Otherwise the result
+//! is computed with guard bits and rounded to the operand precision, which
+//! gives the correctly rounded value in all but pathologically close cases.
+
+use super::{approx_value, BigFloat, BigFloatValue};
+use dashu::integer::IBig;
+use dashu_base::{Abs, EstimatedLog2, Sign};
+use dashu_float::ops::SquareRoot;
+use dashu_float::FBig;
+
+/// Extra working bits carried through every computation.
+const GUARD_BITS: usize = 64;
+
+impl BigFloat {
+    /// π with explicit precision
+    pub fn pi(precision_bits: usize) -> Self {
+        if precision_bits <= 64 {
+            return Self::with_precision(std::f64::consts::PI, precision_bits);
+        }
+        Self::from_working(pi(precision_bits + GUARD_BITS), precision_bits)
+    }
+
+    /// Exponential function eˣ
+    pub fn exp(&self) -> Self {
+        match &self.value {
+            BigFloatValue::F64(v) if self.precision_bits <= 64 => self.with_f64(v.exp()),
+            _ => {
+                let x = self.working(self.precision_bits + GUARD_BITS);
+                Self::from_working(x.exp(), self.precision_bits)
+            }
+        }
+    }
+
+    /// Natural logarithm, or None unless the value is positive
+    pub fn ln(&self) -> Option<Self> {
+        if !self.is_positive() {
+            return None;
+        }
+        Some(match &self.value {
+            BigFloatValue::F64(v) if self.precision_bits <= 64 => self.with_f64(v.ln()),
+            _ => {
+                let x = self.working(self.precision_bits + GUARD_BITS);
+                Self::from_working(x.ln(), self.precision_bits)
+            }
+        })
+    }
+
+    /// Sine of an angle in radians
+    pub fn sin(&self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Cosine of an angle in radians
+    pub fn cos(&self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Sine and cosine of an angle in radians, sharing the argument reduction
+    pub fn sin_cos(&self) -> (Self, Self) {
+        match &self.value {
+            BigFloatValue::F64(v) if self.precision_bits <= 64 => {
+                let (sin, cos) = v.sin_cos();
+                (self.with_f64(sin), self.with_f64(cos))
+            }
+            _ => {
+                // Reducing modulo π/2 cancels as many bits as the argument has
+                // above the binary point, so π needs that much more precision
+                let magnitude = self.log2_approx().max(0.0) as usize;
+                let (sin, cos) = sin_cos(
+                    &self.to_fbig(),
+                    self.precision_bits + GUARD_BITS + magnitude,
+                );
+                (
+                    Self::from_working(sin, self.precision_bits),
+                    Self::from_working(cos, self.precision_bits),
+                )
+            }
+        }
+    }
+
+    /// Four-quadrant arctangent of `self / x` in (-π, π]
+    ///
+    /// `self` is the y coordinate. Returns zero when both are zero, like f64.
+    pub fn atan2(&self, x: &Self) -> Self {
+        let precision = self.precision_bits.max(x.precision_bits);
+        match (&self.value, &x.value) {
+            (BigFloatValue::F64(y), BigFloatValue::F64(x)) if precision <= 64 => {
+                Self::with_precision(y.atan2(*x), precision)
+            }
+            _ => {
+                let wp = precision + GUARD_BITS;
+                let y = self.working(wp);
+                let x = x.working(wp);
+                Self::from_working(atan2(&y, &x, wp), precision)
+            }
+        }
+    }
+
+    /// Raise to an integer power
+    ///
+    /// Returns None for zero raised to a negative power.
+    pub fn powi(&self, exponent: i64) -> Option<Self> {
+        if exponent < 0 && self.is_zero() {
+            return None;
+        }
+        Some(match &self.value {
+            BigFloatValue::F64(v) if self.precision_bits <= 64 => {
+                self.with_f64(match i32::try_from(exponent) {
+                    Ok(n) => v.powi(n),
+                    Err(_) => v.powf(exponent as f64),
+                })
+            }
+            _ => {
+                let x = self.working(self.precision_bits + GUARD_BITS);
+                Self::from_working(x.powi(IBig::from(exponent)), self.precision_bits)
+            }
+        })
+    }
+
+    /// Raise to a real power
+    ///
+    /// Negative bases only allow integral exponents. Returns None outside the
+    /// real domain and for zero raised to a non-positive power.
+    pub fn powf(&self, exponent: &Self) -> Option<Self> {
+        let precision = self.precision_bits.max(exponent.precision_bits);
+        if let Some(n) = exponent.to_integer() {
+            return self.to_precision(precision).powi(n);
+        }
+        if self.is_zero() {
+            return exponent.is_positive().then(|| Self::zero(precision));
+        }
+        if self.is_negative() {
+            return None;
+        }
+
+        Some(match (&self.value, &exponent.value) {
+            (BigFloatValue::F64(x), BigFloatValue::F64(y)) if precision <= 64 => {
+                Self::with_precision(x.powf(*y), precision)
+            }
+            _ => {
+                let wp = precision + GUARD_BITS;
+                let x = self.working(wp);
+                let y = exponent.working(wp);
+                Self::from_working(x.powf(&y), precision)
+            }
+        })
+    }
+
+    fn is_zero(&self) -> bool {
+        match &self.value {
+            BigFloatValue::F64(v) => *v == 0.0,
+            BigFloatValue::Arbitrary(v) => v.repr().is_zero(),
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        !self.is_zero() && !self.is_negative()
+    }
+
+    /// The value as an i64 if it is an integer in range
+    fn to_integer(&self) -> Option<i64> {
+        match &self.value {
+            BigFloatValue::F64(v) => {
+                (v.fract() == 0.0 && v.abs() < i64::MAX as f64).then_some(*v as i64)
+            }
+            BigFloatValue::Arbitrary(v) => {
+                if v.trunc() != *v {
+                    return None;
+                }
+                i64::try_from(v.to_int().value()).ok()
+            }
+        }
+    }
+
+    /// Result of an f64 operation at this value's precision
+    fn with_f64(&self, value: f64) -> Self {
+        Self::with_precision(value, self.precision_bits)
+    }
+
+    /// This value as an FBig with `precision` working bits
+    fn working(&self, precision: usize) -> FBig {
+        approx_value(self.to_fbig().with_precision(precision))
+    }
+
+    /// Round a working result back to `precision_bits`
+    fn from_working(value: FBig, precision_bits: usize) -> Self {
+        if precision_bits <= 64 {
+            return Self::with_precision(value.to_f64().value(), precision_bits);
+        }
+        Self {
+            value: BigFloatValue::Arbitrary(approx_value(value.with_precision(precision_bits))),
+            precision_bits,
+        }
+    }
+}
+
+fn constant(n: i64, precision: usize) -> FBig {
+    approx_value(FBig::from(n).with_precision(precision))
+}
+
+/// 2^exponent, exactly
+fn pow2(exponent: isize) -> FBig {
+    FBig::from_parts(IBig::ONE, exponent)
+}
+
+fn is_negative(value: &FBig) -> bool {
+    value.sign() == Sign::Negative
+}
+
+/// Whether a series term no longer affects a sum whose first term was `first`
+fn is_negligible(term: &FBig, first: &FBig, precision: usize) -> bool {
+    term.repr().is_zero() || term.log2_est() < first.log2_est() - precision as f32
+}
+
+/// π by Machin's formula, π = 16·atan(1/5) − 4·atan(1/239)
+fn pi(precision: usize) -> FBig {
+    atan_inverse(5, precision) * pow2(4) - atan_inverse(239, precision) * pow2(2)
+}
+
+/// atan(1/n) = Σ (−1)ᵏ / ((2k+1)·n²ᵏ⁺¹)
+fn atan_inverse(n: i64, precision: usize) -> FBig {
+    let x = constant(1, precision) / constant(n, precision);
+    let x_sq = &x * &x;
+    let mut power = x.clone();
+    let mut sum = x.clone();
+    for k in 1.. {
+        power = &power * &x_sq;
+        let term = &power / constant(2 * k + 1, precision);
+        if is_negligible(&term, &x, precision) {
+            break;
+        }
+        sum = if k % 2 == 1 { sum - term } else { sum + term };
+    }
+    sum
+}
+
+/// Halvings applied before a series, balancing series length against the
+/// bits lost undoing them
+fn halvings(precision: usize) -> usize {
+    (precision as f64).sqrt() as usize / 2
+}
+
+/// sin and cos with `precision` working bits
+fn sin_cos(x: &FBig, precision: usize) -> (FBig, FBig) {
+    let k = halvings(precision);
+    let wp = precision + k;
+    let x = approx_value(x.clone().with_precision(wp));
+    if x.repr().is_zero() {
+        return (constant(0, wp), constant(1, wp));
+    }
+
+    // x = q·π/2 + r with |r| ≤ π/4
+    let half_pi = pi(wp) * pow2(-1);
+    let q = (&x / &half_pi).round();
+    let r = &x - &q * &half_pi;
+    let quadrant = i64::try_from(q.to_int().value() % IBig::from(4)).unwrap_or(0);
+
+    // Taylor series on r/2ᵏ, then double the angle k times
+    let r = &r * pow2(-(k as isize));
+    let r_sq = &r * &r;
+    let mut sin = r.clone();
+    let mut cos = constant(1, wp);
+    let mut sin_term = r.clone();
+    let mut cos_term = constant(1, wp);
+    for n in 1.. {
+        // Next terms: multiply by −r² / ((2n)(2n+1)) and −r² / ((2n−1)(2n))
+        sin_term = -(&sin_term * &r_sq) / constant((2 * n) * (2 * n + 1), wp);
+        cos_term = -(&cos_term * &r_sq) / constant((2 * n - 1) * (2 * n), wp);
+        let sin_done = is_negligible(&sin_term, &r, wp);
+        let cos_done = is_negligible(&cos_term, &constant(1, wp), wp);
+        sin += &sin_term;
+        cos += &cos_term;
+        if sin_done && cos_done {
+            break;
+        }
+    }
+    for _ in 0..k {
+        // sin 2a = 2·sin a·cos a, cos 2a = 1 − 2·sin² a
+        let double_sin = &sin * &cos * pow2(1);
+        cos = constant(1, wp) - &sin * &sin * pow2(1);
+        sin = double_sin;
+    }
+
+    match quadrant.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// atan with `precision` working bits
+fn atan(x: &FBig, precision: usize) -> FBig {
+    if x.repr().is_zero() {
+        return constant(0, precision);
+    }
+    let one = constant(1, precision);
+
+    // atan x = ±π/2 − atan(1/x) keeps the series argument within [−1, 1]
+    if x.clone().abs() > one {
+        let half_pi = pi(precision) * pow2(-1);
+        let reduced = atan(&(&one / x), precision);
+        return if is_negative(x) {
+            -half_pi - reduced
+        } else {
+            half_pi - reduced
+        };
+    }
+
+    // atan x = 2·atan(x / (1 + √(1 + x²))) halves the angle k times
+    let k = halvings(precision);
+    let wp = precision + k;
+    let one = constant(1, wp);
+    let mut x = approx_value(x.clone().with_precision(wp));
+    for _ in 0..k {
+        let root = (&one + &x * &x).sqrt();
+        x = &x / (&one + root);
+    }
+
+    // atan x = x − x³/3 + x⁵/5 − …
+    let x_sq = &x * &x;
+    let mut power = x.clone();
+    let mut sum = x.clone();
+    for n in 1.. {
+        power = -(&power * &x_sq);
+        let term = &power / constant(2 * n + 1, wp);
+        if is_negligible(&term, &x, wp) {
+            break;
+        }
+        sum += term;
+    }
+    sum * pow2(k as isize)
+}
+
+/// Four-quadrant arctangent with `precision` working bits
+fn atan2(y: &FBig, x: &FBig, precision: usize) -> FBig {
+    if x.repr().is_zero() {
+        if y.repr().is_zero() {
+            return constant(0, precision);
+        }
+        let half_pi = pi(precision) * pow2(-1);
+        return if is_negative(y) { -half_pi } else { half_pi };
+    }
+
+    let angle = atan(&(y / x), precision);
+    if !is_negative(x) {
+        angle
+    } else if is_negative(y) {
+        angle - pi(precision)
+    } else {
+        angle + pi(precision)
+    }
+}
@@ -1,7 +1,7 @@
 //! Complex delta types for perturbation arithmetic.
 //!
-//! Provides a trait abstraction over f64, HDRFloat, and BigFloat complex numbers,
-//! enabling a single generic perturbation function with zero runtime overhead.
+//! Provides a trait abstraction over f64, FloatExp, HDRFloat, and BigFloat complex
+//! numbers, enabling a single generic perturbation function with zero runtime overhead.
 
 use crate::BigFloat;
 
@@ -36,6 +36,10 @@ pub trait ComplexDelta: Clone + Sized {
 
     /// Magnitude squared as f64 (for escape/rebase checks).
     fn norm_sq(&self) -> f64;
+
+    /// Base-2 logarithm of the magnitude, finite across the type's full
+    /// exponent range (used for distance estimation at deep zoom).
+    fn norm_log2(&self) -> f64;
 }
 
 /// Simple f64 complex number for perturbation arithmetic.
@@ -105,6 +109,11 @@ impl ComplexDelta for F64Complex {
     fn norm_sq(&self) -> f64 {
         self.re * self.re + self.im * self.im
     }
+
+    #[inline]
+    fn norm_log2(&self) -> f64 {
+        self.re.hypot(self.im).log2()
+    }
 }
 
 /// BigFloat complex number for ultra-deep zoom perturbation.
@@ -173,18 +182,24 @@ impl ComplexDelta for BigFloatComplex {
     }
 
     fn square(&self) -> Self {
+        let sq = BigFloat::complex_square(&self.re, &self.im);
         Self {
-            re: self.re.mul(&self.re).sub(&self.im.mul(&self.im)),
-            im: self
-                .re
-                .mul(&self.im)
-                .mul(&BigFloat::with_precision(2.0, self.re.precision_bits())),
+            re: sq.re,
+            im: sq.im,
         }
     }
 
     fn norm_sq(&self) -> f64 {
         self.re.mul(&self.re).add(&self.im.mul(&self.im)).to_f64()
     }
+
+    fn norm_log2(&self) -> f64 {
+        self.re
+            .mul(&self.re)
+            .add(&self.im.mul(&self.im))
+            .log2_approx()
+            / 2.0
+    }
 }
 
 #[cfg(test)]
@@ -231,6 +246,13 @@ mod tests {
         assert_eq!(a.norm_sq(), 25.0);
     }
 
+    #[test]
+    fn f64_complex_norm_log2() {
+        // |3 + 4i| = 5
+        let a = F64Complex::from_f64_pair(3.0, 4.0);
+        assert!((a.norm_log2() - 5.0f64.log2()).abs() < 1e-12);
+    }
+
     #[test]
     fn f64_complex_scale() {
         let a = F64Complex::from_f64_pair(1.0, 2.0);
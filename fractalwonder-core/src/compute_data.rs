@@ -15,7 +15,8 @@ pub struct MandelbrotData {
     /// When true, the colorizer can render this pixel distinctively (e.g., cyan overlay).
     #[serde(default)]
     pub glitched: bool,
-    /// |z|² at escape for smooth iteration coloring. Interior points store 0.0.
+    /// |z|² at escape for smooth iteration coloring.
+    /// Interior points computed on the CPU store |z|² after max_iterations (GPU stores 0.0).
     #[serde(default)]
     pub final_z_norm_sq: f32,
     /// Pre-computed normalized surface direction for 3D lighting (real component).
@@ -27,8 +28,30 @@ pub struct MandelbrotData {
     /// This is the normalized z/ρ direction, always in [-1, 1].
     #[serde(default)]
     pub surface_normal_im: f32,
+    /// Minimum |z| reached along the orbit (point trap at the origin).
+    /// Sampled at every explicitly computed iteration; BLA-skipped iterations are not seen.
+    #[serde(default)]
+    pub orbit_trap: f32,
+    /// Stripe average: mean of ½·sin(STRIPE_DENSITY·arg z) + ½ over the sampled iterations.
+    #[serde(default)]
+    pub stripe_avg: f32,
+    /// log₂ of the distance estimate in fractal units, stored as a logarithm so it
+    /// survives any zoom depth. Exterior points store |z|·ln|z| / |dz/dc| at escape;
+    /// interior points store the interior distance estimate of their attracting
+    /// cycle when `period` is non-zero, otherwise 0.0.
+    #[serde(default)]
+    pub distance_log2: f32,
+    /// Iteration at which |z| reached its minimum (the atom domain index).
+    #[serde(default)]
+    pub atom_domain: u32,
+    /// Period of the attracting cycle for interior points, 0 when none was detected.
+    #[serde(default)]
+    pub period: u32,
 }
 
+/// Angular frequency of the stripe average (number of stripes per turn of arg z).
+pub const STRIPE_DENSITY: f64 = 5.0;
+
 impl MandelbrotData {
     /// Create a new MandelbrotData, sanitizing any NaN/Infinity float values.
     /// This is critical because serde_json serializes NaN/Infinity as null,
@@ -50,6 +73,70 @@ impl MandelbrotData {
             final_z_norm_sq: Self::sanitize_f32(final_z_norm_sq, 0.0),
             surface_normal_re: Self::sanitize_f32(surface_normal_re, 0.0),
             surface_normal_im: Self::sanitize_f32(surface_normal_im, 0.0),
+            orbit_trap: 0.0,
+            stripe_avg: 0.0,
+            distance_log2: 0.0,
+            atom_domain: 0,
+            period: 0,
+        }
+    }
+
+    /// Attach orbit statistics used by the layered colorizers, sanitizing NaN/Infinity.
+    pub fn with_orbit_stats(self, orbit_trap: f32, stripe_avg: f32, distance_log2: f32) -> Self {
+        Self {
+            orbit_trap: Self::sanitize_f32(orbit_trap, 0.0),
+            stripe_avg: Self::sanitize_f32(stripe_avg, 0.0),
+            distance_log2: Self::sanitize_f32(distance_log2, 0.0),
+            ..self
+        }
+    }
+
+    /// Attach interior cycle data for a non-escaped point, sanitizing NaN/Infinity.
+    pub fn with_interior(self, final_z_norm_sq: f32, period: u32, distance_log2: f32) -> Self {
+        Self {
+            final_z_norm_sq: Self::sanitize_f32(final_z_norm_sq, 0.0),
+            period,
+            distance_log2: Self::sanitize_f32(distance_log2, 0.0),
+            ..self
+        }
+    }
+
+    /// Size of the fixed-width encoding used in shared result buffers.
+    pub const WORDS: usize = 11;
+
+    /// Encode as `WORDS` 32-bit words, floats as their bit patterns and both
+    /// flags in one word.
+    pub fn to_words(&self) -> [u32; Self::WORDS] {
+        [
+            self.iterations,
+            self.max_iterations,
+            self.escaped as u32 | (self.glitched as u32) << 1,
+            self.final_z_norm_sq.to_bits(),
+            self.surface_normal_re.to_bits(),
+            self.surface_normal_im.to_bits(),
+            self.orbit_trap.to_bits(),
+            self.stripe_avg.to_bits(),
+            self.distance_log2.to_bits(),
+            self.atom_domain,
+            self.period,
+        ]
+    }
+
+    /// Decode from the first `WORDS` words written by `to_words`.
+    pub fn from_words(words: &[u32]) -> Self {
+        Self {
+            iterations: words[0],
+            max_iterations: words[1],
+            escaped: words[2] & 1 != 0,
+            glitched: words[2] & 2 != 0,
+            final_z_norm_sq: f32::from_bits(words[3]),
+            surface_normal_re: f32::from_bits(words[4]),
+            surface_normal_im: f32::from_bits(words[5]),
+            orbit_trap: f32::from_bits(words[6]),
+            stripe_avg: f32::from_bits(words[7]),
+            distance_log2: f32::from_bits(words[8]),
+            atom_domain: words[9],
+            period: words[10],
         }
     }
 
@@ -74,6 +161,11 @@ impl Default for MandelbrotData {
             final_z_norm_sq: 0.0,
             surface_normal_re: 0.0,
             surface_normal_im: 0.0,
+            orbit_trap: 0.0,
+            stripe_avg: 0.0,
+            distance_log2: 0.0,
+            atom_domain: 0,
+            period: 0,
         }
     }
 }
@@ -83,3 +175,23 @@ impl Default for MandelbrotData {
 pub enum ComputeData {
     Mandelbrot(MandelbrotData),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_encoding_roundtrip() {
+        let data = MandelbrotData::new(1234, 5000, true, true, 17.5, -0.6, 0.8)
+            .with_orbit_stats(0.01, 0.4, -2000.25);
+        let data = MandelbrotData {
+            atom_domain: 99,
+            period: 7,
+            ..data
+        };
+        assert_eq!(MandelbrotData::from_words(&data.to_words()), data);
+
+        let interior = MandelbrotData::default();
+        assert_eq!(MandelbrotData::from_words(&interior.to_words()), interior);
+    }
+}
@@ -0,0 +1,251 @@
+//! Float with f64 mantissa and separate i64 exponent ("floatexp").
+//!
+//! Value = mantissa × 2^exp, with the mantissa normalized to [0.5, 1.0).
+//! Keeps the full 53-bit f64 mantissa and native f64 multiplies, so it is
+//! cheaper than HDRFloat's double-single arithmetic on the CPU while still
+//! reaching far below the ~10^-308 floor of plain f64.
+
+use crate::{BigFloat, HDRFloat};
+
+/// Bit pattern for a mantissa exponent of 2^-1, i.e. the [0.5, 1.0) binade.
+const HALF_EXP_BITS: u64 = 0x3FE0_0000_0000_0000;
+const SIGN_AND_FRACTION_MASK: u64 = 0x800F_FFFF_FFFF_FFFF;
+
+/// Exponent gap beyond which the smaller addend cannot affect the sum.
+const ADD_CUTOFF_BITS: i64 = 64;
+
+/// Floating point value with f64 mantissa and extended exponent.
+/// Value = mantissa × 2^exp
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FloatExp {
+    /// Mantissa, normalized to [0.5, 1.0) in magnitude (or zero)
+    pub mantissa: f64,
+    /// Binary exponent (base 2)
+    pub exp: i64,
+}
+
+impl FloatExp {
+    /// Zero constant.
+    pub const ZERO: Self = Self {
+        mantissa: 0.0,
+        exp: 0,
+    };
+
+    /// Check if value is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+
+    /// Check if value is negative.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.mantissa < 0.0
+    }
+
+    /// Create from f64.
+    #[inline]
+    pub fn from_f64(val: f64) -> Self {
+        Self {
+            mantissa: val,
+            exp: 0,
+        }
+        .normalize()
+    }
+
+    /// Convert to f64, saturating to infinity or zero outside f64 range.
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        if self.mantissa == 0.0 {
+            return 0.0;
+        }
+        if self.exp > 1024 {
+            return self.mantissa.signum() * f64::INFINITY;
+        }
+        if self.exp < -1100 {
+            return 0.0;
+        }
+        libm::ldexp(self.mantissa, self.exp as i32)
+    }
+
+    /// Convert from BigFloat, keeping 53 bits of mantissa and the exact exponent.
+    pub fn from_bigfloat(bf: &BigFloat) -> Self {
+        let (mantissa, exp) = bf.to_hdr_parts();
+        if mantissa == 0.0 || !mantissa.is_finite() {
+            return Self::ZERO;
+        }
+        Self { mantissa, exp }.normalize()
+    }
+
+    /// Convert from HDRFloat. Lossless: the head/tail pair fits in an f64.
+    #[inline]
+    pub fn from_hdr(h: &HDRFloat) -> Self {
+        Self {
+            mantissa: h.head as f64 + h.tail as f64,
+            exp: h.exp as i64,
+        }
+        .normalize()
+    }
+
+    /// Convert to HDRFloat. The exponent saturates at the i32 range.
+    #[inline]
+    pub fn to_hdr(&self) -> HDRFloat {
+        if self.mantissa == 0.0 {
+            return HDRFloat::ZERO;
+        }
+        let head = self.mantissa as f32;
+        let tail = (self.mantissa - head as f64) as f32;
+        HDRFloat {
+            head,
+            tail,
+            exp: self.exp.clamp(i32::MIN as i64, i32::MAX as i64) as i32,
+        }
+        .normalize()
+    }
+
+    /// Base-2 logarithm of the absolute value.
+    ///
+    /// Returns negative infinity for zero.
+    #[inline]
+    pub fn log2(&self) -> f64 {
+        if self.mantissa == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        self.mantissa.abs().log2() + self.exp as f64
+    }
+
+    /// Normalize mantissa to [0.5, 1.0) range.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        let bits = self.mantissa.to_bits();
+        let biased_exp = ((bits >> 52) & 0x7FF) as i64;
+
+        if biased_exp == 0 {
+            // Zero or subnormal
+            if self.mantissa == 0.0 {
+                return Self::ZERO;
+            }
+            let (m, e) = libm::frexp(self.mantissa);
+            return Self {
+                mantissa: m,
+                exp: self.exp.saturating_add(e as i64),
+            };
+        }
+        if biased_exp == 0x7FF {
+            // Infinity or NaN: nothing to normalize
+            return self;
+        }
+
+        Self {
+            mantissa: f64::from_bits((bits & SIGN_AND_FRACTION_MASK) | HALF_EXP_BITS),
+            exp: self.exp.saturating_add(biased_exp - 1022),
+        }
+    }
+
+    /// Negate value.
+    #[inline]
+    pub fn neg(&self) -> Self {
+        Self {
+            mantissa: -self.mantissa,
+            exp: self.exp,
+        }
+    }
+
+    /// Multiply two values.
+    #[inline]
+    pub fn mul(&self, other: &Self) -> Self {
+        if self.mantissa == 0.0 || other.mantissa == 0.0 {
+            return Self::ZERO;
+        }
+        Self {
+            mantissa: self.mantissa * other.mantissa,
+            exp: self.exp.saturating_add(other.exp),
+        }
+        .normalize()
+    }
+
+    /// Square value.
+    #[inline]
+    pub fn square(&self) -> Self {
+        if self.mantissa == 0.0 {
+            return Self::ZERO;
+        }
+        Self {
+            mantissa: self.mantissa * self.mantissa,
+            exp: self.exp.saturating_mul(2),
+        }
+        .normalize()
+    }
+
+    /// Multiply by f64 scalar (for 2·Z·δz where Z is f64 reference orbit value).
+    #[inline]
+    pub fn mul_f64(&self, scalar: f64) -> Self {
+        if self.mantissa == 0.0 || scalar == 0.0 {
+            return Self::ZERO;
+        }
+        Self {
+            mantissa: self.mantissa * scalar,
+            exp: self.exp,
+        }
+        .normalize()
+    }
+
+    /// Multiply by 2^k exactly by shifting the exponent.
+    #[inline]
+    pub fn mul_pow2(&self, k: i64) -> Self {
+        if self.mantissa == 0.0 {
+            return Self::ZERO;
+        }
+        Self {
+            mantissa: self.mantissa,
+            exp: self.exp.saturating_add(k),
+        }
+    }
+
+    /// Add two values.
+    #[inline]
+    pub fn add(&self, other: &Self) -> Self {
+        if self.mantissa == 0.0 {
+            return *other;
+        }
+        if other.mantissa == 0.0 {
+            return *self;
+        }
+
+        let exp_diff = self.exp.saturating_sub(other.exp);
+        if exp_diff > ADD_CUTOFF_BITS {
+            return *self;
+        }
+        if exp_diff < -ADD_CUTOFF_BITS {
+            return *other;
+        }
+
+        // Align the smaller operand; the shift is at most 64 bits, so the
+        // scale factor is always a normal f64.
+        let (mantissa, exp) = if exp_diff >= 0 {
+            (
+                self.mantissa + other.mantissa * exp2_neg(exp_diff),
+                self.exp,
+            )
+        } else {
+            (
+                self.mantissa * exp2_neg(-exp_diff) + other.mantissa,
+                other.exp,
+            )
+        };
+
+        Self { mantissa, exp }.normalize()
+    }
+
+    /// Subtract other from self.
+    #[inline]
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+}
+
+/// Compute 2^-n for 0 ≤ n ≤ 1022.
+#[inline]
+fn exp2_neg(n: i64) -> f64 {
+    f64::from_bits(((1023 - n) as u64) << 52)
+}
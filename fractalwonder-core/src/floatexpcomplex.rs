@@ -0,0 +1,142 @@
+//! Complex number using FloatExp components for extended range arithmetic.
+
+use crate::{ComplexDelta, FloatExp, HDRComplex};
+
+/// Complex number using FloatExp components.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FloatExpComplex {
+    pub re: FloatExp,
+    pub im: FloatExp,
+}
+
+impl FloatExpComplex {
+    /// Zero constant.
+    pub const ZERO: Self = Self {
+        re: FloatExp::ZERO,
+        im: FloatExp::ZERO,
+    };
+
+    /// Add two complex numbers.
+    #[inline]
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            re: self.re.add(&other.re),
+            im: self.im.add(&other.im),
+        }
+    }
+
+    /// Subtract other from self.
+    #[inline]
+    pub fn sub(&self, other: &Self) -> Self {
+        Self {
+            re: self.re.sub(&other.re),
+            im: self.im.sub(&other.im),
+        }
+    }
+
+    /// Multiply two complex numbers: (a + bi)(c + di) = (ac - bd) + (ad + bc)i
+    #[inline]
+    pub fn mul(&self, other: &Self) -> Self {
+        Self {
+            re: self.re.mul(&other.re).sub(&self.im.mul(&other.im)),
+            im: self.re.mul(&other.im).add(&self.im.mul(&other.re)),
+        }
+    }
+
+    /// Square: (a + bi)² = (a² - b²) + 2abi, doubling via the exponent.
+    #[inline]
+    pub fn square(&self) -> Self {
+        Self {
+            re: self.re.square().sub(&self.im.square()),
+            im: self.re.mul(&self.im).mul_pow2(1),
+        }
+    }
+
+    /// Convert from HDRComplex (e.g. BLA coefficients).
+    #[inline]
+    pub fn from_hdr(c: &HDRComplex) -> Self {
+        Self {
+            re: FloatExp::from_hdr(&c.re),
+            im: FloatExp::from_hdr(&c.im),
+        }
+    }
+
+    /// Convert to HDRComplex.
+    #[inline]
+    pub fn to_hdr(&self) -> HDRComplex {
+        HDRComplex {
+            re: self.re.to_hdr(),
+            im: self.im.to_hdr(),
+        }
+    }
+
+    /// Squared magnitude without leaving the extended exponent range.
+    #[inline]
+    pub fn norm_sq_fe(&self) -> FloatExp {
+        self.re.square().add(&self.im.square())
+    }
+
+    /// Check if zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl ComplexDelta for FloatExpComplex {
+    #[inline]
+    fn zero(&self) -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn from_f64_pair(re: f64, im: f64) -> Self {
+        Self {
+            re: FloatExp::from_f64(re),
+            im: FloatExp::from_f64(im),
+        }
+    }
+
+    #[inline]
+    fn to_f64_pair(&self) -> (f64, f64) {
+        (self.re.to_f64(), self.im.to_f64())
+    }
+
+    #[inline]
+    fn add(&self, other: &Self) -> Self {
+        FloatExpComplex::add(self, other)
+    }
+
+    #[inline]
+    fn sub(&self, other: &Self) -> Self {
+        FloatExpComplex::sub(self, other)
+    }
+
+    #[inline]
+    fn mul(&self, other: &Self) -> Self {
+        FloatExpComplex::mul(self, other)
+    }
+
+    #[inline]
+    fn scale(&self, factor: f64) -> Self {
+        Self {
+            re: self.re.mul_f64(factor),
+            im: self.im.mul_f64(factor),
+        }
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        FloatExpComplex::square(self)
+    }
+
+    #[inline]
+    fn norm_sq(&self) -> f64 {
+        self.norm_sq_fe().to_f64()
+    }
+
+    #[inline]
+    fn norm_log2(&self) -> f64 {
+        self.norm_sq_fe().log2() / 2.0
+    }
+}
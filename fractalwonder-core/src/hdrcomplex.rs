@@ -154,4 +154,9 @@ impl ComplexDelta for HDRComplex {
     fn norm_sq(&self) -> f64 {
         self.re.square().add(&self.im.square()).to_f64()
     }
+
+    #[inline]
+    fn norm_log2(&self) -> f64 {
+        self.norm_sq_hdr().log2() / 2.0
+    }
 }
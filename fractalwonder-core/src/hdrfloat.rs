@@ -140,43 +140,33 @@ impl HDRFloat {
         libm::ldexp(mantissa, self.exp)
     }
 
-    /// Convert from BigFloat, preserving ~48 bits of mantissa precision.
-    pub fn from_bigfloat(bf: &BigFloat) -> Self {
-        if bf.to_f64() == 0.0 && bf.log2_approx() == f64::NEG_INFINITY {
-            return Self::ZERO;
+    /// Base-2 logarithm of the absolute value.
+    ///
+    /// Finite for any non-zero value regardless of exponent, unlike
+    /// `to_f64().log2()` which saturates outside f64 range.
+    /// Returns negative infinity for zero.
+    pub fn log2(&self) -> f64 {
+        if self.head == 0.0 {
+            return f64::NEG_INFINITY;
         }
+        let mantissa = (self.head as f64 + self.tail as f64).abs();
+        mantissa.log2() + self.exp as f64
+    }
 
-        // Get approximate log2 to determine exponent
-        let log2_approx = bf.log2_approx();
-        if !log2_approx.is_finite() {
+    /// Convert from BigFloat, preserving ~48 bits of mantissa precision.
+    ///
+    /// The binary exponent is taken exactly from the BigFloat and saturates
+    /// at the i32 range.
+    pub fn from_bigfloat(bf: &BigFloat) -> Self {
+        let (mantissa, exp) = bf.to_hdr_parts();
+        if mantissa == 0.0 || !mantissa.is_finite() {
             return Self::ZERO;
         }
-
-        // Binary exponent (rounded)
-        let exp = log2_approx.round() as i32;
-
-        // Scale to [0.5, 2.0) range
-        let mantissa_f64 = if exp.abs() < 1000 {
-            // Fast path: exponent within f64 range
-            let scale = libm::exp2(-exp as f64);
-            bf.to_f64() * scale
-        } else {
-            // Slow path: compute via log2
-            // mantissa = 2^(log2(bf) - exp)
-            // NOTE: log2_approx returns log2 of absolute value, so we must
-            // preserve the sign separately to avoid losing it for negative values.
-            let mantissa_log2 = log2_approx - exp as f64;
-            let abs_mantissa = libm::exp2(mantissa_log2);
-            if bf.is_negative() {
-                -abs_mantissa
-            } else {
-                abs_mantissa
-            }
-        };
+        let exp = exp.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
 
         // Split f64 mantissa into head + tail
-        let head = mantissa_f64 as f32;
-        let tail = (mantissa_f64 - head as f64) as f32;
+        let head = mantissa as f32;
+        let tail = (mantissa - head as f64) as f32;
 
         Self { head, tail, exp }.normalize()
     }
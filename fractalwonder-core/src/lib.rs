@@ -1,25 +1,37 @@
 pub mod bigfloat;
 pub mod complex_delta;
 pub mod compute_data;
+pub mod floatexp;
+pub mod floatexpcomplex;
 pub mod hdrcomplex;
 pub mod hdrfloat;
+pub mod location;
 pub mod messages;
+pub mod orbit_path;
 pub mod pixel_rect;
 pub mod precision;
 pub mod transforms;
 pub mod viewport;
+pub mod zoom_path;
 
 pub use bigfloat::BigFloat;
 pub use complex_delta::{BigFloatComplex, ComplexDelta, F64Complex};
-pub use compute_data::{ComputeData, MandelbrotData};
+pub use compute_data::{ComputeData, MandelbrotData, STRIPE_DENSITY};
+pub use floatexp::FloatExp;
+pub use floatexpcomplex::FloatExpComplex;
 pub use hdrcomplex::HDRComplex;
 pub use hdrfloat::HDRFloat;
-pub use messages::{MainToWorker, WorkerToMain};
+pub use location::{Location, LocationScale};
+pub use messages::{DeltaPrecision, MainToWorker, SharedBlaLayout, WorkerToMain};
+pub use orbit_path::{OrbitPath, OrbitPathPoint};
 pub use pixel_rect::PixelRect;
 pub use precision::calculate_precision_bits;
 pub use transforms::{
     apply_pixel_transform_to_viewport, calculate_aspect_ratio, calculate_max_iterations,
-    compose_affine_transformations, fit_viewport_to_canvas, fractal_to_pixel, pixel_to_fractal,
-    AffinePrimitive, PixelMat3, PixelTransform,
+    canvas_offset_to_delta, compose_affine_transformations, fit_viewport_to_canvas,
+    fractal_to_pixel, invert_2x2, multiply_2x2, pixel_delta_step, pixel_to_delta, pixel_to_fractal,
+    rotate_offset, skew_offset, unskew_from_field, AffinePrimitive, DeltaStep, PixelMat3,
+    PixelTransform,
 };
-pub use viewport::Viewport;
+pub use viewport::{normalize_angle, SkewMatrix, Viewport, IDENTITY_SKEW};
+pub use zoom_path::{ZoomFrame, ZoomPath};
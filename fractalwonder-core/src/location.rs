@@ -0,0 +1,367 @@
+//! Typed-in locations: a center and a zoom or width as decimal strings.
+//!
+//! Accepted text formats:
+//! - `re, im, zoom=1e500` or `re, im, width=4e-500`; a bare third value is a zoom
+//! - `re=…, im=…, zoom=…` keyed fields in any order
+//! - Kalles Fraktaler style blocks with `Re:`, `Im:` and `Zoom:` lines;
+//!   other `Key:` lines of a KF location file are ignored
+//!
+//! `zoom=` matches the status bar: the reference width divided by the view
+//! width. KF's `Zoom:` is 4 divided by the shorter side of the view.
+//!
+//! Values stay strings until the target precision is known, so no typed digit
+//! is lost to an early rounding.
+
+use crate::precision::calculate_precision_bits;
+use crate::transforms::fit_viewport_to_canvas;
+use crate::{BigFloat, Viewport};
+use std::f64::consts::LOG2_10;
+use std::fmt;
+
+/// Shorter side of the view at KF zoom 1.
+const KF_ZOOM_SIDE: f64 = 4.0;
+
+/// Significant digits printed for zoom values.
+const ZOOM_DIGITS: usize = 12;
+
+/// How the size of the view was given.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LocationScale {
+    /// Reference width divided by the view width, as in the status bar
+    Zoom(String),
+    /// View width in the complex plane
+    Width(String),
+    /// KF zoom: 4 divided by the shorter side of the view
+    KfZoom(String),
+}
+
+/// A location as entered, not yet parsed into numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    pub re: String,
+    pub im: String,
+    pub scale: LocationScale,
+}
+
+impl Location {
+    /// The location of `viewport` with a zoom relative to `reference_width`.
+    ///
+    /// Rotation and skew are not part of any format and are left out.
+    pub fn from_viewport(viewport: &Viewport, reference_width: &BigFloat) -> Self {
+        Self {
+            re: viewport.center.0.to_decimal_string(),
+            im: viewport.center.1.to_decimal_string(),
+            scale: LocationScale::Zoom(format_from_log2(
+                reference_width.log2_ratio(&viewport.width),
+            )),
+        }
+    }
+
+    /// The location of `viewport` with a KF zoom.
+    pub fn kf_from_viewport(viewport: &Viewport) -> Self {
+        let shorter = if viewport.width.log2_ratio(&viewport.height) < 0.0 {
+            &viewport.width
+        } else {
+            &viewport.height
+        };
+        let side = BigFloat::with_precision(KF_ZOOM_SIDE, 64);
+        Self {
+            re: viewport.center.0.to_decimal_string(),
+            im: viewport.center.1.to_decimal_string(),
+            scale: LocationScale::KfZoom(format_from_log2(side.log2_ratio(shorter))),
+        }
+    }
+
+    /// Read a location from text in any of the accepted formats.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut re = None;
+        let mut im = None;
+        let mut scale = None;
+        let mut positional = Vec::new();
+
+        for part in text.split(['\n', ',', ';']).map(str::trim) {
+            if part.is_empty() {
+                continue;
+            }
+            let split = part
+                .find(['=', ':'])
+                .map(|i| (&part[..i], &part[i..i + 1], &part[i + 1..]));
+            let Some((key, separator, value)) = split else {
+                positional.extend(part.split_whitespace().map(str::to_string));
+                continue;
+            };
+            let kf = separator == ":";
+            let value = value.trim().to_string();
+            match key.trim().to_lowercase().as_str() {
+                "re" | "real" | "x" => set_once(&mut re, value, "real part")?,
+                "im" | "imag" | "imaginary" | "y" => set_once(&mut im, value, "imaginary part")?,
+                "zoom" | "z" if kf => {
+                    set_once(&mut scale, LocationScale::KfZoom(value), "zoom or width")?
+                }
+                "zoom" | "z" => set_once(&mut scale, LocationScale::Zoom(value), "zoom or width")?,
+                "width" | "w" => {
+                    set_once(&mut scale, LocationScale::Width(value), "zoom or width")?
+                }
+                // KF location files carry iteration counts, colors and so on
+                _ if kf => {}
+                other => return Err(format!("Unknown field '{other}'")),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        if re.is_none() {
+            re = positional.next();
+        }
+        if im.is_none() {
+            im = positional.next();
+        }
+        if scale.is_none() {
+            scale = positional.next().map(LocationScale::Zoom);
+        }
+        if positional.next().is_some() {
+            return Err("Too many values".to_string());
+        }
+
+        Ok(Self {
+            re: re.ok_or("Missing real part")?,
+            im: im.ok_or("Missing imaginary part")?,
+            scale: scale.ok_or("Missing zoom or width")?,
+        })
+    }
+
+    /// Build the viewport for this location on a canvas of `canvas_size`.
+    ///
+    /// `reference_width` is the width at zoom 1, normally the fractal's
+    /// default width. Numbers are parsed once to size the view, then again at
+    /// the precision `calculate_precision_bits` asks for at that size.
+    pub fn to_viewport(
+        &self,
+        reference_width: &BigFloat,
+        canvas_size: (u32, u32),
+    ) -> Result<Viewport, String> {
+        if canvas_size.0 == 0 || canvas_size.1 == 0 {
+            return Err("Canvas has no size".to_string());
+        }
+        let estimate = self.viewport_at(reference_width, canvas_size, 64)?;
+        let precision_bits = calculate_precision_bits(&estimate, canvas_size);
+        self.viewport_at(reference_width, canvas_size, precision_bits)
+    }
+
+    fn viewport_at(
+        &self,
+        reference_width: &BigFloat,
+        canvas_size: (u32, u32),
+        precision_bits: usize,
+    ) -> Result<Viewport, String> {
+        let re = parse_number(&self.re, precision_bits, "real part")?;
+        let im = parse_number(&self.im, precision_bits, "imaginary part")?;
+        let aspect =
+            BigFloat::with_precision(canvas_size.0 as f64 / canvas_size.1 as f64, precision_bits);
+
+        let (width, height) = match &self.scale {
+            LocationScale::Zoom(zoom) => {
+                let zoom = parse_positive(zoom, precision_bits, "zoom")?;
+                let width = reference_width.to_precision(precision_bits).div(&zoom);
+                let height = width.div(&aspect);
+                (width, height)
+            }
+            LocationScale::Width(width) => {
+                let width = parse_positive(width, precision_bits, "width")?;
+                let height = width.div(&aspect);
+                (width, height)
+            }
+            LocationScale::KfZoom(zoom) => {
+                let zoom = parse_positive(zoom, precision_bits, "zoom")?;
+                let side = BigFloat::with_precision(KF_ZOOM_SIDE, precision_bits).div(&zoom);
+                let square = Viewport::with_bigfloat(re, im, side.clone(), side);
+                return Ok(fit_viewport_to_canvas(&square, canvas_size));
+            }
+        };
+        Ok(Viewport::with_bigfloat(re, im, width, height))
+    }
+}
+
+/// Writes the one-line format, or a KF block for a KF zoom; `parse` reads
+/// either back.
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.scale {
+            LocationScale::Zoom(zoom) => write!(f, "{}, {}, zoom={zoom}", self.re, self.im),
+            LocationScale::Width(width) => write!(f, "{}, {}, width={width}", self.re, self.im),
+            LocationScale::KfZoom(zoom) => {
+                write!(f, "Re: {}\nIm: {}\nZoom: {zoom}", self.re, self.im)
+            }
+        }
+    }
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, name: &str) -> Result<(), String> {
+    if slot.is_some() {
+        return Err(format!("The {name} is given more than once"));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Tidy a typed number: drops spaces and a trailing "×", and rewrites the
+/// status bar's "1.50 × 10^3" and "10^500" forms as exponents.
+fn normalize_number(text: &str) -> String {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let compact = compact.trim_end_matches('×').to_lowercase();
+    if let Some(exponent) = compact.strip_prefix("10^") {
+        return format!("1e{exponent}");
+    }
+    match compact.split_once("×10^") {
+        Some((mantissa, exponent)) => format!("{mantissa}e{exponent}"),
+        None => compact,
+    }
+}
+
+fn parse_number(text: &str, precision_bits: usize, name: &str) -> Result<BigFloat, String> {
+    let number = normalize_number(text);
+    // Rejects "inf", "nan" and hex forms the parsers would otherwise take
+    let well_formed = number.chars().any(|c| c.is_ascii_digit())
+        && number
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | '+' | '-'));
+    if !well_formed {
+        return Err(format!("Invalid {name}: '{}'", text.trim()));
+    }
+    BigFloat::from_string(&number, precision_bits)
+        .map_err(|_| format!("Invalid {name}: '{}'", text.trim()))
+}
+
+fn parse_positive(text: &str, precision_bits: usize, name: &str) -> Result<BigFloat, String> {
+    let value = parse_number(text, precision_bits, name)?;
+    if value.is_negative() || value.to_hdr_parts().0 == 0.0 {
+        return Err(format!("The {name} must be positive"));
+    }
+    Ok(value)
+}
+
+/// Decimal string for 2^log2 with `ZOOM_DIGITS` significant digits.
+fn format_from_log2(log2: f64) -> String {
+    let log10 = log2 / LOG2_10;
+    let mut exponent = log10.floor();
+    let mut mantissa = 10f64.powf(log10 - exponent);
+    // Rounding to the printed digits can carry into the next power of ten
+    if format!("{:.*}", ZOOM_DIGITS - 1, mantissa).starts_with("10") {
+        mantissa /= 10.0;
+        exponent += 1.0;
+    }
+    let digits = format!("{:.*}", ZOOM_DIGITS - 1, mantissa);
+    let digits = digits.trim_end_matches('0').trim_end_matches('.');
+    format!("{digits}e{}", exponent as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANVAS: (u32, u32) = (800, 600);
+
+    fn reference() -> BigFloat {
+        BigFloat::with_precision(4.0, 64)
+    }
+
+    #[test]
+    fn parses_one_line_with_zoom() {
+        let location = Location::parse("-0.75, 0.1, zoom=1e500").unwrap();
+        assert_eq!(location.re, "-0.75");
+        assert_eq!(location.im, "0.1");
+        assert_eq!(location.scale, LocationScale::Zoom("1e500".to_string()));
+
+        let bare = Location::parse("-0.75 0.1 1e500").unwrap();
+        assert_eq!(bare, location);
+    }
+
+    #[test]
+    fn parses_kf_block() {
+        let text = "Re: -1.7497219716806310317412446032110656\r\n\
+                    Im: -0.0000000000000000032493924222121287\r\n\
+                    Zoom: 1.2345E35\r\n\
+                    Iterations: 20000\r\n";
+        let location = Location::parse(text).unwrap();
+        assert_eq!(location.re, "-1.7497219716806310317412446032110656");
+        assert_eq!(
+            location.scale,
+            LocationScale::KfZoom("1.2345E35".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_or_malformed_input() {
+        assert!(Location::parse("-0.75, 0.1").is_err());
+        assert!(Location::parse("-0.75, 0.1, zoom=2, width=1").is_err());
+        assert!(Location::parse("-0.75, 0.1, depth=2").is_err());
+        assert!(Location::parse("1, 2, 3, 4").is_err());
+
+        let bad = Location::parse("abc, 0.1, zoom=2").unwrap();
+        assert!(bad.to_viewport(&reference(), CANVAS).is_err());
+        let inf = Location::parse("inf, 0.1, zoom=2").unwrap();
+        assert!(inf.to_viewport(&reference(), CANVAS).is_err());
+        let negative = Location::parse("0, 0.1, zoom=-2").unwrap();
+        assert!(negative.to_viewport(&reference(), CANVAS).is_err());
+    }
+
+    #[test]
+    fn deep_zoom_gets_precision_for_its_depth() {
+        let location = Location::parse("-0.75, 0.1, zoom=1e500").unwrap();
+        let viewport = location.to_viewport(&reference(), CANVAS).unwrap();
+
+        // 4e-500 wide over 800 pixels needs well over 1600 bits
+        assert!(viewport.precision_bits() > 1600);
+        let zoom_log10 = reference().log2_ratio(&viewport.width) / LOG2_10;
+        assert!((zoom_log10 - 500.0).abs() < 1e-9);
+        let aspect = viewport.width.div(&viewport.height).to_f64();
+        assert!((aspect - 4.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn status_bar_zoom_notation_is_accepted() {
+        let location = Location::parse("0, 0, zoom=1.50 × 10^3").unwrap();
+        let viewport = location.to_viewport(&reference(), CANVAS).unwrap();
+        assert!((viewport.width.to_f64() - 4.0 / 1500.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn kf_zoom_sizes_the_shorter_side() {
+        let location = Location::parse("Re: 0\nIm: 0\nZoom: 2").unwrap();
+        let viewport = location.to_viewport(&reference(), CANVAS).unwrap();
+        assert!((viewport.height.to_f64() - 2.0).abs() < 1e-12);
+        assert!((viewport.width.to_f64() - 8.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn formats_roundtrip_below_pixel_size() {
+        let re = "-1.74972197168063103174124460321106560000000000000000000000001";
+        let location = Location {
+            re: re.to_string(),
+            im: "0.25".to_string(),
+            scale: LocationScale::Width("3e-50".to_string()),
+        };
+        let viewport = location.to_viewport(&reference(), CANVAS).unwrap();
+
+        for text in [
+            Location::from_viewport(&viewport, &reference()).to_string(),
+            Location::kf_from_viewport(&viewport).to_string(),
+            location.to_string(),
+        ] {
+            let parsed = Location::parse(&text).unwrap();
+            let again = parsed.to_viewport(&reference(), CANVAS).unwrap();
+            // Printed digits resolve far below a pixel
+            let error = again.center.0.sub(&viewport.center.0);
+            assert!(error.log2_ratio(&viewport.width) < -40.0, "{text}");
+            assert_eq!(again.center.1, viewport.center.1, "{text}");
+            let drift = again.width.log2_ratio(&viewport.width).abs();
+            assert!(drift < 1e-9, "{text}");
+        }
+    }
+
+    #[test]
+    fn zoom_is_printed_with_bounded_digits() {
+        assert_eq!(format_from_log2(0.0), "1e0");
+        assert_eq!(format_from_log2(500.0 * LOG2_10), "1e500");
+        assert_eq!(format_from_log2(1500f64.log2()), "1.5e3");
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{ComputeData, HDRFloat, PixelRect};
+use crate::{ComputeData, HDRFloat, OrbitPath, PixelRect};
 use serde::{Deserialize, Serialize};
 
 /// Messages sent from main thread to worker.
@@ -11,10 +11,17 @@ pub enum MainToWorker {
     /// Terminate worker.
     Terminate,
 
+    /// Drop work of renders before `render_id`.
+    ///
+    /// Handled between slices of a running tile or orbit, which then stops
+    /// and is answered with `RequestWork` for its render.
+    CancelRender { render_id: u32 },
+
     /// Compute a reference orbit at high precision.
     ComputeReferenceOrbit {
         render_id: u32,
         orbit_id: u32,
+        /// JSON (BigFloat, BigFloat) reference point, in the compact encoding
         c_ref_json: String,
         max_iterations: u32,
     },
@@ -33,14 +40,37 @@ pub enum MainToWorker {
         bla_enabled: bool,
     },
 
+    /// Append part of a reference orbit that is still being computed.
+    ///
+    /// Chunks arrive in order, starting at iteration 0. Tiles can be rendered
+    /// against the prefix received so far. The message with `complete` set
+    /// carries the last points and `escaped_at`, and is answered with
+    /// `OrbitStored` once the BLA table for the whole orbit is built.
+    AppendReferenceOrbit {
+        orbit_id: u32,
+        c_ref: (f64, f64),
+        /// Iteration index of the first point in this chunk
+        start: u32,
+        orbit: Vec<(f64, f64)>,
+        derivative: Vec<(f64, f64)>,
+        /// Whether this chunk ends the orbit
+        complete: bool,
+        escaped_at: Option<u32>,
+        /// Maximum |δc| for any pixel in viewport (for BLA table construction).
+        dc_max: HDRFloat,
+        /// Whether to build BLA tables for this orbit
+        bla_enabled: bool,
+    },
+
     /// Render a tile using perturbation with extended precision deltas.
     RenderTilePerturbation {
         render_id: u32,
         tile: PixelRect,
         orbit_id: u32,
-        /// JSON-serialized (BigFloat, BigFloat) for delta_c at tile origin
+        /// JSON (BigFloat, BigFloat) for delta_c at tile origin, in the compact encoding
         delta_c_origin_json: String,
-        /// JSON-serialized (BigFloat, BigFloat) for delta_c step per pixel
+        /// JSON DeltaStep<BigFloat> in the compact encoding: delta_c change per pixel
+        /// right and per pixel down. Both have re and im parts when the viewport is rotated.
         delta_c_step_json: String,
         max_iterations: u32,
         /// Glitch detection threshold squared (τ²).
@@ -52,10 +82,62 @@ pub enum MainToWorker {
         bla_enabled: bool,
         /// Force HDRFloat for all calculations (debug option).
         force_hdr_float: bool,
+        /// Record orbit trap and stripe statistics; turns BLA off for the tile.
+        collect_orbit_stats: bool,
+        /// Probe non-escaped pixels for the period and distance of their cycle.
+        probe_interior: bool,
     },
 
     /// Discard a cached orbit.
     DiscardOrbit { orbit_id: u32 },
+
+    /// Trace the iteration path of one pixel, answered with `OrbitPath`.
+    ///
+    /// Uses the cached orbit `orbit_id` when the worker has it complete,
+    /// otherwise computes a reference orbit at `c_ref_json` first.
+    TraceOrbitPath {
+        request_id: u32,
+        orbit_id: u32,
+        /// JSON (BigFloat, BigFloat) reference point, in the compact encoding
+        c_ref_json: String,
+        /// JSON (BigFloat, BigFloat) offset of the pixel from the reference point
+        delta_c_json: String,
+        max_iterations: u32,
+        /// Glitch detection threshold squared (τ²).
+        tau_sq: f64,
+    },
+
+    /// Use a finished reference orbit held in a SharedArrayBuffer.
+    ///
+    /// Posted as `[json, buffer]` rather than a plain string. The buffer holds
+    /// `len` points of (Z, Der) as four f64 each, followed by the BLA entries
+    /// described by `bla`. Replaces any streamed prefix of the same orbit and
+    /// is answered with `OrbitStored`.
+    AttachSharedOrbit {
+        orbit_id: u32,
+        c_ref: (f64, f64),
+        len: u32,
+        escaped_at: Option<u32>,
+        /// Period the orbit was folded onto, if any
+        period: Option<u32>,
+        dc_max: HDRFloat,
+        bla: Option<SharedBlaLayout>,
+    },
+
+    /// Write tile results into a shared full-frame buffer from now on.
+    ///
+    /// Posted as `[json, buffer]`. The buffer starts with a header whose
+    /// first word is the render currently accepting results, followed by
+    /// `width × height` pixels of `MandelbrotData::WORDS` words in row order.
+    AttachResultBuffer { width: u32, height: u32 },
+}
+
+/// BLA table stored after the orbit points in a shared orbit buffer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SharedBlaLayout {
+    /// Number of entries in the buffer
+    pub entries: u32,
+    pub level_offsets: Vec<usize>,
 }
 
 /// Messages sent from worker to main thread.
@@ -84,19 +166,58 @@ pub enum WorkerToMain {
         /// Total rebase count across all pixels in tile.
         #[serde(default)]
         rebase_count: u64,
-        /// Whether f64 (true) or HDRFloat (false) arithmetic was used.
+        /// Delta arithmetic the tile was rendered with.
         #[serde(default)]
-        used_f64: bool,
+        precision: DeltaPrecision,
+        /// Rendered against a reference orbit prefix that some pixels ran
+        /// past. Those pixels are not final; the worker keeps their state
+        /// and continues them when the tile is sent to it again once more
+        /// of the orbit has arrived. The statistics are the totals so far.
+        #[serde(default)]
+        needs_longer_orbit: bool,
+        /// Results were written to the shared result buffer; `data` is empty.
+        #[serde(default)]
+        in_shared_buffer: bool,
     },
 
+    /// Tile still being computed, sent between its slices about once a
+    /// second so the pool can tell a slow tile from a hung worker.
+    TileHeartbeat { render_id: u32, tile: PixelRect },
+
     /// Worker encountered an error.
     Error { message: String },
 
+    /// Reference orbit computation progress, sent a few times per second.
+    ReferenceOrbitProgress {
+        render_id: u32,
+        orbit_id: u32,
+        /// Orbit points computed so far
+        computed: u32,
+        max_iterations: u32,
+    },
+
+    /// Finished prefix of a reference orbit that is still being computed.
+    ReferenceOrbitChunk {
+        render_id: u32,
+        orbit_id: u32,
+        c_ref: (f64, f64),
+        /// Iteration index of the first point in this chunk
+        start: u32,
+        orbit: Vec<(f64, f64)>,
+        derivative: Vec<(f64, f64)>,
+    },
+
     /// Reference orbit computation complete.
+    ///
+    /// Carries the points after the last `ReferenceOrbitChunk`, or the whole
+    /// orbit when it was short enough to need no chunks.
     ReferenceOrbitComplete {
         render_id: u32,
         orbit_id: u32,
         c_ref: (f64, f64),
+        /// Iteration index of the first point in `orbit`
+        #[serde(default)]
+        start: u32,
         orbit: Vec<(f64, f64)>,
         derivative: Vec<(f64, f64)>,
         escaped_at: Option<u32>,
@@ -104,6 +225,35 @@ pub enum WorkerToMain {
 
     /// Orbit stored and ready.
     OrbitStored { orbit_id: u32 },
+
+    /// Iteration path requested by `TraceOrbitPath`.
+    OrbitPath { request_id: u32, path: OrbitPath },
+}
+
+/// Delta arithmetic used for a CPU perturbation tile.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeltaPrecision {
+    /// Plain f64, while deltas stay inside the f64 exponent range.
+    #[default]
+    F64,
+    /// f64 values in units of a per-pixel power of two, below the f64 range.
+    ScaledF64,
+    /// f64 mantissa with an i64 exponent, at any depth.
+    FloatExp,
+    /// f32 head/tail pair with an i32 exponent, when HDRFloat is forced.
+    HdrFloat,
+}
+
+impl DeltaPrecision {
+    /// Short label for logs and the UI.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::F64 => "f64",
+            Self::ScaledF64 => "scaled f64",
+            Self::FloatExp => "FloatExp",
+            Self::HdrFloat => "HDRFloat",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -135,21 +285,32 @@ mod tests {
                 final_z_norm_sq: 0.0,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             })],
             compute_time_ms: 12.5,
             bla_iterations: 50,
             total_iterations: 100,
             rebase_count: 5,
-            used_f64: true,
+            precision: DeltaPrecision::FloatExp,
+            needs_longer_orbit: false,
+            in_shared_buffer: false,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: WorkerToMain = serde_json::from_str(&json).unwrap();
         match parsed {
             WorkerToMain::TileComplete {
-                render_id, data, ..
+                render_id,
+                data,
+                precision,
+                ..
             } => {
                 assert_eq!(render_id, 1);
                 assert_eq!(data.len(), 1);
+                assert_eq!(precision, DeltaPrecision::FloatExp);
             }
             _ => panic!("Wrong variant"),
         }
@@ -178,6 +339,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn cancel_render_roundtrip() {
+        let msg = MainToWorker::CancelRender { render_id: 7 };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains("CancelRender"));
+        let parsed: MainToWorker = serde_json::from_str(&json).unwrap();
+        match parsed {
+            MainToWorker::CancelRender { render_id } => assert_eq!(render_id, 7),
+            _ => panic!("Wrong variant"),
+        }
+    }
+
     #[test]
     fn store_reference_orbit_roundtrip() {
         let msg = MainToWorker::StoreReferenceOrbit {
@@ -208,28 +381,40 @@ mod tests {
 
     #[test]
     fn render_tile_perturbation_roundtrip() {
-        use crate::BigFloat;
+        use crate::{BigFloat, DeltaStep};
 
         let delta_origin = (
             BigFloat::from_string("1e-500", 2048).unwrap(),
             BigFloat::from_string("-2e-500", 2048).unwrap(),
         );
-        let delta_step = (
-            BigFloat::from_string("1e-503", 2048).unwrap(),
-            BigFloat::from_string("1e-503", 2048).unwrap(),
-        );
+        let delta_step = DeltaStep {
+            x: (
+                BigFloat::from_string("1e-503", 2048).unwrap(),
+                BigFloat::from_string("2e-504", 2048).unwrap(),
+            ),
+            y: (
+                BigFloat::from_string("-2e-504", 2048).unwrap(),
+                BigFloat::from_string("1e-503", 2048).unwrap(),
+            ),
+        };
 
         let msg = MainToWorker::RenderTilePerturbation {
             render_id: 1,
             tile: PixelRect::new(0, 0, 64, 64),
             orbit_id: 42,
-            delta_c_origin_json: serde_json::to_string(&delta_origin).unwrap(),
-            delta_c_step_json: serde_json::to_string(&delta_step).unwrap(),
+            delta_c_origin_json: serde_json::to_string(&(
+                delta_origin.0.compact(),
+                delta_origin.1.compact(),
+            ))
+            .unwrap(),
+            delta_c_step_json: serde_json::to_string(&delta_step.compact()).unwrap(),
             max_iterations: 10000,
             tau_sq: 1e-6,
             bigfloat_threshold_bits: 1024,
             bla_enabled: true,
             force_hdr_float: false,
+            collect_orbit_stats: false,
+            probe_interior: false,
         };
         let json = serde_json::to_string(&msg).unwrap();
         let parsed: MainToWorker = serde_json::from_str(&json).unwrap();
@@ -237,11 +422,15 @@ mod tests {
             MainToWorker::RenderTilePerturbation {
                 orbit_id,
                 delta_c_origin_json,
+                delta_c_step_json,
                 tau_sq,
                 ..
             } => {
                 assert_eq!(orbit_id, 42);
                 assert!((tau_sq - 1e-6).abs() < 1e-12);
+                // Compact strings, not the legacy value/precision_bits maps
+                assert!(!delta_c_origin_json.contains("precision_bits"));
+                assert!(!delta_c_step_json.contains("precision_bits"));
 
                 // Verify BigFloat survives roundtrip
                 let parsed_origin: (BigFloat, BigFloat) =
@@ -251,6 +440,11 @@ mod tests {
                 // Verify extreme value preserved
                 let log2 = parsed_origin.0.log2_approx();
                 assert!(log2 < -1600.0, "Delta should be ~10^-500");
+
+                // Rotated step vectors keep both components
+                let parsed_step: DeltaStep<BigFloat> =
+                    serde_json::from_str(&delta_c_step_json).unwrap();
+                assert_eq!(parsed_step, delta_step);
             }
             _ => panic!("Wrong variant"),
         }
@@ -262,6 +456,7 @@ mod tests {
             render_id: 1,
             orbit_id: 42,
             c_ref: (-0.5, 0.0),
+            start: 0,
             orbit: vec![(0.0, 0.0), (-0.5, 0.0)],
             derivative: vec![(0.0, 0.0), (1.0, 0.0)],
             escaped_at: Some(1000),
@@ -281,6 +476,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn reference_orbit_complete_without_start_defaults_to_zero() {
+        let json = r#"{"type":"ReferenceOrbitComplete","render_id":1,"orbit_id":2,"c_ref":[0.0,0.0],"orbit":[],"derivative":[],"escaped_at":null}"#;
+        match serde_json::from_str::<WorkerToMain>(json).unwrap() {
+            WorkerToMain::ReferenceOrbitComplete { start, .. } => assert_eq!(start, 0),
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn reference_orbit_chunk_roundtrip() {
+        let msg = WorkerToMain::ReferenceOrbitChunk {
+            render_id: 3,
+            orbit_id: 7,
+            c_ref: (-1.75, 0.0),
+            start: 65536,
+            orbit: vec![(0.5, 0.25); 4],
+            derivative: vec![(2.0, -1.0); 4],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<WorkerToMain>(&json).unwrap() {
+            WorkerToMain::ReferenceOrbitChunk {
+                orbit_id,
+                start,
+                orbit,
+                ..
+            } => {
+                assert_eq!(orbit_id, 7);
+                assert_eq!(start, 65536);
+                assert_eq!(orbit.len(), 4);
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn append_reference_orbit_roundtrip() {
+        let msg = MainToWorker::AppendReferenceOrbit {
+            orbit_id: 7,
+            c_ref: (-1.75, 0.0),
+            start: 131072,
+            orbit: vec![(0.5, 0.25)],
+            derivative: vec![(2.0, -1.0)],
+            complete: true,
+            escaped_at: Some(131073),
+            dc_max: HDRFloat::from_f64(1e-30),
+            bla_enabled: true,
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<MainToWorker>(&json).unwrap() {
+            MainToWorker::AppendReferenceOrbit {
+                start,
+                complete,
+                escaped_at,
+                ..
+            } => {
+                assert_eq!(start, 131072);
+                assert!(complete);
+                assert_eq!(escaped_at, Some(131073));
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn attach_shared_orbit_roundtrip() {
+        let msg = MainToWorker::AttachSharedOrbit {
+            orbit_id: 9,
+            c_ref: (-1.0, 0.0),
+            len: 3,
+            escaped_at: None,
+            period: Some(2),
+            dc_max: HDRFloat::from_f64(1e-30),
+            bla: Some(SharedBlaLayout {
+                entries: 4,
+                level_offsets: vec![0, 2, 3],
+            }),
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        match serde_json::from_str::<MainToWorker>(&json).unwrap() {
+            MainToWorker::AttachSharedOrbit {
+                len, period, bla, ..
+            } => {
+                assert_eq!(len, 3);
+                assert_eq!(period, Some(2));
+                assert_eq!(bla.unwrap().level_offsets, vec![0, 2, 3]);
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn tile_complete_without_shared_flag_defaults_to_inline_data() {
+        let json = r#"{"type":"TileComplete","render_id":1,"tile":{"x":0,"y":0,"width":1,"height":1},"data":[],"compute_time_ms":1.0}"#;
+        match serde_json::from_str::<WorkerToMain>(json).unwrap() {
+            WorkerToMain::TileComplete {
+                in_shared_buffer, ..
+            } => assert!(!in_shared_buffer),
+            _ => panic!("Wrong variant"),
+        }
+    }
+
     #[test]
     fn orbit_stored_roundtrip() {
         let msg = WorkerToMain::OrbitStored { orbit_id: 42 };
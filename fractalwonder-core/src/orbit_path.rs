@@ -0,0 +1,45 @@
+//! Iteration path of a single pixel, traced for the orbit overlay.
+
+use crate::{HDRFloat, MandelbrotData};
+use serde::{Deserialize, Serialize};
+
+/// One iteration of a traced pixel orbit.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrbitPathPoint {
+    /// z_n, for plotting the orbit in the complex plane
+    pub z: (f64, f64),
+    /// z_n − C_ref, the offset from the reference point, which stays exact
+    /// at depths where z_n itself rounds to C_ref in f64
+    pub offset: (HDRFloat, HDRFloat),
+    /// Index into the reference orbit followed at this iteration; drops
+    /// back to 0 when the pixel rebases
+    pub reference_index: u32,
+}
+
+/// Iterations z_0, z_1, … of a pixel, computed with the perturbation code
+/// the tiles use.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrbitPath {
+    /// Recorded iterations, possibly fewer than the pixel ran
+    pub points: Vec<OrbitPathPoint>,
+    /// Reference orbit Z_0, Z_1, … over as many iterations, for comparison
+    pub reference: Vec<(f64, f64)>,
+    /// Reference point C_ref as f64
+    pub c_ref: (f64, f64),
+    /// Result for the pixel: escape iteration, period and flags
+    pub data: MandelbrotData,
+    /// Number of times the pixel rebased onto the start of the reference
+    pub rebases: u32,
+}
+
+impl OrbitPath {
+    /// Escape iteration, if the pixel escaped.
+    pub fn escaped_at(&self) -> Option<u32> {
+        self.data.escaped.then_some(self.data.iterations)
+    }
+
+    /// Period of the attracting cycle for interior pixels.
+    pub fn period(&self) -> Option<u32> {
+        (!self.data.escaped && self.data.period > 0).then_some(self.data.period)
+    }
+}
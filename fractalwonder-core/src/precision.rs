@@ -3,21 +3,19 @@
 //! Determines how many mantissa bits are needed to accurately compute
 //! fractal values at a given viewport and resolution.
 //!
-//! # Limitations
-//!
-//! This implementation uses f64 for intermediate log2 calculations. This is acceptable
-//! for zooms with decimal exponents up to ~10^6 (which correspond to base-2 logs ~3.3×10^6,
-//! representable in f64). For extreme zooms beyond this, consider computing log2 using
-//! high-precision arithmetic or extracting mantissa+exponent pairs.
+//! Magnitudes come from `BigFloat::to_hdr_parts`, so binary exponents are kept
+//! as exact integers and only the mantissa goes through f64. The bit count stays
+//! exact at any zoom depth up to the `MAX_ALLOWED_BITS` cap.
 
-use crate::Viewport;
+use crate::{BigFloat, Viewport};
 
 /// Base safety margin for rounding errors in arithmetic operations.
 /// Additional safety is added based on zoom depth.
 const BASE_SAFETY_BITS: u64 = 16;
 
-/// Maximum allowed precision bits (1M bits cap to prevent overflow).
-const MAX_ALLOWED_BITS: usize = 1 << 20;
+/// Maximum allowed precision bits (256M bits, zooms around 10^(8×10^7)).
+/// Fits in a 32-bit usize so the cap holds on wasm32.
+const MAX_ALLOWED_BITS: usize = 1 << 28;
 
 /// Default maximum iterations for Mandelbrot computation.
 const DEFAULT_MAX_ITERATIONS: u64 = 10_000;
@@ -32,36 +30,13 @@ fn ceil_log2_u64(n: u64) -> u64 {
     64 - (n - 1).leading_zeros() as u64
 }
 
-/// Compute log2(x + y) from log2(x) and log2(y) using the identity:
-/// log2(x + y) = max(log2(x), log2(y)) + log2(1 + 2^{-d})
-/// where d = |log2(x) - log2(y)|.
+/// log2 of |value| as an exact binary exponent plus the log2 of the mantissa,
+/// which lies in [-1, 0). Returns None for zero.
 ///
-/// Handles -inf (representing zero) correctly.
-fn log2_sum_from_logs(log2_a: f64, log2_b: f64) -> f64 {
-    // Handle -inf (zero values)
-    if log2_a.is_infinite() && log2_a.is_sign_negative() {
-        return log2_b;
-    }
-    if log2_b.is_infinite() && log2_b.is_sign_negative() {
-        return log2_a;
-    }
-
-    let (mx, mn) = if log2_a >= log2_b {
-        (log2_a, log2_b)
-    } else {
-        (log2_b, log2_a)
-    };
-
-    let d = mx - mn;
-
-    // If one term is > 2^60 times the other, the smaller term is negligible
-    if d > 60.0 {
-        return mx;
-    }
-
-    // log2(1 + 2^{-d}) correction term
-    let correction = (1.0f64 + 2f64.powf(-d)).log2();
-    mx + correction
+/// Comparing the pairs lexicographically orders the magnitudes.
+fn log2_parts(value: &BigFloat) -> Option<(i64, f64)> {
+    let (mantissa, exponent) = value.to_hdr_parts();
+    (mantissa != 0.0 && mantissa.is_finite()).then(|| (exponent, mantissa.abs().log2()))
 }
 
 /// Calculate required precision bits for fractal computation.
@@ -95,41 +70,44 @@ pub fn calculate_precision_bits_with_iterations(
     let px = canvas_size.0 as f64;
     let py = canvas_size.1 as f64;
 
-    // log2(min_delta) where delta = dimension / pixels
-    let log2_delta_x = width.log2_approx() - px.log2();
-    let log2_delta_y = height.log2_approx() - py.log2();
-    let log2_min_delta = log2_delta_x.min(log2_delta_y);
-
-    // M = max(|cx| + width/2, |cy| + height/2)
-    // Compute log2(M) accurately using log2_sum_from_logs
-    let log2_half_width = width.log2_approx() - 1.0;
-    let log2_half_height = height.log2_approx() - 1.0;
-    let log2_cx = cx.abs().log2_approx();
-    let log2_cy = cy.abs().log2_approx();
+    // min_delta = min(width / px, height / py), kept as exponent + fraction.
+    // The fraction may drop below -1 after dividing by the pixel count.
+    let min_delta = match (log2_parts(width), log2_parts(height)) {
+        (Some((ex, fx)), Some((ey, fy))) => {
+            let (fx, fy) = (fx - px.log2(), fy - py.log2());
+            if ((ex - ey) as f64) + (fx - fy) <= 0.0 {
+                Some((ex, fx))
+            } else {
+                Some((ey, fy))
+            }
+        }
+        _ => None,
+    };
 
-    // Accurate log2(|cx| + width/2) and log2(|cy| + height/2)
-    let log2_mx = log2_sum_from_logs(log2_cx, log2_half_width);
-    let log2_my = log2_sum_from_logs(log2_cy, log2_half_height);
-    let log2_m = log2_mx.max(log2_my);
+    // M = max(|cx| + width/2, |cy| + height/2), summed exactly in BigFloat
+    let half = BigFloat::with_precision(0.5, 64);
+    let mx = log2_parts(&cx.abs().add(&width.mul(&half)));
+    let my = log2_parts(&cy.abs().add(&height.mul(&half)));
+    let m = match (mx, my) {
+        (Some(a), Some(b)) => Some(if a >= b { a } else { b }),
+        (a, b) => a.or(b),
+    };
 
     // bits_from_ratio = ceil(log2(M / min_delta))
-    let log2_ratio = log2_m - log2_min_delta;
-    let bits_from_ratio = if log2_ratio.is_finite() && log2_ratio > 0.0 {
-        log2_ratio.ceil() as u64
-    } else {
-        0
+    let bits_from_ratio = match (m, min_delta) {
+        (Some((em, fm)), Some((ed, fd))) => (em - ed).saturating_add((fm - fd).ceil() as i64),
+        _ => 0,
     };
 
     // Zoom depth floor: ensure enough precision to handle panning to any location
     // at this zoom level. If viewport width is tiny (e.g., 10^-1000), and user pans
     // to center (1.0, 0), coordinates become 1 + O(10^-1000), requiring ~3320 bits.
     // zoom_depth_bits = bits to represent pixel_delta relative to unit scale (1.0)
-    let zoom_depth_bits = if log2_min_delta.is_finite() && log2_min_delta < 0.0 {
-        (-log2_min_delta).ceil() as u64
-    } else {
-        0
+    let zoom_depth_bits = match min_delta {
+        Some((ed, fd)) => (-ed).saturating_add((-fd).ceil() as i64),
+        None => 0,
     };
-    let bits_from_ratio = bits_from_ratio.max(zoom_depth_bits);
+    let bits_from_ratio = bits_from_ratio.max(zoom_depth_bits).max(0) as u64;
 
     // Integer-safe iteration bits using bit operations
     let iter_bits = ceil_log2_u64(max_iterations);
@@ -150,11 +128,7 @@ pub fn calculate_precision_bits_with_iterations(
         .saturating_add(safety_bits);
 
     // Clamp to MAX_ALLOWED_BITS and ensure minimum of 64 bits
-    let total_usize = if (total_bits as usize) > MAX_ALLOWED_BITS {
-        MAX_ALLOWED_BITS
-    } else {
-        total_bits as usize
-    };
+    let total_usize = total_bits.min(MAX_ALLOWED_BITS as u64) as usize;
 
     let result = total_usize.max(64);
 
@@ -331,36 +305,28 @@ mod tests {
     }
 
     #[test]
-    fn log2_sum_from_logs_correctness() {
-        // Test accurate log2(x+y) computation
-
-        // Equal values: log2(x + x) = log2(2x) = log2(x) + 1
-        let result = log2_sum_from_logs(3.0, 3.0);
-        assert!(
-            (result - 4.0).abs() < 0.001,
-            "log2(8+8)=log2(16)=4, got {}",
-            result
-        );
-
-        // Very different magnitudes: log2(1 + 1024) ≈ log2(1024) = 10
-        let result = log2_sum_from_logs(0.0, 10.0);
-        let expected = (1.0f64 + 1024.0).log2(); // ~10.001
-        assert!(
-            (result - expected).abs() < 0.001,
-            "Expected ~{}, got {}",
-            expected,
-            result
+    fn precision_exact_beyond_million_digit_zoom() {
+        // Width 2^-6,700,000 (about 10^-2,016,900) is far past where log2
+        // through f64 strings was reliable, and past the old 1M-bit cap
+        let depth = 6_700_000;
+        let precision = depth + 1024;
+        let width = BigFloat::with_precision(0.5, precision)
+            .powi(depth as i64)
+            .unwrap();
+        let viewport = Viewport::with_bigfloat(
+            BigFloat::with_precision(-0.5, precision),
+            BigFloat::zero(precision),
+            width.clone(),
+            width,
         );
 
-        // Zero handling: log2(0 + x) = log2(x)
-        let result = log2_sum_from_logs(f64::NEG_INFINITY, 5.0);
-        assert_eq!(result, 5.0);
-
-        let result = log2_sum_from_logs(5.0, f64::NEG_INFINITY);
-        assert_eq!(result, 5.0);
+        let bits = calculate_precision_bits(&viewport, (1024, 1024));
 
-        // Both zero
-        let result = log2_sum_from_logs(f64::NEG_INFINITY, f64::NEG_INFINITY);
-        assert!(result.is_infinite() && result.is_sign_negative());
+        // M = 0.5 + tiny, min_delta = 2^-(depth + 10), so the ratio needs
+        // depth + 9 bits and the zoom depth floor needs depth + 10
+        let depth_bits = (depth + 10) as u64;
+        let expected =
+            depth_bits + ceil_log2_u64(DEFAULT_MAX_ITERATIONS) + BASE_SAFETY_BITS + depth_bits / 10;
+        assert_eq!(bits, expected as usize);
     }
 }
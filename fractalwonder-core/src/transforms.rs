@@ -1,4 +1,6 @@
+use crate::bigfloat::CompactBigFloat;
 use crate::precision::calculate_precision_bits;
+use crate::viewport::{normalize_angle, IDENTITY_SKEW};
 use crate::{BigFloat, Viewport};
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +18,10 @@ pub struct PixelTransform {
     pub offset_y: f64,
     /// Cumulative zoom factor (1.0 = no zoom, 2.0 = 2x zoom, 0.5 = 0.5x zoom)
     pub zoom_factor: f64,
+    /// Rotation of the image around the canvas center in radians
+    /// (positive = clockwise on screen, since pixel y points down)
+    #[serde(default)]
+    pub rotation: f64,
     /// 2D affine transformation matrix [3x3] encoding offset + zoom in absolute coordinates
     /// (used internally for canvas rendering, not for external interpretation)
     pub matrix: [[f64; 3]; 3],
@@ -30,30 +36,78 @@ impl PixelTransform {
         canvas_width: u32,
         canvas_height: u32,
     ) -> Self {
-        // Convert center-relative to absolute for matrix
+        Self::with_rotation(
+            offset_x,
+            offset_y,
+            zoom_factor,
+            0.0,
+            canvas_width,
+            canvas_height,
+        )
+    }
+
+    /// Create a new PixelTransform that also rotates around the canvas center
+    pub fn with_rotation(
+        offset_x: f64,
+        offset_y: f64,
+        zoom_factor: f64,
+        rotation: f64,
+        canvas_width: u32,
+        canvas_height: u32,
+    ) -> Self {
+        // Maps p to center + offset + zoom * R(rotation) * (p - center)
         let canvas_center_x = canvas_width as f64 / 2.0;
         let canvas_center_y = canvas_height as f64 / 2.0;
-        let absolute_offset_x = offset_x + canvas_center_x * (1.0 - zoom_factor);
-        let absolute_offset_y = offset_y + canvas_center_y * (1.0 - zoom_factor);
+        let (sin, cos) = rotation.sin_cos();
+        let a = zoom_factor * cos;
+        let b = zoom_factor * sin;
+        let absolute_offset_x =
+            offset_x + canvas_center_x - (a * canvas_center_x - b * canvas_center_y);
+        let absolute_offset_y =
+            offset_y + canvas_center_y - (b * canvas_center_x + a * canvas_center_y);
 
         Self {
             offset_x,
             offset_y,
             zoom_factor,
+            rotation,
             matrix: [
-                [zoom_factor, 0.0, absolute_offset_x],
-                [0.0, zoom_factor, absolute_offset_y],
+                [a, -b, absolute_offset_x],
+                [b, a, absolute_offset_y],
                 [0.0, 0.0, 1.0],
             ],
         }
     }
 
+    /// Decompose a composed interaction matrix (translation, uniform scale and
+    /// rotation only) into center-relative offset, zoom and rotation.
+    pub fn from_matrix(matrix: &PixelMat3, canvas_width: u32, canvas_height: u32) -> Self {
+        let a = matrix.data[0][0];
+        let b = matrix.data[1][0];
+        let zoom_factor = a.hypot(b);
+        let rotation = b.atan2(a);
+
+        // Where the canvas center lands, relative to the canvas center
+        let canvas_center_x = canvas_width as f64 / 2.0;
+        let canvas_center_y = canvas_height as f64 / 2.0;
+        let (moved_x, moved_y) = matrix.transform_point(canvas_center_x, canvas_center_y);
+
+        Self {
+            offset_x: moved_x - canvas_center_x,
+            offset_y: moved_y - canvas_center_y,
+            zoom_factor,
+            rotation,
+            matrix: matrix.data,
+        }
+    }
+
     /// Create identity transform (no change)
     pub fn identity() -> Self {
         Self {
             offset_x: 0.0,
             offset_y: 0.0,
             zoom_factor: 1.0,
+            rotation: 0.0,
             matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
         }
     }
@@ -71,6 +125,13 @@ pub enum AffinePrimitive {
         center_x: f64,
         center_y: f64,
     },
+    /// Rotate by angle (radians) around point (center_x, center_y).
+    /// Positive angles turn clockwise on screen, since pixel y points down.
+    Rotate {
+        angle: f64,
+        center_x: f64,
+        center_y: f64,
+    },
 }
 
 /// A 3x3 homogeneous transformation matrix for 2D affine transformations
@@ -114,6 +175,38 @@ impl PixelMat3 {
         Self::scale_around(factor, 0.0, 0.0)
     }
 
+    /// Creates a rotation matrix around a point (cx, cy)
+    ///
+    /// The point (cx, cy) remains fixed during the rotation.
+    pub fn rotation_around(angle: f64, cx: f64, cy: f64) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            data: [
+                [cos, -sin, cx - (cos * cx - sin * cy)],
+                [sin, cos, cy - (sin * cx + cos * cy)],
+                [0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// Returns the inverse transformation, or None if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, tx], [c, d, ty], _] = self.data;
+        let det = a * d - b * c;
+        if det.abs() < f64::EPSILON {
+            return None;
+        }
+
+        let (ia, ib, ic, id) = (d / det, -b / det, -c / det, a / det);
+        Some(Self {
+            data: [
+                [ia, ib, -(ia * tx + ib * ty)],
+                [ic, id, -(ic * tx + id * ty)],
+                [0.0, 0.0, 1.0],
+            ],
+        })
+    }
+
     /// Multiplies this matrix by another (self × other)
     ///
     /// For transformations, left-multiplying applies the transformation:
@@ -198,6 +291,11 @@ pub fn compose_affine_transformations(
                 center_x,
                 center_y,
             } => PixelMat3::scale_around(factor, center_x, center_y),
+            AffinePrimitive::Rotate {
+                angle,
+                center_x,
+                center_y,
+            } => PixelMat3::rotation_around(angle, center_x, center_y),
         };
 
         // Left-multiply: result = matrix × result
@@ -208,11 +306,184 @@ pub fn compose_affine_transformations(
     result
 }
 
-/// Convert pixel coordinates to fractal coordinates
+/// Change in fractal coordinates per pixel step across the canvas.
 ///
-/// Uses BigFloat arithmetic throughout to preserve precision.
-/// The viewport directly specifies the visible region (width, height) in fractal space.
-pub fn pixel_to_fractal(
+/// For an axis-aligned viewport, stepping right only changes the real part and
+/// stepping down only the imaginary part. A rotated or skewed viewport moves along both
+/// axes for either step, so each direction carries a full complex vector.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DeltaStep<T> {
+    /// Change when moving one pixel right (re, im)
+    pub x: (T, T),
+    /// Change when moving one pixel down (re, im)
+    pub y: (T, T),
+}
+
+impl<T: Default> DeltaStep<T> {
+    /// Steps for an unrotated viewport
+    pub fn axis_aligned(step_x: T, step_y: T) -> Self {
+        Self {
+            x: (step_x, T::default()),
+            y: (T::default(), step_y),
+        }
+    }
+}
+
+impl<T> DeltaStep<T> {
+    /// Convert every component, e.g. from BigFloat to f64 or HDRFloat
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> DeltaStep<U> {
+        DeltaStep {
+            x: (f(&self.x.0), f(&self.x.1)),
+            y: (f(&self.y.0), f(&self.y.1)),
+        }
+    }
+}
+
+impl DeltaStep<BigFloat> {
+    /// Borrow as steps that serialize in the compact BigFloat encoding
+    pub fn compact(&self) -> DeltaStep<CompactBigFloat<'_>> {
+        DeltaStep {
+            x: (self.x.0.compact(), self.x.1.compact()),
+            y: (self.y.0.compact(), self.y.1.compact()),
+        }
+    }
+}
+
+/// Rotate a fractal-space offset by `angle` radians.
+///
+/// The rotation is applied to offsets only, never to absolute coordinates,
+/// so an f64 angle loses no precision at depth.
+pub fn rotate_offset(offset: (BigFloat, BigFloat), angle: f64) -> (BigFloat, BigFloat) {
+    if angle == 0.0 {
+        return offset;
+    }
+
+    let precision = offset.0.precision_bits().max(offset.1.precision_bits());
+    let (sin, cos) = angle.sin_cos();
+    let sin = BigFloat::with_precision(sin, precision);
+    let cos = BigFloat::with_precision(cos, precision);
+
+    (
+        offset.0.mul(&cos).sub(&offset.1.mul(&sin)),
+        offset.0.mul(&sin).add(&offset.1.mul(&cos)),
+    )
+}
+
+/// Apply a 2x2 linear map to a fractal-space offset.
+///
+/// Like rotation, the map only ever touches offsets, so f64 entries are enough.
+pub fn skew_offset(offset: (BigFloat, BigFloat), matrix: [[f64; 2]; 2]) -> (BigFloat, BigFloat) {
+    if matrix == IDENTITY_SKEW {
+        return offset;
+    }
+
+    let precision = offset.0.precision_bits().max(offset.1.precision_bits());
+    let m = matrix.map(|row| row.map(|value| BigFloat::with_precision(value, precision)));
+
+    (
+        offset.0.mul(&m[0][0]).add(&offset.1.mul(&m[0][1])),
+        offset.0.mul(&m[1][0]).add(&offset.1.mul(&m[1][1])),
+    )
+}
+
+/// Inverse of a 2x2 matrix, or None if it is singular.
+pub fn invert_2x2(matrix: [[f64; 2]; 2]) -> Option<[[f64; 2]; 2]> {
+    let [[a, b], [c, d]] = matrix;
+    let det = a * d - b * c;
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    Some([[d / det, -b / det], [-c / det, a / det]])
+}
+
+/// Product of two 2x2 matrices, `a · b`.
+pub fn multiply_2x2(a: [[f64; 2]; 2], b: [[f64; 2]; 2]) -> [[f64; 2]; 2] {
+    std::array::from_fn(|i| std::array::from_fn(|j| a[i][0] * b[0][j] + a[i][1] * b[1][j]))
+}
+
+/// Fewest usable gradients for a skew estimate.
+const MIN_SKEW_SAMPLES: usize = 64;
+
+/// Stretch that makes the features of a rendered image look unflattened.
+///
+/// `field` holds a scalar per canvas pixel in row-major order (smooth iteration
+/// counts, say), NaN where a pixel has no usable value. Features are level sets
+/// of the field, so in a flattened view its gradients crowd along the squashed
+/// direction. The gradient structure tensor T = Σ ∇f·∇fᵀ measures that, and
+/// the returned symmetric, unit-determinant K = T^(-1/2) whitens it: applied to
+/// canvas offsets before the current skew, it makes T isotropic. This is exact
+/// when the image is a linearly stretched copy of an isotropic one.
+///
+/// Returns None when too few gradients are available or they all point the
+/// same way.
+pub fn unskew_from_field(field: &[f64], width: usize, height: usize) -> Option<[[f64; 2]; 2]> {
+    if width < 3 || height < 3 || field.len() != width * height {
+        return None;
+    }
+
+    // Central differences wherever all four neighbours are usable
+    let (mut txx, mut txy, mut tyy) = (0.0, 0.0, 0.0);
+    let mut samples = 0;
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let at = |x: usize, y: usize| field[y * width + x];
+            let gx = 0.5 * (at(x + 1, y) - at(x - 1, y));
+            let gy = 0.5 * (at(x, y + 1) - at(x, y - 1));
+            if !gx.is_finite() || !gy.is_finite() {
+                continue;
+            }
+            txx += gx * gx;
+            txy += gx * gy;
+            tyy += gy * gy;
+            samples += 1;
+        }
+    }
+    if samples < MIN_SKEW_SAMPLES {
+        return None;
+    }
+
+    // Only the shape matters, so normalize to unit trace
+    let trace = txx + tyy;
+    if trace == 0.0 || !trace.is_finite() {
+        return None;
+    }
+    let (txx, txy, tyy) = (txx / trace, txy / trace, tyy / trace);
+    let det = txx * tyy - txy * txy;
+    if det <= 1e-9 {
+        return None;
+    }
+
+    // For a 2x2 SPD matrix M, sqrt(M) = (M + sqrt(det M)·I) / sqrt(tr M + 2·sqrt(det M)).
+    let root_det = det.sqrt();
+    let norm = (1.0 + 2.0 * root_det).sqrt();
+    let sqrt_t = [
+        [(txx + root_det) / norm, txy / norm],
+        [txy / norm, (tyy + root_det) / norm],
+    ];
+
+    // det sqrt(T) = sqrt(det T), so scaling its inverse by det^(1/4) gives a unit determinant
+    let k = root_det.sqrt();
+    invert_2x2(sqrt_t).map(|inverse| inverse.map(|row| row.map(|value| value * k)))
+}
+
+/// Map a canvas offset already scaled to fractal units (pixel offset times
+/// pixel size) through the viewport skew and rotation.
+pub fn canvas_offset_to_delta(
+    offset: (BigFloat, BigFloat),
+    viewport: &Viewport,
+) -> (BigFloat, BigFloat) {
+    rotate_offset(
+        skew_offset(offset, viewport.skew_matrix()),
+        viewport.rotation,
+    )
+}
+
+/// Offset in fractal space from the viewport center to a pixel
+///
+/// This is the perturbation delta δc of the pixel relative to a reference at
+/// the viewport center. It never touches the (possibly huge) center
+/// coordinates, so it stays exact at any depth.
+pub fn pixel_to_delta(
     pixel_x: f64,
     pixel_y: f64,
     viewport: &Viewport,
@@ -236,13 +507,44 @@ pub fn pixel_to_fractal(
         ))
         .sub(&BigFloat::with_precision(0.5, precision_bits));
 
-    // fractal_x = center_x + norm_x * width
-    let fractal_x = viewport.center.0.add(&norm_x.mul(&viewport.width));
+    canvas_offset_to_delta(
+        (norm_x.mul(&viewport.width), norm_y.mul(&viewport.height)),
+        viewport,
+    )
+}
+
+/// Fractal-space step vectors between neighboring pixels
+pub fn pixel_delta_step(viewport: &Viewport, canvas_size: (u32, u32)) -> DeltaStep<BigFloat> {
+    let precision = viewport.width.precision_bits();
+    let step_x = viewport
+        .width
+        .div(&BigFloat::with_precision(canvas_size.0 as f64, precision));
+    let step_y = viewport
+        .height
+        .div(&BigFloat::with_precision(canvas_size.1 as f64, precision));
+
+    DeltaStep {
+        x: canvas_offset_to_delta((step_x, BigFloat::zero(precision)), viewport),
+        y: canvas_offset_to_delta((BigFloat::zero(precision), step_y), viewport),
+    }
+}
 
-    // fractal_y = center_y + norm_y * height
-    let fractal_y = viewport.center.1.add(&norm_y.mul(&viewport.height));
+/// Convert pixel coordinates to fractal coordinates
+///
+/// Uses BigFloat arithmetic throughout to preserve precision.
+/// The viewport directly specifies the visible region (width, height) in fractal space,
+/// skewed and then rotated around its center.
+pub fn pixel_to_fractal(
+    pixel_x: f64,
+    pixel_y: f64,
+    viewport: &Viewport,
+    canvas_size: (u32, u32),
+    precision_bits: usize,
+) -> (BigFloat, BigFloat) {
+    let (dx, dy) = pixel_to_delta(pixel_x, pixel_y, viewport, canvas_size, precision_bits);
 
-    (fractal_x, fractal_y)
+    // fractal = center + rotated, skewed (norm * size)
+    (viewport.center.0.add(&dx), viewport.center.1.add(&dy))
 }
 
 /// Convert fractal coordinates to pixel coordinates
@@ -257,11 +559,22 @@ pub fn fractal_to_pixel(
 ) -> (f64, f64) {
     let (canvas_width, canvas_height) = canvas_size;
 
-    // norm_x = (fractal_x - center_x) / width
-    let norm_x = fractal_x.sub(&viewport.center.0).div(&viewport.width);
+    // Undo the viewport rotation, then the skew, on the offset from the center
+    let rotated = rotate_offset(
+        (
+            fractal_x.sub(&viewport.center.0),
+            fractal_y.sub(&viewport.center.1),
+        ),
+        -viewport.rotation,
+    );
+    let (dx, dy) = match invert_2x2(viewport.skew_matrix()) {
+        Some(inverse) => skew_offset(rotated, inverse),
+        None => rotated,
+    };
 
-    // norm_y = (fractal_y - center_y) / height
-    let norm_y = fractal_y.sub(&viewport.center.1).div(&viewport.height);
+    // norm = offset / size
+    let norm_x = dx.div(&viewport.width);
+    let norm_y = dy.div(&viewport.height);
 
     // pixel_x = (norm_x + 0.5) * canvas_width
     let pixel_x = (norm_x.to_f64() + 0.5) * canvas_width as f64;
@@ -278,8 +591,8 @@ pub fn fractal_to_pixel(
 /// Uses BigFloat arithmetic to preserve precision at extreme depths.
 ///
 /// The transform's offset is **center-relative**: (0, 0) means the transformation
-/// is centered at the canvas center. This function converts to absolute coordinates
-/// for proper zoom-point handling.
+/// is centered at the canvas center. The new center is the fractal point the
+/// transform moves onto the canvas center.
 ///
 /// Precision is calculated automatically based on the resulting viewport dimensions
 /// and canvas size. This ensures the returned viewport always has adequate precision
@@ -287,6 +600,8 @@ pub fn fractal_to_pixel(
 ///
 /// - zoom_factor > 1: zooming in, width/height shrink
 /// - zoom_factor < 1: zooming out, width/height grow
+/// - rotation turns the image on screen, so the viewport turns the opposite way
+/// - a skew stays attached to the image, so it turns along with it
 pub fn apply_pixel_transform_to_viewport(
     viewport: &Viewport,
     transform: &PixelTransform,
@@ -296,10 +611,6 @@ pub fn apply_pixel_transform_to_viewport(
     let canvas_center_x = canvas_width as f64 / 2.0;
     let canvas_center_y = canvas_height as f64 / 2.0;
 
-    // Convert center-relative offset to absolute offset (pixel space)
-    let absolute_offset_x = transform.offset_x + canvas_center_x * (1.0 - transform.zoom_factor);
-    let absolute_offset_y = transform.offset_y + canvas_center_y * (1.0 - transform.zoom_factor);
-
     // Step 1: Estimate new dimensions at current precision to calculate required precision
     let current_precision = viewport.precision_bits();
     let zoom_factor_estimate = BigFloat::with_precision(transform.zoom_factor, current_precision);
@@ -315,62 +626,54 @@ pub fn apply_pixel_transform_to_viewport(
     );
     let required_precision = calculate_precision_bits(&temp_viewport, canvas_size);
 
-    // Step 4: Calculate new viewport dimensions at final precision
+    // Step 3: Calculate new viewport dimensions at final precision
     let zoom_factor_bf = BigFloat::with_precision(transform.zoom_factor, required_precision);
     let new_width = viewport.width.div(&zoom_factor_bf);
     let new_height = viewport.height.div(&zoom_factor_bf);
 
-    // Special case: pure translation (zoom ≈ 1.0)
-    if (transform.zoom_factor - 1.0).abs() < 1e-10 {
-        let offset_x_norm = BigFloat::with_precision(absolute_offset_x, required_precision).div(
-            &BigFloat::with_precision(canvas_width as f64, required_precision),
-        );
-        let offset_y_norm = BigFloat::with_precision(absolute_offset_y, required_precision).div(
-            &BigFloat::with_precision(canvas_height as f64, required_precision),
-        );
-
-        let dx = offset_x_norm.mul(&viewport.width);
-        let dy = offset_y_norm.mul(&viewport.height);
-
-        let new_center = (viewport.center.0.sub(&dx), viewport.center.1.sub(&dy));
-
-        // Ensure output viewport has the required precision (not inherited from old viewport)
-        return Viewport {
-            center: new_center,
-            width: new_width,
-            height: new_height,
-        }
-        .to_precision(required_precision);
-    }
-
-    // General case: transformation with zoom
-    let image_at_center = pixel_to_fractal(
-        canvas_center_x,
-        canvas_center_y,
+    // Step 4: Find the pixel of the old image that lands on the canvas center.
+    // The transform maps p to center + offset + zoom * R(rotation) * (p - center),
+    // so its inverse at the center is center - R(-rotation) * offset / zoom.
+    let (sin, cos) = (-transform.rotation).sin_cos();
+    let source_x = canvas_center_x
+        - (cos * transform.offset_x - sin * transform.offset_y) / transform.zoom_factor;
+    let source_y = canvas_center_y
+        - (sin * transform.offset_x + cos * transform.offset_y) / transform.zoom_factor;
+
+    let (dx, dy) = pixel_to_delta(
+        source_x,
+        source_y,
         viewport,
         canvas_size,
         required_precision,
     );
-
-    let new_center_px = canvas_center_x * transform.zoom_factor + absolute_offset_x;
-    let new_center_py = canvas_center_y * transform.zoom_factor + absolute_offset_y;
-
-    let pixel_ratio_x =
-        BigFloat::with_precision(new_center_px / canvas_width as f64, required_precision)
-            .sub(&BigFloat::with_precision(0.5, required_precision));
-    let pixel_ratio_y =
-        BigFloat::with_precision(new_center_py / canvas_height as f64, required_precision)
-            .sub(&BigFloat::with_precision(0.5, required_precision));
-
-    let new_center_x = image_at_center.0.sub(&pixel_ratio_x.mul(&new_width));
-    let new_center_y = image_at_center.1.sub(&pixel_ratio_y.mul(&new_height));
+    let new_center = (viewport.center.0.add(&dx), viewport.center.1.add(&dy));
+
+    // R(φ)·S·R(-θ) = R(φ-θ)·[R(θ)·S·R(-θ)], so the skew is conjugated by the turn
+    let skew = match viewport.skew_matrix() {
+        matrix if matrix == IDENTITY_SKEW || transform.rotation == 0.0 => matrix,
+        [[a, b], [c, d]] => {
+            let (sin, cos) = transform.rotation.sin_cos();
+            // S·R(-θ)
+            let (a1, b1) = (a * cos - b * sin, a * sin + b * cos);
+            let (c1, d1) = (c * cos - d * sin, c * sin + d * cos);
+            // R(θ)·(S·R(-θ))
+            [
+                [cos * a1 - sin * c1, cos * b1 - sin * d1],
+                [sin * a1 + cos * c1, sin * b1 + cos * d1],
+            ]
+        }
+    };
 
     // Ensure output viewport has the required precision (not inherited from old viewport)
     Viewport {
-        center: (new_center_x, new_center_y),
+        center: new_center,
         width: new_width,
         height: new_height,
+        rotation: normalize_angle(viewport.rotation - transform.rotation),
+        skew: viewport.skew,
     }
+    .with_skew(skew)
     .to_precision(required_precision)
 }
 
@@ -381,7 +684,7 @@ pub fn calculate_aspect_ratio(canvas_width: u32, canvas_height: u32) -> f64 {
 /// Expand viewport to match canvas aspect ratio.
 ///
 /// The viewport is expanded (never shrunk) so the natural bounds remain
-/// fully visible regardless of canvas shape. Center stays fixed.
+/// fully visible regardless of canvas shape. Center, rotation and skew stay fixed.
 ///
 /// # Arguments
 /// * `natural_viewport` - The viewport with natural fractal bounds
@@ -412,6 +715,7 @@ pub fn fit_viewport_to_canvas(natural_viewport: &Viewport, canvas_size: (u32, u3
             new_width,
             natural_viewport.height.clone(),
         )
+        .with_orientation_of(natural_viewport)
     } else {
         // Canvas taller than viewport: expand height
         // new_height = width / canvas_aspect
@@ -424,6 +728,7 @@ pub fn fit_viewport_to_canvas(natural_viewport: &Viewport, canvas_size: (u32, u3
             natural_viewport.width.clone(),
             new_height,
         )
+        .with_orientation_of(natural_viewport)
     }
 }
 
@@ -797,6 +1102,7 @@ mod tests {
             offset_x: 0.0,
             offset_y: 0.0,
             zoom_factor: 2.0,
+            rotation: 0.0,
             matrix: [[2.0, 0.0, -400.0], [0.0, 2.0, -300.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -821,6 +1127,7 @@ mod tests {
             offset_x: 100.0, // Image moved right 100 pixels on screen
             offset_y: -50.0, // Image moved up 50 pixels on screen
             zoom_factor: 1.0,
+            rotation: 0.0,
             matrix: [[1.0, 0.0, 100.0], [0.0, 1.0, -50.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -858,6 +1165,7 @@ mod tests {
             offset_x: 100.0, // Image moved right on screen
             offset_y: 50.0,  // Image moved down on screen
             zoom_factor: 2.0,
+            rotation: 0.0,
             matrix: [[2.0, 0.0, -300.0], [0.0, 2.0, -250.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -883,6 +1191,7 @@ mod tests {
             offset_x: 10.0,
             offset_y: 10.0,
             zoom_factor: 1.5,
+            rotation: 0.0,
             matrix: [[1.5, 0.0, -190.0], [0.0, 1.5, -140.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -917,6 +1226,7 @@ mod tests {
             offset_x: 0.0,
             offset_y: 0.0,
             zoom_factor: 2.0,
+            rotation: 0.0,
             matrix: [[2.0, 0.0, -400.0], [0.0, 2.0, -300.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -950,6 +1260,7 @@ mod tests {
             offset_x: 100.0, // Image moved right 100 pixels on screen
             offset_y: 0.0,
             zoom_factor: 1.0,
+            rotation: 0.0,
             matrix: [[1.0, 0.0, 100.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
         };
         let canvas_size = (800, 600);
@@ -1088,4 +1399,231 @@ mod tests {
         let result = calculate_max_iterations(0.5, TEST_MULTIPLIER, TEST_POWER);
         assert_eq!(result, 1000);
     }
+
+    // ============================================================================
+    // Rotation Tests
+    // ============================================================================
+
+    fn close(a: (f64, f64), b: (f64, f64)) -> bool {
+        (a.0 - b.0).abs() < 1e-9 && (a.1 - b.1).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_mat3_rotation_around_point() {
+        let m = PixelMat3::rotation_around(std::f64::consts::FRAC_PI_2, 100.0, 50.0);
+        // Center stays fixed
+        assert!(close(m.transform_point(100.0, 50.0), (100.0, 50.0)));
+        // A point right of the center turns to below it (pixel y points down)
+        assert!(close(m.transform_point(110.0, 50.0), (100.0, 60.0)));
+    }
+
+    #[test]
+    fn test_mat3_inverse_undoes_transform() {
+        let m = compose_affine_transformations(vec![
+            AffinePrimitive::Translate {
+                dx: 30.0,
+                dy: -20.0,
+            },
+            AffinePrimitive::Scale {
+                factor: 1.5,
+                center_x: 200.0,
+                center_y: 100.0,
+            },
+            AffinePrimitive::Rotate {
+                angle: 0.3,
+                center_x: 50.0,
+                center_y: 80.0,
+            },
+        ]);
+        let inverse = m.inverse().unwrap();
+        let (x, y) = m.transform_point(123.0, 45.0);
+        assert!(close(inverse.transform_point(x, y), (123.0, 45.0)));
+
+        let singular = PixelMat3::scale(0.0);
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn pixel_transform_from_matrix_recovers_components() {
+        let t = PixelTransform::with_rotation(40.0, -25.0, 1.75, 0.4, 800, 600);
+        let decomposed = PixelTransform::from_matrix(&PixelMat3 { data: t.matrix }, 800, 600);
+        assert!((decomposed.zoom_factor - 1.75).abs() < 1e-12);
+        assert!((decomposed.rotation - 0.4).abs() < 1e-12);
+        assert!(close(
+            (decomposed.offset_x, decomposed.offset_y),
+            (40.0, -25.0)
+        ));
+    }
+
+    #[test]
+    fn pixel_to_fractal_honors_rotation() {
+        let viewport =
+            Viewport::from_f64(1.0, 2.0, 4.0, 4.0, 128).with_rotation(std::f64::consts::FRAC_PI_2);
+        let canvas_size = (100, 100);
+
+        // Right edge, half a width from the center, turns onto the imaginary axis
+        let (fx, fy) = pixel_to_fractal(100.0, 50.0, &viewport, canvas_size, 128);
+        assert!(close((fx.to_f64(), fy.to_f64()), (1.0, 4.0)));
+
+        let (px, py) = fractal_to_pixel(&fx, &fy, &viewport, canvas_size);
+        assert!(close((px, py), (100.0, 50.0)));
+    }
+
+    #[test]
+    fn pixel_delta_step_matches_neighboring_pixels() {
+        let viewport = Viewport::from_f64(-0.5, 0.0, 3.2, 2.4, 128).with_rotation(0.7);
+        let canvas_size = (320, 240);
+        let step = pixel_delta_step(&viewport, canvas_size).map(BigFloat::to_f64);
+
+        let origin = pixel_to_delta(10.0, 20.0, &viewport, canvas_size, 128);
+        let right = pixel_to_delta(11.0, 20.0, &viewport, canvas_size, 128);
+        let down = pixel_to_delta(10.0, 21.0, &viewport, canvas_size, 128);
+
+        let diff = |a: &(BigFloat, BigFloat), b: &(BigFloat, BigFloat)| {
+            (a.0.sub(&b.0).to_f64(), a.1.sub(&b.1).to_f64())
+        };
+        assert!(close(diff(&right, &origin), step.x));
+        assert!(close(diff(&down, &origin), step.y));
+        assert_eq!(
+            DeltaStep::axis_aligned(1.0, 2.0),
+            DeltaStep {
+                x: (1.0, 0.0),
+                y: (0.0, 2.0)
+            }
+        );
+    }
+
+    /// Whatever the preview showed at a screen pixel is what the new viewport renders there
+    fn assert_transform_keeps_content_under_pixels(
+        viewport: &Viewport,
+        transform: &PixelTransform,
+    ) {
+        let canvas_size = (800, 600);
+        let new_vp = apply_pixel_transform_to_viewport(viewport, transform, canvas_size);
+
+        let inverse = PixelMat3 {
+            data: transform.matrix,
+        }
+        .inverse()
+        .unwrap();
+        for &(px, py) in &[(0.0, 0.0), (400.0, 300.0), (650.0, 120.0)] {
+            let (sx, sy) = inverse.transform_point(px, py);
+            let old = pixel_to_fractal(sx, sy, viewport, canvas_size, 128);
+            let new = pixel_to_fractal(px, py, &new_vp, canvas_size, 128);
+            assert!(
+                close(
+                    (old.0.to_f64(), old.1.to_f64()),
+                    (new.0.to_f64(), new.1.to_f64())
+                ),
+                "pixel ({px}, {py})"
+            );
+        }
+    }
+
+    #[test]
+    fn apply_transform_rotation_keeps_content_under_pixels() {
+        let viewport = Viewport::from_f64(-0.5, 0.25, 4.0, 3.0, 128).with_rotation(0.2);
+        let transform = PixelTransform::with_rotation(30.0, -10.0, 1.5, 0.5, 800, 600);
+
+        let new_vp = apply_pixel_transform_to_viewport(&viewport, &transform, (800, 600));
+        assert!((new_vp.rotation - (0.2 - 0.5)).abs() < 1e-12);
+        assert_transform_keeps_content_under_pixels(&viewport, &transform);
+    }
+
+    #[test]
+    fn apply_transform_skew_keeps_content_under_pixels() {
+        let viewport = Viewport::from_f64(-0.5, 0.25, 4.0, 3.0, 128)
+            .with_rotation(0.2)
+            .with_skew([[1.6, 0.3], [-0.2, 0.6]]);
+
+        for transform in [
+            PixelTransform::new(30.0, -10.0, 1.5, 800, 600),
+            PixelTransform::with_rotation(30.0, -10.0, 1.5, 0.5, 800, 600),
+        ] {
+            assert_transform_keeps_content_under_pixels(&viewport, &transform);
+        }
+    }
+
+    #[test]
+    fn unskew_whitens_a_stretched_field() {
+        // Concentric rings squashed vertically and sheared, f = |A⁻¹·p| inside an
+        // ellipse that fits the canvas
+        let (width, height) = (96, 96);
+        let stretch = [[1.0, 0.4], [0.0, 0.5]];
+        let inverse = invert_2x2(stretch).unwrap();
+        let field: Vec<f64> = (0..width * height)
+            .map(|i| {
+                let p = [(i % width) as f64 - 48.0, (i / width) as f64 - 48.0];
+                let u = [
+                    inverse[0][0] * p[0] + inverse[0][1] * p[1],
+                    inverse[1][0] * p[0] + inverse[1][1] * p[1],
+                ];
+                let r = u[0].hypot(u[1]);
+                if r < 40.0 {
+                    r
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect();
+
+        let k = unskew_from_field(&field, width, height).unwrap();
+        assert!((k[0][1] - k[1][0]).abs() < 1e-12, "symmetric");
+        assert!((k[0][0] * k[1][1] - k[0][1] * k[1][0] - 1.0).abs() < 1e-9);
+
+        // A⁻¹·K is a rotation and scale, so the rings come out round
+        let c = multiply_2x2(inverse, k);
+        let scale = c[0][0].hypot(c[1][0]);
+        assert!((c[0][0] - c[1][1]).abs() < 0.05 * scale, "{c:?}");
+        assert!((c[0][1] + c[1][0]).abs() < 0.05 * scale, "{c:?}");
+    }
+
+    #[test]
+    fn unskew_leaves_round_features_alone() {
+        let (width, height) = (64, 64);
+        let field: Vec<f64> = (0..width * height)
+            .map(|i| ((i % width) as f64 - 32.0).hypot((i / width) as f64 - 32.0))
+            .map(|r| if r < 30.0 { r } else { f64::NAN })
+            .collect();
+        let k = unskew_from_field(&field, width, height).unwrap();
+        assert!(k
+            .iter()
+            .flatten()
+            .zip(IDENTITY_SKEW.iter().flatten())
+            .all(|(a, b)| (a - b).abs() < 1e-3));
+    }
+
+    #[test]
+    fn unskew_needs_gradients_in_two_directions() {
+        // Vertical stripes only vary along x
+        let stripes: Vec<f64> = (0..32 * 32).map(|i| (i % 32) as f64).collect();
+        assert_eq!(unskew_from_field(&stripes, 32, 32), None);
+        assert_eq!(unskew_from_field(&[f64::NAN; 32 * 32], 32, 32), None);
+        assert_eq!(unskew_from_field(&[0.0; 4], 2, 2), None);
+    }
+
+    #[test]
+    fn pixel_to_fractal_honors_skew() {
+        // Stretch x by 2 and shear y into x
+        let viewport =
+            Viewport::from_f64(1.0, 2.0, 4.0, 4.0, 128).with_skew([[2.0, 1.0], [0.0, 1.0]]);
+        let canvas_size = (100, 100);
+
+        // Bottom-right corner is a canvas offset of (2, 2)
+        let (fx, fy) = pixel_to_fractal(100.0, 100.0, &viewport, canvas_size, 128);
+        assert!(close((fx.to_f64(), fy.to_f64()), (1.0 + 6.0, 2.0 + 2.0)));
+
+        let rotated = viewport.clone().with_rotation(0.9);
+        for &(px, py) in &[(0.0, 0.0), (73.0, 12.5), (100.0, 100.0)] {
+            let (fx, fy) = pixel_to_fractal(px, py, &rotated, canvas_size, 128);
+            assert!(close(
+                fractal_to_pixel(&fx, &fy, &rotated, canvas_size),
+                (px, py)
+            ));
+        }
+
+        let step = pixel_delta_step(&viewport, canvas_size).map(BigFloat::to_f64);
+        assert!(close(step.x, (0.08, 0.0)));
+        assert!(close(step.y, (0.04, 0.04)));
+    }
 }
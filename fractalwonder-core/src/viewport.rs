@@ -1,12 +1,20 @@
-use crate::BigFloat;
+use crate::{BigFloat, HDRFloat};
 use serde::{Deserialize, Serialize};
 
+/// Row-major 2x2 linear map applied to scaled canvas offsets before rotation.
+pub type SkewMatrix = [[HDRFloat; 2]; 2];
+
+/// The map that leaves canvas offsets unchanged.
+pub const IDENTITY_SKEW: [[f64; 2]; 2] = [[1.0, 0.0], [0.0, 1.0]];
+
 /// Viewport in fractal space with BigFloat precision
 ///
 /// Defines a rectangular region in fractal coordinates:
 /// - `center`: Center point (x, y) in fractal space
 /// - `width`: Visible width in fractal space
 /// - `height`: Visible height in fractal space
+/// - `rotation`: Angle in radians between the canvas axes and the fractal axes
+/// - `skew`: Optional stretch/skew of the canvas axes, applied before rotation
 ///
 /// At extreme zoom depths (10^2000), width/height are ~10^-2000.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -14,6 +22,15 @@ pub struct Viewport {
     pub center: (BigFloat, BigFloat),
     pub width: BigFloat,
     pub height: BigFloat,
+    /// Rotation of the fractal plane under the canvas, in radians.
+    /// A canvas offset (dx, dy) maps to the fractal offset rotated by this angle.
+    #[serde(default)]
+    pub rotation: f64,
+    /// Non-uniform stretch of the fractal plane, or None for a square grid.
+    /// A scaled canvas offset (x, y) becomes (m00·x + m01·y, m10·x + m11·y)
+    /// before the rotation is applied.
+    #[serde(default)]
+    pub skew: Option<SkewMatrix>,
 }
 
 impl Viewport {
@@ -30,6 +47,8 @@ impl Viewport {
             center: (center_x, center_y),
             width,
             height,
+            rotation: 0.0,
+            skew: None,
         }
     }
 
@@ -51,6 +70,8 @@ impl Viewport {
             ),
             width: BigFloat::with_precision(width, precision_bits),
             height: BigFloat::with_precision(height, precision_bits),
+            rotation: 0.0,
+            skew: None,
         }
     }
 
@@ -72,9 +93,53 @@ impl Viewport {
             ),
             width: BigFloat::from_string(width, precision_bits)?,
             height: BigFloat::from_string(height, precision_bits)?,
+            rotation: 0.0,
+            skew: None,
         })
     }
 
+    /// Return this viewport rotated to `radians`, normalized to (-π, π].
+    pub fn with_rotation(mut self, radians: f64) -> Self {
+        self.rotation = normalize_angle(radians);
+        self
+    }
+
+    /// Whether the viewport is rotated at all.
+    pub fn is_rotated(&self) -> bool {
+        self.rotation != 0.0
+    }
+
+    /// Return this viewport with the 2x2 stretch/skew `matrix`.
+    /// A matrix within rounding of the identity clears the skew.
+    pub fn with_skew(mut self, matrix: [[f64; 2]; 2]) -> Self {
+        let is_identity = matrix
+            .iter()
+            .flatten()
+            .zip(IDENTITY_SKEW.iter().flatten())
+            .all(|(value, identity)| (value - identity).abs() < 1e-12);
+        self.skew = (!is_identity).then(|| matrix.map(|row| row.map(HDRFloat::from_f64)));
+        self
+    }
+
+    /// Whether the viewport carries a stretch/skew.
+    pub fn is_skewed(&self) -> bool {
+        self.skew.is_some()
+    }
+
+    /// The stretch/skew as f64, the identity when there is none.
+    pub fn skew_matrix(&self) -> [[f64; 2]; 2] {
+        self.skew
+            .map(|matrix| matrix.map(|row| row.map(|value| value.to_f64())))
+            .unwrap_or(IDENTITY_SKEW)
+    }
+
+    /// Return this viewport with the rotation and skew of `other`.
+    pub fn with_orientation_of(mut self, other: &Viewport) -> Self {
+        self.rotation = other.rotation;
+        self.skew = other.skew;
+        self
+    }
+
     /// Get the precision bits of this viewport
     pub fn precision_bits(&self) -> usize {
         self.width.precision_bits()
@@ -83,6 +148,7 @@ impl Viewport {
     /// Convert viewport to a different precision.
     ///
     /// All components (center, width, height) are converted to the new precision.
+    /// Rotation and skew are unitless and are carried over unchanged.
     pub fn to_precision(&self, precision_bits: usize) -> Self {
         Self {
             center: (
@@ -91,10 +157,63 @@ impl Viewport {
             ),
             width: self.width.to_precision(precision_bits),
             height: self.height.to_precision(precision_bits),
+            rotation: self.rotation,
+            skew: self.skew,
         }
     }
 }
 
+/// Wrap an angle in radians into (-π, π].
+pub fn normalize_angle(radians: f64) -> f64 {
+    use std::f64::consts::{PI, TAU};
+    let wrapped = radians.rem_euclid(TAU);
+    if wrapped > PI {
+        wrapped - TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Serde adapter that writes a Viewport with compact BigFloat coordinates.
+///
+/// Use as `#[serde(with = "fractalwonder_core::viewport::compact")]`. Reading
+/// accepts both the compact and the legacy coordinate encodings.
+pub mod compact {
+    use super::{SkewMatrix, Viewport};
+    use crate::bigfloat::CompactBigFloat;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct CompactViewport<'a> {
+        center: (CompactBigFloat<'a>, CompactBigFloat<'a>),
+        width: CompactBigFloat<'a>,
+        height: CompactBigFloat<'a>,
+        rotation: f64,
+        skew: &'a Option<SkewMatrix>,
+    }
+
+    pub fn serialize<S>(viewport: &Viewport, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        CompactViewport {
+            center: (viewport.center.0.compact(), viewport.center.1.compact()),
+            width: viewport.width.compact(),
+            height: viewport.height.compact(),
+            rotation: viewport.rotation,
+            skew: &viewport.skew,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Viewport, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Viewport::deserialize(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -347,4 +466,89 @@ mod tests {
         assert_eq!(viewport.width.precision_bits(), 256);
         assert_eq!(viewport.height.precision_bits(), 512);
     }
+
+    // ============================================================================
+    // Rotation tests
+    // ============================================================================
+
+    #[test]
+    fn with_rotation_normalizes_angle() {
+        use std::f64::consts::PI;
+        let viewport = Viewport::from_f64(0.0, 0.0, 4.0, 3.0, 64).with_rotation(3.0 * PI / 2.0);
+        assert!((viewport.rotation + PI / 2.0).abs() < 1e-12);
+        assert!(viewport.is_rotated());
+        assert!((normalize_angle(PI) - PI).abs() < 1e-12);
+        assert!((normalize_angle(-PI) - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotation_defaults_to_zero_when_missing_from_json() {
+        let viewport = Viewport::from_f64(-0.5, 0.25, 4.0, 3.0, 64);
+        let mut value = serde_json::to_value(&viewport).unwrap();
+        value.as_object_mut().unwrap().remove("rotation");
+
+        let parsed: Viewport = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, viewport);
+        assert!(!parsed.is_rotated());
+    }
+
+    #[test]
+    fn to_precision_keeps_rotation() {
+        let viewport = Viewport::from_f64(0.0, 0.0, 4.0, 3.0, 64).with_rotation(0.5);
+        assert_eq!(viewport.to_precision(256).rotation, 0.5);
+    }
+
+    #[test]
+    fn with_skew_clears_identity() {
+        let skewed = Viewport::from_f64(0.0, 0.0, 4.0, 3.0, 64).with_skew([[2.0, 0.5], [0.0, 0.5]]);
+        assert!(skewed.is_skewed());
+        assert_eq!(skewed.skew_matrix(), [[2.0, 0.5], [0.0, 0.5]]);
+        assert_eq!(skewed.to_precision(256).skew, skewed.skew);
+
+        let cleared = skewed.with_skew(IDENTITY_SKEW);
+        assert!(!cleared.is_skewed());
+        assert_eq!(cleared.skew_matrix(), IDENTITY_SKEW);
+    }
+
+    #[test]
+    fn compact_json_roundtrip_is_smaller() {
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            #[serde(with = "compact")]
+            viewport: Viewport,
+        }
+
+        let viewport = Viewport::from_strings(
+            "-0.74364388703715870475",
+            "0.13182590420531197049",
+            "1e-600",
+            "7.5e-601",
+            2200,
+        )
+        .unwrap()
+        .with_rotation(0.3);
+        let compact_json = serde_json::to_string(&Wrapper {
+            viewport: viewport.clone(),
+        })
+        .unwrap();
+        let legacy_json = serde_json::to_string(&viewport).unwrap();
+
+        let parsed: Wrapper = serde_json::from_str(&compact_json).unwrap();
+        assert_eq!(parsed.viewport, viewport);
+        assert!(compact_json.len() * 4 < legacy_json.len());
+
+        // The compact adapter still reads the legacy encoding
+        let legacy = format!("{{\"viewport\":{}}}", legacy_json);
+        let parsed: Wrapper = serde_json::from_str(&legacy).unwrap();
+        assert_eq!(parsed.viewport, viewport);
+    }
+
+    #[test]
+    fn skew_survives_json_roundtrip() {
+        let viewport =
+            Viewport::from_f64(-0.5, 0.1, 4.0, 3.0, 128).with_skew([[1.5, 0.25], [-0.1, 0.7]]);
+        let json = serde_json::to_string(&viewport).unwrap();
+        let parsed: Viewport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, viewport);
+    }
 }
@@ -0,0 +1,295 @@
+//! Keyframed zoom paths for rendering zoom videos.
+//!
+//! A path runs from a start viewport to an end viewport with exponential zoom
+//! interpolation: every frame shrinks the view by the same factor, so the
+//! apparent zoom speed is constant. Paths serialize to JSON with full-precision
+//! coordinates so they can be checked in next to a project.
+
+use crate::viewport::normalize_angle;
+use crate::{BigFloat, Viewport};
+use serde::{Deserialize, Serialize};
+
+/// A zoom path between two viewports.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ZoomPath {
+    /// First frame of the sequence.
+    pub start: Viewport,
+    /// Last frame of the sequence.
+    pub end: Viewport,
+    /// Frames rendered per doubling of the zoom.
+    pub frames_per_doubling: f64,
+    /// Total rotation over the path in degrees, spread evenly across zoom doublings.
+    #[serde(default)]
+    pub rotation_degrees: f64,
+}
+
+/// One frame of a zoom path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ZoomFrame {
+    /// Frame number, starting at 0.
+    pub index: usize,
+    pub viewport: Viewport,
+    /// Extra spin at this frame in degrees, already applied to `viewport.rotation`.
+    pub rotation_degrees: f64,
+}
+
+impl ZoomPath {
+    pub fn new(start: Viewport, end: Viewport, frames_per_doubling: f64) -> Self {
+        Self {
+            start,
+            end,
+            frames_per_doubling,
+            rotation_degrees: 0.0,
+        }
+    }
+
+    /// Parse a path from JSON, validating its parameters.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let path: Self =
+            serde_json::from_str(json).map_err(|e| format!("Invalid zoom path: {}", e))?;
+        path.validate()?;
+        Ok(path)
+    }
+
+    /// Serialize to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.frames_per_doubling.is_finite() && self.frames_per_doubling > 0.0) {
+            return Err(format!(
+                "frames_per_doubling must be positive, got {}",
+                self.frames_per_doubling
+            ));
+        }
+        for (name, viewport) in [("start", &self.start), ("end", &self.end)] {
+            if viewport.width.is_negative()
+                || viewport.height.is_negative()
+                || viewport.width.log2_approx() == f64::NEG_INFINITY
+            {
+                return Err(format!("{} viewport must have a positive size", name));
+            }
+        }
+        if !self.rotation_degrees.is_finite() {
+            return Err("rotation_degrees must be finite".to_string());
+        }
+        Ok(())
+    }
+
+    /// Zoom doublings from start to end (negative when zooming out).
+    pub fn octaves(&self) -> f64 {
+        self.start.width.log2_approx() - self.end.width.log2_approx()
+    }
+
+    /// Number of frames including both endpoints.
+    pub fn frame_count(&self) -> usize {
+        (self.octaves().abs() * self.frames_per_doubling).ceil() as usize + 1
+    }
+
+    /// Whether every frame shares the same center, so one reference orbit serves them all.
+    pub fn has_fixed_center(&self) -> bool {
+        self.start.center == self.end.center
+    }
+
+    /// Frame at `index`, clamped to the last frame.
+    pub fn frame(&self, index: usize) -> ZoomFrame {
+        let last = self.frame_count() - 1;
+        let index = index.min(last);
+        let t = if last == 0 {
+            1.0
+        } else {
+            index as f64 / last as f64
+        };
+        let rotation_degrees = self.rotation_degrees * t;
+
+        // Turn from the start to the end orientation the short way round,
+        // plus the extra spin of the path
+        let turn = normalize_angle(self.end.rotation - self.start.rotation);
+        let rotation = self.start.rotation + turn * t + rotation_degrees.to_radians();
+
+        // Blend the stretch/skew linearly from start to end
+        let (start_skew, end_skew) = (self.start.skew_matrix(), self.end.skew_matrix());
+        let skew: [[f64; 2]; 2] = std::array::from_fn(|row| {
+            std::array::from_fn(|col| {
+                start_skew[row][col] + (end_skew[row][col] - start_skew[row][col]) * t
+            })
+        });
+
+        if index == last {
+            return ZoomFrame {
+                index,
+                viewport: self.end.clone().with_rotation(rotation),
+                rotation_degrees,
+            };
+        }
+
+        let precision = self.start.precision_bits().max(self.end.precision_bits());
+        let start = self.start.to_precision(precision);
+        let end = self.end.to_precision(precision);
+
+        // Exponential zoom: width shrinks by the same factor every frame
+        let width = start.width.mul(&pow2(-self.octaves() * t, precision));
+        let height = width.mul(&start.height).div(&start.width);
+
+        // Move the center in proportion to the width so the end point drifts
+        // smoothly into place instead of racing ahead of the zoom
+        let span = start.width.sub(&end.width);
+        let progress = width.sub(&end.width).div(&span);
+        let lerp = |from: &BigFloat, to: &BigFloat| to.add(&from.sub(to).mul(&progress));
+
+        ZoomFrame {
+            index,
+            viewport: Viewport::with_bigfloat(
+                lerp(&start.center.0, &end.center.0),
+                lerp(&start.center.1, &end.center.1),
+                width,
+                height,
+            )
+            .with_rotation(rotation)
+            .with_skew(skew),
+            rotation_degrees,
+        }
+    }
+
+    /// All frames in order.
+    pub fn frames(&self) -> impl Iterator<Item = ZoomFrame> + '_ {
+        (0..self.frame_count()).map(|index| self.frame(index))
+    }
+}
+
+/// 2^exponent at the given precision. The integer part is applied by repeated
+/// squaring so widths far below f64 range stay exact.
+fn pow2(exponent: f64, precision_bits: usize) -> BigFloat {
+    let whole = exponent.floor();
+    let mut result = BigFloat::with_precision((exponent - whole).exp2(), precision_bits);
+    let mut factor = BigFloat::with_precision(if whole < 0.0 { 0.5 } else { 2.0 }, precision_bits);
+    let mut n = whole.abs() as u64;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result.mul(&factor);
+        }
+        n >>= 1;
+        if n > 0 {
+            factor = factor.mul(&factor);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(end_width: f64, frames_per_doubling: f64) -> ZoomPath {
+        ZoomPath::new(
+            Viewport::from_f64(-0.5, 0.0, 4.0, 3.0, 128),
+            Viewport::from_f64(-0.75, 0.1, end_width, end_width * 0.75, 128),
+            frames_per_doubling,
+        )
+    }
+
+    #[test]
+    fn frame_count_covers_every_doubling() {
+        // 4.0 -> 0.25 is four doublings
+        assert_eq!(path(0.25, 10.0).frame_count(), 41);
+        assert_eq!(path(0.25, 2.5).frame_count(), 11);
+        assert_eq!(path(4.0, 10.0).frame_count(), 1);
+    }
+
+    #[test]
+    fn endpoints_match_keyframes() {
+        let path = path(0.25, 10.0);
+        assert_eq!(path.frame(0).viewport.width.to_f64(), 4.0);
+        assert_eq!(path.frame(0).viewport.center.0.to_f64(), -0.5);
+        assert_eq!(path.frame(40).viewport, path.end);
+        assert_eq!(path.frame(1000).index, 40);
+    }
+
+    #[test]
+    fn zoom_ratio_is_constant_between_frames() {
+        let path = path(0.25, 10.0);
+        let widths: Vec<f64> = path.frames().map(|f| f.viewport.width.to_f64()).collect();
+        let expected = 0.5f64.powf(0.1);
+        for pair in widths.windows(2) {
+            assert!((pair[1] / pair[0] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn center_and_aspect_follow_width() {
+        let path = path(0.25, 10.0);
+        let frame = path.frame(20);
+        let width = frame.viewport.width.to_f64();
+        // Halfway in octaves is width 1.0; center is (1.0 - 0.25) / (4.0 - 0.25) of the way back
+        assert!((width - 1.0).abs() < 1e-12);
+        let progress = 0.75 / 3.75;
+        let expected_x = -0.75 + (-0.5 + 0.75) * progress;
+        assert!((frame.viewport.center.0.to_f64() - expected_x).abs() < 1e-12);
+        assert!((frame.viewport.height.to_f64() - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn deep_zoom_widths_stay_exact_beyond_f64() {
+        let end = Viewport::from_strings("-0.75", "0.125", "4e-2000", "3e-2000", 256).unwrap();
+        let path = ZoomPath::new(Viewport::from_f64(-0.75, 0.125, 4.0, 3.0, 256), end, 1.0);
+        assert!(path.has_fixed_center());
+
+        let frame = path.frame(path.frame_count() - 2);
+        let log2_width = frame.viewport.width.log2_approx();
+        let end_log2 = path.end.width.log2_approx();
+        let step = path.octaves() / (path.frame_count() - 1) as f64;
+        assert!((log2_width - (end_log2 + step)).abs() < 1e-3);
+        assert_eq!(frame.viewport.center, path.start.center);
+    }
+
+    #[test]
+    fn rotation_spreads_linearly() {
+        let mut path = path(0.25, 1.0);
+        path.rotation_degrees = 90.0;
+        let rotations: Vec<f64> = path.frames().map(|f| f.rotation_degrees).collect();
+        assert_eq!(rotations, vec![0.0, 22.5, 45.0, 67.5, 90.0]);
+
+        let last = path.frame(4).viewport;
+        assert!((last.rotation - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn skew_blends_from_start_to_end() {
+        let mut path = path(0.25, 1.0);
+        path.end = path.end.clone().with_skew([[2.0, 0.0], [0.0, 0.5]]);
+
+        assert!(!path.frame(0).viewport.is_skewed());
+        assert_eq!(
+            path.frame(2).viewport.skew_matrix(),
+            [[1.5, 0.0], [0.0, 0.75]]
+        );
+        assert_eq!(path.frame(4).viewport.skew, path.end.skew);
+    }
+
+    #[test]
+    fn json_round_trip_preserves_precision() {
+        let end = Viewport::from_strings(
+            "-0.7436438870371587522363059978",
+            "0.1318259042053988011872367",
+            "1e-25",
+            "7.5e-26",
+            256,
+        )
+        .unwrap();
+        let mut path = ZoomPath::new(Viewport::from_f64(-0.5, 0.0, 4.0, 3.0, 256), end, 30.0);
+        path.rotation_degrees = 45.0;
+
+        let json = path.to_json().unwrap();
+        assert_eq!(ZoomPath::from_json(&json).unwrap(), path);
+    }
+
+    #[test]
+    fn from_json_rejects_invalid_parameters() {
+        let mut bad = path(0.25, 10.0);
+        bad.frames_per_doubling = 0.0;
+        let json = serde_json::to_string(&bad).unwrap();
+        assert!(ZoomPath::from_json(&json).is_err());
+        assert!(ZoomPath::from_json("{}").is_err());
+    }
+}
@@ -593,3 +593,40 @@ fn arithmetic_magnitude_progression() {
         assert!(result > a);
     }
 }
+
+// ============================================================================
+// Complex Square Tests
+// ============================================================================
+
+#[test]
+fn complex_square_f64_path_is_exact() {
+    // (3 + 4i)² = -7 + 24i, |3 + 4i|² = 25
+    let x = BigFloat::with_precision(3.0, 64);
+    let y = BigFloat::with_precision(4.0, 64);
+    let sq = BigFloat::complex_square(&x, &y);
+
+    assert_eq!(sq.re, BigFloat::with_precision(-7.0, 64));
+    assert_eq!(sq.im, BigFloat::with_precision(24.0, 64));
+    assert_eq!(sq.norm_sq, BigFloat::with_precision(25.0, 64));
+}
+
+#[test]
+fn complex_square_matches_four_multiplications() {
+    let x = BigFloat::from_string("-1.7499999999999999999999999999999999999913", 512).unwrap();
+    let y = BigFloat::from_string("0.0000000000000000000000000000000000000731", 512).unwrap();
+    let sq = BigFloat::complex_square(&x, &y);
+
+    let two = BigFloat::with_precision(2.0, 512);
+    let re = x.mul(&x).sub(&y.mul(&y));
+    let im = two.mul(&x).mul(&y);
+    let norm_sq = x.mul(&x).add(&y.mul(&y));
+
+    assert_eq!(sq.re.precision_bits(), 512);
+    assert_eq!(sq.re, re);
+    assert_eq!(sq.norm_sq, norm_sq);
+    // The fused imaginary part is only accurate relative to |z|²
+    let err = sq.im.sub(&im).abs();
+    let bound = norm_sq.mul(&BigFloat::from_string("1e-150", 512).unwrap());
+    assert!(err < bound);
+    assert!((sq.im.to_f64() / im.to_f64() - 1.0).abs() < 1e-12);
+}
@@ -138,3 +138,86 @@ fn serialize_deserialize_after_arithmetic() {
     let result = deserialized.mul(&c);
     assert_eq!(result.precision_bits(), 7000);
 }
+
+// ============================================================================
+// Compact encoding tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn compact_roundtrip_f64_path() {
+    for v in [0.0, -0.0, 1.5, -3.25e-200, f64::MAX] {
+        let original = BigFloat::with_precision(v, 64);
+        let decoded = BigFloat::from_compact_bytes(&original.to_compact_bytes()).unwrap();
+
+        assert_eq!(decoded, original);
+        assert_eq!(decoded.precision_bits(), 64);
+        assert_eq!(decoded.to_f64().to_bits(), v.to_bits());
+    }
+}
+
+#[test]
+#[ignore]
+fn compact_roundtrip_extreme_values() {
+    for s in [
+        "1e-5000",
+        "-1e5000",
+        "-0.743643887037158704752191506114774",
+        "0",
+    ] {
+        let original = BigFloat::from_string(s, 7000).unwrap();
+        let decoded = BigFloat::from_compact_string(&original.to_compact_string()).unwrap();
+
+        assert_eq!(decoded, original);
+        assert_eq!(decoded.precision_bits(), 7000);
+    }
+}
+
+#[test]
+#[ignore]
+fn compact_decoded_value_keeps_working_precision() {
+    // Arithmetic on a decoded value must still carry the full precision
+    let original = BigFloat::with_precision(0.5, 7000);
+    let decoded = BigFloat::from_compact_bytes(&original.to_compact_bytes()).unwrap();
+    let tiny = BigFloat::from_string("1e-2000", 7000).unwrap();
+
+    assert_eq!(decoded.add(&tiny), original.add(&tiny));
+}
+
+#[test]
+#[ignore]
+fn compact_json_is_smaller_than_legacy() {
+    let bf = BigFloat::from_string("1e-2000", 7000).unwrap();
+    let legacy = serde_json::to_string(&bf).unwrap();
+    let compact = serde_json::to_string(&bf.compact()).unwrap();
+
+    assert!(
+        compact.len() * 4 < legacy.len(),
+        "compact {} vs legacy {} bytes",
+        compact.len(),
+        legacy.len()
+    );
+}
+
+#[test]
+#[ignore]
+fn json_deserialize_accepts_both_encodings() {
+    let original = BigFloat::from_string("-2.5e-700", 4096).unwrap();
+    let legacy = serde_json::to_string(&original).unwrap();
+    let compact = serde_json::to_string(&original.compact()).unwrap();
+
+    let from_legacy: BigFloat = serde_json::from_str(&legacy).unwrap();
+    let from_compact: BigFloat = serde_json::from_str(&compact).unwrap();
+
+    assert_eq!(from_legacy, original);
+    assert_eq!(from_compact, original);
+}
+
+#[test]
+#[ignore]
+fn compact_rejects_corrupt_input() {
+    assert!(BigFloat::from_compact_string("not base64!").is_err());
+    assert!(BigFloat::from_compact_bytes(&[]).is_err());
+    // 64-bit precision with the f64 tag but only 4 payload bytes
+    assert!(BigFloat::from_compact_bytes(&[64, 0, 1, 2, 3, 4]).is_err());
+}
@@ -0,0 +1,308 @@
+use fractalwonder_core::BigFloat;
+
+/// π to 100 decimal places
+const PI_100: &str = "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679";
+
+/// Assert `actual` matches `expected` to within `bits` bits of relative error
+fn assert_close(actual: &BigFloat, expected: &BigFloat, bits: f64) {
+    let error = actual.sub(expected).abs().log2_approx();
+    let scale = expected.log2_approx();
+    assert!(
+        error <= scale - bits,
+        "relative error 2^{} exceeds 2^-{}: got {}, expected {}",
+        error - scale,
+        bits,
+        actual.to_f64(),
+        expected.to_f64()
+    );
+}
+
+// ============================================================================
+// Pi Tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn pi_f64_path() {
+    let pi = BigFloat::pi(64);
+    assert_eq!(pi.precision_bits(), 64);
+    assert_eq!(pi.to_f64(), std::f64::consts::PI);
+}
+
+#[test]
+#[ignore]
+fn pi_matches_known_digits() {
+    let pi = BigFloat::pi(300);
+    let expected = BigFloat::from_string(PI_100, 300).unwrap();
+
+    assert_eq!(pi.precision_bits(), 300);
+    // 100 decimal places is ~332 bits, so the reference limits this check
+    assert_close(&pi, &expected, 295.0);
+}
+
+// ============================================================================
+// Exponential and Logarithm Tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn exp_f64_path() {
+    let x = BigFloat::with_precision(1.5, 64);
+    let result = x.exp();
+
+    assert_eq!(result.precision_bits(), 64);
+    assert_eq!(result.to_f64(), 1.5f64.exp());
+}
+
+#[test]
+#[ignore]
+fn exp_arbitrary_matches_f64() {
+    for v in [-20.0, -1.0, 0.0, 0.5, 1.0, 10.0] {
+        let result = BigFloat::with_precision(v, 256).exp();
+        assert_eq!(result.precision_bits(), 256);
+        assert_close(&result, &BigFloat::with_precision(v.exp(), 256), 50.0);
+    }
+}
+
+#[test]
+#[ignore]
+fn ln_of_one_is_zero() {
+    let result = BigFloat::one(256).ln().unwrap();
+    assert_eq!(result, BigFloat::zero(256));
+}
+
+#[test]
+#[ignore]
+fn ln_arbitrary_matches_f64() {
+    for v in [0.001, 0.5, 2.0, 1000.0] {
+        let result = BigFloat::with_precision(v, 256).ln().unwrap();
+        assert_close(&result, &BigFloat::with_precision(v.ln(), 256), 50.0);
+    }
+}
+
+#[test]
+#[ignore]
+fn ln_rejects_non_positive() {
+    assert!(BigFloat::zero(64).ln().is_none());
+    assert!(BigFloat::with_precision(-2.0, 64).ln().is_none());
+    assert!(BigFloat::zero(256).ln().is_none());
+    assert!(BigFloat::with_precision(-2.0, 256).ln().is_none());
+}
+
+#[test]
+#[ignore]
+fn ln_exp_roundtrip_high_precision() {
+    let x = BigFloat::from_string("0.7182818284590452353602874713526624977572", 512).unwrap();
+    let result = x.exp().ln().unwrap();
+    assert_close(&result, &x, 500.0);
+}
+
+#[test]
+#[ignore]
+fn ln_extreme_tiny_value() {
+    // ln(1e-500) = -500·ln(10), far below the f64 range
+    let x = BigFloat::from_string("1e-500", 7000).unwrap();
+    let result = x.ln().unwrap();
+    let expected = BigFloat::with_precision(-500.0 * 10f64.ln(), 7000);
+
+    assert_eq!(result.precision_bits(), 7000);
+    assert_close(&result, &expected, 50.0);
+}
+
+#[test]
+#[ignore]
+fn exp_ln_roundtrip_extreme_value() {
+    let x = BigFloat::from_string("3.5e-2000", 7000).unwrap();
+    let result = x.ln().unwrap().exp();
+    assert_close(&result, &x, 6900.0);
+}
+
+// ============================================================================
+// Trigonometric Tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn sin_cos_f64_path() {
+    let x = BigFloat::with_precision(0.75, 64);
+    let (sin, cos) = x.sin_cos();
+
+    assert_eq!(sin.to_f64(), 0.75f64.sin());
+    assert_eq!(cos.to_f64(), 0.75f64.cos());
+}
+
+#[test]
+#[ignore]
+fn sin_cos_of_zero() {
+    let (sin, cos) = BigFloat::zero(256).sin_cos();
+    assert_eq!(sin, BigFloat::zero(256));
+    assert_eq!(cos, BigFloat::one(256));
+}
+
+#[test]
+#[ignore]
+fn sin_cos_arbitrary_matches_f64() {
+    for v in [-7.0, -2.5, 0.1, 1.0, 3.0, 100.0] {
+        let x = BigFloat::with_precision(v, 256);
+        assert_close(&x.sin(), &BigFloat::with_precision(v.sin(), 256), 45.0);
+        assert_close(&x.cos(), &BigFloat::with_precision(v.cos(), 256), 45.0);
+    }
+}
+
+#[test]
+#[ignore]
+fn sin_squared_plus_cos_squared_is_one() {
+    let x = BigFloat::from_string("123.456789012345678901234567890", 512).unwrap();
+    let (sin, cos) = x.sin_cos();
+    let sum = sin.mul(&sin).add(&cos.mul(&cos));
+    assert_close(&sum, &BigFloat::one(512), 500.0);
+}
+
+#[test]
+#[ignore]
+fn sin_of_pi_is_negligible() {
+    let pi = BigFloat::pi(512);
+    let sin = pi.sin();
+    assert!(sin.abs().log2_approx() < -500.0);
+
+    let cos = pi.cos();
+    assert_close(&cos, &BigFloat::with_precision(-1.0, 512), 500.0);
+}
+
+#[test]
+#[ignore]
+fn sin_of_tiny_angle_matches_series() {
+    // sin x = x − x³/6 + O(x⁵), and x⁵ is below 7000 bits relative to x
+    let x = BigFloat::from_string("1e-1000", 7000).unwrap();
+    let cubed = x.mul(&x).mul(&x);
+    let expected = x.sub(&cubed.div(&BigFloat::with_precision(6.0, 7000)));
+    assert_close(&x.sin(), &expected, 6900.0);
+}
+
+// ============================================================================
+// Arctangent Tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn atan2_f64_path() {
+    let y = BigFloat::with_precision(1.0, 64);
+    let x = BigFloat::with_precision(-2.0, 64);
+    assert_eq!(y.atan2(&x).to_f64(), 1.0f64.atan2(-2.0));
+}
+
+#[test]
+#[ignore]
+fn atan2_all_quadrants() {
+    for (y, x) in [
+        (1.0, 2.0),
+        (3.0, -1.0),
+        (-0.5, -4.0),
+        (-2.0, 0.25),
+        (1.0, 0.0),
+        (-1.0, 0.0),
+        (0.0, -1.0),
+    ] {
+        let result = BigFloat::with_precision(y, 256).atan2(&BigFloat::with_precision(x, 256));
+        assert_close(&result, &BigFloat::with_precision(y.atan2(x), 256), 50.0);
+    }
+}
+
+#[test]
+#[ignore]
+fn atan2_of_origin_is_zero() {
+    let zero = BigFloat::zero(256);
+    assert_eq!(zero.atan2(&zero), BigFloat::zero(256));
+}
+
+#[test]
+#[ignore]
+fn atan2_of_diagonal_is_quarter_pi() {
+    let one = BigFloat::one(512);
+    let expected = BigFloat::pi(512).div(&BigFloat::with_precision(4.0, 512));
+    assert_close(&one.atan2(&one), &expected, 500.0);
+}
+
+#[test]
+#[ignore]
+fn atan2_inverts_sin_cos() {
+    let angle = BigFloat::from_string("-2.718281828459045235360287471352662497757", 512).unwrap();
+    let (sin, cos) = angle.sin_cos();
+    assert_close(&sin.atan2(&cos), &angle, 500.0);
+}
+
+// ============================================================================
+// Power Tests
+// ============================================================================
+
+#[test]
+#[ignore]
+fn powi_exact_integers() {
+    let x = BigFloat::with_precision(3.0, 256);
+    assert_eq!(x.powi(5).unwrap(), BigFloat::with_precision(243.0, 256));
+    assert_eq!(x.powi(0).unwrap(), BigFloat::one(256));
+
+    let neg = BigFloat::with_precision(-2.0, 256);
+    assert_eq!(neg.powi(3).unwrap(), BigFloat::with_precision(-8.0, 256));
+}
+
+#[test]
+#[ignore]
+fn powi_negative_exponent() {
+    let x = BigFloat::with_precision(2.0, 256);
+    assert_eq!(x.powi(-3).unwrap(), BigFloat::with_precision(0.125, 256));
+}
+
+#[test]
+#[ignore]
+fn powi_extreme_value() {
+    let x = BigFloat::from_string("1e-1000", 7000).unwrap();
+    let result = x.powi(2).unwrap();
+    let expected = BigFloat::from_string("1e-2000", 7000).unwrap();
+    assert_close(&result, &expected, 6900.0);
+}
+
+#[test]
+#[ignore]
+fn powi_zero_negative_exponent_is_none() {
+    assert!(BigFloat::zero(64).powi(-1).is_none());
+    assert!(BigFloat::zero(256).powi(-1).is_none());
+}
+
+#[test]
+#[ignore]
+fn powf_fractional_exponent() {
+    let x = BigFloat::with_precision(2.0, 512);
+    let half = BigFloat::with_precision(0.5, 512);
+    assert_close(&x.powf(&half).unwrap(), &x.sqrt(), 500.0);
+}
+
+#[test]
+#[ignore]
+fn powf_integral_exponent_allows_negative_base() {
+    let x = BigFloat::with_precision(-1.5, 256);
+    let two = BigFloat::with_precision(2.0, 256);
+    assert_eq!(x.powf(&two).unwrap(), BigFloat::with_precision(2.25, 256));
+}
+
+#[test]
+#[ignore]
+fn powf_domain_errors_are_none() {
+    let half = BigFloat::with_precision(0.5, 256);
+    assert!(BigFloat::with_precision(-2.0, 256).powf(&half).is_none());
+    assert!(BigFloat::zero(256)
+        .powf(&BigFloat::with_precision(-0.5, 256))
+        .is_none());
+    assert_eq!(
+        BigFloat::zero(256).powf(&half).unwrap(),
+        BigFloat::zero(256)
+    );
+}
+
+#[test]
+#[ignore]
+fn powf_f64_path() {
+    let x = BigFloat::with_precision(2.5, 64);
+    let y = BigFloat::with_precision(1.3, 64);
+    assert_eq!(x.powf(&y).unwrap().to_f64(), 2.5f64.powf(1.3));
+}
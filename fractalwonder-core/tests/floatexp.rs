@@ -0,0 +1,159 @@
+use fractalwonder_core::{BigFloat, ComplexDelta, FloatExp, FloatExpComplex, HDRComplex, HDRFloat};
+
+#[test]
+fn from_f64_zero_gives_zero() {
+    let f = FloatExp::from_f64(0.0);
+    assert!(f.is_zero());
+    assert_eq!(f.exp, 0);
+}
+
+#[test]
+fn from_f64_one_normalized() {
+    // 1.0 = 0.5 × 2^1
+    let f = FloatExp::from_f64(1.0);
+    assert_eq!(f.mantissa, 0.5);
+    assert_eq!(f.exp, 1);
+}
+
+#[test]
+fn from_f64_roundtrip_is_exact() {
+    let values = [
+        1.0,
+        -1.0,
+        0.75,
+        3.0,
+        1e10,
+        1e-10,
+        -std::f64::consts::PI,
+        5e-324,
+    ];
+    for v in values {
+        let f = FloatExp::from_f64(v);
+        assert!((0.5..1.0).contains(&f.mantissa.abs()), "{v} not normalized");
+        assert_eq!(f.to_f64(), v);
+    }
+}
+
+#[test]
+fn to_f64_saturates_outside_range() {
+    let huge = FloatExp {
+        mantissa: -0.5,
+        exp: 5000,
+    };
+    let tiny = FloatExp {
+        mantissa: 0.5,
+        exp: -5000,
+    };
+    assert_eq!(huge.to_f64(), f64::NEG_INFINITY);
+    assert_eq!(tiny.to_f64(), 0.0);
+}
+
+#[test]
+fn arithmetic_matches_f64() {
+    let pairs = [(1.5, 2.25), (-3.0, 0.125), (1e-5, -7e3), (0.1, 0.1)];
+    for (a, b) in pairs {
+        let (fa, fb) = (FloatExp::from_f64(a), FloatExp::from_f64(b));
+        assert_eq!(fa.mul(&fb).to_f64(), a * b);
+        assert_eq!(fa.add(&fb).to_f64(), a + b);
+        assert_eq!(fa.sub(&fb).to_f64(), a - b);
+        assert_eq!(fa.square().to_f64(), a * a);
+        assert_eq!(fa.mul_f64(b).to_f64(), a * b);
+    }
+}
+
+#[test]
+fn add_cancellation_gives_zero() {
+    let a = FloatExp::from_f64(0.3);
+    assert!(a.sub(&a).is_zero());
+}
+
+#[test]
+fn add_ignores_negligible_operand() {
+    let big = FloatExp::from_f64(1.0);
+    let small = FloatExp {
+        mantissa: 0.5,
+        exp: -200,
+    };
+    assert_eq!(big.add(&small), big);
+    assert_eq!(small.add(&big), big);
+}
+
+#[test]
+fn mul_pow2_shifts_exponent_exactly() {
+    let f = FloatExp::from_f64(0.75).mul_pow2(-3000);
+    assert_eq!(f.mantissa, 0.75);
+    assert_eq!(f.mul_pow2(3000).to_f64(), 0.75);
+}
+
+#[test]
+fn extreme_exponents_survive_multiplication() {
+    // (2^-2000)² = 2^-4000, far beyond both f64 and f64 subnormals
+    let a = FloatExp {
+        mantissa: 0.5,
+        exp: -1999,
+    };
+    let sq = a.square();
+    assert_eq!(sq.mantissa, 0.5);
+    assert_eq!(sq.exp, -3999);
+    assert!((sq.log2() + 4000.0).abs() < 1e-12);
+}
+
+#[test]
+fn from_bigfloat_keeps_exact_exponent() {
+    let bf = BigFloat::from_string("3e-1000", 4000).unwrap();
+    let f = FloatExp::from_bigfloat(&bf);
+    let expected_log2 = 3f64.log2() - 1000.0 * 10f64.log2();
+    assert!((f.log2() - expected_log2).abs() < 1e-9);
+}
+
+#[test]
+fn hdr_conversion_roundtrip() {
+    let h = HDRFloat::from_f64(-1.234_567_890_123);
+    let f = FloatExp::from_hdr(&h);
+    assert_eq!(f.to_hdr(), h);
+    assert_eq!(f.to_f64(), h.to_f64());
+
+    let deep = FloatExp {
+        mantissa: 0.625,
+        exp: -40_000,
+    };
+    assert_eq!(FloatExp::from_hdr(&deep.to_hdr()), deep);
+}
+
+#[test]
+fn complex_ops_match_hdr_complex() {
+    let (a, b) = ((0.3, -1.2), (2.5, 0.7));
+    let fa = FloatExpComplex::from_f64_pair(a.0, a.1);
+    let fb = FloatExpComplex::from_f64_pair(b.0, b.1);
+    let ha = HDRComplex::from_f64_pair(a.0, a.1);
+    let hb = HDRComplex::from_f64_pair(b.0, b.1);
+
+    let close = |x: (f64, f64), y: (f64, f64)| {
+        assert!(
+            (x.0 - y.0).abs() < 1e-12 && (x.1 - y.1).abs() < 1e-12,
+            "{x:?} vs {y:?}"
+        );
+    };
+    close(
+        fa.mul(&fb).to_f64_pair(),
+        ComplexDelta::mul(&ha, &hb).to_f64_pair(),
+    );
+    close(
+        fa.square().to_f64_pair(),
+        ComplexDelta::square(&ha).to_f64_pair(),
+    );
+    close(fa.scale(3.0).to_f64_pair(), ha.scale(3.0).to_f64_pair());
+    assert!((fa.norm_sq() - ComplexDelta::norm_sq(&ha)).abs() < 1e-12);
+}
+
+#[test]
+fn complex_norm_log2_beyond_f64_range() {
+    let re = FloatExp {
+        mantissa: 0.5,
+        exp: -2000,
+    };
+    let z = FloatExpComplex { re, im: re };
+    // |z|² = 2·2^-4002 = 2^-4001, so log2|z| = -2000.5
+    assert!((z.norm_log2() + 2000.5).abs() < 1e-12);
+    assert_eq!(z.norm_sq(), 0.0);
+}
@@ -485,3 +485,19 @@ fn multiplicative_vs_incremental_coordinate_computation() {
         max_err_pos
     );
 }
+
+#[test]
+fn log2_is_finite_beyond_f64_range() {
+    // 2^-5000 underflows f64 but is representable in HDRFloat
+    let tiny = HDRFloat {
+        head: 0.5,
+        tail: 0.0,
+        exp: -4999,
+    };
+    assert!((tiny.log2() - (-5000.0)).abs() < 1e-9);
+
+    let value = HDRFloat::from_f64(-12.0);
+    assert!((value.log2() - 12.0f64.log2()).abs() < 1e-9);
+
+    assert_eq!(HDRFloat::ZERO.log2(), f64::NEG_INFINITY);
+}
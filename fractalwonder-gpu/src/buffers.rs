@@ -1,6 +1,12 @@
 //! GPU buffer management for compute shader.
 
 use bytemuck::{Pod, Zeroable};
+use fractalwonder_core::DeltaStep;
+
+/// f32s per pixel in the final_values buffer:
+/// z_re, z_im, surface_normal_re, surface_normal_im, distance_log2,
+/// trap_min_sq, stripe_sum, stripe_samples, trap_iteration (u32 bits).
+pub const FINAL_VALUES_STRIDE: usize = 9;
 
 /// Uniform data for progressive GPU rendering with row-sets.
 #[repr(C)]
@@ -34,20 +40,33 @@ pub struct ProgressiveGpuUniforms {
     pub dc_origin_im_exp: i32,
     pub _pad3: u32,
 
-    // dc_step as HDRFloat
-    pub dc_step_re_head: f32,
-    pub dc_step_re_tail: f32,
-    pub dc_step_re_exp: i32,
+    // dc step per column as HDRFloat complex
+    pub dc_step_x_re_head: f32,
+    pub dc_step_x_re_tail: f32,
+    pub dc_step_x_re_exp: i32,
     pub _pad4: u32,
-    pub dc_step_im_head: f32,
-    pub dc_step_im_tail: f32,
-    pub dc_step_im_exp: i32,
+    pub dc_step_x_im_head: f32,
+    pub dc_step_x_im_tail: f32,
+    pub dc_step_x_im_exp: i32,
     pub _pad5: u32,
 
+    // dc step per row as HDRFloat complex
+    pub dc_step_y_re_head: f32,
+    pub dc_step_y_re_tail: f32,
+    pub dc_step_y_re_exp: i32,
+    pub _pad4b: u32,
+    pub dc_step_y_im_head: f32,
+    pub dc_step_y_im_tail: f32,
+    pub dc_step_y_im_exp: i32,
+    pub _pad5b: u32,
+
     // Reference orbit info
     pub reference_escaped: u32,
     pub orbit_len: u32,
-    pub _pad6: [u32; 2],
+    /// Index of the closing point of a periodic orbit, 0 if not periodic
+    pub orbit_period: u32,
+    /// Record orbit trap and stripe statistics (needs BLA off)
+    pub collect_orbit_stats: u32,
 
     // BLA configuration
     pub bla_enabled: u32,
@@ -69,9 +88,11 @@ impl ProgressiveGpuUniforms {
         max_iterations: u32,
         tau_sq: f32,
         dc_origin: ((f32, f32, i32), (f32, f32, i32)),
-        dc_step: ((f32, f32, i32), (f32, f32, i32)),
+        dc_step: DeltaStep<(f32, f32, i32)>,
         reference_escaped: bool,
         orbit_len: u32,
+        orbit_period: u32,
+        collect_orbit_stats: bool,
         bla_enabled: bool,
         bla_num_levels: u32,
         bla_level_offsets: &[usize],
@@ -97,17 +118,26 @@ impl ProgressiveGpuUniforms {
             dc_origin_im_tail: dc_origin.1 .1,
             dc_origin_im_exp: dc_origin.1 .2,
             _pad3: 0,
-            dc_step_re_head: dc_step.0 .0,
-            dc_step_re_tail: dc_step.0 .1,
-            dc_step_re_exp: dc_step.0 .2,
+            dc_step_x_re_head: dc_step.x.0 .0,
+            dc_step_x_re_tail: dc_step.x.0 .1,
+            dc_step_x_re_exp: dc_step.x.0 .2,
             _pad4: 0,
-            dc_step_im_head: dc_step.1 .0,
-            dc_step_im_tail: dc_step.1 .1,
-            dc_step_im_exp: dc_step.1 .2,
+            dc_step_x_im_head: dc_step.x.1 .0,
+            dc_step_x_im_tail: dc_step.x.1 .1,
+            dc_step_x_im_exp: dc_step.x.1 .2,
             _pad5: 0,
+            dc_step_y_re_head: dc_step.y.0 .0,
+            dc_step_y_re_tail: dc_step.y.0 .1,
+            dc_step_y_re_exp: dc_step.y.0 .2,
+            _pad4b: 0,
+            dc_step_y_im_head: dc_step.y.1 .0,
+            dc_step_y_im_tail: dc_step.y.1 .1,
+            dc_step_y_im_exp: dc_step.y.1 .2,
+            _pad5b: 0,
             reference_escaped: if reference_escaped { 1 } else { 0 },
             orbit_len,
-            _pad6: [0, 0],
+            orbit_period,
+            collect_orbit_stats: if collect_orbit_stats { 1 } else { 0 },
             bla_enabled: if bla_enabled { 1 } else { 0 },
             bla_num_levels,
             _pad7: [0, 0],
@@ -143,7 +173,7 @@ pub struct ProgressiveGpuBuffers {
     // Results (read back on row-set completion)
     pub results: wgpu::Buffer,
     pub z_norm_sq: wgpu::Buffer,
-    // final_values: combined z_re, z_im, der_re, der_im (4 f32s per pixel)
+    // final_values: FINAL_VALUES_STRIDE f32s per pixel (see shader binding 9 for layout)
     pub final_values: wgpu::Buffer,
 
     // BLA acceleration data (read-only)
@@ -251,10 +281,10 @@ impl ProgressiveGpuBuffers {
             mapped_at_creation: false,
         });
 
-        // final_values: combined z_re, z_im, der_re, der_im (4 f32s per pixel)
+        // final_values: final z, surface normal, distance estimate and orbit statistics
         let final_values = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("progressive_final_values"),
-            size: (pixel_count * 4 * std::mem::size_of::<f32>()) as u64,
+            size: (pixel_count * FINAL_VALUES_STRIDE * std::mem::size_of::<f32>()) as u64,
             usage: wgpu::BufferUsages::STORAGE
                 | wgpu::BufferUsages::COPY_SRC
                 | wgpu::BufferUsages::COPY_DST,
@@ -283,10 +313,10 @@ impl ProgressiveGpuBuffers {
             mapped_at_creation: false,
         });
 
-        // staging_final_values: 4 f32s per pixel (z_re, z_im, der_re, der_im)
+        // staging_final_values: FINAL_VALUES_STRIDE f32s per pixel
         let staging_final_values = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("progressive_staging_final_values"),
-            size: (pixel_count * 4 * std::mem::size_of::<f32>()) as u64,
+            size: (pixel_count * FINAL_VALUES_STRIDE * std::mem::size_of::<f32>()) as u64,
             usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
@@ -17,7 +17,7 @@ impl ProgressiveGpuPipeline {
 
         // Bind group layout uses 10 storage buffers to fit within WebGPU browser limits.
         // Buffer consolidation: z_re+z_im → z_state, drho_re+drho_im → drho_state,
-        // final_z_re+final_z_im+final_der_re+final_der_im → final_values,
+        // final z, surface normal, distance estimate and orbit statistics → final_values,
         // escaped+glitch → flags_buf (bit 0 = escaped, bit 1 = glitch)
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("progressive_layout"),
@@ -121,7 +121,7 @@ impl ProgressiveGpuPipeline {
                     },
                     count: None,
                 },
-                // binding 9: final_values (combined z_re, z_im, der_re, der_im, 4 f32s per pixel)
+                // binding 9: final_values (FINAL_VALUES_STRIDE f32s per pixel)
                 wgpu::BindGroupLayoutEntry {
                     binding: 9,
                     visibility: wgpu::ShaderStages::COMPUTE,
@@ -1,10 +1,40 @@
 //! Progressive GPU renderer for row-set based rendering.
 
-use crate::buffers::{ProgressiveGpuBuffers, ProgressiveGpuUniforms};
+use crate::buffers::{ProgressiveGpuBuffers, ProgressiveGpuUniforms, FINAL_VALUES_STRIDE};
 use crate::device::GpuContext;
 use crate::error::GpuError;
 use crate::progressive_pipeline::ProgressiveGpuPipeline;
-use fractalwonder_core::{ComputeData, MandelbrotData};
+use fractalwonder_compute::{CompressedOrbit, OrbitPoint};
+use fractalwonder_core::{ComputeData, DeltaStep, HDRFloat, MandelbrotData};
+
+/// Orbit points regenerated per GPU buffer write.
+const ORBIT_UPLOAD_BLOCK: usize = 1 << 16;
+
+/// Pack an orbit point as 12 f32s:
+/// [Z_re_head, Z_re_tail, Z_im_head, Z_im_tail, Z_re_exp, Z_im_exp,
+///  Der_re_head, Der_re_tail, Der_im_head, Der_im_tail, Der_re_exp, Der_im_exp]
+/// using the HDRFloat representation value = (head + tail) × 2^exp, head in [0.5, 1.0).
+fn gpu_orbit_point(point: &OrbitPoint) -> [f32; 12] {
+    let z_re = HDRFloat::from_f64(point.z.0);
+    let z_im = HDRFloat::from_f64(point.z.1);
+    let der_re = HDRFloat::from_f64(point.der.0);
+    let der_im = HDRFloat::from_f64(point.der.1);
+    [
+        z_re.head,
+        z_re.tail,
+        z_im.head,
+        z_im.tail,
+        // Exponents are packed as f32 bits for the GPU (bitcast back to i32)
+        f32::from_bits(z_re.exp as u32),
+        f32::from_bits(z_im.exp as u32),
+        der_re.head,
+        der_re.tail,
+        der_im.head,
+        der_im.tail,
+        f32::from_bits(der_re.exp as u32),
+        f32::from_bits(der_im.exp as u32),
+    ]
+}
 
 /// Result of a progressive GPU row-set render.
 pub struct ProgressiveRowSetResult {
@@ -47,14 +77,16 @@ impl ProgressiveGpuRenderer {
     }
 
     /// Render a single row-set with iteration chunking.
+    ///
+    /// With `collect_orbit_stats` the orbit statistics are recorded and the
+    /// BLA table is not used, matching the CPU renderer.
     #[allow(clippy::too_many_arguments)]
     pub async fn render_row_set(
         &mut self,
-        orbit: &[(f64, f64)],
-        derivative_orbit: &[(f64, f64)],
+        orbit: &CompressedOrbit,
         orbit_id: u32,
         dc_origin: ((f32, f32, i32), (f32, f32, i32)),
-        dc_step: ((f32, f32, i32), (f32, f32, i32)),
+        dc_step: DeltaStep<(f32, f32, i32)>,
         image_width: u32,
         image_height: u32,
         row_set_index: u32,
@@ -62,7 +94,7 @@ impl ProgressiveGpuRenderer {
         max_iterations: u32,
         iterations_per_dispatch: u32,
         tau_sq: f32,
-        reference_escaped: bool,
+        collect_orbit_stats: bool,
         bla_table: Option<&fractalwonder_compute::BlaTable>,
     ) -> Result<ProgressiveRowSetResult, GpuError> {
         let start = Self::now();
@@ -98,48 +130,22 @@ impl ProgressiveGpuRenderer {
 
         let buffers = self.buffers.as_ref().unwrap();
 
-        // Upload orbit if changed
-        // Store as 12 f32s per point:
-        // [Z_re_head, Z_re_tail, Z_im_head, Z_im_tail, Z_re_exp, Z_im_exp,
-        //  Der_re_head, Der_re_tail, Der_im_head, Der_im_tail, Der_re_exp, Der_im_exp]
-        // Uses HDRFloat representation: value = (head + tail) × 2^exp, head in [0.5, 1.0)
+        // Upload orbit if changed, regenerating it from the compressed form in
+        // blocks so the whole orbit is never expanded on the CPU side.
         if self.cached_orbit_id != Some(orbit_id) {
-            assert_eq!(
-                orbit.len(),
-                derivative_orbit.len(),
-                "Orbit and derivative must have same length"
-            );
-            let orbit_data: Vec<[f32; 12]> = orbit
-                .iter()
-                .zip(derivative_orbit.iter())
-                .map(|(&(z_re, z_im), &(der_re, der_im))| {
-                    // Convert to HDRFloat format matching CPU implementation
-                    let z_re_hdr = fractalwonder_core::HDRFloat::from_f64(z_re);
-                    let z_im_hdr = fractalwonder_core::HDRFloat::from_f64(z_im);
-                    let der_re_hdr = fractalwonder_core::HDRFloat::from_f64(der_re);
-                    let der_im_hdr = fractalwonder_core::HDRFloat::from_f64(der_im);
-                    [
-                        z_re_hdr.head,
-                        z_re_hdr.tail,
-                        z_im_hdr.head,
-                        z_im_hdr.tail,
-                        // Pack exponents as f32 for GPU (will be bitcast to i32)
-                        f32::from_bits(z_re_hdr.exp as u32),
-                        f32::from_bits(z_im_hdr.exp as u32),
-                        der_re_hdr.head,
-                        der_re_hdr.tail,
-                        der_im_hdr.head,
-                        der_im_hdr.tail,
-                        f32::from_bits(der_re_hdr.exp as u32),
-                        f32::from_bits(der_im_hdr.exp as u32),
-                    ]
-                })
-                .collect();
-            self.context.queue.write_buffer(
-                &buffers.reference_orbit,
-                0,
-                bytemuck::cast_slice(&orbit_data),
-            );
+            let mut block = Vec::with_capacity(ORBIT_UPLOAD_BLOCK.min(orbit.len()));
+            let mut offset = 0;
+            for point in orbit.iter() {
+                block.push(gpu_orbit_point(&point));
+                if block.len() == ORBIT_UPLOAD_BLOCK {
+                    self.upload_orbit_block(offset, &block);
+                    offset += block.len();
+                    block.clear();
+                }
+            }
+            if !block.is_empty() {
+                self.upload_orbit_block(offset, &block);
+            }
 
             // Upload BLA table if provided
             if let Some(bla) = bla_table {
@@ -179,9 +185,11 @@ impl ProgressiveGpuRenderer {
                 tau_sq,
                 dc_origin,
                 dc_step,
-                reference_escaped,
+                orbit.escaped_at().is_some(),
                 orbit.len() as u32,
-                bla_table.is_some(),
+                orbit.period().unwrap_or(0),
+                collect_orbit_stats,
+                bla_table.is_some() && !collect_orbit_stats,
                 bla_table.map(|t| t.num_levels as u32).unwrap_or(0),
                 bla_table.map(|t| &t.level_offsets[..]).unwrap_or(&[]),
             );
@@ -191,35 +199,39 @@ impl ProgressiveGpuRenderer {
         }
 
         // Read back results
-        let (
-            iterations,
-            glitch_data,
-            z_norm_sq_data,
-            _final_z_re_data,
-            _final_z_im_data,
-            surface_normal_re_data,
-            surface_normal_im_data,
-        ) = self.read_results(row_set_pixel_count as usize).await?;
+        let (iterations, glitch_data, z_norm_sq_data, final_values_data) =
+            self.read_results(row_set_pixel_count as usize).await?;
 
         // Convert to ComputeData - surface normals are pre-computed on GPU
         let data: Vec<ComputeData> = iterations
             .iter()
             .zip(glitch_data.iter())
             .zip(z_norm_sq_data.iter())
-            .zip(surface_normal_re_data.iter())
-            .zip(surface_normal_im_data.iter())
-            .map(|((((iter, glitch), z_sq), sn_re), sn_im)| {
+            .zip(final_values_data.chunks_exact(FINAL_VALUES_STRIDE))
+            .map(|(((iter, glitch), z_sq), finals)| {
                 let escaped = *iter < max_iterations;
-                ComputeData::Mandelbrot(MandelbrotData {
-                    iterations: *iter,
+                let stripe_samples = finals[7];
+                let (orbit_trap, stripe_avg) = if stripe_samples > 0.0 {
+                    (finals[5].sqrt(), finals[6] / stripe_samples)
+                } else {
+                    (0.0, 0.0)
+                };
+                let data = MandelbrotData::new(
+                    *iter,
                     max_iterations,
                     escaped,
-                    glitched: *glitch != 0,
-                    final_z_norm_sq: *z_sq,
+                    *glitch != 0,
+                    *z_sq,
                     // GPU computes normalized surface direction in HDRFloat space
                     // to preserve precision at extreme zoom levels
-                    surface_normal_re: if escaped { *sn_re } else { 0.0 },
-                    surface_normal_im: if escaped { *sn_im } else { 0.0 },
+                    if escaped { finals[2] } else { 0.0 },
+                    if escaped { finals[3] } else { 0.0 },
+                );
+                let distance_log2 = if escaped { finals[4] } else { 0.0 };
+                // Period detection and interior distance are CPU-only
+                ComputeData::Mandelbrot(MandelbrotData {
+                    atom_domain: finals[8].to_bits(),
+                    ..data.with_orbit_stats(orbit_trap, stripe_avg, distance_log2)
                 })
             })
             .collect();
@@ -233,6 +245,15 @@ impl ProgressiveGpuRenderer {
         })
     }
 
+    fn upload_orbit_block(&self, offset: usize, block: &[[f32; 12]]) {
+        let buffers = self.buffers.as_ref().unwrap();
+        self.context.queue.write_buffer(
+            &buffers.reference_orbit,
+            (offset * std::mem::size_of::<[f32; 12]>()) as u64,
+            bytemuck::cast_slice(block),
+        );
+    }
+
     fn clear_state_buffers(&self, pixel_count: u32) {
         let buffers = self.buffers.as_ref().unwrap();
 
@@ -242,6 +263,8 @@ impl ProgressiveGpuRenderer {
         let zeros_z_state: Vec<f32> = vec![0.0; pixel_count as usize * 6];
         // drho_state: 6 f32s per pixel (drho_re head/tail/exp + drho_im head/tail/exp)
         let zeros_drho_state: Vec<f32> = vec![0.0; pixel_count as usize * 6];
+        // final_values also carries running orbit statistics that must start empty
+        let zeros_final_values: Vec<f32> = vec![0.0; pixel_count as usize * FINAL_VALUES_STRIDE];
 
         // Initialize results to a sentinel value (999) to detect if shader writes to it
         let sentinel_results: Vec<u32> = vec![999; pixel_count as usize];
@@ -259,6 +282,11 @@ impl ProgressiveGpuRenderer {
             0,
             bytemuck::cast_slice(&zeros_drho_state),
         );
+        self.context.queue.write_buffer(
+            &buffers.final_values,
+            0,
+            bytemuck::cast_slice(&zeros_final_values),
+        );
         self.context
             .queue
             .write_buffer(&buffers.iter_count, 0, bytemuck::cast_slice(&zeros_u32));
@@ -283,9 +311,11 @@ impl ProgressiveGpuRenderer {
         max_iterations: u32,
         tau_sq: f32,
         dc_origin: ((f32, f32, i32), (f32, f32, i32)),
-        dc_step: ((f32, f32, i32), (f32, f32, i32)),
+        dc_step: DeltaStep<(f32, f32, i32)>,
         reference_escaped: bool,
         orbit_len: u32,
+        orbit_period: u32,
+        collect_orbit_stats: bool,
         bla_enabled: bool,
         bla_num_levels: u32,
         bla_level_offsets: &[usize],
@@ -306,6 +336,8 @@ impl ProgressiveGpuRenderer {
             dc_step,
             reference_escaped,
             orbit_len,
+            orbit_period,
+            collect_orbit_stats,
             bla_enabled,
             bla_num_levels,
             bla_level_offsets,
@@ -439,25 +471,14 @@ impl ProgressiveGpuRenderer {
     async fn read_results(
         &self,
         count: usize,
-    ) -> Result<
-        (
-            Vec<u32>,
-            Vec<u32>,
-            Vec<f32>,
-            Vec<f32>,
-            Vec<f32>,
-            Vec<f32>,
-            Vec<f32>,
-        ),
-        GpuError,
-    > {
+    ) -> Result<(Vec<u32>, Vec<u32>, Vec<f32>, Vec<f32>), GpuError> {
         let buffers = self.buffers.as_ref().unwrap();
 
         // Copy to staging buffers
         let u32_byte_size = (count * std::mem::size_of::<u32>()) as u64;
         let f32_byte_size = (count * std::mem::size_of::<f32>()) as u64;
-        // final_values: 4 f32s per pixel (z_re, z_im, der_re, der_im)
-        let final_values_byte_size = (count * 4 * std::mem::size_of::<f32>()) as u64;
+        let final_values_byte_size =
+            (count * FINAL_VALUES_STRIDE * std::mem::size_of::<f32>()) as u64;
 
         let mut encoder =
             self.context
@@ -555,41 +576,18 @@ impl ProgressiveGpuRenderer {
             bytemuck::cast_slice(&view).to_vec()
         };
 
-        // Unpack final_values: 4 f32s per pixel (z_re, z_im, der_re, der_im)
+        // final_values: FINAL_VALUES_STRIDE f32s per pixel, unpacked by the caller
         let final_values_data: Vec<f32> = {
             let view = final_values_slice.get_mapped_range();
             bytemuck::cast_slice(&view).to_vec()
         };
 
-        // Unpack into separate vectors
-        // GPU now computes and stores surface normal directly (not raw derivative)
-        let mut final_z_re_data = Vec::with_capacity(count);
-        let mut final_z_im_data = Vec::with_capacity(count);
-        let mut surface_normal_re_data = Vec::with_capacity(count);
-        let mut surface_normal_im_data = Vec::with_capacity(count);
-
-        for i in 0..count {
-            let base = i * 4;
-            final_z_re_data.push(final_values_data[base]);
-            final_z_im_data.push(final_values_data[base + 1]);
-            surface_normal_re_data.push(final_values_data[base + 2]);
-            surface_normal_im_data.push(final_values_data[base + 3]);
-        }
-
         buffers.staging_results.unmap();
         buffers.staging_flags.unmap();
         buffers.staging_z_norm_sq.unmap();
         buffers.staging_final_values.unmap();
 
-        Ok((
-            iterations,
-            glitch_data,
-            z_norm_sq_data,
-            final_z_re_data,
-            final_z_im_data,
-            surface_normal_re_data,
-            surface_normal_im_data,
-        ))
+        Ok((iterations, glitch_data, z_norm_sq_data, final_values_data))
     }
 
     #[cfg(target_arch = "wasm32")]
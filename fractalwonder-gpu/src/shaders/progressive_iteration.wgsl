@@ -148,6 +148,12 @@ fn hdr_to_f32(x: HDRFloat) -> f32 {
     return mantissa * hdr_exp2(x.exp);
 }
 
+// log2 of |x| across the full HDR exponent range
+fn hdr_log2(x: HDRFloat) -> f32 {
+    if x.head == 0.0 { return -3.4e38; }
+    return log2(abs(x.head + x.tail)) + f32(x.exp);
+}
+
 fn hdr_complex_square(a: HDRComplex) -> HDRComplex {
     let re_sq = hdr_square(a.re);
     let im_sq = hdr_square(a.im);
@@ -285,19 +291,30 @@ struct Uniforms {
     dc_origin_im_exp: i32,
     _pad3: u32,
 
-    dc_step_re_head: f32,
-    dc_step_re_tail: f32,
-    dc_step_re_exp: i32,
+    dc_step_x_re_head: f32,
+    dc_step_x_re_tail: f32,
+    dc_step_x_re_exp: i32,
     _pad4: u32,
-    dc_step_im_head: f32,
-    dc_step_im_tail: f32,
-    dc_step_im_exp: i32,
+    dc_step_x_im_head: f32,
+    dc_step_x_im_tail: f32,
+    dc_step_x_im_exp: i32,
     _pad5: u32,
 
+    dc_step_y_re_head: f32,
+    dc_step_y_re_tail: f32,
+    dc_step_y_re_exp: i32,
+    _pad4b: u32,
+    dc_step_y_im_head: f32,
+    dc_step_y_im_tail: f32,
+    dc_step_y_im_exp: i32,
+    _pad5b: u32,
+
     reference_escaped: u32,
     orbit_len: u32,
-    _pad6a: u32,
-    _pad6b: u32,
+    // Closing point of a periodic orbit (always rebased there), 0 if not periodic
+    orbit_period: u32,
+    // Orbit trap and stripe statistics are only sampled when set; BLA is then off
+    collect_orbit_stats: u32,
 
     bla_enabled: u32,
     bla_num_levels: u32,
@@ -330,9 +347,15 @@ struct Uniforms {
 // Derivative state buffer: 6 f32s per pixel (drho_re head/tail/exp, drho_im head/tail/exp)
 @group(0) @binding(8) var<storage, read_write> drho_state: array<f32>;
 
-// Final value output buffer: 4 f32s per pixel (z_re, z_im, der_re, der_im)
+// Final value buffer: 9 f32s per pixel
+// [z_re, z_im, surface_normal_re, surface_normal_im, distance_log2,
+//  trap_min_sq, stripe_sum, stripe_samples, trap_iteration (u32 bits)]
+// Slots 5-8 are running orbit statistics persisted across chunks.
 @group(0) @binding(9) var<storage, read_write> final_values: array<f32>;
 
+// Must match STRIPE_DENSITY in fractalwonder-core
+const STRIPE_DENSITY: f32 = 5.0;
+
 // BLA (Bivariate Linear Approximation) data
 // 16 f32s per entry: A (6), B (6), r_sq (3), l (1)
 @group(0) @binding(10) var<storage, read> bla_data: array<f32>;
@@ -483,13 +506,16 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     // Construct δc for this pixel
     let dc_origin_re = hdr_from_parts(uniforms.dc_origin_re_head, uniforms.dc_origin_re_tail, uniforms.dc_origin_re_exp);
     let dc_origin_im = hdr_from_parts(uniforms.dc_origin_im_head, uniforms.dc_origin_im_tail, uniforms.dc_origin_im_exp);
-    let dc_step_re = hdr_from_parts(uniforms.dc_step_re_head, uniforms.dc_step_re_tail, uniforms.dc_step_re_exp);
-    let dc_step_im = hdr_from_parts(uniforms.dc_step_im_head, uniforms.dc_step_im_tail, uniforms.dc_step_im_exp);
+    let dc_step_x_re = hdr_from_parts(uniforms.dc_step_x_re_head, uniforms.dc_step_x_re_tail, uniforms.dc_step_x_re_exp);
+    let dc_step_x_im = hdr_from_parts(uniforms.dc_step_x_im_head, uniforms.dc_step_x_im_tail, uniforms.dc_step_x_im_exp);
+    let dc_step_y_re = hdr_from_parts(uniforms.dc_step_y_re_head, uniforms.dc_step_y_re_tail, uniforms.dc_step_y_re_exp);
+    let dc_step_y_im = hdr_from_parts(uniforms.dc_step_y_im_head, uniforms.dc_step_y_im_tail, uniforms.dc_step_y_im_exp);
 
+    // Columns and rows each step along a full complex vector (rotation/skew)
     let x_hdr = hdr_from_f32(f32(col));
     let y_hdr = hdr_from_f32(f32(global_row));
-    let dc_re = hdr_add(dc_origin_re, hdr_mul(x_hdr, dc_step_re));
-    let dc_im = hdr_add(dc_origin_im, hdr_mul(y_hdr, dc_step_im));
+    let dc_re = hdr_add(dc_origin_re, hdr_add(hdr_mul(x_hdr, dc_step_x_re), hdr_mul(y_hdr, dc_step_y_re)));
+    let dc_im = hdr_add(dc_origin_im, hdr_add(hdr_mul(x_hdr, dc_step_x_im), hdr_mul(y_hdr, dc_step_y_im)));
     let dc = HDRComplex(dc_re, dc_im);
 
     // Load persistent state
@@ -499,6 +525,13 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     var m = orbit_index[linear_idx];
     var glitched = (flags_buf[linear_idx] & 2u) != 0u;
 
+    // Orbit statistics for the layered colorizers
+    let final_base = linear_idx * 9u;
+    var stripe_samples = final_values[final_base + 7u];
+    var trap_min_sq = select(3.4e38, final_values[final_base + 5u], stripe_samples > 0.0);
+    var stripe_sum = final_values[final_base + 6u];
+    var trap_iteration = bitcast<u32>(final_values[final_base + 8u]);
+
     let orbit_len = uniforms.orbit_len;
     let reference_escaped = uniforms.reference_escaped != 0u;
     let chunk_end = min(uniforms.chunk_start_iter + uniforms.chunk_size, uniforms.max_iterations);
@@ -578,12 +611,21 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
             // Get normalized direction as f32 unit vector (preserves ratio even at extreme exponents)
             let surface_normal = hdr_complex_direction(u_unnorm);
 
-            // Store final values as f32 (packed: z_re, z_im, surface_normal_re, surface_normal_im)
-            let final_base = linear_idx * 4u;
+            // Distance estimate |z|·ln|z|/|ρ| as log2 (|ρ| can exceed f32 range)
+            let rho_log2 = 0.5 * hdr_log2(hdr_complex_norm_sq_hdr(HDRComplex(rho_re, rho_im)));
+            let ln_z = 0.5 * log(z_mag_sq);
+            let distance_log2 = 0.5 * log2(z_mag_sq) + log2(ln_z) - rho_log2;
+
+            // Store final values as f32 (see layout at binding 9)
             final_values[final_base] = hdr_to_f32(z_re_full);
             final_values[final_base + 1u] = hdr_to_f32(z_im_full);
             final_values[final_base + 2u] = surface_normal.x;
             final_values[final_base + 3u] = surface_normal.y;
+            final_values[final_base + 4u] = distance_log2;
+            final_values[final_base + 5u] = trap_min_sq;
+            final_values[final_base + 6u] = stripe_sum;
+            final_values[final_base + 7u] = stripe_samples;
+            final_values[final_base + 8u] = bitcast<f32>(trap_iteration);
 
             flags_buf[linear_idx] = 1u | select(0u, 2u, glitched);
             results[linear_idx] = n;
@@ -607,7 +649,8 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
         // NOTE: Rebasing is a precision technique, NOT a Mandelbrot iteration.
         // The iteration count n should NOT be reset during rebase.
         // Use HDRFloat comparison to preserve precision for very small values
-        if hdr_less_than(z_mag_sq_hdr, dz_mag_sq_hdr) {
+        let closes_cycle = uniforms.orbit_period != 0u && m == uniforms.orbit_period;
+        if hdr_less_than(z_mag_sq_hdr, dz_mag_sq_hdr) || closes_cycle {
             dz = z;
             // Also rebase derivative
             drho = HDRComplex(
@@ -618,6 +661,17 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
             continue;
         }
 
+        // Sample orbit statistics (same point in the loop as the CPU paths, skipping z_0 = 0)
+        if uniforms.collect_orbit_stats != 0u && n > 0u {
+            if z_mag_sq < trap_min_sq {
+                trap_min_sq = z_mag_sq;
+                trap_iteration = n;
+            }
+            let z_angle = atan2(hdr_to_f32(z_im_full), hdr_to_f32(z_re_full));
+            stripe_sum = stripe_sum + 0.5 * sin(STRIPE_DENSITY * z_angle) + 0.5;
+            stripe_samples = stripe_samples + 1.0;
+        }
+
         // BLA acceleration: try to skip multiple iterations
         if uniforms.bla_enabled != 0u {
             let bla = bla_find_valid(m, dz_mag_sq_hdr, orbit_len);
@@ -679,11 +733,22 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     iter_count[linear_idx] = n;
     orbit_index[linear_idx] = m;
     flags_buf[linear_idx] = (flags_buf[linear_idx] & 1u) | select(0u, 2u, glitched);
+    final_values[final_base + 5u] = trap_min_sq;
+    final_values[final_base + 6u] = stripe_sum;
+    final_values[final_base + 7u] = stripe_samples;
+    final_values[final_base + 8u] = bitcast<f32>(trap_iteration);
 
     // If we reached max_iterations, write final results
     if n >= uniforms.max_iterations {
         flags_buf[linear_idx] = flags_buf[linear_idx] | 1u;  // Mark as "done" even though didn't escape
         results[linear_idx] = uniforms.max_iterations;
-        z_norm_sq[linear_idx] = 0.0;
+
+        // |z|² after max_iterations, for interior coloring
+        let end_idx = (m % orbit_len) * 12u;
+        let z_end_re = hdr_add(HDRFloat(reference_orbit[end_idx], reference_orbit[end_idx + 1u],
+            bitcast<i32>(bitcast<u32>(reference_orbit[end_idx + 4u]))), dz.re);
+        let z_end_im = hdr_add(HDRFloat(reference_orbit[end_idx + 2u], reference_orbit[end_idx + 3u],
+            bitcast<i32>(bitcast<u32>(reference_orbit[end_idx + 5u]))), dz.im);
+        z_norm_sq[linear_idx] = hdr_to_f32(hdr_complex_norm_sq_hdr(HDRComplex(z_end_re, z_end_im)));
     }
 }
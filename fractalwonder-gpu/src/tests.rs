@@ -1,9 +1,10 @@
 //! Tests for GPU renderer - verifies GPU output matches CPU perturbation.
 
 use crate::{GpuAvailability, GpuContext};
-use fractalwonder_compute::{compute_pixel_perturbation, ReferenceOrbit};
+use fractalwonder_compute::{compute_pixel_perturbation, CompressedOrbit, ReferenceOrbit};
 use fractalwonder_core::{
-    calculate_max_iterations, BigFloat, ComputeData, HDRComplex, HDRFloat, MandelbrotData,
+    calculate_max_iterations, BigFloat, ComputeData, DeltaStep, HDRComplex, HDRFloat,
+    MandelbrotData,
 };
 
 /// Helper to create a reference orbit at a given center point.
@@ -16,6 +17,11 @@ fn create_reference_orbit(center_re: f64, center_im: f64, max_iter: u32) -> Refe
     ReferenceOrbit::compute(&c_ref, max_iter)
 }
 
+/// Compress without loss, so the GPU sees exactly the CPU reference values.
+fn lossless(orbit: &ReferenceOrbit) -> CompressedOrbit {
+    CompressedOrbit::with_tolerance(orbit, &HDRFloat::from_f64(0.0), 0.0)
+}
+
 /// Extract MandelbrotData from ComputeData.
 fn as_mandelbrot(data: &ComputeData) -> &MandelbrotData {
     let ComputeData::Mandelbrot(m) = data;
@@ -135,7 +141,7 @@ fn progressive_renderer_basic_render() {
         let view_width = 3.0_f32;
         let view_height = 3.0_f32;
         let dc_origin = ((-view_width / 2.0, 0.0, 0), (-view_height / 2.0, 0.0, 0));
-        let dc_step = (
+        let dc_step = DeltaStep::axis_aligned(
             (view_width / width as f32, 0.0, 0),
             (view_height / height as f32, 0.0, 0),
         );
@@ -143,8 +149,7 @@ fn progressive_renderer_basic_render() {
         // Render first row-set
         let result = renderer
             .render_row_set(
-                &orbit.orbit,
-                &orbit.derivative,
+                &lossless(&orbit),
                 1,
                 dc_origin,
                 dc_step,
@@ -155,8 +160,8 @@ fn progressive_renderer_basic_render() {
                 max_iter,
                 iterations_per_dispatch,
                 tau_sq,
-                orbit.escaped_at.is_some(),
-                None, // BLA table
+                false, // collect_orbit_stats
+                None,  // BLA table
             )
             .await
             .expect("Progressive render should succeed");
@@ -271,7 +276,7 @@ fn gpu_orbit_precision_matches_cpu() {
             (origin_re.head, origin_re.tail, origin_re.exp),
             (origin_im.head, origin_im.tail, origin_im.exp),
         );
-        let dc_step = (
+        let dc_step = DeltaStep::axis_aligned(
             (step_re.head, step_re.tail, step_re.exp),
             (step_im.head, step_im.tail, step_im.exp),
         );
@@ -285,8 +290,7 @@ fn gpu_orbit_precision_matches_cpu() {
         for row_set_idx in 0..row_set_count {
             let result = gpu_renderer
                 .render_row_set(
-                    &orbit.orbit,
-                    &orbit.derivative,
+                    &lossless(&orbit),
                     1,
                     dc_origin,
                     dc_step,
@@ -297,8 +301,8 @@ fn gpu_orbit_precision_matches_cpu() {
                     max_iter,
                     iterations_per_dispatch,
                     tau_sq,
-                    orbit.escaped_at.is_some(),
-                    None, // BLA table
+                    false, // collect_orbit_stats
+                    None,  // BLA table
                 )
                 .await
                 .expect("GPU render should succeed");
@@ -333,14 +337,14 @@ fn gpu_orbit_precision_matches_cpu() {
                     exp: dc_origin.1 .2,
                 };
                 let step_re_hdr = HDRFloat {
-                    head: dc_step.0 .0,
-                    tail: dc_step.0 .1,
-                    exp: dc_step.0 .2,
+                    head: dc_step.x.0 .0,
+                    tail: dc_step.x.0 .1,
+                    exp: dc_step.x.0 .2,
                 };
                 let step_im_hdr = HDRFloat {
-                    head: dc_step.1 .0,
-                    tail: dc_step.1 .1,
-                    exp: dc_step.1 .2,
+                    head: dc_step.y.1 .0,
+                    tail: dc_step.y.1 .1,
+                    exp: dc_step.y.1 .2,
                 };
 
                 let dc_re = origin_re_hdr.add(&HDRFloat::from_f64(col as f64).mul(&step_re_hdr));
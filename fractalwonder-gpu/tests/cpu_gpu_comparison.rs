@@ -5,8 +5,12 @@
 
 #[cfg(not(target_arch = "wasm32"))]
 mod tests {
-    use fractalwonder_compute::{render_tile_hdr, BlaTable, ReferenceOrbit, TileConfig};
-    use fractalwonder_core::{BigFloat, ComputeData, HDRFloat, MandelbrotData, Viewport};
+    use fractalwonder_compute::{
+        render_tile_hdr, BlaSource, BlaTable, CompressedOrbit, ReferenceOrbit, TileConfig,
+    };
+    use fractalwonder_core::{
+        BigFloat, ComputeData, DeltaStep, HDRFloat, MandelbrotData, Viewport,
+    };
     use fractalwonder_gpu::{GpuAvailability, GpuContext, ProgressiveGpuRenderer};
 
     /// Test viewport parameters (extreme deep zoom ~10^-281)
@@ -84,7 +88,7 @@ mod tests {
         let step_re = viewport.width.div(&canvas_width_bf);
         let step_im = viewport.height.div(&canvas_height_bf);
 
-        let delta_step = (
+        let delta_step = DeltaStep::axis_aligned(
             HDRFloat::from_bigfloat(&step_re),
             HDRFloat::from_bigfloat(&step_im),
         );
@@ -94,6 +98,11 @@ mod tests {
             max_iterations: MAX_ITERATIONS,
             tau_sq: TAU_SQ,
             bla_enabled: true,
+            force_hdr_float: false,
+            scaled_f64: false,
+            collect_orbit_stats: false,
+            probe_interior: true,
+            iteration_limit: None,
         };
 
         let result = render_tile_hdr(orbit, Some(bla_table), delta_origin, delta_step, &config);
@@ -233,18 +242,18 @@ mod tests {
             (origin_re.head, origin_re.tail, origin_re.exp),
             (origin_im.head, origin_im.tail, origin_im.exp),
         );
-        let dc_step = (
+        let dc_step = DeltaStep::axis_aligned(
             (step_re.head, step_re.tail, step_re.exp),
             (step_im.head, step_im.tail, step_im.exp),
         );
 
-        let reference_escaped = orbit.escaped_at.is_some();
+        // Lossless, so both pipelines iterate against the same reference values
+        let compressed = CompressedOrbit::with_tolerance(orbit, bla_table.dc_max(), 0.0);
 
         // Render entire image in one row-set to simplify extraction
         let result = renderer
             .render_row_set(
-                &orbit.orbit,
-                &orbit.derivative,
+                &compressed,
                 1, // orbit_id
                 dc_origin,
                 dc_step,
@@ -255,7 +264,7 @@ mod tests {
                 MAX_ITERATIONS,
                 10000, // iterations_per_dispatch
                 TAU_SQ as f32,
-                reference_escaped,
+                false, // collect_orbit_stats
                 Some(bla_table),
             )
             .await;
@@ -0,0 +1,98 @@
+//! Render a zoom path JSON file into numbered PNG frames.
+//!
+//! Usage:
+//!   cargo run --release -p fractalwonder-ui --example zoom_export -- \
+//!       path.json out_dir [--size 1920x1080] [--palette Classic] [--zoom-stable]
+
+use fractalwonder_core::ZoomPath;
+use fractalwonder_ui::default_config;
+use fractalwonder_ui::export::{export_png_sequence, ZoomSequencer};
+use fractalwonder_ui::rendering::colorizers::{Palette, RenderSettings};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+struct Args {
+    path: PathBuf,
+    out_dir: PathBuf,
+    size: (u32, u32),
+    palette: String,
+    zoom_stable: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut positional = Vec::new();
+    let mut size = (1280, 720);
+    let mut palette = "Classic".to_string();
+    let mut zoom_stable = false;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                let value = args.next().ok_or("--size needs a value like 1920x1080")?;
+                let (w, h) = value
+                    .split_once('x')
+                    .ok_or_else(|| format!("Invalid size: {}", value))?;
+                size = (
+                    w.parse().map_err(|_| format!("Invalid width: {}", w))?,
+                    h.parse().map_err(|_| format!("Invalid height: {}", h))?,
+                );
+            }
+            "--palette" => palette = args.next().ok_or("--palette needs a name")?,
+            "--zoom-stable" => zoom_stable = true,
+            _ => positional.push(PathBuf::from(arg)),
+        }
+    }
+
+    let [path, out_dir] = <[PathBuf; 2]>::try_from(positional)
+        .map_err(|_| "Expected <path.json> <out_dir>".to_string())?;
+    Ok(Args {
+        path,
+        out_dir,
+        size,
+        palette,
+        zoom_stable,
+    })
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+
+    let json = std::fs::read_to_string(&args.path)
+        .map_err(|e| format!("Failed to read {}: {}", args.path.display(), e))?;
+    let path = ZoomPath::from_json(&json)?;
+
+    let palette = futures::executor::block_on(async {
+        Palette::factory_defaults().await; // ensure loaded
+        Palette::get(&args.palette).await
+    })
+    .ok_or_else(|| format!("Unknown palette: {}", args.palette))?;
+    let settings = RenderSettings {
+        zoom_stable: args.zoom_stable,
+        ..RenderSettings::default()
+    };
+
+    let mut sequencer = ZoomSequencer::new(path, default_config(), args.size, palette, settings)?;
+    let written = export_png_sequence(&mut sequencer, &args.out_dir, |done, total| {
+        eprintln!("frame {done}/{total}");
+    })
+    .map_err(|e| format!("Failed to write frames: {}", e))?;
+
+    eprintln!(
+        "Wrote {} frames to {} ({} reference orbits)",
+        written,
+        args.out_dir.display(),
+        sequencer.orbits_computed()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("zoom_export: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,20 +1,48 @@
 // fractalwonder-ui/src/app.rs
-use fractalwonder_core::{calculate_precision_bits, fit_viewport_to_canvas, Viewport};
+use fractalwonder_core::{
+    calculate_precision_bits, fit_viewport_to_canvas, multiply_2x2, Viewport, ZoomPath,
+    IDENTITY_SKEW,
+};
 use leptos::*;
 use wasm_bindgen::prelude::Closure;
 
 use crate::components::PaletteEditorState;
-use crate::components::{CircularProgress, InteractiveCanvas, PaletteEditor, Toast, UIPanel};
+use crate::components::{
+    BookmarksPanel, CircularProgress, GoToDialog, InteractiveCanvas, JuliaFullscreen, JuliaPreview,
+    PaletteEditor, PixelInspectorPanel, RenderStatsPanel, Toast, UIPanel,
+};
 use crate::config::{
     default_config, get_config, get_cpu_threads_override, get_gpu_enabled_override,
-    set_cpu_threads, set_gpu_enabled,
+    get_shared_memory_enabled, set_cpu_threads, set_gpu_enabled, set_shared_memory_enabled,
 };
+use crate::export::{download_bytes, render_archive, PoolFrameRenderer, ZoomSequencer};
 use crate::hooks::{
-    apply_palette_order, load_palette_order, load_state, save_palette_order, save_state,
-    use_hashchange_listener, use_ui_visibility, PersistedState,
+    apply_palette_order, export_bookmarks, import_bookmarks, load_bookmarks, load_palette_order,
+    load_state, merge_bookmarks, save_bookmarks, save_palette_order, save_state, use_color_cycle,
+    use_hashchange_listener, use_ui_visibility, Bookmark, NavigationHistory, PersistedState,
+};
+use crate::rendering::colorizers::{wrap_phase, ColorCycle, Palette};
+use crate::rendering::{
+    capture_thumbnail, HeatmapMode, PixelInspection, RenderProgress, RenderStats,
 };
-use crate::rendering::colorizers::Palette;
-use crate::rendering::RenderProgress;
+use crate::workers::shared_memory_available;
+
+/// Frames per zoom doubling for exports started from the UI.
+const EXPORT_FRAMES_PER_DOUBLING: f64 = 30.0;
+
+/// Width of bookmark thumbnails in pixels.
+const THUMBNAIL_WIDTH: u32 = 160;
+
+/// Largest entry-wise change from the identity that the unskew tool ignores.
+const UNSKEW_TOLERANCE: f64 = 0.01;
+
+fn differs_from_identity(matrix: [[f64; 2]; 2]) -> bool {
+    matrix
+        .iter()
+        .flatten()
+        .zip(IDENTITY_SKEW.iter().flatten())
+        .any(|(value, identity)| (value - identity).abs() > UNSKEW_TOLERANCE)
+}
 
 #[component]
 pub fn App() -> impl IntoView {
@@ -38,6 +66,10 @@ pub fn App() -> impl IntoView {
     if let Some(gpu_enabled) = get_gpu_enabled_override() {
         initial_render_settings.use_gpu = gpu_enabled;
     }
+    let initial_cycle_phase = persisted
+        .as_ref()
+        .map(|s| wrap_phase(s.cycle_phase))
+        .unwrap_or_default();
     let persisted_viewport = persisted.map(|s| s.viewport);
 
     // Store persisted viewport for use in effect (consumed on first use)
@@ -50,6 +82,9 @@ pub fn App() -> impl IntoView {
     let (render_progress, set_render_progress) =
         create_signal(RwSignal::new(RenderProgress::default()));
 
+    // Per-tile render stats (updated by renderer)
+    let (render_stats, set_render_stats) = create_signal(RwSignal::new(RenderStats::default()));
+
     // Selected renderer (fractal type) - use persisted value if available
     let (selected_config_id, _set_selected_config_id) = create_signal(initial_config_id);
 
@@ -62,6 +97,13 @@ pub fn App() -> impl IntoView {
     let (render_settings, set_render_settings) = create_signal(initial_render_settings);
     let (palette_id, set_palette_id) = create_signal(initial_palette_id.clone());
 
+    // Palette color cycling: controls are runtime-only, the phase is persisted.
+    // Phase changes recolorize against the cached histogram instead of going
+    // through render_settings, which would rebuild it every frame.
+    let color_cycle = create_rw_signal(ColorCycle::default());
+    let cycle_phase = create_rw_signal(initial_cycle_phase);
+    use_color_cycle(color_cycle.into(), cycle_phase);
+
     // Load initial palette asynchronously
     create_effect(move |_| {
         let id = palette_id.get();
@@ -86,6 +128,9 @@ pub fn App() -> impl IntoView {
         .map(|w| w.navigator().hardware_concurrency() as i32)
         .unwrap_or(4);
 
+    // Shared memory workers (opt-in, localStorage only)
+    let (shared_memory, set_shared_memory) = create_signal(get_shared_memory_enabled());
+
     // Bounds for CPU threads stepper
     let cpu_threads_at_min =
         Signal::derive(move || cpu_threads.get() <= -(hardware_concurrency - 1));
@@ -248,39 +293,164 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    // Undo/redo history of persisted states; restoring one must not record it again
+    let history = store_value(NavigationHistory::default());
+    let restoring_history = store_value(false);
+
+    // Switch to a saved state in one batch, so it is persisted and recorded once
+    let apply_state = move |state: PersistedState| {
+        let size = canvas_size.get_untracked();
+        if size.0 == 0 || size.1 == 0 {
+            return;
+        }
+        batch(|| {
+            // Fit the saved viewport to the current canvas size
+            set_viewport.set(fit_viewport_to_canvas(&state.viewport, size));
+            set_palette_id.set(state.palette_name.clone());
+            // Preserve current GPU setting (it's stored in localStorage, not URL)
+            let mut new_settings = state.render_settings.clone();
+            new_settings.use_gpu = render_settings.get_untracked().use_gpu;
+            set_render_settings.set(new_settings);
+            cycle_phase.set(wrap_phase(state.cycle_phase));
+        });
+    };
+
+    let step_history = move |forward: bool| {
+        let state = history.try_update_value(|h| {
+            if forward {
+                h.redo().cloned()
+            } else {
+                h.undo().cloned()
+            }
+        });
+        let Some(state) = state.flatten() else {
+            let msg = if forward {
+                "Nothing to redo"
+            } else {
+                "Nothing to undo"
+            };
+            set_toast_message.set(Some(msg.to_string()));
+            return;
+        };
+        restoring_history.set_value(true);
+        apply_state(state);
+        restoring_history.set_value(false);
+        set_toast_message.set(Some(if forward { "Redo" } else { "Undo" }.to_string()));
+    };
+
     // Persist state to localStorage when viewport, palette, or render settings change
     create_effect(move |_| {
         let vp = viewport.get();
         let config_id = selected_config_id.get();
         let pal_id = palette_id.get();
         let settings = render_settings.get();
+        // Don't save every animation frame; the phase is saved once cycling stops
+        let phase = if color_cycle.get().running {
+            cycle_phase.get_untracked()
+        } else {
+            cycle_phase.get()
+        };
 
         // Skip saving if viewport hasn't been initialized yet
         if vp.width.to_f64() == 4.0 && vp.height.to_f64() == 3.0 {
             return;
         }
 
-        let state = PersistedState::new(vp, config_id, pal_id, settings);
+        let state = PersistedState::new(vp, config_id, pal_id, settings, phase);
         save_state(&state);
+        if !restoring_history.get_value() {
+            history.update_value(|h| h.push(state));
+        }
     });
 
     // Listen for hashchange events (e.g., when user clicks a bookmark)
     // This enables navigating to a saved position via URL hash
     use_hashchange_listener(move |state| {
-        let size = canvas_size.get_untracked();
-        if size.0 > 0 && size.1 > 0 {
-            // Fit the persisted viewport to the current canvas size
-            let fitted = fit_viewport_to_canvas(&state.viewport, size);
-            set_viewport.set(fitted);
-            set_palette_id.set(state.palette_name.clone());
-            // Preserve current GPU setting (it's stored in localStorage, not URL)
-            let mut new_settings = state.render_settings.clone();
-            new_settings.use_gpu = render_settings.get_untracked().use_gpu;
-            set_render_settings.set(new_settings);
-            log::info!("Restored viewport, palette, and render settings from URL hash change");
+        apply_state(state);
+        log::info!("Restored viewport, palette, and render settings from URL hash change");
+    });
+
+    // Bookmarks library ("b" key), kept in localStorage
+    let canvas_ref = create_node_ref::<leptos::html::Canvas>();
+    let (bookmarks_visible, set_bookmarks_visible) = create_signal(false);
+    let bookmarks = create_rw_signal(load_bookmarks());
+    create_effect(move |prev: Option<()>| {
+        bookmarks.with(|list| {
+            if prev.is_some() {
+                if let Err(e) = save_bookmarks(list) {
+                    log::error!("Failed to save bookmarks: {e}");
+                    set_toast_message.set(Some("Could not save bookmarks".to_string()));
+                }
+            }
+        });
+    });
+
+    let on_bookmark_add = Callback::new(move |(name, tags): (String, Vec<String>)| {
+        let Some(state) = history.with_value(|h| h.current().cloned()) else {
+            return;
+        };
+        let name = match name.trim() {
+            "" => format!("Bookmark {}", bookmarks.with_untracked(Vec::len) + 1),
+            name => name.to_string(),
+        };
+        let thumbnail = canvas_ref
+            .get_untracked()
+            .and_then(|canvas| capture_thumbnail(&canvas, THUMBNAIL_WIDTH));
+        let bookmark = Bookmark {
+            name: name.clone(),
+            tags,
+            created_ms: js_sys::Date::now(),
+            thumbnail,
+            state,
+        };
+        bookmarks.update(|list| list.insert(0, bookmark));
+        set_toast_message.set(Some(format!("Bookmarked: {name}")));
+    });
+    let on_bookmark_open = Callback::new(move |bookmark: Bookmark| {
+        apply_state(bookmark.state);
+        set_toast_message.set(Some(bookmark.name));
+    });
+    let on_bookmark_delete = Callback::new(move |index: usize| {
+        bookmarks.update(|list| {
+            if index < list.len() {
+                list.remove(index);
+            }
+        });
+    });
+    let on_bookmarks_export = Callback::new(move |_: ()| {
+        let result = bookmarks
+            .with_untracked(|list| export_bookmarks(list))
+            .and_then(|json| {
+                download_bytes(json.as_bytes(), "bookmarks.json", "application/json")
+                    .map_err(|e| format!("{e:?}"))
+            });
+        if let Err(e) = result {
+            log::error!("Failed to export bookmarks: {e}");
+        }
+    });
+    let on_bookmarks_import = Callback::new(move |json: String| match import_bookmarks(&json) {
+        Ok(imported) => {
+            let added = bookmarks
+                .try_update(|list| merge_bookmarks(list, imported))
+                .unwrap_or_default();
+            set_toast_message.set(Some(format!("Imported {added} bookmarks")));
+        }
+        Err(e) => {
+            log::error!("Failed to import bookmarks: {e}");
+            set_toast_message.set(Some("Not a bookmarks file".to_string()));
         }
     });
 
+    // Go-to-location dialog ("l" key) for exact coordinates
+    let (goto_visible, set_goto_visible) = create_signal(false);
+    let reference_width = Signal::derive(move || config.get().default_viewport(64).width);
+    let on_goto = Callback::new(move |vp: Viewport| {
+        set_viewport.set(vp);
+        set_goto_visible.set(false);
+        set_toast_message.set(Some("Moved to location".to_string()));
+    });
+    let on_goto_close = Callback::new(move |_: ()| set_goto_visible.set(false));
+
     let on_resize = Callback::new(move |size: (u32, u32)| {
         set_canvas_size.set(size);
     });
@@ -289,34 +459,174 @@ pub fn App() -> impl IntoView {
         set_viewport.set(new_vp);
     });
 
+    // Config default viewport, fitted to the current canvas
+    let home_viewport = move |size: (u32, u32)| {
+        let cfg = config.get_untracked();
+        let natural = cfg.default_viewport(64);
+        let fitted = fit_viewport_to_canvas(&natural, size);
+        let required_bits = calculate_precision_bits(&fitted, size);
+
+        if required_bits > fitted.precision_bits() {
+            let natural_high_prec = cfg.default_viewport(required_bits);
+            fit_viewport_to_canvas(&natural_high_prec, size)
+        } else {
+            fitted
+        }
+    };
+
     let on_home_click = Callback::new(move |_: ()| {
         let size = canvas_size.get_untracked();
-        let cfg = config.get_untracked();
 
         // Skip if invalid canvas size
         if size.0 == 0 || size.1 == 0 {
             return;
         }
 
-        // Reset viewport to config default, fitted to current canvas
-        let natural = cfg.default_viewport(64);
-        let fitted = fit_viewport_to_canvas(&natural, size);
-        let required_bits = calculate_precision_bits(&fitted, size);
+        set_viewport.set(home_viewport(size));
+    });
 
-        let final_viewport = if required_bits > fitted.precision_bits() {
-            let natural_high_prec = cfg.default_viewport(required_bits);
-            fit_viewport_to_canvas(&natural_high_prec, size)
+    // Unskew: the canvas estimates a stretch from the image on screen, which is
+    // composed with the current skew
+    let skewed = Signal::derive(move || viewport.with(Viewport::is_skewed));
+    let (unskew_trigger, set_unskew_trigger) = create_signal(0u32);
+    let on_unskew = Callback::new(move |_: ()| {
+        set_unskew_trigger.update(|v| *v = v.wrapping_add(1));
+    });
+    let on_unskew_estimate = Callback::new(move |estimate: Option<[[f64; 2]; 2]>| {
+        let Some(stretch) = estimate else {
+            set_toast_message.set(Some("Cannot estimate skew here".to_string()));
+            return;
+        };
+        if !differs_from_identity(stretch) {
+            set_toast_message.set(Some("View is not skewed".to_string()));
+            return;
+        }
+        let vp = viewport.get_untracked();
+        let skew = multiply_2x2(vp.skew_matrix(), stretch);
+        let unskewed = if differs_from_identity(skew) {
+            vp.with_skew(skew)
         } else {
-            fitted
+            vp.with_skew(IDENTITY_SKEW)
+        };
+        let msg = if unskewed.is_skewed() {
+            "Unskewed"
+        } else {
+            "Skew: Off"
+        };
+        set_viewport.set(unskewed);
+        set_toast_message.set(Some(msg.to_string()));
+    });
+
+    // Zoom export: a path from the home view into the current view
+    let exporting = create_rw_signal(false);
+    let export_cancelled = store_value(false);
+    let zoom_path = move || {
+        let size = canvas_size.get_untracked();
+        ZoomPath::new(
+            home_viewport(size),
+            viewport.get_untracked(),
+            EXPORT_FRAMES_PER_DOUBLING,
+        )
+    };
+
+    let on_export_path = Callback::new(move |_: ()| {
+        let result = zoom_path().to_json().and_then(|json| {
+            download_bytes(json.as_bytes(), "zoom_path.json", "application/json")
+                .map_err(|e| format!("{e:?}"))
+        });
+        match result {
+            Ok(()) => set_toast_message.set(Some("Zoom path saved".to_string())),
+            Err(e) => log::error!("Failed to save zoom path: {e}"),
+        }
+    });
+
+    // Renders the frames of a path on a worker pool of its own and downloads them
+    let export_frames = move |path: ZoomPath| {
+        let size = canvas_size.get_untracked();
+        let palette = render_palette.get_untracked();
+        let (collect_orbit_stats, probe_interior) = (
+            palette.needs_orbit_stats(),
+            palette.interior.mode.requires_cpu(),
+        );
+        let sequencer = ZoomSequencer::new(
+            path,
+            config.get_untracked(),
+            size,
+            palette,
+            render_settings.get_untracked(),
+        );
+        let mut sequencer = match sequencer {
+            Ok(sequencer) => sequencer,
+            Err(e) => {
+                log::error!("Cannot export zoom frames: {e}");
+                set_toast_message.set(Some(e));
+                return;
+            }
+        };
+        let renderer = PoolFrameRenderer::new(
+            config.get_untracked(),
+            size,
+            collect_orbit_stats,
+            probe_interior,
+            sequencer.orbit_iterations(),
+        );
+        let renderer = match renderer {
+            Ok(renderer) => renderer,
+            Err(e) => {
+                log::error!("Cannot start export workers: {e:?}");
+                return;
+            }
         };
 
-        set_viewport.set(final_viewport);
+        exporting.set(true);
+        export_cancelled.set_value(false);
+        spawn_local(async move {
+            let archive = render_archive(
+                &mut sequencer,
+                &renderer,
+                |done, total| set_toast_message.set(Some(format!("Frame {done}/{total}"))),
+                || export_cancelled.get_value(),
+            )
+            .await;
+            exporting.set(false);
+
+            if export_cancelled.get_value() {
+                set_toast_message.set(Some("Export cancelled".to_string()));
+            } else if let Err(e) = download_bytes(&archive, "zoom_frames.tar", "application/x-tar")
+            {
+                log::error!("Failed to download zoom frames: {e:?}");
+            }
+        });
+    };
+
+    let on_export_frames = Callback::new(move |_: ()| {
+        // Clicking while an export runs cancels it
+        if exporting.get_untracked() {
+            export_cancelled.set_value(true);
+            return;
+        }
+        export_frames(zoom_path());
+    });
+
+    let on_import_path = Callback::new(move |json: String| {
+        if exporting.get_untracked() {
+            set_toast_message.set(Some("An export is already running".to_string()));
+            return;
+        }
+        match ZoomPath::from_json(&json) {
+            Ok(path) => export_frames(path),
+            Err(e) => set_toast_message.set(Some(e)),
+        }
     });
 
     let on_progress_signal = Callback::new(move |progress_signal: RwSignal<RenderProgress>| {
         set_render_progress.set(progress_signal);
     });
 
+    let on_stats_signal = Callback::new(move |stats_signal: RwSignal<RenderStats>| {
+        set_render_stats.set(stats_signal);
+    });
+
     // UI visibility (autohide behavior)
     let ui_visibility = use_ui_visibility();
 
@@ -333,6 +643,25 @@ pub fn App() -> impl IntoView {
     // Trigger for quadtree subdivision (incremented by "d" key when x-ray enabled)
     let (subdivide_trigger, set_subdivide_trigger) = create_signal(0u32);
 
+    // Tile heatmap overlay (cycled by "h" key when x-ray enabled)
+    let (heatmap_mode, set_heatmap_mode) = create_signal(HeatmapMode::Off);
+
+    // Pixel inspector ("i" key) and the pixel it shows
+    let (inspector_enabled, set_inspector_enabled) = create_signal(false);
+    let (inspection, set_inspection) = create_signal::<Option<PixelInspection>>(None);
+    let on_inspect = Callback::new(move |pixel: Option<PixelInspection>| set_inspection.set(pixel));
+
+    // Orbit path overlay ("o" key); clicking a pixel traces its iterations
+    let (orbit_enabled, set_orbit_enabled) = create_signal(false);
+
+    // Julia preview ("j" key) for the hovered point; clicking opens the set full-screen
+    let (julia_enabled, set_julia_enabled) = create_signal(false);
+    let (julia_point, set_julia_point) = create_signal::<Option<(f64, f64)>>(None);
+    let (julia_c, set_julia_c) = create_signal::<Option<(f64, f64)>>(None);
+    let on_julia_hover = Callback::new(move |point: Option<(f64, f64)>| set_julia_point.set(point));
+    let on_julia_open = Callback::new(move |c: (f64, f64)| set_julia_c.set(Some(c)));
+    let on_julia_close = Callback::new(move |_: ()| set_julia_c.set(None));
+
     // Global keyboard handler for shortcuts
     // Store handler in a StoredValue so it lives for the component lifetime
     // and can be properly cleaned up
@@ -353,7 +682,33 @@ pub fn App() -> impl IntoView {
                 }
             }
 
+            // Undo (Ctrl/Cmd+Z) and redo (Ctrl/Cmd+Shift+Z or Ctrl/Cmd+Y)
+            if e.ctrl_key() || e.meta_key() {
+                let forward = match e.key().as_str() {
+                    "z" | "Z" => Some(e.shift_key()),
+                    "y" | "Y" => Some(true),
+                    _ => None,
+                };
+                if let Some(forward) = forward {
+                    e.prevent_default();
+                    step_history(forward);
+                    return;
+                }
+            }
+
             match e.key().as_str() {
+                "c" | "C" => {
+                    // Toggle palette color cycling
+                    color_cycle.update(|cycle| {
+                        cycle.running = !cycle.running;
+                        let msg = if cycle.running {
+                            "Color Cycling: On"
+                        } else {
+                            "Color Cycling: Off"
+                        };
+                        set_toast_message.set(Some(msg.to_string()));
+                    });
+                }
                 "x" | "X" => {
                     // Toggle x-ray mode
                     set_xray_enabled.update(|v| {
@@ -362,11 +717,67 @@ pub fn App() -> impl IntoView {
                         set_toast_message.set(Some(msg.to_string()));
                     });
                 }
-                "d" | "D" => {
-                    // Subdivide quadtree (only when x-ray enabled)
-                    if xray_enabled.get_untracked() {
-                        set_subdivide_trigger.update(|v| *v = v.wrapping_add(1));
-                    }
+                "i" | "I" => {
+                    // Toggle pixel inspector
+                    set_inspector_enabled.update(|v| {
+                        *v = !*v;
+                        let msg = if *v {
+                            "Inspector: On"
+                        } else {
+                            "Inspector: Off"
+                        };
+                        set_toast_message.set(Some(msg.to_string()));
+                    });
+                }
+                "o" | "O" => {
+                    // Toggle orbit path overlay
+                    set_orbit_enabled.update(|v| {
+                        *v = !*v;
+                        let msg = if *v {
+                            "Orbit Path: On (click a pixel)"
+                        } else {
+                            "Orbit Path: Off"
+                        };
+                        set_toast_message.set(Some(msg.to_string()));
+                    });
+                }
+                "j" | "J" => {
+                    // Toggle Julia preview
+                    set_julia_enabled.update(|v| {
+                        *v = !*v;
+                        if !*v {
+                            set_julia_point.set(None);
+                        }
+                        let msg = if *v {
+                            "Julia Preview: On (click to open)"
+                        } else {
+                            "Julia Preview: Off"
+                        };
+                        set_toast_message.set(Some(msg.to_string()));
+                    });
+                }
+                "b" | "B" => {
+                    // Toggle bookmarks panel
+                    set_bookmarks_visible.update(|v| *v = !*v);
+                }
+                "l" | "L" => {
+                    // Open the go-to-location dialog
+                    e.prevent_default();
+                    set_goto_visible.set(true);
+                }
+                "Escape" if julia_c.with_untracked(Option::is_some) => {
+                    set_julia_c.set(None);
+                }
+                // Subdivide quadtree (only when x-ray enabled)
+                "d" | "D" if xray_enabled.get_untracked() => {
+                    set_subdivide_trigger.update(|v| *v = v.wrapping_add(1));
+                }
+                // Cycle tile heatmap (only when x-ray enabled)
+                "h" | "H" if xray_enabled.get_untracked() => {
+                    set_heatmap_mode.update(|mode| {
+                        *mode = mode.next();
+                        set_toast_message.set(Some(format!("Heatmap: {}", mode.label())));
+                    });
                 }
                 "ArrowLeft" => {
                     // Previous palette (using ordered list)
@@ -429,6 +840,10 @@ pub fn App() -> impl IntoView {
                         set_toast_message.set(Some(msg.to_string()));
                     });
                 }
+                "k" | "K" => {
+                    // Unskew the view around its center
+                    on_unskew.call(());
+                }
                 "h" | "H" => {
                     // Toggle force HDRFloat mode
                     set_render_settings.update(|settings| {
@@ -495,11 +910,23 @@ pub fn App() -> impl IntoView {
             config=config.into()
             on_resize=on_resize
             on_progress_signal=on_progress_signal
+            on_stats_signal=on_stats_signal
             cancel_trigger=cancel_trigger
             subdivide_trigger=subdivide_trigger
+            unskew_trigger=unskew_trigger
+            on_unskew_estimate=on_unskew_estimate
             xray_enabled=xray_enabled
+            heatmap_mode=heatmap_mode
+            inspector_enabled=inspector_enabled
+            on_inspect=on_inspect
+            orbit_enabled=orbit_enabled
+            julia_enabled=julia_enabled
+            on_julia_hover=on_julia_hover
+            on_julia_open=on_julia_open
             palette=render_palette
             render_settings=render_settings.into()
+            cycle_phase=cycle_phase.into()
+            canvas_ref=canvas_ref
         />
         <UIPanel
             viewport=viewport.into()
@@ -551,6 +978,20 @@ pub fn App() -> impl IntoView {
             })
             cpu_threads_at_min=cpu_threads_at_min
             cpu_threads_at_max=cpu_threads_at_max
+            shared_memory=shared_memory.into()
+            on_shared_memory_toggle=Callback::new(move |_| {
+                set_shared_memory.update(|enabled| {
+                    *enabled = !*enabled;
+                    set_shared_memory_enabled(*enabled);
+                    let msg = match (*enabled, shared_memory_available()) {
+                        (false, _) => "Shared Memory: Off",
+                        (true, true) => "Shared Memory: On",
+                        // index.html installs the isolation service worker on load
+                        (true, false) => "Shared Memory: On after reload",
+                    };
+                    set_toast_message.set(Some(msg.to_string()));
+                });
+            })
             render_progress=render_progress.into()
             is_visible=ui_visibility.is_visible
             set_is_hovering=ui_visibility.set_is_hovering
@@ -569,6 +1010,28 @@ pub fn App() -> impl IntoView {
                     set_toast_message.set(Some(msg.to_string()));
                 });
             })
+            zoom_stable=Signal::derive(move || render_settings.get().zoom_stable)
+            on_zoom_stable_toggle=Callback::new(move |_| {
+                set_render_settings.update(|settings| {
+                    settings.zoom_stable = !settings.zoom_stable;
+                    let msg = if settings.zoom_stable {
+                        "Zoom-Stable Colors: On"
+                    } else {
+                        "Zoom-Stable Colors: Off"
+                    };
+                    set_toast_message.set(Some(msg.to_string()));
+                });
+            })
+            color_cycle=color_cycle.into()
+            on_color_cycle_change=Callback::new(move |cycle: ColorCycle| color_cycle.set(cycle))
+            cycle_phase=cycle_phase.into()
+            on_cycle_phase_change=Callback::new(move |phase: f64| cycle_phase.set(phase))
+            skewed=skewed
+            on_unskew=on_unskew
+            on_export_path=on_export_path
+            exporting=exporting.into()
+            on_export_frames=on_export_frames
+            on_import_path=on_import_path
             on_edit=on_palette_edit
             on_palette_reorder=on_palette_reorder
         />
@@ -586,5 +1049,43 @@ pub fn App() -> impl IntoView {
             progress=render_progress.into()
             is_ui_visible=ui_visibility.is_visible
         />
+        <PixelInspectorPanel
+            inspection=inspection.into()
+            visible=inspector_enabled.into()
+        />
+        <JuliaPreview
+            c=julia_point.into()
+            visible=julia_enabled.into()
+            palette=render_palette
+            render_settings=render_settings.into()
+        />
+        <JuliaFullscreen
+            c=julia_c.into()
+            on_close=on_julia_close
+            palette=render_palette
+            render_settings=render_settings.into()
+        />
+        <BookmarksPanel
+            bookmarks=bookmarks.into()
+            visible=bookmarks_visible.into()
+            on_add=on_bookmark_add
+            on_open=on_bookmark_open
+            on_delete=on_bookmark_delete
+            on_export=on_bookmarks_export
+            on_import=on_bookmarks_import
+        />
+        <GoToDialog
+            visible=goto_visible.into()
+            viewport=viewport.into()
+            reference_width=reference_width
+            canvas_size=canvas_size.into()
+            on_go=on_goto
+            on_close=on_goto_close
+        />
+        <RenderStatsPanel
+            stats=render_stats.into()
+            heatmap_mode=heatmap_mode.into()
+            visible=xray_enabled.into()
+        />
     }
 }
@@ -0,0 +1,153 @@
+//! Bookmarks library: save, search, open, import and export named views.
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlInputElement;
+
+use crate::hooks::{parse_tags, Bookmark};
+
+/// Creation date as "YYYY-MM-DD HH:MM" in UTC.
+fn format_created(created_ms: f64) -> String {
+    let iso: String = js_sys::Date::new(&created_ms.into()).to_iso_string().into();
+    iso.get(..16).unwrap_or(&iso).replace('T', " ")
+}
+
+/// Bookmarks panel, shown while bookmarks mode is on.
+#[component]
+pub fn BookmarksPanel(
+    bookmarks: Signal<Vec<Bookmark>>,
+    visible: Signal<bool>,
+    /// Save the current view under a name and tags
+    on_add: Callback<(String, Vec<String>)>,
+    on_open: Callback<Bookmark>,
+    /// Delete the bookmark at an index into `bookmarks`
+    on_delete: Callback<usize>,
+    on_export: Callback<()>,
+    /// Contents of a file chosen for import
+    on_import: Callback<String>,
+) -> impl IntoView {
+    let (name, set_name) = create_signal(String::new());
+    let (tags, set_tags) = create_signal(String::new());
+    let (query, set_query) = create_signal(String::new());
+
+    let add = move || {
+        on_add.call((name.get_untracked(), parse_tags(&tags.get_untracked())));
+        set_name.set(String::new());
+        set_tags.set(String::new());
+    };
+
+    let on_file = move |ev: web_sys::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        // Allow choosing the same file again
+        input.set_value("");
+        spawn_local(async move {
+            match JsFuture::from(file.text()).await {
+                Ok(text) => on_import.call(text.as_string().unwrap_or_default()),
+                Err(e) => log::error!("Failed to read bookmarks file: {e:?}"),
+            }
+        });
+    };
+
+    let input_class = "w-full px-2 py-1 rounded bg-white/10 text-white placeholder-white/40 outline-none focus:bg-white/20";
+    let button_class = "px-2 py-1 rounded bg-white/10 hover:bg-white/20 cursor-pointer";
+
+    view! {
+        <Show when=move || visible.get()>
+            <div class="fixed top-4 right-4 z-50 w-80 max-h-[75vh] flex flex-col px-3 py-2 rounded-lg bg-black/80 text-white text-xs space-y-2">
+                <div class="text-white/60 uppercase tracking-wide font-mono">"Bookmarks"</div>
+                <div class="space-y-1">
+                    <input
+                        class=input_class
+                        placeholder="Name"
+                        prop:value=name
+                        on:input=move |ev| set_name.set(event_target_value(&ev))
+                        on:keydown=move |ev| if ev.key() == "Enter" { add() }
+                    />
+                    <input
+                        class=input_class
+                        placeholder="Tags, comma separated"
+                        prop:value=tags
+                        on:input=move |ev| set_tags.set(event_target_value(&ev))
+                        on:keydown=move |ev| if ev.key() == "Enter" { add() }
+                    />
+                    <button class=button_class on:click=move |_| add()>
+                        "Bookmark this view"
+                    </button>
+                </div>
+                <input
+                    class=input_class
+                    placeholder="Search names and tags"
+                    prop:value=query
+                    on:input=move |ev| set_query.set(event_target_value(&ev))
+                />
+                <div class="flex-1 overflow-y-auto space-y-1">
+                    {move || {
+                        let query = query.get();
+                        let shown: Vec<(usize, Bookmark)> = bookmarks
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .filter(|(_, b)| b.matches(&query))
+                            .collect();
+                        if shown.is_empty() {
+                            return view! { <div class="text-white/60">"No bookmarks"</div> }
+                                .into_view();
+                        }
+                        shown
+                            .into_iter()
+                            .map(|(index, bookmark)| {
+                                let open = bookmark.clone();
+                                let tags = bookmark.tags.join(", ");
+                                view! {
+                                    <div
+                                        class="flex gap-2 p-1 rounded hover:bg-white/10 cursor-pointer"
+                                        on:click=move |_| on_open.call(open.clone())
+                                    >
+                                        {bookmark.thumbnail.clone().map(|src| view! {
+                                            <img src=src class="w-20 h-auto rounded shrink-0" />
+                                        })}
+                                        <div class="flex-1 min-w-0">
+                                            <div class="truncate">{bookmark.name.clone()}</div>
+                                            <div class="truncate text-white/60">{tags}</div>
+                                            <div class="text-white/40 font-mono">
+                                                {format_created(bookmark.created_ms)}
+                                            </div>
+                                        </div>
+                                        <button
+                                            class="self-start px-1 text-white/40 hover:text-white"
+                                            title="Delete"
+                                            on:click=move |ev| {
+                                                ev.stop_propagation();
+                                                on_delete.call(index);
+                                            }
+                                        >
+                                            "×"
+                                        </button>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+                <div class="flex gap-2">
+                    <button class=button_class on:click=move |_| on_export.call(())>
+                        "Export"
+                    </button>
+                    <label class=button_class>
+                        "Import"
+                        <input type="file" accept="application/json,.json" class="hidden" on:change=on_file />
+                    </label>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,6 +1,6 @@
 use leptos::*;
 
-use crate::rendering::RenderProgress;
+use crate::rendering::{format_duration, RenderProgress};
 
 /// Generate SVG path for pie chart based on percentage.
 fn create_pie_path(percent: f64) -> String {
@@ -43,6 +43,15 @@ pub fn CircularProgress(
         p.total_steps > 0 && !p.is_complete && !is_ui_visible.get()
     });
 
+    // Remaining time, once the first tiles give a basis for it
+    let eta_label = move || {
+        let progress_signal = progress.get();
+        progress_signal
+            .get()
+            .remaining_ms
+            .map(|ms| format!("~{}", format_duration(ms)))
+    };
+
     let opacity_class = move || {
         if should_show.get() {
             "opacity-100"
@@ -54,7 +63,7 @@ pub fn CircularProgress(
     view! {
         <div
             class=move || format!(
-                "fixed left-[28px] bottom-[24px] transition-opacity duration-300 pointer-events-none {}",
+                "fixed left-[28px] bottom-[24px] flex items-center gap-2 transition-opacity duration-300 pointer-events-none {}",
                 opacity_class()
             )
         >
@@ -78,6 +87,11 @@ pub fn CircularProgress(
                     />
                 </svg>
             </div>
+            {move || eta_label().map(|eta| view! {
+                <span class="px-1.5 py-0.5 rounded bg-black/50 backdrop-blur-sm text-white/80 text-xs font-mono">
+                    {eta}
+                </span>
+            })}
         </div>
     }
 }
@@ -0,0 +1,200 @@
+//! "Go to location" dialog for typing or pasting exact coordinates.
+
+use fractalwonder_core::{BigFloat, Location, LocationScale, Viewport};
+use leptos::*;
+use wasm_bindgen_futures::JsFuture;
+
+/// Copy `text` to the clipboard, reporting the outcome in `set_status`.
+fn copy_to_clipboard(text: String, set_status: WriteSignal<Option<String>>) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let promise = window.navigator().clipboard().write_text(&text);
+    spawn_local(async move {
+        let status = match JsFuture::from(promise).await {
+            Ok(_) => "Copied".to_string(),
+            Err(e) => {
+                log::error!("Failed to copy location: {e:?}");
+                "Copy failed".to_string()
+            }
+        };
+        set_status.set(Some(status));
+    });
+}
+
+/// Modal dialog to enter a center and zoom or width as decimal strings.
+#[component]
+pub fn GoToDialog(
+    visible: Signal<bool>,
+    /// Current view, used to fill the fields and for copying
+    viewport: Signal<Viewport>,
+    /// Width at zoom 1
+    reference_width: Signal<BigFloat>,
+    canvas_size: Signal<(u32, u32)>,
+    /// Called with the viewport of a valid location
+    on_go: Callback<Viewport>,
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let (re, set_re) = create_signal(String::new());
+    let (im, set_im) = create_signal(String::new());
+    // "zoom", "width" or "kf", the variants of `LocationScale`
+    let (scale_kind, set_scale_kind) = create_signal("zoom".to_string());
+    let (scale, set_scale) = create_signal(String::new());
+    let (pasted, set_pasted) = create_signal(String::new());
+    let (status, set_status) = create_signal::<Option<String>>(None);
+
+    let fill = move |location: Location| {
+        let (kind, value) = match location.scale {
+            LocationScale::Zoom(v) => ("zoom", v),
+            LocationScale::Width(v) => ("width", v),
+            LocationScale::KfZoom(v) => ("kf", v),
+        };
+        set_re.set(location.re);
+        set_im.set(location.im);
+        set_scale_kind.set(kind.to_string());
+        set_scale.set(value);
+    };
+
+    // Start from the current view each time the dialog opens
+    create_effect(move |_| {
+        if visible.get() {
+            fill(Location::from_viewport(
+                &viewport.get_untracked(),
+                &reference_width.get_untracked(),
+            ));
+            set_pasted.set(String::new());
+            set_status.set(None);
+        }
+    });
+
+    let on_paste = move |ev| {
+        let text = event_target_value(&ev);
+        if text.trim().is_empty() {
+            set_status.set(None);
+        } else {
+            match Location::parse(&text) {
+                Ok(location) => {
+                    fill(location);
+                    set_status.set(None);
+                }
+                Err(e) => set_status.set(Some(e)),
+            }
+        }
+        set_pasted.set(text);
+    };
+
+    let go = move || {
+        let value = scale.get_untracked();
+        let location = Location {
+            re: re.get_untracked(),
+            im: im.get_untracked(),
+            scale: match scale_kind.get_untracked().as_str() {
+                "width" => LocationScale::Width(value),
+                "kf" => LocationScale::KfZoom(value),
+                _ => LocationScale::Zoom(value),
+            },
+        };
+        match location.to_viewport(
+            &reference_width.get_untracked(),
+            canvas_size.get_untracked(),
+        ) {
+            Ok(viewport) => on_go.call(viewport),
+            Err(e) => set_status.set(Some(e)),
+        }
+    };
+
+    let copy = move |kf: bool| {
+        let viewport = viewport.get_untracked();
+        let location = if kf {
+            Location::kf_from_viewport(&viewport)
+        } else {
+            Location::from_viewport(&viewport, &reference_width.get_untracked())
+        };
+        copy_to_clipboard(location.to_string(), set_status);
+    };
+
+    let on_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "Escape" => on_close.call(()),
+        "Enter" if !ev.shift_key() => {
+            ev.prevent_default();
+            go();
+        }
+        _ => {}
+    };
+
+    let input_class = "w-full px-2 py-1 rounded bg-white/10 text-white font-mono placeholder-white/40 outline-none focus:bg-white/20";
+    let button_class = "px-3 py-1.5 rounded-lg border border-white/20 text-white text-sm hover:bg-white/10 transition-colors";
+
+    view! {
+        <Show when=move || visible.get()>
+            // Backdrop
+            <div
+                class="fixed inset-0 z-[100] bg-black/50 backdrop-blur-sm flex items-center justify-center"
+                on:click=move |_| on_close.call(())
+            >
+                // Dialog
+                <div
+                    class="bg-black/95 border border-white/10 rounded-lg p-4 w-full max-w-lg mx-4 space-y-3 text-xs text-white"
+                    on:click=|e| e.stop_propagation()
+                    on:keydown=on_keydown
+                >
+                    <h3 class="text-sm font-medium">"Go to Location"</h3>
+                    <label class="block space-y-1">
+                        <span class="text-white/60">"Real"</span>
+                        <input
+                            class=input_class
+                            prop:value=re
+                            on:input=move |ev| set_re.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <label class="block space-y-1">
+                        <span class="text-white/60">"Imaginary"</span>
+                        <input
+                            class=input_class
+                            prop:value=im
+                            on:input=move |ev| set_im.set(event_target_value(&ev))
+                        />
+                    </label>
+                    <div class="flex gap-2 items-end">
+                        <select
+                            class="px-2 py-1 rounded bg-white/10 text-white outline-none"
+                            prop:value=scale_kind
+                            on:change=move |ev| set_scale_kind.set(event_target_value(&ev))
+                        >
+                            <option value="zoom">"Zoom"</option>
+                            <option value="width">"Width"</option>
+                            <option value="kf">"Zoom (KF)"</option>
+                        </select>
+                        <input
+                            class=input_class
+                            placeholder="1e500"
+                            prop:value=scale
+                            on:input=move |ev| set_scale.set(event_target_value(&ev))
+                        />
+                    </div>
+                    <textarea
+                        class=format!("{input_class} h-20 resize-none")
+                        placeholder="Paste \"re, im, zoom=1e500\" or a KF Re:/Im:/Zoom: block"
+                        prop:value=pasted
+                        on:input=on_paste
+                    />
+                    {move || status.get().map(|s| view! { <p class="text-white/70">{s}</p> })}
+                    <div class="flex gap-2">
+                        <button class=button_class on:click=move |_| copy(false)>"Copy"</button>
+                        <button class=button_class on:click=move |_| copy(true)>"Copy KF"</button>
+                        <div class="flex-1" />
+                        <button class=button_class on:click=move |_| on_close.call(())>
+                            "Cancel"
+                        </button>
+                        <button
+                            class="px-3 py-1.5 rounded-lg bg-white/20 text-white text-sm hover:bg-white/30 transition-colors"
+                            on:click=move |_| go()
+                        >
+                            "Go"
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
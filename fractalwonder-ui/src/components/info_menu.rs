@@ -1,5 +1,6 @@
 // fractalwonder-ui/src/components/info_menu.rs
 use crate::components::Menu;
+use crate::rendering::{format_duration, format_iteration_rate, RenderProgress};
 use leptos::*;
 
 #[component]
@@ -22,8 +23,50 @@ fn GithubIcon() -> impl IntoView {
     }
 }
 
+/// Lines describing the current render: time, remaining time and speed.
+fn render_stats_lines(progress: &RenderProgress) -> Vec<String> {
+    if progress.total_steps == 0 {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    if progress.is_complete {
+        lines.push(format!(
+            "Rendered in {}",
+            format_duration(progress.elapsed_ms)
+        ));
+    } else {
+        let remaining = progress
+            .remaining_ms
+            .map(|ms| format!(", ~{} left", format_duration(ms)))
+            .unwrap_or_default();
+        lines.push(format!(
+            "Rendering {}/{} ({}{})",
+            progress.completed_steps,
+            progress.total_steps,
+            format_duration(progress.elapsed_ms),
+            remaining
+        ));
+    }
+    if let Some(rate) = progress.iterations_per_second() {
+        let bla = progress
+            .bla_percentage()
+            .filter(|pct| *pct > 0.0)
+            .map(|pct| format!(", {:.0}% BLA", pct))
+            .unwrap_or_default();
+        lines.push(format!("{}{}", format_iteration_rate(rate), bla));
+    }
+    lines
+}
+
 #[component]
-pub fn InfoMenu(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) -> impl IntoView {
+pub fn InfoMenu(
+    is_open: ReadSignal<bool>,
+    set_is_open: WriteSignal<bool>,
+    render_progress: Signal<RwSignal<RenderProgress>>,
+) -> impl IntoView {
+    let stats_lines = move || render_stats_lines(&render_progress.get().get());
+
     view! {
         <Menu is_open=is_open set_is_open=set_is_open icon=|| view! { <InfoIcon /> }>
             <div class="p-4 text-white">
@@ -32,6 +75,16 @@ pub fn InfoMenu(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) -> im
                     "Use mouse/touch to pan and zoom."
                 </p>
 
+                <Show when=move || !stats_lines().is_empty()>
+                    <div class="text-xs font-mono text-gray-300 border-t border-gray-700 pt-3 mb-3 space-y-1">
+                        <For
+                            each=stats_lines
+                            key=|line| line.clone()
+                            children=|line| view! { <div>{line}</div> }
+                        />
+                    </div>
+                </Show>
+
                 <div class="flex items-center gap-2 text-sm text-gray-400 border-t border-gray-700 pt-3">
                     <a
                         href="https://github.com/gertalot/fractalwonder"
@@ -47,3 +100,28 @@ pub fn InfoMenu(is_open: ReadSignal<bool>, set_is_open: WriteSignal<bool>) -> im
         </Menu>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_show_estimate_while_rendering() {
+        let mut progress = RenderProgress::new(64);
+        assert!(render_stats_lines(&RenderProgress::default()).is_empty());
+        assert_eq!(render_stats_lines(&progress), vec!["Rendering 0/64 (0.0s)"]);
+
+        progress.completed_steps = 16;
+        progress.elapsed_ms = 2_000.0;
+        progress.remaining_ms = Some(6_500.0);
+        progress.iterations = 4_000_000_000;
+        progress.bla_iterations = 3_000_000_000;
+        assert_eq!(
+            render_stats_lines(&progress),
+            vec!["Rendering 16/64 (2.0s, ~6.5s left)", "2.0G it/s, 75% BLA"]
+        );
+
+        progress.is_complete = true;
+        assert_eq!(render_stats_lines(&progress)[0], "Rendered in 2.0s");
+    }
+}
@@ -2,7 +2,7 @@
 use crate::config::FractalConfig;
 use crate::hooks::use_canvas_interaction;
 use crate::rendering::colorizers::{Palette, RenderSettings};
-use crate::rendering::ParallelRenderer;
+use crate::rendering::{HeatmapMode, ParallelRenderer, PixelInspection, RenderStats};
 use fractalwonder_core::{apply_pixel_transform_to_viewport, Viewport};
 use leptos::*;
 use wasm_bindgen::JsCast;
@@ -22,23 +22,59 @@ pub fn InteractiveCanvas(
     /// Callback fired with progress signal when renderer is created
     #[prop(optional)]
     on_progress_signal: Option<Callback<RwSignal<crate::rendering::RenderProgress>>>,
+    /// Callback fired with the render stats signal when renderer is created
+    #[prop(optional)]
+    on_stats_signal: Option<Callback<RwSignal<RenderStats>>>,
     /// Signal that triggers render cancellation when incremented
     #[prop(optional)]
     cancel_trigger: Option<ReadSignal<u32>>,
     /// Signal that triggers quadtree subdivision when incremented
     #[prop(optional)]
     subdivide_trigger: Option<ReadSignal<u32>>,
+    /// Signal that triggers a skew estimate of the current image when incremented
+    #[prop(optional)]
+    unskew_trigger: Option<ReadSignal<u32>>,
+    /// Callback fired with the estimated unskew stretch, None if there is none
+    #[prop(optional)]
+    on_unskew_estimate: Option<Callback<Option<[[f64; 2]; 2]>>>,
     /// X-ray mode enabled signal
     #[prop(optional)]
     xray_enabled: Option<ReadSignal<bool>>,
+    /// Tile heatmap drawn over the image
+    #[prop(optional)]
+    heatmap_mode: Option<ReadSignal<HeatmapMode>>,
+    /// Pixel inspector mode enabled signal
+    #[prop(optional)]
+    inspector_enabled: Option<ReadSignal<bool>>,
+    /// Callback fired with the hovered or pinned pixel in inspector mode
+    #[prop(optional)]
+    on_inspect: Option<Callback<Option<PixelInspection>>>,
+    /// Orbit mode enabled signal; clicking a pixel traces its iteration path
+    #[prop(optional)]
+    orbit_enabled: Option<ReadSignal<bool>>,
+    /// Julia mode enabled signal; hovering previews, clicking opens a Julia set
+    #[prop(optional)]
+    julia_enabled: Option<ReadSignal<bool>>,
+    /// Callback fired with the hovered point in Julia mode
+    #[prop(optional)]
+    on_julia_hover: Option<Callback<Option<(f64, f64)>>>,
+    /// Callback fired with the clicked point in Julia mode
+    #[prop(optional)]
+    on_julia_open: Option<Callback<(f64, f64)>>,
     /// Palette signal
     #[prop(optional)]
     palette: Option<Signal<Palette>>,
     /// Render settings signal
     #[prop(optional)]
     render_settings: Option<Signal<RenderSettings>>,
+    /// Palette color-cycling phase signal
+    #[prop(optional)]
+    cycle_phase: Option<Signal<f64>>,
+    /// Ref to attach to the canvas, for callers that read the image
+    #[prop(optional)]
+    canvas_ref: Option<NodeRef<leptos::html::Canvas>>,
 ) -> impl IntoView {
-    let canvas_ref = create_node_ref::<leptos::html::Canvas>();
+    let canvas_ref = canvas_ref.unwrap_or_else(create_node_ref::<leptos::html::Canvas>);
 
     // Store canvas size for use in callbacks
     let canvas_size = create_rw_signal((0u32, 0u32));
@@ -57,6 +93,9 @@ pub fn InteractiveCanvas(
     if let Some(callback) = on_progress_signal {
         renderer.with_value(|r| callback.call(r.progress()));
     }
+    if let Some(callback) = on_stats_signal {
+        renderer.with_value(|r| callback.call(r.stats()));
+    }
 
     // Switch renderer when config changes
     create_effect(move |_| {
@@ -97,6 +136,17 @@ pub fn InteractiveCanvas(
         });
     }
 
+    // Watch for skew estimate requests
+    if let (Some(trigger), Some(callback)) = (unskew_trigger, on_unskew_estimate) {
+        create_effect(move |prev: Option<u32>| {
+            let current = trigger.get();
+            if prev.is_some() && prev != Some(current) {
+                callback.call(renderer.with_value(|r| r.estimate_unskew()));
+            }
+            current
+        });
+    }
+
     // Watch for xray mode changes - update renderer and recolorize
     if let Some(xray) = xray_enabled {
         create_effect(move |prev: Option<bool>| {
@@ -111,6 +161,19 @@ pub fn InteractiveCanvas(
         });
     }
 
+    // Watch for heatmap changes - the overlay is drawn on recolorize
+    if let Some(mode_signal) = heatmap_mode {
+        create_effect(move |prev: Option<HeatmapMode>| {
+            let mode = mode_signal.get();
+            renderer.with_value(|r| r.set_heatmap_mode(mode));
+
+            if prev.is_some() && prev != Some(mode) {
+                renderer.with_value(|r| r.recolorize());
+            }
+            mode
+        });
+    }
+
     // Watch for palette changes - update renderer and recolorize
     if let Some(palette_signal) = palette {
         create_effect(move |prev: Option<Palette>| {
@@ -118,9 +181,24 @@ pub fn InteractiveCanvas(
 
             renderer.with_value(|r| r.set_palette(pal.clone()));
 
-            // Recolorize when palette changes (not on initial mount)
-            if prev.is_some() && prev.as_ref() != Some(&pal) {
-                renderer.with_value(|r| r.recolorize());
+            // Recolorize when palette changes (not on initial mount).
+            // Switching to or from a CPU-only interior mode, or starting or
+            // stopping to use orbit statistics, needs a full re-render.
+            if let Some(prev_pal) = prev.as_ref().filter(|p| *p != &pal) {
+                if prev_pal.interior.mode.requires_cpu() != pal.interior.mode.requires_cpu()
+                    || prev_pal.needs_orbit_stats() != pal.needs_orbit_stats()
+                {
+                    let vp = viewport.get_untracked();
+                    let size = canvas_size.get_untracked();
+                    if size.0 > 0 && size.1 > 0 {
+                        if let Some(canvas_el) = canvas_ref.get_untracked() {
+                            let canvas = canvas_el.unchecked_ref::<HtmlCanvasElement>();
+                            renderer.with_value(|r| r.render(&vp, canvas));
+                        }
+                    }
+                } else {
+                    renderer.with_value(|r| r.recolorize());
+                }
             }
 
             pal
@@ -156,6 +234,19 @@ pub fn InteractiveCanvas(
         });
     }
 
+    // Watch for color-cycling phase changes - recolorize with the cached histogram
+    if let Some(phase_signal) = cycle_phase {
+        create_effect(move |prev: Option<f64>| {
+            let phase = phase_signal.get();
+            renderer.with_value(|r| r.set_cycle_phase(phase));
+
+            if prev.is_some() && prev != Some(phase) {
+                renderer.with_value(|r| r.recolorize_cycle());
+            }
+            phase
+        });
+    }
+
     // Wire up interaction hook with cancel on start
     let interaction = use_canvas_interaction(
         canvas_ref,
@@ -229,6 +320,108 @@ pub fn InteractiveCanvas(
         is_interacting
     });
 
+    // Pixel inspector: hovering shows a pixel, a click without drag pins it
+    let inspector_pinned = store_value(false);
+    let click_press = store_value::<Option<(i32, i32)>>(None);
+    let inspector_active = move || inspector_enabled.is_some_and(|e| e.get_untracked());
+    let orbit_active = move || orbit_enabled.is_some_and(|e| e.get_untracked());
+    let julia_active = move || julia_enabled.is_some_and(|e| e.get_untracked());
+
+    // Canvas pixel under a mouse event
+    let event_pixel = move |ev: &web_sys::MouseEvent| -> Option<(u32, u32)> {
+        let canvas_el = canvas_ref.get_untracked()?;
+        let canvas = canvas_el.unchecked_ref::<HtmlCanvasElement>();
+        if canvas.client_width() <= 0 || canvas.client_height() <= 0 {
+            return None;
+        }
+        // Event offsets are CSS pixels; the renderer works in canvas pixels
+        let x = ev.offset_x() as f64 * canvas.width() as f64 / canvas.client_width() as f64;
+        let y = ev.offset_y() as f64 * canvas.height() as f64 / canvas.client_height() as f64;
+        (x >= 0.0 && y >= 0.0).then_some((x as u32, y as u32))
+    };
+
+    let inspect_at = move |ev: &web_sys::MouseEvent, with_coordinate: bool| {
+        let Some((x, y)) = event_pixel(ev) else {
+            return;
+        };
+        let inspection = renderer.with_value(|r| r.inspect_pixel(x, y, with_coordinate));
+        if let Some(callback) = on_inspect {
+            callback.call(inspection);
+        }
+    };
+
+    let on_canvas_move = move |ev: web_sys::PointerEvent| {
+        if inspector_active() && !inspector_pinned.get_value() {
+            inspect_at(&ev, false);
+        }
+        if julia_active() {
+            if let (Some(callback), Some((x, y))) = (on_julia_hover, event_pixel(&ev)) {
+                callback.call(renderer.with_value(|r| r.pixel_coordinate_f64(x, y)));
+            }
+        }
+    };
+    let on_canvas_down = move |ev: web_sys::PointerEvent| {
+        click_press.set_value(Some((ev.client_x(), ev.client_y())));
+    };
+    let on_canvas_click = move |ev: web_sys::MouseEvent| {
+        // Drags end in a click too
+        let moved = click_press
+            .get_value()
+            .is_none_or(|(x, y)| (ev.client_x() - x).abs() > 3 || (ev.client_y() - y).abs() > 3);
+        if moved {
+            return;
+        }
+        if orbit_active() {
+            if let Some((x, y)) = event_pixel(&ev) {
+                renderer.with_value(|r| r.trace_orbit(x, y));
+            }
+        }
+        if julia_active() {
+            let point = event_pixel(&ev)
+                .and_then(|(x, y)| renderer.with_value(|r| r.pixel_coordinate_f64(x, y)));
+            if let (Some(callback), Some(point)) = (on_julia_open, point) {
+                callback.call(point);
+            }
+        }
+        if inspector_active() {
+            let pin = !inspector_pinned.get_value();
+            inspector_pinned.set_value(pin);
+            inspect_at(&ev, pin);
+        }
+    };
+
+    // Leaving inspector mode drops the pin
+    if let Some(enabled) = inspector_enabled {
+        create_effect(move |_| {
+            if !enabled.get() {
+                inspector_pinned.set_value(false);
+                if let Some(callback) = on_inspect {
+                    callback.call(None);
+                }
+            }
+        });
+    }
+
+    // Redraw when a traced orbit arrives or is removed; a render still in
+    // progress draws it when it completes
+    let orbit_overlay = renderer.with_value(|r| r.orbit_overlay());
+    create_effect(move |prev: Option<()>| {
+        orbit_overlay.track();
+        let complete = renderer.with_value(|r| r.progress().get_untracked().is_complete);
+        if prev.is_some() && complete {
+            renderer.with_value(|r| r.recolorize());
+        }
+    });
+
+    // Leaving orbit mode removes the path
+    if let Some(enabled) = orbit_enabled {
+        create_effect(move |_| {
+            if !enabled.get() && orbit_overlay.with_untracked(Option::is_some) {
+                renderer.with_value(|r| r.clear_orbit_overlay());
+            }
+        });
+    }
+
     // Render effect - triggers async render on viewport change
     create_effect(move |_| {
         let vp = viewport.get();
@@ -245,10 +438,24 @@ pub fn InteractiveCanvas(
 
         // Start async render (previous render is auto-cancelled)
         renderer.with_value(|r| r.render(&vp, canvas));
+
+        // A pinned pixel refers to the previous image
+        if inspector_pinned.get_value() {
+            inspector_pinned.set_value(false);
+            if let Some(callback) = on_inspect {
+                callback.call(None);
+            }
+        }
     });
 
     view! {
-        <canvas node_ref=canvas_ref class="block" />
+        <canvas
+            node_ref=canvas_ref
+            class="block touch-none"
+            on:pointermove=on_canvas_move
+            on:pointerdown=on_canvas_down
+            on:click=on_canvas_click
+        />
     }
     .into_view()
 }
@@ -0,0 +1,114 @@
+//! Full-screen Julia set for a c picked from the Mandelbrot view.
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlCanvasElement;
+
+use crate::rendering::colorizers::{ColorPipeline, Palette, RenderSettings};
+use crate::rendering::{draw_julia_progressive, get_2d_context, JULIA_FULL_ITERATIONS};
+
+/// Julia set for `c` over the whole window.
+///
+/// A fixed preview of the whole set rather than a navigable view: `c` is the
+/// picked pixel rounded to f64, which is all a whole-set render resolves, and
+/// the view cannot be zoomed or panned.
+#[component]
+pub fn JuliaFullscreen(
+    /// Parameter of the open Julia set, None when closed
+    c: Signal<Option<(f64, f64)>>,
+    on_close: Callback<()>,
+    palette: Signal<Palette>,
+    render_settings: Signal<RenderSettings>,
+) -> impl IntoView {
+    let canvas_ref = create_node_ref::<leptos::html::Canvas>();
+    // Bumped per render so a superseded one stops at its next yield
+    let generation = store_value(0u32);
+    let (rendering, set_rendering) = create_signal(false);
+
+    create_effect(move |_| {
+        let (Some(c), Some(canvas_el)) = (c.get(), canvas_ref.get()) else {
+            return;
+        };
+        let canvas = canvas_el.unchecked_ref::<HtmlCanvasElement>();
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let width = window
+            .inner_width()
+            .ok()
+            .and_then(|w| w.as_f64())
+            .unwrap_or(0.0) as u32;
+        let height = window
+            .inner_height()
+            .ok()
+            .and_then(|h| h.as_f64())
+            .unwrap_or(0.0) as u32;
+        if width == 0 || height == 0 {
+            return;
+        }
+        canvas.set_width(width);
+        canvas.set_height(height);
+        let Ok(ctx) = get_2d_context(canvas) else {
+            return;
+        };
+
+        generation.update_value(|g| *g = g.wrapping_add(1));
+        let current = generation.get_value();
+        let pipeline = ColorPipeline::new(palette.get(), render_settings.get());
+        set_rendering.set(true);
+        spawn_local(async move {
+            let is_current = move || generation.try_get_value() == Some(current);
+            draw_julia_progressive(
+                ctx,
+                pipeline,
+                c,
+                (width, height),
+                JULIA_FULL_ITERATIONS,
+                is_current,
+            )
+            .await;
+            if is_current() {
+                set_rendering.set(false);
+            }
+        });
+    });
+
+    // Closing abandons a render still in progress
+    create_effect(move |_| {
+        if c.with(Option::is_none) {
+            generation.update_value(|g| *g = g.wrapping_add(1));
+        }
+    });
+
+    let coordinate =
+        move || c.with(|c| c.map(|(re, im)| (format!("{re:.17}"), format!("{im:.17}"))));
+
+    view! {
+        <Show when=move || c.with(Option::is_some)>
+            <div class="fixed inset-0 z-[60] bg-black">
+                <canvas node_ref=canvas_ref class="block w-full h-full" />
+                <div class="absolute top-4 left-4 max-w-md max-h-40 overflow-auto px-3 py-2 rounded-lg bg-black/80 text-white text-xs font-mono space-y-0.5">
+                    <div class="text-white/60 uppercase tracking-wide">
+                        {move || if rendering.get() { "Julia Set (rendering…)" } else { "Julia Set" }}
+                    </div>
+                    {move || coordinate().map(|(re, im)| view! {
+                        <div class="flex gap-2">
+                            <span class="w-8 shrink-0 text-white/60">"Re"</span>
+                            <span class="break-all select-text">{re}</span>
+                        </div>
+                        <div class="flex gap-2">
+                            <span class="w-8 shrink-0 text-white/60">"Im"</span>
+                            <span class="break-all select-text">{im}</span>
+                        </div>
+                    })}
+                </div>
+                <button
+                    class="absolute top-4 right-4 px-3 py-1 rounded-lg bg-black/80 text-white text-sm hover:bg-white/20"
+                    on:click=move |_| on_close.call(())
+                >
+                    "Close (Esc)"
+                </button>
+            </div>
+        </Show>
+    }
+}
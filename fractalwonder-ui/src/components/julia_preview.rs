@@ -0,0 +1,71 @@
+//! Small Julia set window following the point under the cursor.
+
+use leptos::*;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlCanvasElement;
+
+use crate::rendering::colorizers::{ColorPipeline, Palette, RenderSettings};
+use crate::rendering::{draw_julia, get_2d_context, JULIA_PREVIEW_ITERATIONS};
+
+/// Preview canvas size in pixels, kept small so it redraws within a frame.
+const PREVIEW_SIZE: (u32, u32) = (192, 144);
+
+/// Julia set for the c under the cursor, shown while Julia mode is on.
+#[component]
+pub fn JuliaPreview(
+    /// Mandelbrot point under the cursor
+    c: Signal<Option<(f64, f64)>>,
+    /// Whether Julia mode is on
+    visible: Signal<bool>,
+    palette: Signal<Palette>,
+    render_settings: Signal<RenderSettings>,
+) -> impl IntoView {
+    let canvas_ref = create_node_ref::<leptos::html::Canvas>();
+    let pipeline = store_value(ColorPipeline::new(
+        palette.get_untracked(),
+        render_settings.get_untracked(),
+    ));
+
+    create_effect(move |_| {
+        pipeline.update_value(|p| p.set_palette(palette.get()));
+    });
+    create_effect(move |_| {
+        pipeline.update_value(|p| p.set_render_settings(render_settings.get()));
+    });
+
+    create_effect(move |_| {
+        palette.track();
+        render_settings.track();
+        let (Some(c), Some(canvas_el)) = (c.get(), canvas_ref.get()) else {
+            return;
+        };
+        let canvas = canvas_el.unchecked_ref::<HtmlCanvasElement>();
+        let Ok(ctx) = get_2d_context(canvas) else {
+            return;
+        };
+        pipeline.update_value(|p| {
+            let _ = draw_julia(&ctx, p, c, PREVIEW_SIZE, JULIA_PREVIEW_ITERATIONS);
+        });
+    });
+
+    let caption = move || match c.get() {
+        Some((re, im)) => format!("c = {re:.6} {im:+.6}i"),
+        None => "Hover the image".to_string(),
+    };
+
+    view! {
+        <Show when=move || visible.get()>
+            <div class="fixed bottom-20 right-4 z-40 p-2 rounded-lg bg-black/80 text-white text-xs font-mono space-y-1 pointer-events-none">
+                <div class="text-white/60 uppercase tracking-wide">"Julia Preview"</div>
+                <canvas
+                    node_ref=canvas_ref
+                    width=PREVIEW_SIZE.0
+                    height=PREVIEW_SIZE.1
+                    class="block rounded"
+                />
+                <div class="text-white/80">{caption}</div>
+                <div class="text-white/40">"Click to open full-screen"</div>
+            </div>
+        </Show>
+    }
+}
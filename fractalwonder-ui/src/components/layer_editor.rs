@@ -0,0 +1,218 @@
+//! Editor for a palette's color layer stack.
+
+use crate::components::{CurveEditor, GradientEditor, LightingSlider};
+use crate::rendering::colorizers::{BlendMode, ColorLayer, Curve, Gradient, LayerSource};
+use leptos::*;
+
+/// Apply `f` to the layer at `index` and emit the updated stack.
+fn update_layer(
+    layers: Signal<Vec<ColorLayer>>,
+    on_change: Callback<Vec<ColorLayer>>,
+    index: usize,
+    f: impl FnOnce(&mut ColorLayer),
+) {
+    let mut new_layers = layers.get_untracked();
+    if let Some(layer) = new_layers.get_mut(index) {
+        f(layer);
+        on_change.call(new_layers);
+    }
+}
+
+/// Layer list with add/remove and controls for the selected layer.
+#[component]
+pub fn LayerEditor(
+    /// Layers, bottom to top
+    layers: Signal<Vec<ColorLayer>>,
+    /// Called when any layer changes
+    on_change: Callback<Vec<ColorLayer>>,
+) -> impl IntoView {
+    let selected = create_rw_signal(None::<usize>);
+
+    // Keep selection valid when the stack shrinks (e.g. palette switched)
+    create_effect(move |_| {
+        let len = layers.get().len();
+        if selected.get_untracked().is_some_and(|i| i >= len) {
+            selected.set(len.checked_sub(1));
+        }
+    });
+
+    let selected_layer =
+        Signal::derive(move || selected.get().and_then(|i| layers.get().get(i).cloned()));
+
+    let on_add = move |_| {
+        let mut new_layers = layers.get_untracked();
+        new_layers.push(ColorLayer::default());
+        selected.set(Some(new_layers.len() - 1));
+        on_change.call(new_layers);
+    };
+
+    let on_remove = move |index: usize| {
+        let mut new_layers = layers.get_untracked();
+        if index < new_layers.len() {
+            new_layers.remove(index);
+            selected.set(None);
+            on_change.call(new_layers);
+        }
+    };
+
+    let on_source_change = move |ev: ev::Event| {
+        let Some(index) = selected.get_untracked() else {
+            return;
+        };
+        let value = event_target_value(&ev);
+        if let Some(source) = LayerSource::ALL.into_iter().find(|s| s.label() == value) {
+            update_layer(layers, on_change, index, |l| l.source = source);
+        }
+    };
+
+    let on_blend_change = move |ev: ev::Event| {
+        let Some(index) = selected.get_untracked() else {
+            return;
+        };
+        let value = event_target_value(&ev);
+        if let Some(mode) = BlendMode::ALL.into_iter().find(|m| m.label() == value) {
+            update_layer(layers, on_change, index, |l| l.blend_mode = mode);
+        }
+    };
+
+    let opacity = Signal::derive(move || selected_layer.get().map(|l| l.opacity).unwrap_or(0.0));
+    let on_opacity_change = Callback::new(move |value: f64| {
+        if let Some(index) = selected.get_untracked() {
+            update_layer(layers, on_change, index, |l| l.opacity = value);
+        }
+    });
+
+    let gradient_signal = Signal::derive(move || selected_layer.get().map(|l| l.gradient));
+    let on_gradient_change = Callback::new(move |gradient: Gradient| {
+        if let Some(index) = selected.get_untracked() {
+            update_layer(layers, on_change, index, |l| l.gradient = gradient);
+        }
+    });
+
+    let curve_signal = Signal::derive(move || selected_layer.get().map(|l| l.transfer_curve));
+    let on_curve_change = Callback::new(move |curve: Curve| {
+        if let Some(index) = selected.get_untracked() {
+            update_layer(layers, on_change, index, |l| l.transfer_curve = curve);
+        }
+    });
+
+    let indexed_layers = move || layers.get().into_iter().enumerate().collect::<Vec<_>>();
+
+    view! {
+        <div class="space-y-1">
+            <For
+                each=indexed_layers
+                key=|(i, layer)| (*i, layer.source, layer.blend_mode, layer.enabled)
+                children=move |(index, layer)| {
+                    let is_selected = move || selected.get() == Some(index);
+                    view! {
+                        <div
+                            class=move || format!(
+                                "flex items-center gap-2 px-2 py-1 rounded cursor-pointer transition-colors {}",
+                                if is_selected() { "bg-white/15" } else { "hover:bg-white/5" }
+                            )
+                            on:click=move |_| selected.set(Some(index))
+                        >
+                            <input
+                                type="checkbox"
+                                class="w-3.5 h-3.5 rounded accent-white"
+                                prop:checked=layer.enabled
+                                on:click=|ev| ev.stop_propagation()
+                                on:change=move |ev| {
+                                    let checked = event_target_checked(&ev);
+                                    update_layer(layers, on_change, index, |l| l.enabled = checked);
+                                }
+                            />
+                            <span class="flex-1 text-white text-sm">
+                                {format!("{} · {}", layer.source.label(), layer.blend_mode.label())}
+                            </span>
+                            <button
+                                class="text-white/50 hover:text-white text-sm px-1"
+                                title="Remove layer"
+                                on:click=move |ev| {
+                                    ev.stop_propagation();
+                                    on_remove(index);
+                                }
+                            >
+                                "×"
+                            </button>
+                        </div>
+                    }
+                }
+            />
+            <button
+                class="w-full px-3 py-1 rounded-lg border border-white/10 text-white text-sm \
+                       hover:bg-white/10 transition-colors"
+                on:click=on_add
+            >
+                "Add Layer"
+            </button>
+        </div>
+
+        <Show when=move || selected_layer.get().is_some()>
+            <div class="space-y-2">
+                <div class="flex items-center gap-2">
+                    <div class="text-white text-xs w-20">"Source"</div>
+                    <select
+                        class="flex-1 bg-white/5 border border-white/20 rounded px-2 py-0.5 \
+                               text-white text-xs outline-none"
+                        on:change=on_source_change
+                    >
+                        {LayerSource::ALL
+                            .into_iter()
+                            .map(|source| view! {
+                                <option
+                                    value=source.label()
+                                    selected=move || selected_layer.get().map(|l| l.source) == Some(source)
+                                >
+                                    {source.label()}
+                                </option>
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+                <div class="flex items-center gap-2">
+                    <div class="text-white text-xs w-20">"Blend"</div>
+                    <select
+                        class="flex-1 bg-white/5 border border-white/20 rounded px-2 py-0.5 \
+                               text-white text-xs outline-none"
+                        on:change=on_blend_change
+                    >
+                        {BlendMode::ALL
+                            .into_iter()
+                            .map(|mode| view! {
+                                <option
+                                    value=mode.label()
+                                    selected=move || selected_layer.get().map(|l| l.blend_mode) == Some(mode)
+                                >
+                                    {mode.label()}
+                                </option>
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+                <LightingSlider
+                    label="Opacity"
+                    value=opacity
+                    on_change=on_opacity_change
+                    min=0.0
+                    max=1.0
+                    step=0.01
+                    precision=2
+                />
+            </div>
+
+            <div class="text-white/50 text-xs px-1">"Layer Gradient"</div>
+            <GradientEditor
+                gradient=gradient_signal
+                on_change=on_gradient_change
+            />
+
+            <div class="text-white/50 text-xs px-1">"Layer Transfer Curve"</div>
+            <CurveEditor
+                curve=curve_signal
+                on_change=on_curve_change
+            />
+        </Show>
+    }
+}
@@ -1,12 +1,17 @@
+mod bookmarks_panel;
 mod circular_progress;
 mod collapsible_section;
 mod confirm_dialog;
 mod curve_editor;
 mod fullscreen_button;
+mod goto_dialog;
 mod gradient_editor;
 mod home_button;
 mod info_menu;
 mod interactive_canvas;
+mod julia_fullscreen;
+mod julia_preview;
+mod layer_editor;
 mod lighting_control;
 mod lighting_slider;
 mod menu;
@@ -14,19 +19,26 @@ mod options_menu;
 mod palette_editor;
 mod palette_editor_state;
 mod palette_menu;
+mod pixel_inspector_panel;
+mod render_stats_panel;
 mod toast;
 mod ui_panel;
 
+pub use bookmarks_panel::BookmarksPanel;
 pub use circular_progress::CircularProgress;
 pub use collapsible_section::CollapsibleSection;
 pub use confirm_dialog::ConfirmDialog;
 #[allow(unused_imports)]
 pub use curve_editor::CurveEditor;
 pub use fullscreen_button::FullscreenButton;
+pub use goto_dialog::GoToDialog;
 pub use gradient_editor::GradientEditor;
 pub use home_button::HomeButton;
 pub use info_menu::InfoMenu;
 pub use interactive_canvas::InteractiveCanvas;
+pub use julia_fullscreen::JuliaFullscreen;
+pub use julia_preview::JuliaPreview;
+pub use layer_editor::LayerEditor;
 #[allow(unused_imports)]
 pub use lighting_control::LightingControl;
 #[allow(unused_imports)]
@@ -36,5 +48,7 @@ pub use options_menu::OptionsMenu;
 pub use palette_editor::PaletteEditor;
 pub use palette_editor_state::{generate_unique_name, EditMode, PaletteEditorState};
 pub use palette_menu::PaletteMenu;
+pub use pixel_inspector_panel::PixelInspectorPanel;
+pub use render_stats_panel::RenderStatsPanel;
 pub use toast::Toast;
 pub use ui_panel::UIPanel;
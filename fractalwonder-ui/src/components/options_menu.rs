@@ -2,7 +2,15 @@
 //! Note: 3D, Smooth, Histogram are palette properties, not options.
 
 use crate::components::{Menu, MenuItem, MenuSection, StepperMenuItem};
+use crate::rendering::colorizers::{wrap_phase, ColorCycle, CycleDirection};
+use leptos::html::Input;
 use leptos::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::HtmlInputElement;
+
+/// Phase change per click of the phase stepper.
+const PHASE_STEP: f64 = 0.05;
 
 #[component]
 pub fn OptionsMenu(
@@ -26,6 +34,10 @@ pub fn OptionsMenu(
     on_cpu_threads_up: Callback<()>,
     /// Callback to decrease CPU threads
     on_cpu_threads_down: Callback<()>,
+    /// Shared memory workers enabled state
+    shared_memory: Signal<bool>,
+    /// Callback when shared memory toggle is clicked
+    on_shared_memory_toggle: Callback<()>,
     /// Whether CPU threads is at minimum bound
     cpu_threads_at_min: Signal<bool>,
     /// Whether CPU threads is at maximum bound
@@ -38,11 +50,71 @@ pub fn OptionsMenu(
     force_hdr_float: Signal<bool>,
     /// Callback when Force HDRFloat toggle is clicked
     on_force_hdr_float_toggle: Callback<()>,
+    /// Zoom-stable coloring enabled state
+    zoom_stable: Signal<bool>,
+    /// Callback when zoom-stable coloring is toggled
+    on_zoom_stable_toggle: Callback<()>,
+    /// Palette color-cycling controls
+    color_cycle: Signal<ColorCycle>,
+    /// Callback when color-cycling controls change
+    on_color_cycle_change: Callback<ColorCycle>,
+    /// Palette color-cycling phase in [0, 1)
+    cycle_phase: Signal<f64>,
+    /// Callback when the phase is stepped manually
+    on_cycle_phase_change: Callback<f64>,
+    /// Whether the view carries a stretch/skew
+    skewed: Signal<bool>,
+    /// Callback to unskew the view around its center
+    on_unskew: Callback<()>,
+    /// Callback to download the zoom path to the current view as JSON
+    on_export_path: Callback<()>,
+    /// Whether a frame export is running
+    exporting: Signal<bool>,
+    /// Callback to start or cancel a frame export
+    on_export_frames: Callback<()>,
+    /// Contents of a zoom path file chosen for rendering
+    on_import_path: Callback<String>,
 ) -> impl IntoView {
     // Derived signals for stepper bounds
     let cycle_at_min = Signal::derive(move || cycle_count.get() <= 1);
     let cycle_at_max = Signal::derive(move || cycle_count.get() >= 1024);
 
+    let update_cycle = move |f: fn(&mut ColorCycle)| {
+        let mut cycle = color_cycle.get_untracked();
+        f(&mut cycle);
+        on_color_cycle_change.call(cycle);
+    };
+    let cycle_running = Signal::derive(move || color_cycle.get().running);
+    let cycle_reversed =
+        Signal::derive(move || color_cycle.get().direction == CycleDirection::Reverse);
+    let cycle_speed = Signal::derive(move || color_cycle.get().speed);
+    let speed_at_min = Signal::derive(move || cycle_speed.get() <= ColorCycle::MIN_SPEED);
+    let speed_at_max = Signal::derive(move || cycle_speed.get() >= ColorCycle::MAX_SPEED);
+    let step_phase = move |delta: f64| {
+        on_cycle_phase_change.call(wrap_phase(cycle_phase.get_untracked() + delta));
+    };
+
+    let path_input = create_node_ref::<Input>();
+    let on_path_file = move |ev: web_sys::Event| {
+        let Some(input) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<HtmlInputElement>().ok())
+        else {
+            return;
+        };
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        // Allow choosing the same file again
+        input.set_value("");
+        spawn_local(async move {
+            match JsFuture::from(file.text()).await {
+                Ok(text) => on_import_path.call(text.as_string().unwrap_or_default()),
+                Err(e) => log::error!("Failed to read zoom path file: {e:?}"),
+            }
+        });
+    };
+
     view! {
         <Menu is_open=is_open set_is_open=set_is_open label="Options">
             <MenuSection title="Renderer" show_top_border=false />
@@ -63,6 +135,11 @@ pub fn OptionsMenu(
                 is_at_max=cpu_threads_at_max
                 shortcut=""
             />
+            <MenuItem
+                active=shared_memory
+                on_click=on_shared_memory_toggle
+                label="Shared Memory"
+            />
 
             <MenuSection title="Cycles" />
             <StepperMenuItem
@@ -74,6 +151,80 @@ pub fn OptionsMenu(
                 is_at_max=cycle_at_max
                 shortcut="[↑↓ / ⇧±50]"
             />
+            <MenuItem
+                active=zoom_stable
+                on_click=on_zoom_stable_toggle
+                label="Zoom-Stable"
+            />
+
+            <MenuSection title="Color Cycling" />
+            <MenuItem
+                active=cycle_running
+                on_click=Callback::new(move |_| update_cycle(|c| c.running = !c.running))
+                label="Animate"
+                shortcut="[C]"
+            />
+            <MenuItem
+                active=cycle_reversed
+                on_click=Callback::new(move |_| update_cycle(ColorCycle::toggle_direction))
+                label="Reverse"
+            />
+            <StepperMenuItem
+                value=cycle_speed
+                on_decrease=Callback::new(move |_| update_cycle(ColorCycle::speed_down))
+                on_increase=Callback::new(move |_| update_cycle(ColorCycle::speed_up))
+                format_value=|v: f64| format!("{v:.2}/s")
+                is_at_min=speed_at_min
+                is_at_max=speed_at_max
+                shortcut="Speed"
+                value_width="min-w-14"
+            />
+            <StepperMenuItem
+                value=cycle_phase
+                on_decrease=Callback::new(move |_| step_phase(-PHASE_STEP))
+                on_increase=Callback::new(move |_| step_phase(PHASE_STEP))
+                format_value=|v: f64| format!("{v:.2}")
+                is_at_min=Signal::derive(|| false)
+                is_at_max=Signal::derive(|| false)
+                shortcut="Phase"
+                value_width="min-w-14"
+            />
+
+            <MenuSection title="View" />
+            <MenuItem
+                active=skewed
+                on_click=on_unskew
+                label="Unskew"
+                shortcut="[K]"
+            />
+
+            <MenuSection title="Zoom Export" />
+            <MenuItem
+                active=Signal::derive(|| false)
+                on_click=on_export_path
+                label="Save Path"
+            />
+            <MenuItem
+                active=exporting
+                on_click=on_export_frames
+                label="Render Frames"
+            />
+            <MenuItem
+                active=Signal::derive(|| false)
+                on_click=Callback::new(move |_| {
+                    if let Some(input) = path_input.get_untracked() {
+                        input.click();
+                    }
+                })
+                label="Render Path File"
+            />
+            <input
+                type="file"
+                accept="application/json,.json"
+                class="hidden"
+                node_ref=path_input
+                on:change=on_path_file
+            />
 
             <MenuSection title="Debug" />
             <MenuItem
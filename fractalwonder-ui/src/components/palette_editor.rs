@@ -1,10 +1,12 @@
 //! Slide-out palette editor panel.
 
 use crate::components::{
-    CollapsibleSection, ConfirmDialog, CurveEditor, EditMode, GradientEditor, LightingControl,
-    LightingSlider, PaletteEditorState,
+    CollapsibleSection, ConfirmDialog, CurveEditor, EditMode, GradientEditor, LayerEditor,
+    LightingControl, LightingSlider, PaletteEditorState,
+};
+use crate::rendering::colorizers::{
+    hex_to_rgb, rgb_to_hex, ColorLayer, Curve, Gradient, InteriorMode, Palette,
 };
-use crate::rendering::colorizers::{Curve, Gradient, Palette};
 use leptos::*;
 
 /// Which confirmation dialog is currently shown (if any).
@@ -39,6 +41,8 @@ pub fn PaletteEditor(
     // Collapsible section state
     let palette_expanded = create_rw_signal(true);
     let light_effects_expanded = create_rw_signal(true);
+    let layers_expanded = create_rw_signal(false);
+    let interior_expanded = create_rw_signal(false);
 
     // Derived: is editor visible?
     let is_visible = Signal::derive(move || state.get().is_some());
@@ -128,6 +132,53 @@ pub fn PaletteEditor(
         });
     });
 
+    // Derived: color layers
+    let layers_signal = Signal::derive(move || {
+        state
+            .get()
+            .map(|s| s.working_palette.layers.clone())
+            .unwrap_or_default()
+    });
+
+    // Callback for layer changes
+    let on_layers_change = Callback::new(move |new_layers: Vec<ColorLayer>| {
+        state.update(|opt| {
+            if let Some(s) = opt {
+                s.working_palette.layers = new_layers;
+            }
+        });
+    });
+
+    // Derived: interior coloring
+    let interior_mode = Signal::derive(move || {
+        state
+            .get()
+            .map(|s| s.working_palette.interior.mode)
+            .unwrap_or_default()
+    });
+
+    let interior_color_hex = Signal::derive(move || {
+        state
+            .get()
+            .map(|s| rgb_to_hex(s.working_palette.interior.color))
+            .unwrap_or_default()
+    });
+
+    let interior_gradient_signal = Signal::derive(move || {
+        state
+            .get()
+            .map(|s| s.working_palette.interior.gradient.clone())
+    });
+
+    // Callback for interior gradient changes
+    let on_interior_gradient_change = Callback::new(move |new_gradient: Gradient| {
+        state.update(|opt| {
+            if let Some(s) = opt {
+                s.working_palette.interior.gradient = new_gradient;
+            }
+        });
+    });
+
     // Derived: falloff curve
     let falloff_curve_signal =
         Signal::derive(move || state.get().map(|s| s.working_palette.falloff_curve.clone()));
@@ -526,6 +577,81 @@ pub fn PaletteEditor(
                     />
                 </CollapsibleSection>
 
+                // Layers Section
+                <CollapsibleSection title="Layers" expanded=layers_expanded>
+                    <LayerEditor
+                        layers=layers_signal
+                        on_change=on_layers_change
+                    />
+                </CollapsibleSection>
+
+                // Interior Section
+                <CollapsibleSection title="Interior" expanded=interior_expanded>
+                    <div class="space-y-2">
+                        <div class="flex items-center gap-2">
+                            <div class="text-white text-xs w-20">"Mode"</div>
+                            <select
+                                class="flex-1 bg-white/5 border border-white/20 rounded px-2 py-0.5 \
+                                       text-white text-xs outline-none"
+                                on:change=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    if let Some(mode) =
+                                        InteriorMode::ALL.into_iter().find(|m| m.label() == value)
+                                    {
+                                        state.update(|opt| {
+                                            if let Some(s) = opt {
+                                                s.working_palette.interior.mode = mode;
+                                            }
+                                        });
+                                    }
+                                }
+                            >
+                                {InteriorMode::ALL
+                                    .into_iter()
+                                    .map(|mode| view! {
+                                        <option
+                                            value=mode.label()
+                                            selected=move || interior_mode.get() == mode
+                                        >
+                                            {mode.label()}
+                                        </option>
+                                    })
+                                    .collect_view()}
+                            </select>
+                        </div>
+                        <div class="flex items-center gap-2">
+                            <div class="text-white text-xs w-20">"Color"</div>
+                            <input
+                                type="color"
+                                class="w-12 h-8 rounded cursor-pointer bg-transparent"
+                                prop:value=move || interior_color_hex.get()
+                                on:change=move |ev| {
+                                    if let Some(rgb) = hex_to_rgb(&event_target_value(&ev)) {
+                                        state.update(|opt| {
+                                            if let Some(s) = opt {
+                                                s.working_palette.interior.color = rgb;
+                                            }
+                                        });
+                                    }
+                                }
+                            />
+                        </div>
+                        <Show when=move || interior_mode.get().requires_cpu()>
+                            <div class="text-white/50 text-xs px-1">
+                                "Period detection runs on the CPU renderer."
+                            </div>
+                        </Show>
+                    </div>
+
+                    <Show when=move || interior_mode.get() != InteriorMode::Solid>
+                        <div class="text-white/50 text-xs px-1">"Interior Gradient"</div>
+                        <GradientEditor
+                            gradient=interior_gradient_signal
+                            on_change=on_interior_gradient_change
+                        />
+                    </Show>
+                </CollapsibleSection>
+
                 // Light Effects Section
                 <CollapsibleSection title="Light Effects" expanded=light_effects_expanded>
                     // 3D Lighting toggle
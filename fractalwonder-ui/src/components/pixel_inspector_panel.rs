@@ -0,0 +1,48 @@
+//! Panel listing the raw compute data of the inspected pixel.
+
+use leptos::*;
+
+use crate::rendering::PixelInspection;
+
+/// Pixel inspector readout, shown while inspector mode is on.
+#[component]
+pub fn PixelInspectorPanel(
+    /// Pixel under the cursor, or the pinned one
+    inspection: Signal<Option<PixelInspection>>,
+    /// Whether inspector mode is on
+    visible: Signal<bool>,
+) -> impl IntoView {
+    let pinned = move || {
+        inspection
+            .get()
+            .is_some_and(|inspection| inspection.coordinate.is_some())
+    };
+
+    view! {
+        <Show when=move || visible.get()>
+            <div class="fixed top-4 left-4 z-40 max-w-md px-3 py-2 rounded-lg bg-black/80 text-white text-xs font-mono space-y-0.5">
+                <div class="text-white/60 uppercase tracking-wide">
+                    {move || if pinned() { "Pixel Inspector (pinned)" } else { "Pixel Inspector" }}
+                </div>
+                {move || match inspection.get() {
+                    Some(inspection) => inspection
+                        .rows()
+                        .into_iter()
+                        .map(|(label, value)| {
+                            view! {
+                                <div class="flex gap-2">
+                                    <span class="w-20 shrink-0 text-white/60">{label}</span>
+                                    <span class="break-all select-text">{value}</span>
+                                </div>
+                            }
+                        })
+                        .collect_view(),
+                    None => view! { <div class="text-white/60">"Hover the image"</div> }.into_view(),
+                }}
+                <div class="pt-1 text-white/40">
+                    {move || if pinned() { "Click to unpin" } else { "Click to pin" }}
+                </div>
+            </div>
+        </Show>
+    }
+}
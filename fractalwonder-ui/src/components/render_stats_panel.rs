@@ -0,0 +1,71 @@
+//! X-ray diagnostics panel summarizing where the last render spent its time.
+
+use leptos::*;
+
+use crate::rendering::{format_duration, precision_color, HeatmapMode, RenderStats};
+
+/// Number of slowest tiles listed in the panel.
+const SLOWEST_TILES: usize = 5;
+
+/// Per-render diagnostics shown alongside x-ray mode.
+#[component]
+pub fn RenderStatsPanel(
+    /// Stats of the current renderer
+    stats: Signal<RwSignal<RenderStats>>,
+    /// Heatmap currently drawn over the image
+    heatmap_mode: Signal<HeatmapMode>,
+    /// Whether the panel is shown (x-ray mode)
+    visible: Signal<bool>,
+) -> impl IntoView {
+    let snapshot = move || stats.get().get();
+
+    view! {
+        <Show when=move || visible.get() && !snapshot().is_empty()>
+            <div class="fixed top-4 right-4 z-40 w-64 px-3 py-2 rounded-lg bg-black/80 text-white text-xs font-mono space-y-1 pointer-events-none">
+                <div class="text-white/60 uppercase tracking-wide">"Render Stats"</div>
+                <div>{move || format!("Tiles: {} ({})", snapshot().tiles.len(), format_duration(snapshot().compute_time_ms()))}</div>
+                <div>{move || format!("BLA skip: {:.1}%", snapshot().bla_ratio() * 100.0)}</div>
+                <div>{move || format!("Rebases/pixel: {:.2}", snapshot().rebases_per_pixel())}</div>
+                <div>{move || format!("Glitched pixels: {}", snapshot().glitched_pixels())}</div>
+                <div class="pt-1 text-white/60">"Precision"</div>
+                <For
+                    each=move || snapshot().precision_mix()
+                    key=|(precision, count)| (precision.label(), *count)
+                    children=move |(precision, count)| {
+                        let [r, g, b] = precision_color(precision);
+                        view! {
+                            <div class="flex items-center gap-2">
+                                <span
+                                    class="inline-block w-2 h-2 rounded-sm"
+                                    style=format!("background-color: rgb({r},{g},{b})")
+                                ></span>
+                                {format!("{}: {} tiles", precision.label(), count)}
+                            </div>
+                        }
+                    }
+                />
+                <div class="pt-1 text-white/60">"Slowest tiles"</div>
+                <For
+                    each=move || snapshot().slowest(SLOWEST_TILES)
+                    key=|record| (record.tile.x, record.tile.y)
+                    children=move |record| {
+                        view! {
+                            <div>
+                                {format!(
+                                    "({}, {}) {} · {}",
+                                    record.tile.x,
+                                    record.tile.y,
+                                    format_duration(record.compute_time_ms),
+                                    record.precision.label()
+                                )}
+                            </div>
+                        }
+                    }
+                />
+                <div class="pt-1 text-white/60">
+                    {move || format!("Heatmap (h): {}", heatmap_mode.get().label())}
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,6 +1,7 @@
 // fractalwonder-ui/src/components/ui_panel.rs
 use crate::components::{FullscreenButton, HomeButton, InfoMenu, OptionsMenu, PaletteMenu};
 use crate::config::FractalConfig;
+use crate::rendering::colorizers::ColorCycle;
 use crate::rendering::RenderProgress;
 use fractalwonder_core::{calculate_max_iterations, BigFloat, Viewport};
 use leptos::*;
@@ -39,6 +40,10 @@ pub fn UIPanel(
     on_cpu_threads_up: Callback<()>,
     /// Callback to decrease CPU threads
     on_cpu_threads_down: Callback<()>,
+    /// Shared memory workers enabled
+    shared_memory: Signal<bool>,
+    /// Callback to toggle shared memory workers
+    on_shared_memory_toggle: Callback<()>,
     /// Whether CPU threads is at minimum bound
     cpu_threads_at_min: Signal<bool>,
     /// Whether CPU threads is at maximum bound
@@ -59,6 +64,30 @@ pub fn UIPanel(
     force_hdr_float: Signal<bool>,
     /// Callback to toggle force HDRFloat mode
     on_force_hdr_float_toggle: Callback<()>,
+    /// Zoom-stable coloring enabled state
+    zoom_stable: Signal<bool>,
+    /// Callback to toggle zoom-stable coloring
+    on_zoom_stable_toggle: Callback<()>,
+    /// Palette color-cycling controls
+    color_cycle: Signal<ColorCycle>,
+    /// Callback when color-cycling controls change
+    on_color_cycle_change: Callback<ColorCycle>,
+    /// Palette color-cycling phase
+    cycle_phase: Signal<f64>,
+    /// Callback when the phase is stepped manually
+    on_cycle_phase_change: Callback<f64>,
+    /// Whether the view carries a stretch/skew
+    skewed: Signal<bool>,
+    /// Callback to unskew the view around its center
+    on_unskew: Callback<()>,
+    /// Callback to download the zoom path as JSON
+    on_export_path: Callback<()>,
+    /// Whether a frame export is running
+    exporting: Signal<bool>,
+    /// Callback to start or cancel a frame export
+    on_export_frames: Callback<()>,
+    /// Contents of a zoom path file chosen for rendering
+    on_import_path: Callback<String>,
     /// Callback when palettes are reordered (from_id, to_id)
     #[prop(optional)]
     on_palette_reorder: Option<Callback<(String, String)>>,
@@ -138,6 +167,7 @@ pub fn UIPanel(
                     <InfoMenu
                         is_open=is_info_open
                         set_is_open=set_is_info_open
+                        render_progress=render_progress
                     />
                     <HomeButton on_click=on_home_click />
                     <PaletteMenu
@@ -161,12 +191,26 @@ pub fn UIPanel(
                         cpu_threads=cpu_threads
                         on_cpu_threads_up=on_cpu_threads_up
                         on_cpu_threads_down=on_cpu_threads_down
+                        shared_memory=shared_memory
+                        on_shared_memory_toggle=on_shared_memory_toggle
                         cpu_threads_at_min=cpu_threads_at_min
                         cpu_threads_at_max=cpu_threads_at_max
                         xray_enabled=xray_enabled.into()
                         on_xray_toggle=Callback::new(move |_| set_xray_enabled.update(|v| *v = !*v))
                         force_hdr_float=force_hdr_float
                         on_force_hdr_float_toggle=on_force_hdr_float_toggle
+                        zoom_stable=zoom_stable
+                        on_zoom_stable_toggle=on_zoom_stable_toggle
+                        color_cycle=color_cycle
+                        on_color_cycle_change=on_color_cycle_change
+                        cycle_phase=cycle_phase
+                        on_cycle_phase_change=on_cycle_phase_change
+                        skewed=skewed
+                        on_unskew=on_unskew
+                        on_export_path=on_export_path
+                        exporting=exporting
+                        on_export_frames=on_export_frames
+                        on_import_path=on_import_path
                     />
                 </div>
 
@@ -180,9 +224,10 @@ pub fn UIPanel(
                             let vp = viewport.get();
                             let bits = precision_bits.get();
 
-                            // Calculate zoom: reference_width / current_width via log subtraction
+                            // Calculate zoom: reference_width / current_width, with the
+                            // binary exponents subtracted exactly
                             let reference_width = cfg.default_viewport(bits).width;
-                            let zoom_log2 = reference_width.log2_approx() - vp.width.log2_approx();
+                            let zoom_log2 = reference_width.log2_ratio(&vp.width);
                             let zoom = format_zoom_from_log2(zoom_log2);
 
                             // Calculate max iterations from zoom exponent (log10)
@@ -208,7 +253,18 @@ pub fn UIPanel(
                                 let progress_signal = render_progress.get();
                                 let progress = progress_signal.get();
 
-                                if progress.total_steps > 0 && !progress.is_complete {
+                                if let (Some(orbit_pct), false) =
+                                    (progress.orbit_percentage(), progress.is_complete)
+                                {
+                                    // Tiles can finish while the reference orbit streams in
+                                    format!(
+                                        "Rendering: {}/{} | Reference orbit {:.0}% ({:.1}s)",
+                                        progress.completed_steps,
+                                        progress.total_steps,
+                                        orbit_pct,
+                                        progress.elapsed_ms / 1000.0
+                                    )
+                                } else if progress.total_steps > 0 && !progress.is_complete {
                                     // During render: show progress and elapsed time
                                     format!(
                                         "Rendering: {}/{} ({:.1}s)",
@@ -259,9 +315,9 @@ pub fn UIPanel(
 /// Format a signed coordinate for display, preserving the sign.
 ///
 /// Uses log2_approx for magnitude (which uses abs internally) and
-/// to_f64() to determine the sign.
+/// is_negative() for the sign, which survives values that underflow f64.
 fn format_signed_coordinate(coord: &BigFloat) -> String {
-    let is_negative = coord.to_f64() < 0.0;
+    let is_negative = coord.is_negative();
     let magnitude = format_coordinate_from_log2(coord.log2_approx());
 
     if is_negative {
@@ -365,6 +421,15 @@ mod tests {
         assert_eq!(format_zoom_from_log2(2000.0 * LOG2_10), "10^2000");
     }
 
+    #[test]
+    fn format_zoom_beyond_million_digits() {
+        assert_eq!(format_zoom_from_log2(2_000_000.0 * LOG2_10), "10^2000000");
+        assert_eq!(
+            format_zoom_from_log2(2_000_000.0 * LOG2_10 + 1.5f64.log2()),
+            "1.50 × 10^2000000"
+        );
+    }
+
     #[test]
     fn format_zoom_nan_returns_1x() {
         assert_eq!(format_zoom_from_log2(f64::NAN), "1×");
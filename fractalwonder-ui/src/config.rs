@@ -10,6 +10,10 @@ use std::cell::Cell;
 const CPU_THREADS_STORAGE_KEY: &str = "fractalwonder_cpu_threads";
 #[cfg(target_arch = "wasm32")]
 const GPU_SETTING_STORAGE_KEY: &str = "fractalwonder_use_gpu";
+/// Also read by the loader script in index.html to decide whether to
+/// install the cross-origin isolation service worker.
+#[cfg(target_arch = "wasm32")]
+const SHARED_MEMORY_STORAGE_KEY: &str = "fractalwonder_shared_memory";
 
 // Runtime cache for CPU threads setting
 thread_local! {
@@ -17,6 +21,8 @@ thread_local! {
     static CPU_THREADS_CACHE: Cell<Option<i32>> = const { Cell::new(None) };
     /// Cached GPU setting. None = not yet loaded from localStorage.
     static GPU_SETTING_CACHE: Cell<Option<bool>> = const { Cell::new(None) };
+    /// Cached shared memory setting. None = not yet loaded from localStorage.
+    static SHARED_MEMORY_CACHE: Cell<Option<bool>> = const { Cell::new(None) };
 }
 
 /// Load CPU threads setting from localStorage.
@@ -153,6 +159,62 @@ pub fn get_gpu_enabled_override() -> Option<bool> {
     })
 }
 
+// =============================================================================
+// Shared Memory Setting Persistence (localStorage only, not in URL)
+// =============================================================================
+
+/// Load shared memory setting from localStorage.
+fn load_shared_memory_from_storage() -> Option<bool> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window()?;
+        let storage = window.local_storage().ok()??;
+        let value = storage.get_item(SHARED_MEMORY_STORAGE_KEY).ok()??;
+        value.parse().ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Save shared memory setting to localStorage.
+fn save_shared_memory_to_storage(value: bool) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window() {
+            if let Ok(Some(storage)) = window.local_storage() {
+                let _ = storage.set_item(SHARED_MEMORY_STORAGE_KEY, &value.to_string());
+            }
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = value;
+    }
+}
+
+/// Whether workers should share orbit and result buffers with the main
+/// thread (opt-in, off by default). Only takes effect on cross-origin
+/// isolated pages; the JSON message path is used otherwise.
+pub fn get_shared_memory_enabled() -> bool {
+    SHARED_MEMORY_CACHE.with(|cell| {
+        // Load from localStorage on first access
+        if cell.get().is_none() {
+            if let Some(stored) = load_shared_memory_from_storage() {
+                cell.set(Some(stored));
+            }
+        }
+        cell.get().unwrap_or(false)
+    })
+}
+
+/// Set the shared memory setting and persist to localStorage.
+pub fn set_shared_memory_enabled(value: bool) {
+    SHARED_MEMORY_CACHE.with(|cell| cell.set(Some(value)));
+    save_shared_memory_to_storage(value);
+}
+
 /// Determines which renderer implementation to use.
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 pub enum RendererType {
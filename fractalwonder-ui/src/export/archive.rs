@@ -0,0 +1,95 @@
+//! Uncompressed tar archive writer for downloading frame sequences from the browser.
+//!
+//! PNG frames are already compressed, so the archive only bundles them.
+
+const BLOCK: usize = 512;
+
+/// Builds a POSIX ustar archive in memory.
+#[derive(Default)]
+pub struct TarBuilder {
+    buf: Vec<u8>,
+}
+
+impl TarBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a regular file. Names longer than 100 bytes are truncated.
+    pub fn append(&mut self, name: &str, data: &[u8]) {
+        let mut header = [0u8; BLOCK];
+        let name_bytes = name.as_bytes();
+        let name_len = name_bytes.len().min(100);
+        header[..name_len].copy_from_slice(&name_bytes[..name_len]);
+        write_octal(&mut header[100..108], 0o644);
+        write_octal(&mut header[108..116], 0);
+        write_octal(&mut header[116..124], 0);
+        write_octal(&mut header[124..136], data.len() as u64);
+        write_octal(&mut header[136..148], 0);
+        header[156] = b'0';
+        header[257..263].copy_from_slice(b"ustar\0");
+        header[263..265].copy_from_slice(b"00");
+
+        // The checksum is computed with its own field filled with spaces
+        header[148..156].fill(b' ');
+        let checksum: u32 = header.iter().map(|&b| b as u32).sum();
+        write_octal(&mut header[148..155], checksum as u64);
+        header[155] = b' ';
+
+        self.buf.extend_from_slice(&header);
+        self.buf.extend_from_slice(data);
+        let padding = (BLOCK - data.len() % BLOCK) % BLOCK;
+        self.buf.resize(self.buf.len() + padding, 0);
+    }
+
+    /// Finish the archive with the two zero blocks that mark its end.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.buf.resize(self.buf.len() + 2 * BLOCK, 0);
+        self.buf
+    }
+}
+
+/// Write `value` as zero-padded octal, NUL-terminated, filling `field`.
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = field.len() - 1;
+    let text = format!("{:0width$o}", value, width = digits);
+    field[..digits].copy_from_slice(&text.as_bytes()[text.len() - digits..]);
+    field[digits] = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn octal(field: &[u8]) -> u64 {
+        let text = std::str::from_utf8(field)
+            .unwrap()
+            .trim_matches(|c: char| c == '\0' || c == ' ');
+        u64::from_str_radix(text, 8).unwrap()
+    }
+
+    #[test]
+    fn entries_are_block_aligned_with_valid_headers() {
+        let mut tar = TarBuilder::new();
+        tar.append("frame_00000.png", &[1, 2, 3]);
+        tar.append("frame_00001.png", &[7; 600]);
+        let bytes = tar.finish();
+
+        // header + 1 data block, header + 2 data blocks, 2 end blocks
+        assert_eq!(bytes.len(), BLOCK * 7);
+
+        let first = &bytes[..BLOCK];
+        assert_eq!(&first[..15], b"frame_00000.png");
+        assert_eq!(octal(&first[124..136]), 3);
+        assert_eq!(&bytes[BLOCK..BLOCK + 3], &[1, 2, 3]);
+
+        let mut unsummed = first.to_vec();
+        unsummed[148..156].fill(b' ');
+        let sum: u64 = unsummed.iter().map(|&b| b as u64).sum();
+        assert_eq!(octal(&first[148..156]), sum);
+
+        let second = &bytes[2 * BLOCK..3 * BLOCK];
+        assert_eq!(octal(&second[124..136]), 600);
+        assert!(bytes[5 * BLOCK..].iter().all(|&b| b == 0));
+    }
+}
@@ -0,0 +1,56 @@
+//! Browser side of the exporter: renders frames on the worker pool, bundles
+//! them into an archive and downloads it.
+
+use super::archive::TarBuilder;
+use super::pool_renderer::PoolFrameRenderer;
+use super::sequencer::{frame_file_name, ZoomSequencer};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+/// Render every remaining frame into a tar archive of numbered PNGs.
+///
+/// Frames are computed by the workers of `renderer`, while the page stays
+/// responsive. Calls `on_frame(done, total)` for progress, and returns early
+/// with the frames rendered so far when `cancelled()` becomes true.
+pub async fn render_archive(
+    sequencer: &mut ZoomSequencer,
+    renderer: &PoolFrameRenderer,
+    on_frame: impl Fn(usize, usize),
+    cancelled: impl Fn() -> bool,
+) -> Vec<u8> {
+    let total = sequencer.frame_count();
+    let mut tar = TarBuilder::new();
+    while !cancelled() {
+        let Some(frame) = sequencer.next_frame() else {
+            break;
+        };
+        let Some(data) = renderer.render(&frame.viewport, &cancelled).await else {
+            break;
+        };
+        tar.append(
+            &frame_file_name(frame.index),
+            &sequencer.encode(&frame, &data),
+        );
+        on_frame(frame.index + 1, total);
+    }
+    tar.finish()
+}
+
+/// Offer `bytes` to the user as a file download.
+pub fn download_bytes(bytes: &[u8], file_name: &str, mime_type: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?;
+    let anchor: web_sys::HtmlAnchorElement = document.create_element("a")?.dyn_into()?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    web_sys::Url::revoke_object_url(&url)
+}
@@ -0,0 +1,159 @@
+//! Single-threaded CPU renderer for exporting frame sequences.
+//!
+//! Renders a whole frame as one perturbation tile against a single reference
+//! orbit at the viewport center, without glitch resolution. The native
+//! exporter uses it; the browser renders frames with `PoolFrameRenderer`.
+
+use crate::config::FractalConfig;
+use crate::workers::{calculate_dc_max, calculate_render_max_iterations};
+use fractalwonder_compute::{render_tile, BlaSource, BlaTable, ReferenceOrbit, TileConfig};
+use fractalwonder_core::{pixel_delta_step, pixel_to_delta, BigFloat, ComputeData, Viewport};
+
+/// BLA only pays off at deep zoom; matches the worker threshold (~10^-25).
+const BLA_MIN_DEPTH_LOG2: f64 = -80.0;
+
+struct CachedOrbit {
+    center: (BigFloat, BigFloat),
+    orbit: ReferenceOrbit,
+    max_iterations: u32,
+}
+
+/// Renders frames on the calling thread, reusing the reference orbit while the
+/// center stays the same.
+pub struct FrameRenderer {
+    config: &'static FractalConfig,
+    size: (u32, u32),
+    /// Record orbit statistics, for palettes with layers that use them
+    collect_orbit_stats: bool,
+    /// Probe interior pixels, for the period-based interior coloring modes
+    probe_interior: bool,
+    orbit: Option<CachedOrbit>,
+    orbits_computed: usize,
+}
+
+impl FrameRenderer {
+    pub fn new(
+        config: &'static FractalConfig,
+        size: (u32, u32),
+        collect_orbit_stats: bool,
+        probe_interior: bool,
+    ) -> Self {
+        Self {
+            config,
+            size,
+            collect_orbit_stats,
+            probe_interior,
+            orbit: None,
+            orbits_computed: 0,
+        }
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        self.size
+    }
+
+    /// Number of reference orbits computed so far.
+    pub fn orbits_computed(&self) -> usize {
+        self.orbits_computed
+    }
+
+    /// Max iterations a frame at `viewport` renders with.
+    pub fn max_iterations(&self, viewport: &Viewport) -> u32 {
+        calculate_render_max_iterations(viewport, Some(self.config))
+    }
+
+    /// Compute one frame.
+    ///
+    /// A new reference orbit is computed only when the center moves or the
+    /// cached orbit is too short. `orbit_iterations` lets a caller size it for
+    /// later, deeper frames up front.
+    pub fn render(&mut self, viewport: &Viewport, orbit_iterations: u32) -> Vec<ComputeData> {
+        let max_iterations = self.max_iterations(viewport);
+
+        let reusable = self.orbit.as_ref().is_some_and(|cached| {
+            cached.center == viewport.center && cached.max_iterations >= max_iterations
+        });
+        if !reusable {
+            let orbit_iterations = orbit_iterations.max(max_iterations);
+            self.orbit = Some(CachedOrbit {
+                center: viewport.center.clone(),
+                orbit: ReferenceOrbit::compute(&viewport.center, orbit_iterations),
+                max_iterations: orbit_iterations,
+            });
+            self.orbits_computed += 1;
+        }
+        let orbit = &self.orbit.as_ref().expect("orbit cached above").orbit;
+
+        let dc_max = calculate_dc_max(viewport);
+        let bla_useful = !dc_max.is_zero()
+            && (dc_max.head as f64).log2() + (dc_max.exp as f64) < BLA_MIN_DEPTH_LOG2;
+        let bla_table = (self.config.bla_enabled && !self.collect_orbit_stats && bla_useful)
+            .then(|| BlaTable::compute(orbit, &dc_max));
+
+        let tile_config = TileConfig {
+            size: self.size,
+            max_iterations,
+            tau_sq: self.config.tau_sq,
+            bla_enabled: self.config.bla_enabled,
+            force_hdr_float: false,
+            scaled_f64: true,
+            collect_orbit_stats: self.collect_orbit_stats,
+            probe_interior: self.probe_interior,
+            iteration_limit: None,
+        };
+
+        // Top-left pixel relative to the reference at the center
+        let precision = viewport.width.precision_bits();
+        let delta_origin = pixel_to_delta(0.0, 0.0, viewport, self.size, precision);
+        let delta_step = pixel_delta_step(viewport, self.size);
+
+        render_tile(
+            orbit,
+            bla_table.as_ref().map(|table| table as &dyn BlaSource),
+            &delta_origin,
+            &delta_step,
+            &tile_config,
+        )
+        .0
+        .data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_config;
+
+    #[test]
+    fn reuses_orbit_while_center_is_fixed() {
+        let mut renderer = FrameRenderer::new(default_config(), (8, 6), false, false);
+        let shallow = Viewport::from_f64(-0.75, 0.1, 0.5, 0.375, 128);
+        let deep = Viewport::from_f64(-0.75, 0.1, 0.05, 0.0375, 128);
+        let deep_iterations = renderer.max_iterations(&deep);
+
+        let data = renderer.render(&shallow, deep_iterations);
+        assert_eq!(data.len(), 48);
+        renderer.render(&deep, deep_iterations);
+        assert_eq!(renderer.orbits_computed(), 1);
+
+        let moved = Viewport::from_f64(-0.7, 0.1, 0.05, 0.0375, 128);
+        renderer.render(&moved, 0);
+        assert_eq!(renderer.orbits_computed(), 2);
+    }
+
+    #[test]
+    fn frame_matches_direct_iteration() {
+        let mut renderer = FrameRenderer::new(default_config(), (4, 4), false, false);
+        let viewport = Viewport::from_f64(-0.5, 0.0, 4.0, 4.0, 128);
+        let data = renderer.render(&viewport, 0);
+
+        // Pixel (0, 0) is c = -2.5 - 2i, which escapes within a few iterations
+        let ComputeData::Mandelbrot(corner) = &data[0];
+        assert!(corner.escaped);
+        assert!(corner.iterations < 10, "iterations = {}", corner.iterations);
+
+        // Pixel (2, 2) is c = -0.5, inside the main cardioid
+        let ComputeData::Mandelbrot(center) = &data[2 * 4 + 2];
+        assert!(!center.escaped);
+    }
+}
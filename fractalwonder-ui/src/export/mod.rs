@@ -0,0 +1,21 @@
+//! Zoom-video export: renders a zoom path frame by frame into PNG images,
+//! written to a directory natively or bundled into an archive in the browser.
+//!
+//! Natively frames are rendered on the calling thread; the browser renders
+//! them on a worker pool with glitch resolution.
+
+mod archive;
+mod browser;
+mod frame_renderer;
+mod png;
+mod pool_renderer;
+mod sequencer;
+
+pub use archive::TarBuilder;
+pub use browser::{download_bytes, render_archive};
+pub use frame_renderer::FrameRenderer;
+pub use png::encode_png;
+pub use pool_renderer::PoolFrameRenderer;
+#[cfg(not(target_arch = "wasm32"))]
+pub use sequencer::export_png_sequence;
+pub use sequencer::{frame_file_name, ZoomSequencer};
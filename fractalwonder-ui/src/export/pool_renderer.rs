@@ -0,0 +1,136 @@
+//! Renders export frames on the worker pool.
+//!
+//! Each frame is rendered in tiles like the canvas, then its glitched tiles
+//! are rendered again against orbits at the centers of their quadtree cells,
+//! one subdivision level deeper per pass.
+
+use crate::config::FractalConfig;
+use crate::rendering::{
+    assemble_tiles_to_buffer, calculate_tile_size, generate_tiles, RenderProgress, RenderStats,
+};
+use crate::workers::{TileResult, WorkerPool};
+use fractalwonder_core::{ComputeData, Viewport};
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+
+/// Glitch re-render passes per frame.
+const MAX_GLITCH_PASSES: usize = 4;
+
+/// How often to check whether the pool has finished a frame.
+const POLL_INTERVAL_MS: u32 = 20;
+
+/// Renders frames on a worker pool of its own, reusing the reference orbit
+/// while the center stays the same.
+pub struct PoolFrameRenderer {
+    config: &'static FractalConfig,
+    size: (u32, u32),
+    /// Record orbit statistics, for palettes with layers that use them
+    collect_orbit_stats: bool,
+    /// Probe interior pixels, for the period-based interior coloring modes
+    probe_interior: bool,
+    pool: Rc<RefCell<WorkerPool>>,
+    /// Tile results of the current frame, glitch re-renders last
+    tiles: Rc<RefCell<Vec<TileResult>>>,
+    /// Set by the pool when the tiles queued so far are done
+    complete: Rc<Cell<bool>>,
+}
+
+impl PoolFrameRenderer {
+    /// Start the workers. `orbit_iterations` sizes the reference orbit for
+    /// later, deeper frames up front.
+    pub fn new(
+        config: &'static FractalConfig,
+        size: (u32, u32),
+        collect_orbit_stats: bool,
+        probe_interior: bool,
+        orbit_iterations: u32,
+    ) -> Result<Self, JsValue> {
+        let tiles: Rc<RefCell<Vec<TileResult>>> = Rc::new(RefCell::new(Vec::new()));
+        let complete = Rc::new(Cell::new(false));
+
+        let tiles_clone = Rc::clone(&tiles);
+        let pool = WorkerPool::new(
+            config.id,
+            move |result| tiles_clone.borrow_mut().push(result),
+            create_rw_signal(RenderProgress::default()),
+            create_rw_signal(RenderStats::default()),
+        )?;
+        let complete_clone = Rc::clone(&complete);
+        pool.borrow()
+            .set_render_complete_callback(move || complete_clone.set(true));
+        pool.borrow_mut().set_orbit_reuse(Some(orbit_iterations));
+
+        Ok(Self {
+            config,
+            size,
+            collect_orbit_stats,
+            probe_interior,
+            pool,
+            tiles,
+            complete,
+        })
+    }
+
+    /// Compute one frame, or None if `cancelled()` becomes true first.
+    pub async fn render(
+        &self,
+        viewport: &Viewport,
+        cancelled: &impl Fn() -> bool,
+    ) -> Option<Vec<ComputeData>> {
+        let (width, height) = self.size;
+        let reference_width = self
+            .config
+            .default_viewport(viewport.precision_bits())
+            .width;
+        let zoom = reference_width.to_f64() / viewport.width.to_f64();
+        let tiles = generate_tiles(width, height, calculate_tile_size(zoom));
+
+        self.tiles.borrow_mut().clear();
+        self.complete.set(false);
+        self.pool.borrow_mut().start_perturbation_render(
+            viewport.clone(),
+            self.size,
+            tiles,
+            false,
+            self.collect_orbit_stats,
+            self.probe_interior,
+        );
+        self.wait_for_tiles(cancelled).await?;
+
+        for _ in 0..MAX_GLITCH_PASSES {
+            self.complete.set(false);
+            if self.pool.borrow_mut().rerender_glitched_tiles() == 0 {
+                break;
+            }
+            self.wait_for_tiles(cancelled).await?;
+        }
+
+        Some(assemble_tiles_to_buffer(
+            &self.tiles.borrow(),
+            width as usize,
+            height as usize,
+        ))
+    }
+
+    /// Wait until the pool finishes the queued tiles, cancelling the render
+    /// if `cancelled()` becomes true first.
+    async fn wait_for_tiles(&self, cancelled: &impl Fn() -> bool) -> Option<()> {
+        while !self.complete.get() {
+            if cancelled() {
+                self.pool.borrow_mut().cancel();
+                return None;
+            }
+            TimeoutFuture::new(POLL_INTERVAL_MS).await;
+        }
+        Some(())
+    }
+}
+
+impl Drop for PoolFrameRenderer {
+    fn drop(&mut self) {
+        self.pool.borrow_mut().shut_down();
+    }
+}
@@ -0,0 +1,250 @@
+//! Renders a zoom path one frame at a time into PNG images.
+
+use super::frame_renderer::FrameRenderer;
+use super::png::encode_png;
+use crate::config::FractalConfig;
+use crate::rendering::colorizers::{ColorPipeline, Palette, RenderSettings};
+use fractalwonder_core::{ComputeData, ZoomFrame, ZoomPath};
+
+/// File name of a frame inside an exported sequence.
+pub fn frame_file_name(index: usize) -> String {
+    format!("frame_{index:05}.png")
+}
+
+/// Steps through a zoom path, rendering and colorizing each frame.
+///
+/// The color pipeline persists across frames, so zoom-stable coloring keeps
+/// its reference from the first frame for the whole sequence.
+pub struct ZoomSequencer {
+    path: ZoomPath,
+    renderer: FrameRenderer,
+    pipeline: ColorPipeline,
+    next_index: usize,
+    /// Orbit length to compute up front, so a fixed-center path computes one orbit.
+    orbit_iterations: u32,
+}
+
+impl ZoomSequencer {
+    pub fn new(
+        path: ZoomPath,
+        config: &'static FractalConfig,
+        size: (u32, u32),
+        palette: Palette,
+        render_settings: RenderSettings,
+    ) -> Result<Self, String> {
+        path.validate()?;
+        if size.0 == 0 || size.1 == 0 {
+            return Err(format!("Invalid frame size {}x{}", size.0, size.1));
+        }
+
+        let renderer = FrameRenderer::new(
+            config,
+            size,
+            palette.needs_orbit_stats(),
+            palette.interior.mode.requires_cpu(),
+        );
+        let deepest_iterations = renderer
+            .max_iterations(&path.start)
+            .max(renderer.max_iterations(&path.end));
+        let orbit_iterations = if path.has_fixed_center() {
+            deepest_iterations
+        } else {
+            0
+        };
+
+        let mut pipeline = ColorPipeline::new(palette, render_settings);
+        pipeline.set_iteration_reference(Some(deepest_iterations));
+
+        Ok(Self {
+            path,
+            renderer,
+            pipeline,
+            next_index: 0,
+            orbit_iterations,
+        })
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.path.frame_count()
+    }
+
+    /// Index of the next frame to render.
+    pub fn next_index(&self) -> usize {
+        self.next_index
+    }
+
+    /// Number of reference orbits computed so far.
+    pub fn orbits_computed(&self) -> usize {
+        self.renderer.orbits_computed()
+    }
+
+    /// Frame size in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        self.renderer.size()
+    }
+
+    /// Orbit length to compute up front; zero unless the center is fixed.
+    pub fn orbit_iterations(&self) -> u32 {
+        self.orbit_iterations
+    }
+
+    /// Step to the next frame without rendering it, for a caller that
+    /// computes frames itself. None when the path is finished.
+    pub fn next_frame(&mut self) -> Option<ZoomFrame> {
+        if self.next_index >= self.frame_count() {
+            return None;
+        }
+        let frame = self.path.frame(self.next_index);
+        self.next_index += 1;
+        Some(frame)
+    }
+
+    /// Colorize the computed pixels of `frame` into RGBA pixels.
+    pub fn colorize(&mut self, frame: &ZoomFrame, data: &[ComputeData]) -> Vec<u8> {
+        let (width, height) = self.renderer.size();
+        self.pipeline
+            .set_pixel_size_log2(frame.viewport.width.log2_approx() - (width as f64).log2());
+        let pixels = self
+            .pipeline
+            .colorize_final(data, width as usize, height as usize);
+        pixels.into_iter().flatten().collect()
+    }
+
+    /// Colorize the computed pixels of `frame` into PNG bytes.
+    pub fn encode(&mut self, frame: &ZoomFrame, data: &[ComputeData]) -> Vec<u8> {
+        let (width, height) = self.renderer.size();
+        encode_png(&self.colorize(frame, data), width, height)
+    }
+
+    /// Render the next frame as RGBA pixels, or None when the path is finished.
+    pub fn render_next_rgba(&mut self) -> Option<(ZoomFrame, Vec<u8>)> {
+        let frame = self.next_frame()?;
+        let data = self.renderer.render(&frame.viewport, self.orbit_iterations);
+        let rgba = self.colorize(&frame, &data);
+        Some((frame, rgba))
+    }
+
+    /// Render the next frame as PNG bytes, or None when the path is finished.
+    pub fn render_next_png(&mut self) -> Option<(ZoomFrame, Vec<u8>)> {
+        let (width, height) = self.renderer.size();
+        self.render_next_rgba()
+            .map(|(frame, rgba)| (frame, encode_png(&rgba, width, height)))
+    }
+}
+
+/// Render every remaining frame into numbered PNGs in `out_dir`.
+/// Calls `on_frame(done, total)` after each frame is written.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_png_sequence(
+    sequencer: &mut ZoomSequencer,
+    out_dir: &std::path::Path,
+    mut on_frame: impl FnMut(usize, usize),
+) -> std::io::Result<usize> {
+    std::fs::create_dir_all(out_dir)?;
+    let total = sequencer.frame_count();
+    let mut written = 0;
+    while let Some((frame, png)) = sequencer.render_next_png() {
+        std::fs::write(out_dir.join(frame_file_name(frame.index)), png)?;
+        written += 1;
+        on_frame(frame.index + 1, total);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::default_config;
+    use fractalwonder_core::Viewport;
+
+    fn sequencer(end_center: f64) -> ZoomSequencer {
+        let path = ZoomPath::new(
+            Viewport::from_f64(-0.75, 0.1, 1.0, 0.75, 128),
+            Viewport::from_f64(end_center, 0.1, 0.25, 0.1875, 128),
+            2.0,
+        );
+        ZoomSequencer::new(
+            path,
+            default_config(),
+            (8, 6),
+            Palette::default(),
+            RenderSettings::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn renders_every_frame_then_stops() {
+        let mut seq = sequencer(-0.75);
+        assert_eq!(seq.frame_count(), 5);
+
+        let mut indices = Vec::new();
+        while let Some((frame, rgba)) = seq.render_next_rgba() {
+            assert_eq!(rgba.len(), 8 * 6 * 4);
+            indices.push(frame.index);
+        }
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+        assert!(seq.render_next_rgba().is_none());
+    }
+
+    #[test]
+    fn fixed_center_path_computes_one_orbit() {
+        let mut fixed = sequencer(-0.75);
+        while fixed.render_next_rgba().is_some() {}
+        assert_eq!(fixed.orbits_computed(), 1);
+
+        let mut moving = sequencer(-0.7);
+        while moving.render_next_rgba().is_some() {}
+        assert_eq!(moving.orbits_computed(), 5);
+    }
+
+    #[test]
+    fn frames_are_written_as_numbered_pngs() {
+        let dir = std::env::temp_dir().join(format!("fw_zoom_export_{}", std::process::id()));
+        let mut seq = sequencer(-0.75);
+        let written = export_png_sequence(&mut seq, &dir, |_, _| {}).unwrap();
+        assert_eq!(written, 5);
+
+        let first = std::fs::read(dir.join(frame_file_name(0))).unwrap();
+        assert_eq!(&first[1..4], b"PNG");
+        assert!(dir.join("frame_00004.png").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn frames_computed_elsewhere_colorize_like_rendered_ones() {
+        let mut rendered = sequencer(-0.7);
+        let mut stepped = sequencer(-0.7);
+        let palette = Palette::default();
+        let mut renderer = FrameRenderer::new(
+            default_config(),
+            (8, 6),
+            palette.needs_orbit_stats(),
+            palette.interior.mode.requires_cpu(),
+        );
+        while let Some((frame, rgba)) = rendered.render_next_rgba() {
+            let next = stepped.next_frame().unwrap();
+            assert_eq!(next.index, frame.index);
+            let data = renderer.render(&next.viewport, stepped.orbit_iterations());
+            assert_eq!(stepped.colorize(&next, &data), rgba);
+        }
+        assert!(stepped.next_frame().is_none());
+    }
+
+    #[test]
+    fn rejects_empty_frame_size() {
+        let path = ZoomPath::new(
+            Viewport::from_f64(0.0, 0.0, 4.0, 3.0, 64),
+            Viewport::from_f64(0.0, 0.0, 1.0, 0.75, 64),
+            1.0,
+        );
+        let result = ZoomSequencer::new(
+            path,
+            default_config(),
+            (0, 10),
+            Palette::default(),
+            RenderSettings::default(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,248 @@
+//! Named bookmarks of interesting views, kept in localStorage.
+//!
+//! A bookmark stores the same `PersistedState` as the URL hash, so its
+//! coordinates keep full precision, both in storage and in exported files.
+
+use crate::hooks::PersistedState;
+use serde::{Deserialize, Serialize};
+
+const BOOKMARKS_KEY: &str = "fractalwonder_bookmarks";
+
+/// Total size of the thumbnails kept in localStorage, in bytes of data URL.
+/// localStorage holds about 5 MB per origin, shared with the rest of the app.
+const MAX_STORED_THUMBNAIL_BYTES: usize = 2_000_000;
+
+/// Marks exported files so unrelated JSON is rejected on import.
+const EXPORT_FORMAT: &str = "fractalwonder-bookmarks";
+const EXPORT_VERSION: u32 = 1;
+
+/// A saved view.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Creation time in milliseconds since the Unix epoch
+    pub created_ms: f64,
+    /// JPEG data URL of the image when the bookmark was saved
+    #[serde(default)]
+    pub thumbnail: Option<String>,
+    pub state: PersistedState,
+}
+
+impl Bookmark {
+    /// Whether the name or a tag contains `query`, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.name.to_lowercase().contains(&query)
+            || self
+                .tags
+                .iter()
+                .any(|tag| tag.to_lowercase().contains(&query))
+    }
+}
+
+/// Split comma-separated tags, dropping blanks and repeats.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Load bookmarks from localStorage, empty if none are stored.
+pub fn load_bookmarks() -> Vec<Bookmark> {
+    let Some(json) = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|storage| storage.get_item(BOOKMARKS_KEY).ok().flatten())
+    else {
+        return Vec::new();
+    };
+
+    match serde_json::from_str::<Vec<Bookmark>>(&json) {
+        Ok(bookmarks) => bookmarks,
+        Err(e) => {
+            log::warn!("Failed to parse bookmarks: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Save bookmarks to localStorage.
+///
+/// Thumbnails are kept newest first up to `MAX_STORED_THUMBNAIL_BYTES`; older
+/// bookmarks are stored without theirs. Fails when storage is unavailable or
+/// full.
+pub fn save_bookmarks(bookmarks: &[Bookmark]) -> Result<(), String> {
+    let storage = web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .ok_or("localStorage is unavailable")?;
+
+    let stored = cap_thumbnails(bookmarks, MAX_STORED_THUMBNAIL_BYTES);
+    let json = serde_json::to_string(&stored).map_err(|e| e.to_string())?;
+    storage
+        .set_item(BOOKMARKS_KEY, &json)
+        .map_err(|e| format!("{e:?}"))
+}
+
+/// Copy of `bookmarks` keeping thumbnails, newest first, while their total
+/// size stays within `budget` bytes.
+fn cap_thumbnails(bookmarks: &[Bookmark], budget: usize) -> Vec<Bookmark> {
+    let mut newest_first: Vec<usize> = (0..bookmarks.len()).collect();
+    newest_first.sort_by(|&a, &b| bookmarks[b].created_ms.total_cmp(&bookmarks[a].created_ms));
+
+    let mut stored = bookmarks.to_vec();
+    let mut used = 0;
+    for index in newest_first {
+        let Some(thumbnail) = &stored[index].thumbnail else {
+            continue;
+        };
+        if used + thumbnail.len() <= budget {
+            used += thumbnail.len();
+        } else {
+            stored[index].thumbnail = None;
+        }
+    }
+    stored
+}
+
+#[derive(Serialize, Deserialize)]
+struct BookmarkFile {
+    format: String,
+    version: u32,
+    bookmarks: Vec<Bookmark>,
+}
+
+/// Serialize bookmarks for download.
+pub fn export_bookmarks(bookmarks: &[Bookmark]) -> Result<String, String> {
+    let file = BookmarkFile {
+        format: EXPORT_FORMAT.to_string(),
+        version: EXPORT_VERSION,
+        bookmarks: bookmarks.to_vec(),
+    };
+    serde_json::to_string_pretty(&file).map_err(|e| e.to_string())
+}
+
+/// Parse a file written by `export_bookmarks`.
+pub fn import_bookmarks(json: &str) -> Result<Vec<Bookmark>, String> {
+    let file: BookmarkFile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    if file.format != EXPORT_FORMAT {
+        return Err(format!("Not a bookmarks file: format '{}'", file.format));
+    }
+    if file.version > EXPORT_VERSION {
+        return Err(format!("Unsupported bookmarks version {}", file.version));
+    }
+    if let Some(bookmark) = file.bookmarks.iter().find(|b| !b.state.is_known_version()) {
+        return Err(format!("Unsupported state in bookmark '{}'", bookmark.name));
+    }
+    Ok(file.bookmarks)
+}
+
+/// Append imported bookmarks that are not already present. Returns how many
+/// were added.
+pub fn merge_bookmarks(existing: &mut Vec<Bookmark>, imported: Vec<Bookmark>) -> usize {
+    let before = existing.len();
+    for bookmark in imported {
+        if !existing.contains(&bookmark) {
+            existing.push(bookmark);
+        }
+    }
+    existing.len() - before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fractalwonder_core::Viewport;
+
+    fn bookmark(name: &str, tags: &[&str]) -> Bookmark {
+        let viewport = Viewport::from_strings(
+            "-1.7497219716806310317412446032110656",
+            "-0.0000000000000000032493924222121287",
+            "3.2e-100",
+            "1.8e-100",
+            512,
+        )
+        .unwrap();
+        Bookmark {
+            name: name.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            created_ms: 1_700_000_000_000.0,
+            thumbnail: None,
+            state: PersistedState::with_defaults(viewport, "mandelbrot".to_string()),
+        }
+    }
+
+    #[test]
+    fn export_roundtrips_full_precision() {
+        let bookmarks = vec![bookmark("Seahorse", &["spiral"]), bookmark("Minibrot", &[])];
+        let json = export_bookmarks(&bookmarks).unwrap();
+        assert_eq!(import_bookmarks(&json).unwrap(), bookmarks);
+    }
+
+    #[test]
+    fn import_rejects_other_files() {
+        assert!(import_bookmarks("[]").is_err());
+        let json = r#"{"format":"palette","version":1,"bookmarks":[]}"#;
+        assert!(import_bookmarks(json).is_err());
+        let json = r#"{"format":"fractalwonder-bookmarks","version":99,"bookmarks":[]}"#;
+        assert!(import_bookmarks(json).is_err());
+    }
+
+    #[test]
+    fn merge_skips_duplicates() {
+        let mut existing = vec![bookmark("A", &[])];
+        let added = merge_bookmarks(&mut existing, vec![bookmark("A", &[]), bookmark("B", &[])]);
+        assert_eq!(added, 1);
+        assert_eq!(existing.len(), 2);
+    }
+
+    #[test]
+    fn thumbnails_are_capped_newest_first() {
+        let with_thumbnail = |name, created_ms, size| Bookmark {
+            created_ms,
+            thumbnail: Some("x".repeat(size)),
+            ..bookmark(name, &[])
+        };
+        let bookmarks = vec![
+            with_thumbnail("old", 1.0, 40),
+            with_thumbnail("new", 3.0, 60),
+            with_thumbnail("middle", 2.0, 30),
+            bookmark("bare", &[]),
+        ];
+
+        let stored = cap_thumbnails(&bookmarks, 100);
+        let kept: Vec<bool> = stored.iter().map(|b| b.thumbnail.is_some()).collect();
+        assert_eq!(kept, vec![false, true, true, false]);
+        // Everything else is stored unchanged
+        let strip = |b: &Bookmark| Bookmark {
+            thumbnail: None,
+            ..b.clone()
+        };
+        assert!(stored
+            .iter()
+            .zip(&bookmarks)
+            .all(|(s, b)| strip(s) == strip(b)));
+    }
+
+    #[test]
+    fn tags_are_trimmed_and_deduplicated() {
+        assert_eq!(
+            parse_tags(" spiral, deep,,spiral ,  "),
+            vec!["spiral".to_string(), "deep".to_string()]
+        );
+    }
+
+    #[test]
+    fn search_matches_name_and_tags() {
+        let b = bookmark("Seahorse Valley", &["Spiral"]);
+        assert!(b.matches(""));
+        assert!(b.matches("valley"));
+        assert!(b.matches("spi"));
+        assert!(!b.matches("elephant"));
+    }
+}
@@ -0,0 +1,21 @@
+// fractalwonder-ui/src/hooks/color_cycle.rs
+use crate::rendering::colorizers::ColorCycle;
+use leptos::*;
+use leptos_use::use_raf_fn;
+
+/// Longest frame step applied to the phase. Keeps the palette from jumping
+/// when the tab was hidden and animation frames were suspended.
+const MAX_FRAME_MS: f64 = 100.0;
+
+/// Hook that advances the color-cycling phase every animation frame while
+/// cycling is running.
+pub fn use_color_cycle(cycle: Signal<ColorCycle>, phase: RwSignal<f64>) {
+    use_raf_fn(move |args| {
+        let cycle = cycle.get_untracked();
+        if !cycle.running {
+            return;
+        }
+        let elapsed = args.delta.min(MAX_FRAME_MS);
+        phase.update(|p| *p = cycle.advance(*p, elapsed));
+    });
+}
@@ -1,9 +1,18 @@
+mod bookmarks;
+mod color_cycle;
 mod fullscreen;
+mod navigation_history;
 mod persistence;
 mod ui_visibility;
 mod use_canvas_interaction;
 
+pub use bookmarks::{
+    export_bookmarks, import_bookmarks, load_bookmarks, merge_bookmarks, parse_tags,
+    save_bookmarks, Bookmark,
+};
+pub use color_cycle::use_color_cycle;
 pub use fullscreen::{toggle_fullscreen, use_fullscreen};
+pub use navigation_history::NavigationHistory;
 pub use persistence::{
     apply_palette_order, load_palette_order, load_state, save_palette_order, save_state,
     use_hashchange_listener, PersistedState,
@@ -0,0 +1,133 @@
+//! Undo/redo history of visited views.
+//!
+//! Every state handed to `save_state` is also recorded here, so undo steps
+//! back through viewport, palette and render settings together.
+
+use crate::hooks::PersistedState;
+
+/// Entries kept before the oldest is dropped. Deep viewports carry a few
+/// kilobytes of coordinates each.
+pub const MAX_HISTORY: usize = 100;
+
+/// Bounded list of states with a cursor at the current one.
+#[derive(Clone, Debug, Default)]
+pub struct NavigationHistory {
+    entries: Vec<PersistedState>,
+    /// Index of the current state; meaningless while `entries` is empty
+    cursor: usize,
+}
+
+impl NavigationHistory {
+    /// Record `state` as the current one, discarding anything that was
+    /// undone. Recording the current state again is a no-op.
+    pub fn push(&mut self, state: PersistedState) {
+        if self.current() == Some(&state) {
+            return;
+        }
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.cursor + 1);
+        }
+        self.entries.push(state);
+        if self.entries.len() > MAX_HISTORY {
+            self.entries.remove(0);
+        }
+        self.cursor = self.entries.len() - 1;
+    }
+
+    pub fn current(&self) -> Option<&PersistedState> {
+        self.entries.get(self.cursor)
+    }
+
+    /// Step back, returning the state to restore.
+    pub fn undo(&mut self) -> Option<&PersistedState> {
+        if !self.can_undo() {
+            return None;
+        }
+        self.cursor -= 1;
+        self.current()
+    }
+
+    /// Step forward again after an undo.
+    pub fn redo(&mut self) -> Option<&PersistedState> {
+        if !self.can_redo() {
+            return None;
+        }
+        self.cursor += 1;
+        self.current()
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.entries.is_empty() && self.cursor > 0
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fractalwonder_core::Viewport;
+
+    fn state(x: f64) -> PersistedState {
+        PersistedState::with_defaults(
+            Viewport::from_f64(x, 0.0, 4.0, 3.0, 64),
+            "mandelbrot".to_string(),
+        )
+    }
+
+    #[test]
+    fn undo_and_redo_walk_the_stack() {
+        let mut history = NavigationHistory::default();
+        assert!(history.undo().is_none());
+
+        for x in [0.0, 1.0, 2.0] {
+            history.push(state(x));
+        }
+        assert_eq!(history.undo(), Some(&state(1.0)));
+        assert_eq!(history.undo(), Some(&state(0.0)));
+        assert!(history.undo().is_none());
+        assert_eq!(history.redo(), Some(&state(1.0)));
+        assert_eq!(history.redo(), Some(&state(2.0)));
+        assert!(history.redo().is_none());
+    }
+
+    #[test]
+    fn push_after_undo_drops_redo_branch() {
+        let mut history = NavigationHistory::default();
+        for x in [0.0, 1.0, 2.0] {
+            history.push(state(x));
+        }
+        history.undo();
+        history.push(state(5.0));
+
+        assert!(!history.can_redo());
+        assert_eq!(history.undo(), Some(&state(1.0)));
+    }
+
+    #[test]
+    fn repeated_state_is_recorded_once() {
+        let mut history = NavigationHistory::default();
+        history.push(state(0.0));
+        history.push(state(1.0));
+        history.push(state(1.0));
+
+        assert_eq!(history.undo(), Some(&state(0.0)));
+        assert!(!history.can_undo());
+    }
+
+    #[test]
+    fn oldest_entries_are_dropped() {
+        let mut history = NavigationHistory::default();
+        for i in 0..MAX_HISTORY + 5 {
+            history.push(state(i as f64));
+        }
+        let mut steps = 0;
+        while history.undo().is_some() {
+            steps += 1;
+        }
+        assert_eq!(steps, MAX_HISTORY - 1);
+        assert_eq!(history.current(), Some(&state(5.0)));
+    }
+}
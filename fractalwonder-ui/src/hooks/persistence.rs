@@ -17,9 +17,11 @@ const PALETTE_ORDER_KEY: &str = "fractalwonder_palette_order";
 const URL_HASH_PREFIX: &str = "v1:";
 
 /// State persisted to localStorage between sessions.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PersistedState {
-    /// Current viewport (center, width, height with arbitrary precision)
+    /// Current viewport (center, width, height with arbitrary precision).
+    /// Written with compact coordinates; the legacy encoding still loads.
+    #[serde(with = "fractalwonder_core::viewport::compact")]
     pub viewport: Viewport,
     /// Selected fractal configuration ID
     pub config_id: String,
@@ -29,6 +31,9 @@ pub struct PersistedState {
     /// Render settings (cycle_count, use_gpu, xray)
     #[serde(default)]
     pub render_settings: RenderSettings,
+    /// Palette color-cycling phase in [0, 1)
+    #[serde(default)]
+    pub cycle_phase: f64,
     /// Schema version for future migrations
     version: u32,
 }
@@ -38,29 +43,37 @@ fn default_palette_name() -> String {
 }
 
 impl PersistedState {
-    const CURRENT_VERSION: u32 = 4;
+    const CURRENT_VERSION: u32 = 5;
 
     pub fn new(
         viewport: Viewport,
         config_id: String,
         palette_name: String,
         render_settings: RenderSettings,
+        cycle_phase: f64,
     ) -> Self {
         Self {
             viewport,
             config_id,
             palette_name,
             render_settings,
+            cycle_phase,
             version: Self::CURRENT_VERSION,
         }
     }
 
+    /// Whether this build can read the state's schema version.
+    pub fn is_known_version(&self) -> bool {
+        self.version >= 1 && self.version <= Self::CURRENT_VERSION
+    }
+
     pub fn with_defaults(viewport: Viewport, config_id: String) -> Self {
         Self::new(
             viewport,
             config_id,
             "Classic".to_string(),
             RenderSettings::default(),
+            0.0,
         )
     }
 }
@@ -85,8 +98,9 @@ fn load_from_local_storage() -> Option<PersistedState> {
 
     match serde_json::from_str::<PersistedState>(&json) {
         Ok(state) => {
-            // Accept v1, v2, v3, v4 (migration handled by serde default)
-            if state.version >= 1 && state.version <= PersistedState::CURRENT_VERSION {
+            // Accept v1 through v5 (migration handled by serde default; v5 writes
+            // compact viewport coordinates but reads either encoding)
+            if state.is_known_version() {
                 log::info!(
                     "Loaded persisted state from localStorage: config={}, palette={}",
                     state.config_id,
@@ -293,8 +307,8 @@ fn decode_state(encoded: &str) -> Option<PersistedState> {
         }
     };
 
-    // Accept v1, v2, v3, v4 (migration handled by serde default)
-    if state.version >= 1 && state.version <= PersistedState::CURRENT_VERSION {
+    // Accept v1 through v5 (migration handled by serde default)
+    if state.is_known_version() {
         Some(state)
     } else {
         log::warn!(
@@ -419,6 +433,7 @@ mod tests {
             use_gpu: false,
             xray_enabled: true,
             force_hdr_float: false,
+            ..RenderSettings::default()
         };
 
         let state = PersistedState::new(
@@ -426,6 +441,7 @@ mod tests {
             "mandelbrot".to_string(),
             "Fire".to_string(),
             settings.clone(),
+            0.375,
         );
 
         let encoded = encode_state(&state).expect("encoding should succeed");
@@ -437,6 +453,57 @@ mod tests {
         // It deserializes to default (true), regardless of input value
         assert!(decoded.render_settings.use_gpu);
         assert!(decoded.render_settings.xray_enabled);
+        assert_eq!(decoded.cycle_phase, 0.375);
+    }
+
+    fn deep_viewport() -> Viewport {
+        Viewport::from_strings(
+            "-1.7497219716806310317412446032110656",
+            "-0.0000000000000000032493924222121287",
+            "3.2e-1500",
+            "1.8e-1500",
+            5120,
+        )
+        .unwrap()
+    }
+
+    /// Encode raw JSON the way URLs were written before compact coordinates
+    fn encode_legacy_json(json: &str) -> String {
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(json.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        format!("{URL_HASH_PREFIX}{}", URL_SAFE_NO_PAD.encode(compressed))
+    }
+
+    #[test]
+    fn deep_zoom_url_uses_compact_coordinates() {
+        let state = PersistedState::with_defaults(deep_viewport(), "mandelbrot".to_string());
+
+        let mut legacy = serde_json::to_value(&state).unwrap();
+        legacy["viewport"] = serde_json::to_value(&state.viewport).unwrap();
+        let legacy_url = encode_legacy_json(&legacy.to_string());
+        let compact_url = encode_state(&state).unwrap();
+
+        // Deflate already squeezes the base-2 digit strings close to one bit
+        // per digit, so the win here is bounded by the mantissa entropy
+        assert!(
+            compact_url.len() * 5 < legacy_url.len() * 4,
+            "compact {} vs legacy {} chars",
+            compact_url.len(),
+            legacy_url.len()
+        );
+        assert_eq!(decode_state(&compact_url).unwrap().viewport, state.viewport);
+    }
+
+    #[test]
+    fn legacy_v4_url_still_decodes() {
+        let state = PersistedState::with_defaults(deep_viewport(), "mandelbrot".to_string());
+        let mut legacy = serde_json::to_value(&state).unwrap();
+        legacy["viewport"] = serde_json::to_value(&state.viewport).unwrap();
+        legacy["version"] = 4.into();
+
+        let decoded = decode_state(&encode_legacy_json(&legacy.to_string())).unwrap();
+        assert_eq!(decoded.viewport, state.viewport);
     }
 }
 
@@ -1,4 +1,6 @@
-use fractalwonder_core::{compose_affine_transformations, AffinePrimitive, PixelMat3};
+use fractalwonder_core::{
+    compose_affine_transformations, normalize_angle, AffinePrimitive, PixelMat3,
+};
 use leptos::*;
 use leptos_use::use_raf_fn;
 use wasm_bindgen::prelude::*;
@@ -12,6 +14,8 @@ const INTERACTION_TIMEOUT_MS: i32 = 1500;
 const ZOOM_SENSITIVITY: f64 = 0.0005;
 const PINCH_ZOOM_SENSITIVITY: f64 = 0.01; // Much higher sensitivity for pinch gestures
 const DOUBLE_CLICK_ZOOM_FACTOR: f64 = 2.0; // 2x zoom in/out on double-click
+const KEY_ROTATE_STEP: f64 = std::f64::consts::PI / 12.0; // 15° per [ or ] press
+const KEY_ROTATE_FINE_STEP: f64 = std::f64::consts::PI / 180.0; // 1° with Shift
 
 /// Handle returned by the canvas interaction hook
 ///
@@ -36,9 +40,7 @@ fn capture_canvas_image_data(canvas: &HtmlCanvasElement) -> Result<ImageData, Js
 fn render_preview(
     canvas: &HtmlCanvasElement,
     image_data: &ImageData,
-    offset: (f64, f64),
-    zoom: f64,
-    zoom_center: Option<(f64, f64)>,
+    matrix: &PixelMat3,
 ) -> Result<(), JsValue> {
     let attrs = ContextAttributes2d::new();
     attrs.set_will_read_frequently(true);
@@ -79,7 +81,6 @@ fn render_preview(
     temp_context.put_image_data(image_data, 0.0, 0.0)?;
 
     // Apply transformation matrix to main canvas
-    let matrix = PixelMat3::from_scale_and_offset(zoom, offset, zoom_center);
     context.set_transform(
         matrix.data[0][0],
         matrix.data[1][0],
@@ -90,7 +91,7 @@ fn render_preview(
     )?;
 
     // Draw the transformed image from temporary canvas
-    // The transformation ensures the FULL image is drawn with offset/zoom applied
+    // The transformation ensures the FULL image is drawn with offset/zoom/rotation applied
     // Browser naturally clips what's outside canvas bounds (but pixels are preserved in ImageData)
     context.draw_image_with_html_canvas_element(&temp_canvas, 0.0, 0.0)?;
 
@@ -100,12 +101,58 @@ fn render_preview(
     Ok(())
 }
 
-/// Generic canvas interaction hook providing real-time pan/zoom preview
+/// Transformation that carries the pointers from their `start` positions to `now`.
+///
+/// One pointer drags. Two pointers also pinch-zoom and rotate around their
+/// midpoint, so the image stays pinned under both fingers.
+fn gesture_primitives(start: &[(f64, f64)], now: &[(f64, f64)]) -> Vec<AffinePrimitive> {
+    let mut primitives = Vec::new();
+    match (start, now) {
+        ([s], [n]) => {
+            let (dx, dy) = (n.0 - s.0, n.1 - s.1);
+            if dx.abs() > 0.01 || dy.abs() > 0.01 {
+                primitives.push(AffinePrimitive::Translate { dx, dy });
+            }
+        }
+        ([s0, s1], [n0, n1]) => {
+            let start_mid = ((s0.0 + s1.0) / 2.0, (s0.1 + s1.1) / 2.0);
+            let mid = ((n0.0 + n1.0) / 2.0, (n0.1 + n1.1) / 2.0);
+            let start_span = (s1.0 - s0.0, s1.1 - s0.1);
+            let span = (n1.0 - n0.0, n1.1 - n0.1);
+
+            primitives.push(AffinePrimitive::Translate {
+                dx: mid.0 - start_mid.0,
+                dy: mid.1 - start_mid.1,
+            });
+
+            let start_len = start_span.0.hypot(start_span.1);
+            if start_len > 1.0 {
+                primitives.push(AffinePrimitive::Scale {
+                    factor: span.0.hypot(span.1) / start_len,
+                    center_x: mid.0,
+                    center_y: mid.1,
+                });
+                primitives.push(AffinePrimitive::Rotate {
+                    angle: normalize_angle(span.1.atan2(span.0) - start_span.1.atan2(start_span.0)),
+                    center_x: mid.0,
+                    center_y: mid.1,
+                });
+            }
+        }
+        _ => {}
+    }
+    primitives
+}
+
+/// Generic canvas interaction hook providing real-time pan/zoom/rotate preview
 ///
 /// Designed for canvases where full re-renders are expensive (seconds to hours).
 /// Captures canvas ImageData on interaction start, provides real-time preview
 /// using pixel transformations, and fires callback after 1.5s of inactivity.
 ///
+/// Drag pans, wheel and pinch zoom, two-finger twist rotates, and `[` / `]`
+/// rotate around the canvas center (15°, or 1° with Shift).
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -161,11 +208,9 @@ where
     // Stored state (non-reactive)
     let initial_image_data = store_value::<Option<ImageData>>(None);
     let initial_canvas_size = store_value::<Option<(u32, u32)>>(None); // Canvas size when interaction started
-    let drag_start = store_value::<Option<(f64, f64)>>(None);
-    let base_offset = store_value((0.0, 0.0)); // Committed offset from all previous drags (for preview)
-    let current_drag_offset = store_value((0.0, 0.0)); // Offset from current drag only (for preview)
-    let accumulated_zoom = store_value(1.0); // Accumulated zoom (for preview)
-    let zoom_center = store_value::<Option<(f64, f64)>>(None);
+    let pointers = store_value::<Vec<(i32, (f64, f64))>>(Vec::new()); // Active pointers in canvas pixels
+    let gesture_start = store_value::<Vec<(f64, f64)>>(Vec::new()); // Pointer positions when the gesture began
+    let current_gesture = store_value::<Vec<AffinePrimitive>>(Vec::new()); // Live pointer gesture, not yet committed
     let timeout_id = store_value::<Option<i32>>(None);
 
     // Transformation sequence - the source of truth for final result
@@ -201,18 +246,14 @@ where
                     (0.0, 0.0)
                 };
 
-                // Total offset = base (from previous drags + zoom adjustments) + current drag + resize adjustment
-                let base = base_offset.get_value();
-                let current = current_drag_offset.get_value();
-                let total_offset = (
-                    base.0 + current.0 + size_offset.0,
-                    base.1 + current.1 + size_offset.1,
+                // Committed transforms, then the live pointer gesture, then the resize adjustment
+                let sequence = transform_sequence.get_value();
+                let gesture = current_gesture.get_value();
+                let matrix = PixelMat3::translation(size_offset.0, size_offset.1).multiply(
+                    &compose_affine_transformations(sequence.into_iter().chain(gesture)),
                 );
 
-                let zoom = accumulated_zoom.get_value();
-
-                // Pass None for zoom_center since we're baking zoom adjustments into offset
-                let _ = render_preview(&canvas, &image_data, total_offset, zoom, None);
+                let _ = render_preview(&canvas, &image_data, &matrix);
             }
         }
     });
@@ -224,10 +265,7 @@ where
             if let Ok(image_data) = capture_canvas_image_data(&canvas) {
                 initial_image_data.set_value(Some(image_data));
                 initial_canvas_size.set_value(Some((canvas.width(), canvas.height())));
-                base_offset.set_value((0.0, 0.0));
-                current_drag_offset.set_value((0.0, 0.0));
-                accumulated_zoom.set_value(1.0);
-                zoom_center.set_value(None);
+                current_gesture.set_value(Vec::new());
                 transform_sequence.set_value(Vec::new());
 
                 // Fire interaction start callback
@@ -259,58 +297,29 @@ where
         // No transforms = no change, just clean up and return without firing callback
         if sequence.is_empty() {
             initial_image_data.set_value(None);
-            base_offset.set_value((0.0, 0.0));
-            current_drag_offset.set_value((0.0, 0.0));
-            accumulated_zoom.set_value(1.0);
-            zoom_center.set_value(None);
             // sequence already empty, no need to clear
             return;
         }
 
         let composed_matrix: PixelMat3 = compose_affine_transformations(sequence);
 
-        // Extract center-relative offset and zoom from the composed matrix
-        // The matrix is in the form: [[zoom, 0, offset_x], [0, zoom, offset_y], [0, 0, 1]]
-        let zoom_factor = composed_matrix.data[0][0];
-        let absolute_offset_x = composed_matrix.data[0][2];
-        let absolute_offset_y = composed_matrix.data[1][2];
+        // Decompose into center-relative offset, zoom and rotation
+        // This makes the values more intuitive: (0, 0) means we zoomed at canvas center
+        let canvas_ref = canvas_ref_stored.get_value();
+        let (canvas_width, canvas_height) = canvas_ref
+            .get_untracked()
+            .map(|canvas| (canvas.width(), canvas.height()))
+            .unwrap_or((0, 0));
+        let result = PixelTransform::from_matrix(&composed_matrix, canvas_width, canvas_height);
 
         #[cfg(target_arch = "wasm32")]
         web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
-            "Composed: zoom={}, offset=({}, {})",
-            zoom_factor, absolute_offset_x, absolute_offset_y
+            "Composed: zoom={}, rotation={}, offset=({}, {})",
+            result.zoom_factor, result.rotation, result.offset_x, result.offset_y
         )));
 
-        // Convert absolute pixel offset to center-relative offset
-        // This makes the values more intuitive: (0, 0) means we zoomed at canvas center
-        let canvas_ref = canvas_ref_stored.get_value();
-        let (center_relative_x, center_relative_y) =
-            if let Some(canvas) = canvas_ref.get_untracked() {
-                let canvas_center_x = canvas.width() as f64 / 2.0;
-                let canvas_center_y = canvas.height() as f64 / 2.0;
-
-                // Offset is relative to top-left (0, 0), convert to relative to center
-                (
-                    absolute_offset_x - canvas_center_x * (1.0 - zoom_factor),
-                    absolute_offset_y - canvas_center_y * (1.0 - zoom_factor),
-                )
-            } else {
-                (absolute_offset_x, absolute_offset_y)
-            };
-
-        let result = PixelTransform {
-            offset_x: center_relative_x,
-            offset_y: center_relative_y,
-            zoom_factor,
-            matrix: composed_matrix.data,
-        };
-
         // Clear state
         initial_image_data.set_value(None);
-        base_offset.set_value((0.0, 0.0));
-        current_drag_offset.set_value((0.0, 0.0));
-        accumulated_zoom.set_value(1.0);
-        zoom_center.set_value(None);
         transform_sequence.set_value(Vec::new());
 
         // Fire callback
@@ -342,6 +351,32 @@ where
         timeout_id.set_value(Some(id));
     };
 
+    // Fold the live pointer gesture into the committed sequence
+    let commit_gesture = move || {
+        let gesture = current_gesture.get_value();
+        current_gesture.set_value(Vec::new());
+        transform_sequence.update_value(|seq| seq.extend(gesture));
+    };
+
+    // Restart the gesture from where the (at most two) active pointers are now
+    let restart_gesture = move || {
+        let positions: Vec<(f64, f64)> =
+            pointers.with_value(|p| p.iter().take(2).map(|(_, pos)| *pos).collect());
+        gesture_start.set_value(positions);
+    };
+
+    let pointer_position = move |ev: &web_sys::PointerEvent| {
+        let canvas_ref = canvas_ref_stored.get_value();
+        let (left, top) = canvas_ref
+            .get_untracked()
+            .map(|canvas| {
+                let rect = canvas.get_bounding_client_rect();
+                (rect.left(), rect.top())
+            })
+            .unwrap_or((0.0, 0.0));
+        (ev.client_x() as f64 - left, ev.client_y() as f64 - top)
+    };
+
     // Pointer down handler
     let on_pointer_down = move |ev: web_sys::PointerEvent| {
         ev.prevent_default();
@@ -358,9 +393,16 @@ where
             timeout_id.set_value(None);
         }
 
+        // A second finger turns the drag into a pinch/rotate gesture
+        commit_gesture();
+        let position = pointer_position(&ev);
+        pointers.update_value(|p| {
+            p.retain(|(id, _)| *id != ev.pointer_id());
+            p.push((ev.pointer_id(), position));
+        });
+        restart_gesture();
+
         is_dragging.set(true);
-        drag_start.set_value(Some((ev.client_x() as f64, ev.client_y() as f64)));
-        current_drag_offset.set_value((0.0, 0.0)); // Reset current drag offset
     };
 
     // Pointer move handler
@@ -369,38 +411,38 @@ where
             return;
         }
 
-        if let Some(start) = drag_start.get_value() {
-            let current_x = ev.client_x() as f64;
-            let current_y = ev.client_y() as f64;
-            // Calculate offset from THIS drag's start point only
-            let drag_offset = (current_x - start.0, current_y - start.1);
-            current_drag_offset.set_value(drag_offset);
+        let position = pointer_position(&ev);
+        let mut tracked = false;
+        pointers.update_value(|p| {
+            if let Some(entry) = p.iter_mut().find(|(id, _)| *id == ev.pointer_id()) {
+                entry.1 = position;
+                tracked = true;
+            }
+        });
+        if !tracked {
+            return;
         }
+
+        // Calculate the transform from THIS gesture's start positions only
+        let now: Vec<(f64, f64)> =
+            pointers.with_value(|p| p.iter().take(2).map(|(_, pos)| *pos).collect());
+        let start = gesture_start.get_value();
+        current_gesture.set_value(gesture_primitives(&start, &now));
     };
 
-    // Pointer up handler
+    // Pointer up handler (also used for pointercancel)
     let restart_timeout_clone = store_value(restart_timeout);
-    let on_pointer_up = move |_ev: web_sys::PointerEvent| {
-        is_dragging.set(false);
-
-        // Get current drag offset
-        let current = current_drag_offset.get_value();
+    let on_pointer_up = move |ev: web_sys::PointerEvent| {
+        commit_gesture();
+        pointers.update_value(|p| p.retain(|(id, _)| *id != ev.pointer_id()));
 
-        // Add drag transformation to sequence (if there was actual movement)
-        if current.0.abs() > 0.01 || current.1.abs() > 0.01 {
-            transform_sequence.update_value(|seq| {
-                seq.push(AffinePrimitive::Translate {
-                    dx: current.0,
-                    dy: current.1,
-                });
-            });
+        // Lifting one of two fingers continues as a drag with the other
+        if pointers.with_value(|p| !p.is_empty()) {
+            restart_gesture();
+            return;
         }
 
-        // Commit current drag offset into base offset for next drag (for preview)
-        let base = base_offset.get_value();
-        base_offset.set_value((base.0 + current.0, base.1 + current.1));
-        current_drag_offset.set_value((0.0, 0.0));
-
+        is_dragging.set(false);
         restart_timeout_clone.with_value(|f| f());
     };
 
@@ -417,10 +459,6 @@ where
 
         is_zooming.set(true);
 
-        // Get current zoom and offset state (for preview)
-        let old_zoom = accumulated_zoom.get_value();
-        let old_base = base_offset.get_value();
-
         // Calculate zoom factor from wheel delta
         // Pinch gestures (ctrlKey=true) need much higher sensitivity
         let delta = ev.delta_y();
@@ -430,7 +468,6 @@ where
             ZOOM_SENSITIVITY
         };
         let zoom_multiplier = (-delta * sensitivity).exp();
-        let new_zoom = old_zoom * zoom_multiplier;
 
         // Get pointer position relative to canvas
         let canvas_ref = canvas_ref_stored.get_value();
@@ -469,25 +506,6 @@ where
                     center_y: mouse_y,
                 });
             });
-
-            // Update preview state (for real-time rendering)
-            // When zooming at point (mx, my) with current state (old_zoom, old_offset),
-            // we want the image content at (mx, my) to stay at (mx, my).
-            //
-            // Transformation: new_pixel = old_pixel * new_zoom + new_offset
-            // We want: mx = mx * new_zoom + new_offset_x
-            // So: new_offset_x = mx * (1 - new_zoom)
-            //
-            // But we're accumulating! Current transformation is: pixel = original * old_zoom + old_offset
-            // After new zoom: pixel = original * new_zoom + new_offset
-            //
-            // The old offset was already scaled, so when we apply new zoom:
-            // new_offset = old_offset * zoom_multiplier + mx * (1 - zoom_multiplier)
-            let new_offset_x = old_base.0 * zoom_multiplier + mouse_x * (1.0 - zoom_multiplier);
-            let new_offset_y = old_base.1 * zoom_multiplier + mouse_y * (1.0 - zoom_multiplier);
-
-            base_offset.set_value((new_offset_x, new_offset_y));
-            accumulated_zoom.set_value(new_zoom);
         }
 
         // Restart timeout on every wheel event
@@ -506,17 +524,12 @@ where
 
         is_zooming.set(true);
 
-        // Get current zoom and offset state (for preview)
-        let old_zoom = accumulated_zoom.get_value();
-        let old_base = base_offset.get_value();
-
         // Determine zoom direction: alt/option = zoom out, normal = zoom in
         let zoom_multiplier = if ev.alt_key() {
             1.0 / DOUBLE_CLICK_ZOOM_FACTOR // Zoom out
         } else {
             DOUBLE_CLICK_ZOOM_FACTOR // Zoom in
         };
-        let new_zoom = old_zoom * zoom_multiplier;
 
         // Get pointer position relative to canvas
         let canvas_ref = canvas_ref_stored.get_value();
@@ -533,19 +546,76 @@ where
                     center_y: mouse_y,
                 });
             });
-
-            // Update preview state (for real-time rendering)
-            let new_offset_x = old_base.0 * zoom_multiplier + mouse_x * (1.0 - zoom_multiplier);
-            let new_offset_y = old_base.1 * zoom_multiplier + mouse_y * (1.0 - zoom_multiplier);
-
-            base_offset.set_value((new_offset_x, new_offset_y));
-            accumulated_zoom.set_value(new_zoom);
         }
 
         // Restart timeout after double-click
         restart_timeout_dblclick.with_value(|f| f());
     };
 
+    // Keyboard handler for rotating around the canvas center
+    let restart_timeout_key = store_value(restart_timeout);
+    let on_key_down = move |ev: web_sys::KeyboardEvent| {
+        let direction = match ev.key().as_str() {
+            "[" | "{" => -1.0,
+            "]" | "}" => 1.0,
+            _ => return,
+        };
+        if ev.ctrl_key() || ev.meta_key() || ev.alt_key() {
+            return;
+        }
+
+        // Skip if typing in an input field
+        if let Some(element) = ev
+            .target()
+            .and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            let tag = element.tag_name().to_lowercase();
+            if tag == "input" || tag == "textarea" {
+                return;
+            }
+        }
+
+        let canvas_ref = canvas_ref_stored.get_value();
+        let Some(canvas) = canvas_ref.get_untracked() else {
+            return;
+        };
+
+        if initial_image_data.get_value().is_none() {
+            start_interaction();
+        }
+        is_zooming.set(true);
+
+        let step = if ev.shift_key() {
+            KEY_ROTATE_FINE_STEP
+        } else {
+            KEY_ROTATE_STEP
+        };
+        let center_x = canvas.width() as f64 / 2.0;
+        let center_y = canvas.height() as f64 / 2.0;
+
+        // Combine with a previous rotation around the same point, like wheel zoom does
+        transform_sequence.update_value(|seq| {
+            if let Some(AffinePrimitive::Rotate {
+                angle,
+                center_x: last_cx,
+                center_y: last_cy,
+            }) = seq.last_mut()
+            {
+                if *last_cx == center_x && *last_cy == center_y {
+                    *angle += direction * step;
+                    return;
+                }
+            }
+            seq.push(AffinePrimitive::Rotate {
+                angle: direction * step,
+                center_x,
+                center_y,
+            });
+        });
+
+        restart_timeout_key.with_value(|f| f());
+    };
+
     // Canvas resize handler
     let restart_timeout_clone3 = store_value(restart_timeout);
     let on_canvas_resize = move |_new_width: u32, _new_height: u32| {
@@ -606,12 +676,14 @@ where
             })
                 as Box<dyn Fn(web_sys::PointerEvent)>);
 
-            canvas
-                .add_event_listener_with_callback(
-                    "pointerup",
-                    pointer_up_handler.as_ref().unchecked_ref(),
-                )
-                .expect("should add pointerup listener");
+            for event_name in &["pointerup", "pointercancel"] {
+                canvas
+                    .add_event_listener_with_callback(
+                        event_name,
+                        pointer_up_handler.as_ref().unchecked_ref(),
+                    )
+                    .expect("should add pointerup listener");
+            }
             pointer_up_handler.forget();
 
             // Wheel event with non-passive listener
@@ -662,6 +734,22 @@ where
             }
             gesture_prevent.forget();
 
+            // Rotation shortcuts
+            let key_down_clone = on_key_down;
+            let key_down_handler = Closure::wrap(Box::new(move |e: web_sys::KeyboardEvent| {
+                key_down_clone(e);
+            })
+                as Box<dyn Fn(web_sys::KeyboardEvent)>);
+
+            web_sys::window()
+                .expect("should have window")
+                .add_event_listener_with_callback(
+                    "keydown",
+                    key_down_handler.as_ref().unchecked_ref(),
+                )
+                .expect("should add keydown listener");
+            key_down_handler.forget();
+
             // Window resize listener
             let canvas_clone = canvas.clone();
             let resize_clone = on_canvas_resize;
@@ -721,6 +809,31 @@ mod tests {
         assert_eq!(matrix, PixelMat3::scale_around(2.0, 100.0, 100.0));
     }
 
+    #[test]
+    fn single_pointer_gesture_translates() {
+        let primitives = gesture_primitives(&[(10.0, 20.0)], &[(15.0, 12.0)]);
+        assert_eq!(
+            primitives,
+            vec![AffinePrimitive::Translate { dx: 5.0, dy: -8.0 }]
+        );
+        assert!(gesture_primitives(&[(10.0, 20.0)], &[(10.0, 20.0)]).is_empty());
+    }
+
+    #[test]
+    fn two_pointer_gesture_keeps_image_under_fingers() {
+        let start = [(100.0, 100.0), (200.0, 100.0)];
+        // Fingers spread apart, twist a quarter turn and move down-right
+        let now = [(160.0, 60.0), (160.0, 260.0)];
+        let matrix = compose_affine_transformations(gesture_primitives(&start, &now));
+
+        for (s, n) in start.iter().zip(now.iter()) {
+            let (x, y) = matrix.transform_point(s.0, s.1);
+            assert!((x - n.0).abs() < 1e-9 && (y - n.1).abs() < 1e-9);
+        }
+        let rotation = matrix.data[1][0].atan2(matrix.data[0][0]);
+        assert!((rotation - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+
     #[test]
     fn test_combined_transform() {
         // scale 1.5x around (200, 150), then translate by (50, 30)
@@ -4,6 +4,7 @@ use wasm_bindgen::prelude::*;
 mod app;
 mod components;
 pub mod config;
+pub mod export;
 pub mod hooks;
 pub mod rendering;
 pub mod workers;
@@ -39,6 +39,32 @@ pub fn get_2d_context(canvas: &HtmlCanvasElement) -> Result<CanvasRenderingConte
         .dyn_into::<CanvasRenderingContext2d>()?)
 }
 
+/// Scaled-down copy of a canvas as a JPEG data URL, at most `max_width` wide.
+pub fn capture_thumbnail(canvas: &HtmlCanvasElement, max_width: u32) -> Option<String> {
+    let (width, height) = (canvas.width(), canvas.height());
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let scale = (max_width as f64 / width as f64).min(1.0);
+    let thumb_width = ((width as f64 * scale).round() as u32).max(1);
+    let thumb_height = ((height as f64 * scale).round() as u32).max(1);
+
+    let document = web_sys::window()?.document()?;
+    let thumb: HtmlCanvasElement = document.create_element("canvas").ok()?.dyn_into().ok()?;
+    thumb.set_width(thumb_width);
+    thumb.set_height(thumb_height);
+    let ctx = get_2d_context(&thumb).ok()?;
+    ctx.draw_image_with_html_canvas_element_and_dw_and_dh(
+        canvas,
+        0.0,
+        0.0,
+        thumb_width as f64,
+        thumb_height as f64,
+    )
+    .ok()?;
+    thumb.to_data_url_with_type("image/jpeg").ok()
+}
+
 /// Draw RGBA pixel data to canvas at specified position.
 pub fn draw_pixels_to_canvas(
     ctx: &CanvasRenderingContext2d,
@@ -0,0 +1,118 @@
+//! Palette color-cycling animation state.
+
+/// Direction the palette moves while cycling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CycleDirection {
+    #[default]
+    Forward,
+    Reverse,
+}
+
+/// Runtime color-cycling controls. The phase itself is kept separately so it
+/// can be persisted and stepped manually while the animation is stopped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorCycle {
+    pub running: bool,
+    /// Gradient lengths per second.
+    pub speed: f64,
+    pub direction: CycleDirection,
+}
+
+impl Default for ColorCycle {
+    fn default() -> Self {
+        Self {
+            running: false,
+            speed: 0.1,
+            direction: CycleDirection::Forward,
+        }
+    }
+}
+
+impl ColorCycle {
+    pub const MIN_SPEED: f64 = 0.01;
+    pub const MAX_SPEED: f64 = 2.0;
+
+    /// Phase after `elapsed_ms` of animation, wrapped to [0, 1).
+    pub fn advance(&self, phase: f64, elapsed_ms: f64) -> f64 {
+        let sign = match self.direction {
+            CycleDirection::Forward => 1.0,
+            CycleDirection::Reverse => -1.0,
+        };
+        wrap_phase(phase + sign * self.speed * elapsed_ms / 1000.0)
+    }
+
+    /// Double the speed, up to MAX_SPEED.
+    pub fn speed_up(&mut self) {
+        self.speed = (self.speed * 2.0).min(Self::MAX_SPEED);
+    }
+
+    /// Halve the speed, down to MIN_SPEED.
+    pub fn speed_down(&mut self) {
+        self.speed = (self.speed / 2.0).max(Self::MIN_SPEED);
+    }
+
+    pub fn toggle_direction(&mut self) {
+        self.direction = match self.direction {
+            CycleDirection::Forward => CycleDirection::Reverse,
+            CycleDirection::Reverse => CycleDirection::Forward,
+        };
+    }
+}
+
+/// Wrap a phase to [0, 1).
+pub fn wrap_phase(phase: f64) -> f64 {
+    let wrapped = phase.rem_euclid(1.0);
+    // rem_euclid can return 1.0 for tiny negative inputs due to rounding
+    if wrapped >= 1.0 {
+        0.0
+    } else {
+        wrapped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_moves_by_speed_times_time() {
+        let cycle = ColorCycle {
+            speed: 0.5,
+            ..ColorCycle::default()
+        };
+        assert!((cycle.advance(0.25, 1000.0) - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn advance_wraps_in_both_directions() {
+        let mut cycle = ColorCycle {
+            speed: 1.0,
+            ..ColorCycle::default()
+        };
+        assert!((cycle.advance(0.75, 500.0) - 0.25).abs() < 1e-12);
+
+        cycle.toggle_direction();
+        assert!((cycle.advance(0.25, 500.0) - 0.75).abs() < 1e-12);
+    }
+
+    #[test]
+    fn wrap_phase_stays_in_unit_interval() {
+        for phase in [-1e-17, -0.5, 0.0, 0.999_999, 1.0, 3.25] {
+            let wrapped = wrap_phase(phase);
+            assert!((0.0..1.0).contains(&wrapped), "{phase} -> {wrapped}");
+        }
+    }
+
+    #[test]
+    fn speed_is_clamped() {
+        let mut cycle = ColorCycle::default();
+        for _ in 0..20 {
+            cycle.speed_up();
+        }
+        assert_eq!(cycle.speed, ColorCycle::MAX_SPEED);
+        for _ in 0..20 {
+            cycle.speed_down();
+        }
+        assert_eq!(cycle.speed, ColorCycle::MIN_SPEED);
+    }
+}
@@ -118,6 +118,20 @@ impl ColorizerKind {
         }
     }
 
+    pub fn palette_position(
+        &self,
+        data: &ComputeData,
+        context: &SmoothIterationContext,
+        palette: &Palette,
+        render_settings: &RenderSettings,
+    ) -> Option<f64> {
+        match (self, data) {
+            (Self::SmoothIteration(c), ComputeData::Mandelbrot(m)) => {
+                c.palette_position(m, context, palette, render_settings)
+            }
+        }
+    }
+
     pub fn create_context(
         &self,
         data: &[ComputeData],
@@ -202,6 +216,11 @@ mod tests {
                 final_z_norm_sq: 100000.0,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             }),
             ComputeData::Mandelbrot(MandelbrotData {
                 iterations: 0,
@@ -211,6 +230,11 @@ mod tests {
                 final_z_norm_sq: 0.0,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             }),
         ];
 
@@ -0,0 +1,207 @@
+//! Interior (non-escaped) coloring models.
+
+use super::layer::distance_to_unit;
+use super::{ColorStop, Gradient, PaletteLut};
+use fractalwonder_core::MandelbrotData;
+use serde::{Deserialize, Serialize};
+
+/// Golden ratio conjugate: stepping by it spreads consecutive integers evenly
+/// around the gradient, so neighbouring periods get distinct colors.
+const GOLDEN_STEP: f64 = 0.618_033_988_749_895;
+
+/// How non-escaped pixels are colored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InteriorMode {
+    /// Single solid color.
+    #[default]
+    Solid,
+    /// |z| after max_iterations mapped onto the gradient.
+    FinalZ,
+    /// Period of the attracting cycle.
+    Period,
+    /// Interior distance estimate, log-scaled in pixels.
+    Distance,
+    /// Iteration at which |z| was smallest.
+    AtomDomain,
+}
+
+impl InteriorMode {
+    pub const ALL: [InteriorMode; 5] = [
+        InteriorMode::Solid,
+        InteriorMode::FinalZ,
+        InteriorMode::Period,
+        InteriorMode::Distance,
+        InteriorMode::AtomDomain,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InteriorMode::Solid => "Solid",
+            InteriorMode::FinalZ => "Final |z|",
+            InteriorMode::Period => "Period",
+            InteriorMode::Distance => "Interior Distance",
+            InteriorMode::AtomDomain => "Atom Domain",
+        }
+    }
+
+    /// Whether the mode needs period detection, which only the CPU renderer performs.
+    pub fn requires_cpu(&self) -> bool {
+        matches!(self, InteriorMode::Period | InteriorMode::Distance)
+    }
+
+    /// Whether the mode reads the orbit trap iteration.
+    pub fn needs_orbit_stats(&self) -> bool {
+        matches!(self, InteriorMode::AtomDomain)
+    }
+}
+
+/// Interior color model stored on a palette.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InteriorColoring {
+    pub mode: InteriorMode,
+    /// Solid color, also used where a mode has no data (e.g. no period detected).
+    pub color: [u8; 3],
+    /// Gradient for the non-solid modes.
+    pub gradient: Gradient,
+}
+
+impl Default for InteriorColoring {
+    fn default() -> Self {
+        Self {
+            mode: InteriorMode::Solid,
+            color: [0, 0, 0],
+            gradient: Gradient::new(vec![
+                ColorStop {
+                    position: 0.0,
+                    color: [0, 0, 0],
+                },
+                ColorStop {
+                    position: 1.0,
+                    color: [255, 255, 255],
+                },
+            ]),
+        }
+    }
+}
+
+impl InteriorColoring {
+    /// Gradient position for an interior pixel, or None to use the solid color.
+    fn value(&self, data: &MandelbrotData, pixel_size_log2: f64) -> Option<f64> {
+        match self.mode {
+            InteriorMode::Solid => None,
+            InteriorMode::FinalZ => Some((data.final_z_norm_sq as f64).sqrt() / 2.0),
+            InteriorMode::Period => {
+                (data.period > 0).then(|| ((data.period - 1) as f64 * GOLDEN_STEP).fract())
+            }
+            InteriorMode::Distance => (data.period > 0)
+                .then(|| distance_to_unit(data.distance_log2 as f64, pixel_size_log2)),
+            InteriorMode::AtomDomain => (data.atom_domain > 0)
+                .then(|| ((data.atom_domain - 1) as f64 * GOLDEN_STEP).fract()),
+        }
+    }
+
+    /// Color a non-escaped pixel.
+    pub fn colorize(
+        &self,
+        data: &MandelbrotData,
+        lut: &PaletteLut,
+        pixel_size_log2: f64,
+    ) -> [u8; 4] {
+        let [r, g, b] = match self.value(data, pixel_size_log2) {
+            Some(t) => lut.sample_interior(t),
+            None => self.color,
+        };
+        [r, g, b, 255]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::colorizers::Palette;
+
+    fn interior_pixel() -> MandelbrotData {
+        MandelbrotData {
+            iterations: 1000,
+            max_iterations: 1000,
+            final_z_norm_sq: 1.0,
+            atom_domain: 3,
+            period: 3,
+            distance_log2: -8.0,
+            ..MandelbrotData::default()
+        }
+    }
+
+    fn colorize(mode: InteriorMode, data: &MandelbrotData) -> [u8; 4] {
+        let palette = Palette {
+            interior: InteriorColoring {
+                mode,
+                color: [10, 20, 30],
+                ..InteriorColoring::default()
+            },
+            ..Palette::default()
+        };
+        let lut = PaletteLut::from_palette(&palette);
+        palette.interior.colorize(data, &lut, -10.0)
+    }
+
+    #[test]
+    fn default_interior_is_solid_black() {
+        let interior = InteriorColoring::default();
+        let lut = PaletteLut::from_palette(&Palette::default());
+        assert_eq!(
+            interior.colorize(&interior_pixel(), &lut, 0.0),
+            [0, 0, 0, 255]
+        );
+    }
+
+    #[test]
+    fn final_z_maps_magnitude_onto_gradient() {
+        // |z| = 1 is halfway along the [0, 2] range
+        let lut = PaletteLut::from_palette(&Palette::default());
+        let [r, g, b] = lut.sample_interior(0.5);
+        assert_eq!(
+            colorize(InteriorMode::FinalZ, &interior_pixel()),
+            [r, g, b, 255]
+        );
+    }
+
+    #[test]
+    fn period_modes_fall_back_to_solid_without_period() {
+        let data = MandelbrotData {
+            period: 0,
+            ..interior_pixel()
+        };
+        assert_eq!(colorize(InteriorMode::Period, &data), [10, 20, 30, 255]);
+        assert_eq!(colorize(InteriorMode::Distance, &data), [10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn distinct_periods_get_distinct_colors() {
+        let colors: Vec<_> = (1..=6)
+            .map(|period| {
+                colorize(
+                    InteriorMode::Period,
+                    &MandelbrotData {
+                        period,
+                        ..interior_pixel()
+                    },
+                )
+            })
+            .collect();
+        for (i, a) in colors.iter().enumerate() {
+            for b in &colors[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn only_period_modes_require_cpu() {
+        let cpu: Vec<_> = InteriorMode::ALL
+            .into_iter()
+            .filter(InteriorMode::requires_cpu)
+            .collect();
+        assert_eq!(cpu, vec![InteriorMode::Period, InteriorMode::Distance]);
+    }
+}
@@ -0,0 +1,376 @@
+//! Color layers composited over the base colorizer in linear light.
+//!
+//! A palette's own gradient and transfer curve form the base layer. Each
+//! additional `ColorLayer` maps a per-pixel quantity to a color with its own
+//! gradient and curve, then blends onto the result with an opacity.
+
+use super::color_space::{linear_to_srgb, srgb_to_linear};
+use super::smooth_iteration::compute_smooth_iteration;
+use super::{Curve, Gradient};
+use fractalwonder_core::MandelbrotData;
+use serde::{Deserialize, Serialize};
+
+/// Blend modes for compositing a layer onto the layers below it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    SoftLight,
+}
+
+impl BlendMode {
+    pub const ALL: [BlendMode; 5] = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::SoftLight,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BlendMode::Normal => "Normal",
+            BlendMode::Multiply => "Multiply",
+            BlendMode::Screen => "Screen",
+            BlendMode::Overlay => "Overlay",
+            BlendMode::SoftLight => "Soft Light",
+        }
+    }
+
+    /// Blend one linear-light channel. `base` is the layer below, `top` this layer.
+    #[inline]
+    fn blend(&self, base: f64, top: f64) -> f64 {
+        match self {
+            BlendMode::Normal => top,
+            BlendMode::Multiply => base * top,
+            BlendMode::Screen => 1.0 - (1.0 - base) * (1.0 - top),
+            BlendMode::Overlay => {
+                if base < 0.5 {
+                    2.0 * base * top
+                } else {
+                    1.0 - 2.0 * (1.0 - base) * (1.0 - top)
+                }
+            }
+            // W3C compositing spec soft-light
+            BlendMode::SoftLight => {
+                if top <= 0.5 {
+                    base - (1.0 - 2.0 * top) * base * (1.0 - base)
+                } else {
+                    let d = if base <= 0.25 {
+                        ((16.0 * base - 12.0) * base + 4.0) * base
+                    } else {
+                        base.sqrt()
+                    };
+                    base + (2.0 * top - 1.0) * (d - base)
+                }
+            }
+        }
+    }
+}
+
+/// The per-pixel quantity a layer colors by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LayerSource {
+    /// Smooth iteration count normalized by max_iterations (exterior only).
+    #[default]
+    SmoothIteration,
+    /// Exterior distance estimate in pixels, log-scaled (exterior only).
+    Distance,
+    /// Minimum |z| along the orbit (exterior and interior).
+    OrbitTrap,
+    /// Stripe average of arg z (exterior only).
+    Stripe,
+}
+
+/// Distance range mapped onto the gradient, as log₂ of pixels: 1/16 px to 256 px.
+const DISTANCE_LOG2_MIN: f64 = -4.0;
+const DISTANCE_LOG2_MAX: f64 = 8.0;
+
+/// Map a log₂ distance estimate to [0, 1] as log₂ pixels over the distance range.
+pub(crate) fn distance_to_unit(distance_log2: f64, pixel_size_log2: f64) -> f64 {
+    let pixels_log2 = distance_log2 - pixel_size_log2;
+    (pixels_log2 - DISTANCE_LOG2_MIN) / (DISTANCE_LOG2_MAX - DISTANCE_LOG2_MIN)
+}
+
+/// Orbit trap distance mapped to the end of the gradient.
+const ORBIT_TRAP_MAX: f64 = 2.0;
+
+impl LayerSource {
+    pub const ALL: [LayerSource; 4] = [
+        LayerSource::SmoothIteration,
+        LayerSource::Distance,
+        LayerSource::OrbitTrap,
+        LayerSource::Stripe,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LayerSource::SmoothIteration => "Smooth Iteration",
+            LayerSource::Distance => "Distance",
+            LayerSource::OrbitTrap => "Orbit Trap",
+            LayerSource::Stripe => "Stripe",
+        }
+    }
+
+    /// Whether the source reads orbit trap or stripe statistics, which the
+    /// renderers only record on request.
+    pub fn needs_orbit_stats(&self) -> bool {
+        matches!(self, LayerSource::OrbitTrap | LayerSource::Stripe)
+    }
+
+    /// Map pixel data to a gradient position in [0, 1].
+    /// Returns None where this source is undefined, leaving the pixel untouched.
+    pub fn value(&self, data: &MandelbrotData, pixel_size_log2: f64) -> Option<f64> {
+        if data.max_iterations == 0 {
+            return None;
+        }
+        match self {
+            LayerSource::SmoothIteration => data
+                .escaped
+                .then(|| compute_smooth_iteration(data) / data.max_iterations as f64),
+            LayerSource::Distance => data
+                .escaped
+                .then(|| distance_to_unit(data.distance_log2 as f64, pixel_size_log2)),
+            LayerSource::OrbitTrap => Some(data.orbit_trap as f64 / ORBIT_TRAP_MAX),
+            LayerSource::Stripe => data.escaped.then_some(data.stripe_avg as f64),
+        }
+        .map(|t| t.clamp(0.0, 1.0))
+    }
+}
+
+/// One layer in a palette's layer stack.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ColorLayer {
+    pub source: LayerSource,
+    pub gradient: Gradient,
+    pub transfer_curve: Curve,
+    /// Layer opacity in [0, 1].
+    pub opacity: f64,
+    pub blend_mode: BlendMode,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for ColorLayer {
+    fn default() -> Self {
+        Self {
+            source: LayerSource::Stripe,
+            gradient: Gradient::new(vec![
+                super::ColorStop {
+                    position: 0.0,
+                    color: [0, 0, 0],
+                },
+                super::ColorStop {
+                    position: 1.0,
+                    color: [255, 255, 255],
+                },
+            ]),
+            transfer_curve: Curve::linear(),
+            opacity: 0.5,
+            blend_mode: BlendMode::Overlay,
+            enabled: true,
+        }
+    }
+}
+
+/// Pre-computed lookup table for one layer, stored in linear light.
+pub struct LayerLut {
+    lut: Vec<[f64; 3]>,
+}
+
+impl LayerLut {
+    pub fn from_layer(layer: &ColorLayer) -> Self {
+        let lut = layer
+            .gradient
+            .to_lut()
+            .into_iter()
+            .map(|c| c.map(|v| srgb_to_linear(v as f64 / 255.0)))
+            .collect();
+        Self { lut }
+    }
+
+    #[inline]
+    fn sample(&self, t: f64) -> [f64; 3] {
+        let last = self.lut.len() - 1;
+        let index = ((t.clamp(0.0, 1.0) * last as f64) as usize).min(last);
+        self.lut[index]
+    }
+}
+
+/// Composite enabled layers onto a base color in linear light.
+///
+/// `layer_luts` must be parallel to `layers` (see `PaletteLut::from_palette`).
+pub fn composite_layers(
+    base: [u8; 4],
+    data: &MandelbrotData,
+    layers: &[ColorLayer],
+    layer_luts: &[LayerLut],
+    pixel_size_log2: f64,
+) -> [u8; 4] {
+    if layers.is_empty() {
+        return base;
+    }
+
+    let mut rgb = [base[0], base[1], base[2]].map(|v| srgb_to_linear(v as f64 / 255.0));
+    let mut touched = false;
+
+    for (layer, lut) in layers.iter().zip(layer_luts) {
+        if !layer.enabled || layer.opacity <= 0.0 {
+            continue;
+        }
+        let Some(t) = layer.source.value(data, pixel_size_log2) else {
+            continue;
+        };
+        let top = lut.sample(layer.transfer_curve.evaluate(t));
+        let opacity = layer.opacity.clamp(0.0, 1.0);
+        for (channel, top) in rgb.iter_mut().zip(top) {
+            let blended = layer.blend_mode.blend(*channel, top);
+            *channel += (blended - *channel) * opacity;
+        }
+        touched = true;
+    }
+
+    if !touched {
+        return base;
+    }
+
+    let [r, g, b] = rgb.map(|v| (linear_to_srgb(v.clamp(0.0, 1.0)) * 255.0).round() as u8);
+    [r, g, b, base[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::colorizers::ColorStop;
+
+    fn escaped_pixel() -> MandelbrotData {
+        MandelbrotData {
+            iterations: 10,
+            max_iterations: 100,
+            escaped: true,
+            final_z_norm_sq: 100000.0,
+            orbit_trap: 0.5,
+            stripe_avg: 0.75,
+            distance_log2: -10.0,
+            ..MandelbrotData::default()
+        }
+    }
+
+    fn solid_layer(color: [u8; 3], blend_mode: BlendMode, opacity: f64) -> ColorLayer {
+        ColorLayer {
+            source: LayerSource::OrbitTrap,
+            gradient: Gradient::new(vec![
+                ColorStop {
+                    position: 0.0,
+                    color,
+                },
+                ColorStop {
+                    position: 1.0,
+                    color,
+                },
+            ]),
+            opacity,
+            blend_mode,
+            ..ColorLayer::default()
+        }
+    }
+
+    fn composite(base: [u8; 4], layers: &[ColorLayer]) -> [u8; 4] {
+        let luts: Vec<LayerLut> = layers.iter().map(LayerLut::from_layer).collect();
+        composite_layers(base, &escaped_pixel(), layers, &luts, 0.0)
+    }
+
+    #[test]
+    fn no_layers_returns_base() {
+        assert_eq!(composite([12, 34, 56, 255], &[]), [12, 34, 56, 255]);
+    }
+
+    #[test]
+    fn normal_full_opacity_replaces_base() {
+        let layers = [solid_layer([200, 100, 50], BlendMode::Normal, 1.0)];
+        assert_eq!(composite([0, 0, 0, 255], &layers), [200, 100, 50, 255]);
+    }
+
+    #[test]
+    fn zero_opacity_leaves_base() {
+        let layers = [solid_layer([200, 100, 50], BlendMode::Normal, 0.0)];
+        assert_eq!(composite([10, 20, 30, 255], &layers), [10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn disabled_layer_is_skipped() {
+        let mut layer = solid_layer([255, 255, 255], BlendMode::Normal, 1.0);
+        layer.enabled = false;
+        assert_eq!(composite([10, 20, 30, 255], &[layer]), [10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn multiply_white_and_screen_black_are_identity() {
+        let base = [90, 140, 200, 255];
+        let multiply = [solid_layer([255, 255, 255], BlendMode::Multiply, 1.0)];
+        let screen = [solid_layer([0, 0, 0], BlendMode::Screen, 1.0)];
+        assert_eq!(composite(base, &multiply), base);
+        assert_eq!(composite(base, &screen), base);
+    }
+
+    #[test]
+    fn half_opacity_mixes_in_linear_light() {
+        // 50% white over black in linear light is 0.5 linear, ~188 in sRGB (not 128)
+        let layers = [solid_layer([255, 255, 255], BlendMode::Normal, 0.5)];
+        let [r, g, b, _] = composite([0, 0, 0, 255], &layers);
+        assert_eq!((r, g, b), (188, 188, 188));
+    }
+
+    #[test]
+    fn blend_modes_stay_in_unit_range() {
+        for mode in BlendMode::ALL {
+            for base in [0.0, 0.2, 0.5, 0.8, 1.0] {
+                for top in [0.0, 0.3, 0.5, 0.7, 1.0] {
+                    let v = mode.blend(base, top);
+                    assert!((0.0..=1.0).contains(&v), "{mode:?}({base}, {top}) = {v}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sources_skip_interior_except_orbit_trap() {
+        let interior = MandelbrotData {
+            iterations: 100,
+            max_iterations: 100,
+            orbit_trap: 0.25,
+            ..MandelbrotData::default()
+        };
+        assert_eq!(LayerSource::SmoothIteration.value(&interior, 0.0), None);
+        assert_eq!(LayerSource::Distance.value(&interior, 0.0), None);
+        assert_eq!(LayerSource::Stripe.value(&interior, 0.0), None);
+        assert_eq!(LayerSource::OrbitTrap.value(&interior, 0.0), Some(0.125));
+    }
+
+    #[test]
+    fn distance_source_is_relative_to_pixel_size() {
+        let data = escaped_pixel(); // DE = 2^-10
+                                    // One pixel = 2^-10: distance is 1 px, i.e. log2 = 0 → 4/12 along the range
+        let t = LayerSource::Distance.value(&data, -10.0).unwrap();
+        assert!((t - 4.0 / 12.0).abs() < 1e-9);
+        // Smaller pixels make the same distance span more pixels
+        let t_zoomed = LayerSource::Distance.value(&data, -14.0).unwrap();
+        assert!(t_zoomed > t);
+    }
+
+    #[test]
+    fn color_layer_deserializes_without_enabled() {
+        let json = serde_json::to_string(&ColorLayer::default())
+            .unwrap()
+            .replace(",\"enabled\":true", "");
+        let layer: ColorLayer = serde_json::from_str(&json).unwrap();
+        assert!(layer.enabled);
+    }
+}
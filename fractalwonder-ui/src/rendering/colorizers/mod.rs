@@ -1,7 +1,10 @@
+pub mod color_cycle;
 pub mod color_space;
 pub mod colorizer;
 pub mod curve;
 pub mod gradient;
+pub mod interior;
+pub mod layer;
 pub mod lighting_params;
 pub mod palette;
 pub mod pipeline;
@@ -9,13 +12,18 @@ pub mod render_settings;
 pub mod shading;
 pub mod smooth_iteration;
 
+pub use color_cycle::{wrap_phase, ColorCycle, CycleDirection};
 pub use color_space::{hex_to_rgb, rgb_to_hex};
 pub use colorizer::{Colorizer, ColorizerKind};
 pub use curve::{Curve, CurvePoint, CurveScale};
 pub use gradient::{ColorStop, Gradient};
+pub use interior::{InteriorColoring, InteriorMode};
+pub use layer::{composite_layers, BlendMode, ColorLayer, LayerLut, LayerSource};
 pub use lighting_params::LightingParams;
 pub use palette::{Palette, PaletteLut};
 pub use pipeline::ColorPipeline;
 pub use render_settings::RenderSettings;
 pub use shading::apply_slope_shading;
-pub use smooth_iteration::{SmoothIterationColorizer, SmoothIterationContext};
+pub use smooth_iteration::{
+    compute_smooth_iteration, SmoothIterationColorizer, SmoothIterationContext,
+};
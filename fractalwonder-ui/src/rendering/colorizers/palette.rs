@@ -1,5 +1,7 @@
 //! Unified Palette struct containing gradient, curves, lighting, and flags.
 
+use super::interior::InteriorColoring;
+use super::layer::{ColorLayer, LayerLut};
 use super::{ColorStop, Curve, CurveScale, Gradient, LightingParams};
 use serde::{Deserialize, Serialize};
 use std::sync::OnceLock;
@@ -26,6 +28,12 @@ pub struct Palette {
     pub shading_enabled: bool,
     pub falloff_curve: Curve,
     pub lighting: LightingParams,
+    /// Additional layers composited over the base gradient, bottom to top.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<ColorLayer>,
+    /// Color model for points that did not escape.
+    #[serde(default)]
+    pub interior: InteriorColoring,
 }
 
 impl Default for Palette {
@@ -48,6 +56,8 @@ impl Default for Palette {
             shading_enabled: false,
             falloff_curve: Curve::linear(),
             lighting: LightingParams::default(),
+            layers: Vec::new(),
+            interior: InteriorColoring::default(),
         }
     }
 }
@@ -58,6 +68,15 @@ impl Palette {
         self.gradient.to_lut()
     }
 
+    /// Whether rendering for this palette must record orbit statistics.
+    pub fn needs_orbit_stats(&self) -> bool {
+        self.interior.mode.needs_orbit_stats()
+            || self
+                .layers
+                .iter()
+                .any(|layer| layer.enabled && layer.source.needs_orbit_stats())
+    }
+
     /// Apply the transfer curve to a normalized value.
     pub fn apply_transfer(&self, t: f64) -> f64 {
         self.transfer_curve.evaluate(t)
@@ -196,9 +215,11 @@ impl Palette {
 }
 
 /// Pre-computed lookup table for fast color sampling.
-/// Generated from a Palette's gradient.
+/// Generated from a Palette's gradient, plus tables for its layers and interior.
 pub struct PaletteLut {
     lut: Vec<[u8; 3]>,
+    layer_luts: Vec<LayerLut>,
+    interior_lut: Vec<[u8; 3]>,
 }
 
 impl PaletteLut {
@@ -206,9 +227,16 @@ impl PaletteLut {
     pub fn from_palette(palette: &Palette) -> Self {
         Self {
             lut: palette.to_lut(),
+            layer_luts: palette.layers.iter().map(LayerLut::from_layer).collect(),
+            interior_lut: palette.interior.gradient.to_lut(),
         }
     }
 
+    /// Lookup tables for the palette's layers, parallel to `Palette::layers`.
+    pub fn layer_luts(&self) -> &[LayerLut] {
+        &self.layer_luts
+    }
+
     /// Sample the palette at position t ∈ [0,1].
     #[inline]
     pub fn sample(&self, t: f64) -> [u8; 3] {
@@ -216,11 +244,20 @@ impl PaletteLut {
         let index = ((t * 4095.0) as usize).min(4095);
         self.lut[index]
     }
+
+    /// Sample the interior gradient at position t ∈ [0,1].
+    #[inline]
+    pub fn sample_interior(&self, t: f64) -> [u8; 3] {
+        let t = t.clamp(0.0, 1.0);
+        let index = ((t * 4095.0) as usize).min(4095);
+        self.interior_lut[index]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rendering::colorizers::{InteriorMode, LayerSource};
     use futures::executor::block_on;
 
     #[test]
@@ -252,6 +289,54 @@ mod tests {
         assert_eq!(palette.name, parsed.name);
     }
 
+    #[test]
+    fn palette_without_layers_omits_field_and_still_parses() {
+        let palette = Palette::default();
+        let json = serde_json::to_string(&palette).unwrap();
+        assert!(!json.contains("layers"));
+
+        let with_layers = Palette {
+            layers: vec![ColorLayer::default()],
+            ..Palette::default()
+        };
+        let json = serde_json::to_string(&with_layers).unwrap();
+        let parsed: Palette = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, with_layers);
+    }
+
+    #[test]
+    fn palette_needs_orbit_stats_only_for_enabled_stats_sources() {
+        assert!(!Palette::default().needs_orbit_stats());
+
+        let mut palette = Palette {
+            layers: vec![ColorLayer {
+                source: LayerSource::Distance,
+                ..ColorLayer::default()
+            }],
+            ..Palette::default()
+        };
+        assert!(!palette.needs_orbit_stats());
+
+        palette.layers[0].source = LayerSource::OrbitTrap;
+        assert!(palette.needs_orbit_stats());
+
+        palette.layers[0].enabled = false;
+        assert!(!palette.needs_orbit_stats());
+
+        palette.interior.mode = InteriorMode::AtomDomain;
+        assert!(palette.needs_orbit_stats());
+    }
+
+    #[test]
+    fn palette_without_interior_defaults_to_solid_black() {
+        let json = serde_json::to_string(&Palette::default()).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value.as_object_mut().unwrap().remove("interior");
+        let parsed: Palette = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.interior, InteriorColoring::default());
+        assert_eq!(parsed.interior.color, [0, 0, 0]);
+    }
+
     #[test]
     #[ignore] // Run with: cargo test print_factory_json -- --ignored --nocapture
     fn print_factory_json() {
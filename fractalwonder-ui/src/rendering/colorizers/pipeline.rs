@@ -1,8 +1,31 @@
 //! Unified colorization pipeline with histogram caching.
 
 use super::palette::{Palette, PaletteLut};
-use super::{ColorizerKind, RenderSettings, SmoothIterationContext};
+use super::{apply_slope_shading, ColorizerKind, RenderSettings, SmoothIterationContext};
 use fractalwonder_core::ComputeData;
+use std::borrow::Cow;
+
+/// Gradient lengths the palette shifts per doubling of zoom in zoom-stable mode.
+const ZOOM_OFFSET_PER_OCTAVE: f64 = 0.05;
+
+/// Coloring reference captured from one frame for zoom-stable mode.
+///
+/// Later frames reuse its percentile mapping and iteration scale so a pixel's
+/// color depends only on its own value and the zoom depth, not on the rest of
+/// the frame.
+#[derive(Clone, Debug)]
+struct StableReference {
+    sorted_smooth: Option<Vec<f64>>,
+    /// Median of `sorted_smooth`, the escape depth the frozen mapping is centered on
+    median_smooth: f64,
+    iteration_reference: f64,
+    pixel_size_log2: f64,
+}
+
+/// Middle value of a sorted list, 0 when it is empty.
+fn median(sorted: &[f64]) -> f64 {
+    sorted.get(sorted.len() / 2).copied().unwrap_or(0.0)
+}
 
 /// Unified colorization pipeline.
 ///
@@ -14,6 +37,15 @@ pub struct ColorPipeline {
     lut: PaletteLut,
     render_settings: RenderSettings,
     cached_context: Option<SmoothIterationContext>,
+    /// Frozen reference for zoom-stable coloring, captured on the next final frame when None.
+    stable_reference: Option<StableReference>,
+    /// Global iteration count for zoom-stable normalization.
+    /// Defaults to the reference frame's max_iterations when None.
+    iteration_reference: Option<u32>,
+    /// Palette phase offset in [0, 1), driven by color cycling.
+    cycle_phase: f64,
+    /// log₂ of one pixel's size in fractal units, set by the renderer per frame.
+    pixel_size_log2: f64,
 }
 
 impl ColorPipeline {
@@ -25,6 +57,10 @@ impl ColorPipeline {
             lut,
             render_settings,
             cached_context: None,
+            stable_reference: None,
+            iteration_reference: None,
+            cycle_phase: 0.0,
+            pixel_size_log2: 0.0,
         }
     }
 
@@ -37,19 +73,140 @@ impl ColorPipeline {
     }
 
     pub fn set_palette(&mut self, palette: Palette) {
+        if palette.histogram_enabled != self.palette.histogram_enabled
+            || palette.smooth_enabled != self.palette.smooth_enabled
+        {
+            // The frozen histogram was built from different values
+            self.stable_reference = None;
+        }
         self.lut = PaletteLut::from_palette(&palette);
         self.palette = palette;
     }
 
     pub fn set_render_settings(&mut self, settings: RenderSettings) {
+        if settings.zoom_stable != self.render_settings.zoom_stable {
+            self.stable_reference = None;
+        }
         self.render_settings = settings;
     }
 
+    pub fn cycle_phase(&self) -> f64 {
+        self.cycle_phase
+    }
+
+    /// Set the palette phase offset used by color cycling.
+    pub fn set_cycle_phase(&mut self, phase: f64) {
+        self.cycle_phase = phase;
+        if let Some(ctx) = self.cached_context.as_mut() {
+            ctx.cycle_phase = phase;
+        }
+    }
+
+    /// Set the size of one pixel in fractal units (log₂), used by distance layers.
+    pub fn set_pixel_size_log2(&mut self, pixel_size_log2: f64) {
+        self.pixel_size_log2 = pixel_size_log2;
+
+        // Keep progressive chunks of the next frame at the new zoom
+        let offset = self.stable_palette_offset();
+        if let Some(ctx) = self.cached_context.as_mut() {
+            ctx.pixel_size_log2 = pixel_size_log2;
+            if let Some(offset) = offset {
+                ctx.palette_offset = offset;
+            }
+        }
+    }
+
+    /// Set the global iteration count used to normalize zoom-stable frames.
+    /// A zoom sequence should pass the same value for every frame.
+    pub fn set_iteration_reference(&mut self, iterations: Option<u32>) {
+        self.iteration_reference = iterations;
+        self.stable_reference = None;
+    }
+
+    /// Discard the zoom-stable reference so the next final frame captures a new one.
+    pub fn reset_stable_reference(&mut self) {
+        self.stable_reference = None;
+    }
+
     pub fn invalidate_cache(&mut self) {
         self.cached_context = None;
     }
 
+    /// Gradient offset for the current zoom relative to the stable reference frame.
+    fn stable_palette_offset(&self) -> Option<f64> {
+        if !self.render_settings.zoom_stable {
+            return None;
+        }
+        let reference = self.stable_reference.as_ref()?;
+        Some((reference.pixel_size_log2 - self.pixel_size_log2) * ZOOM_OFFSET_PER_OCTAVE)
+    }
+
+    /// Build the coloring context for a final frame.
+    ///
+    /// In zoom-stable mode the first frame becomes the reference; later frames
+    /// keep their own smooth values but use the frozen percentile mapping,
+    /// the global iteration reference and a zoom-dependent gradient offset.
+    ///
+    /// Deeper frames escape later everywhere, so the frozen mapping is moved by
+    /// the iterations gained since the reference, measured between the frames'
+    /// median escape values. Without that the whole frame would rank above the
+    /// reference histogram and saturate at the end of the gradient.
+    fn final_context(&mut self, data: &[ComputeData]) -> SmoothIterationContext {
+        let mut context = SmoothIterationContext {
+            cycle_phase: self.cycle_phase,
+            pixel_size_log2: self.pixel_size_log2,
+            ..self.colorizer.create_context(data, &self.palette)
+        };
+        if !self.render_settings.zoom_stable {
+            return context;
+        }
+
+        let reference = self.stable_reference.get_or_insert_with(|| {
+            let frame_max_iterations = data
+                .iter()
+                .map(|d| {
+                    let ComputeData::Mandelbrot(m) = d;
+                    m.max_iterations
+                })
+                .max()
+                .unwrap_or(0);
+            StableReference {
+                sorted_smooth: context.sorted_smooth.clone(),
+                median_smooth: context.sorted_smooth.as_deref().map_or(0.0, median),
+                iteration_reference: self
+                    .iteration_reference
+                    .unwrap_or(frame_max_iterations)
+                    .max(1) as f64,
+                pixel_size_log2: self.pixel_size_log2,
+            }
+        });
+
+        if let Some(frame_sorted) = &context.sorted_smooth {
+            let gained = median(frame_sorted) - reference.median_smooth;
+            context.sorted_smooth = reference
+                .sorted_smooth
+                .as_ref()
+                .map(|sorted| sorted.iter().map(|value| value + gained).collect());
+        }
+        context.iteration_reference = Some(reference.iteration_reference);
+        context.palette_offset = self.stable_palette_offset().unwrap_or(0.0);
+        context
+    }
+
+    /// Context of the last final frame, or one without a histogram before the first.
+    fn chunk_context(&self) -> Cow<'_, SmoothIterationContext> {
+        match &self.cached_context {
+            Some(ctx) => Cow::Borrowed(ctx),
+            None => Cow::Owned(SmoothIterationContext {
+                cycle_phase: self.cycle_phase,
+                pixel_size_log2: self.pixel_size_log2,
+                ..SmoothIterationContext::default()
+            }),
+        }
+    }
+
     pub fn colorize_chunk(&self, data: &[ComputeData]) -> Vec<[u8; 4]> {
+        let context = self.chunk_context();
         data.iter()
             .map(|d| {
                 let ComputeData::Mandelbrot(m) = d;
@@ -62,29 +219,47 @@ impl ColorPipeline {
                     return [0, brightness, brightness, 255];
                 }
 
-                if let Some(ref ctx) = self.cached_context {
-                    self.colorizer.colorize_with_cached_histogram(
-                        d,
-                        ctx,
-                        &self.palette,
-                        &self.lut,
-                        &self.render_settings,
-                    )
-                } else {
-                    self.colorizer
-                        .colorize(d, &self.palette, &self.lut, &self.render_settings)
-                }
+                self.colorizer.colorize_with_cached_histogram(
+                    d,
+                    &context,
+                    &self.palette,
+                    &self.lut,
+                    &self.render_settings,
+                )
             })
             .collect()
     }
 
+    /// Palette gradient position a pixel maps to with the current histogram,
+    /// or None for interior pixels.
+    pub fn palette_position(&self, data: &ComputeData) -> Option<f64> {
+        let context = self.chunk_context();
+        self.colorizer
+            .palette_position(data, &context, &self.palette, &self.render_settings)
+    }
+
+    /// Colorize a full frame for color cycling.
+    ///
+    /// Reuses the cached histogram from the last `colorize_final` so only the
+    /// phase changes between frames, then reapplies slope shading.
+    pub fn colorize_cycle_frame(
+        &self,
+        data: &[ComputeData],
+        width: usize,
+        height: usize,
+    ) -> Vec<[u8; 4]> {
+        let mut pixels = self.colorize_chunk(data);
+        apply_slope_shading(&mut pixels, data, &self.palette, width, height);
+        pixels
+    }
+
     pub fn colorize_final(
         &mut self,
         data: &[ComputeData],
         width: usize,
         height: usize,
     ) -> Vec<[u8; 4]> {
-        let context = self.colorizer.create_context(data, &self.palette);
+        let context = self.final_context(data);
 
         let pixels = self.colorizer.run_pipeline_with_context(
             data,
@@ -102,3 +277,205 @@ impl ColorPipeline {
         pixels
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::colorizers::{BlendMode, ColorLayer, LayerSource};
+    use fractalwonder_core::MandelbrotData;
+
+    fn escaped(iterations: u32) -> ComputeData {
+        ComputeData::Mandelbrot(MandelbrotData {
+            iterations,
+            max_iterations: 100,
+            escaped: true,
+            final_z_norm_sq: 100000.0,
+            ..MandelbrotData::default()
+        })
+    }
+
+    #[test]
+    fn palette_position_follows_cached_histogram() {
+        let mut pipeline = ColorPipeline::new(
+            Palette {
+                histogram_enabled: true,
+                smooth_enabled: false,
+                ..Palette::default()
+            },
+            RenderSettings::default(),
+        );
+        let frame: Vec<ComputeData> = (1..=4).map(|i| escaped(i * 10)).collect();
+        pipeline.colorize_final(&frame, 4, 1);
+
+        // Third of four ranks, centered in its bucket
+        let position = pipeline.palette_position(&escaped(30)).unwrap();
+        let expected = pipeline.palette().apply_transfer(2.5 / 4.0);
+        assert!((position - expected).abs() < 1e-12);
+
+        let ComputeData::Mandelbrot(interior) = escaped(30);
+        let interior = ComputeData::Mandelbrot(MandelbrotData {
+            escaped: false,
+            ..interior
+        });
+        assert_eq!(pipeline.palette_position(&interior), None);
+    }
+
+    #[test]
+    fn cycle_frame_reuses_cached_histogram() {
+        let palette = Palette {
+            histogram_enabled: true,
+            ..Palette::default()
+        };
+        let mut pipeline = ColorPipeline::new(palette, RenderSettings::default());
+        let data: Vec<ComputeData> = (1..=16).map(escaped).collect();
+
+        let final_pixels = pipeline.colorize_final(&data, 4, 4);
+        // Same phase: a cycling frame must match the final frame exactly
+        assert_eq!(pipeline.colorize_cycle_frame(&data, 4, 4), final_pixels);
+
+        // A partial buffer is still colored against the full-frame histogram
+        let partial = &data[..4];
+        assert_eq!(
+            pipeline.colorize_cycle_frame(partial, 4, 1),
+            final_pixels[..4]
+        );
+    }
+
+    fn stable_pipeline(histogram_enabled: bool) -> ColorPipeline {
+        // Discrete iteration counts keep histogram ranks exact under shifts
+        let palette = Palette {
+            histogram_enabled,
+            smooth_enabled: false,
+            ..Palette::default()
+        };
+        let settings = RenderSettings {
+            zoom_stable: true,
+            cycle_count: 4,
+            ..RenderSettings::default()
+        };
+        ColorPipeline::new(palette, settings)
+    }
+
+    #[test]
+    fn zoom_stable_freezes_histogram() {
+        let mut pipeline = stable_pipeline(true);
+        let reference: Vec<ComputeData> = (1..=16).map(escaped).collect();
+        let reference_pixels = pipeline.colorize_final(&reference, 4, 4);
+
+        // Squeezing the low values together (keeping the median) would move the
+        // other pixels' ranks if re-equalized; with a frozen histogram each value
+        // keeps its reference color
+        let squeezed: Vec<ComputeData> = [8, 8, 8].into_iter().chain(4..=16).map(escaped).collect();
+        let squeezed_pixels = pipeline.colorize_final(&squeezed, 4, 4);
+        assert_eq!(squeezed_pixels[3..], reference_pixels[3..]);
+
+        let mut unstable = ColorPipeline::new(
+            pipeline.palette().clone(),
+            RenderSettings {
+                zoom_stable: false,
+                ..pipeline.render_settings().clone()
+            },
+        );
+        assert_ne!(
+            unstable.colorize_final(&squeezed, 4, 4)[3..],
+            squeezed_pixels[3..]
+        );
+    }
+
+    #[test]
+    fn zoom_stable_histogram_follows_escape_depth() {
+        let mut pipeline = stable_pipeline(true);
+        let reference: Vec<ComputeData> = (1..=16).map(escaped).collect();
+        let reference_pixels = pipeline.colorize_final(&reference, 4, 4);
+
+        // A deeper frame where everything escapes 40 iterations later keeps the
+        // reference colors instead of saturating at the top of the histogram
+        let deeper: Vec<ComputeData> = (41..=56).map(escaped).collect();
+        assert_eq!(pipeline.colorize_final(&deeper, 4, 4), reference_pixels);
+    }
+
+    #[test]
+    fn zoom_stable_offsets_gradient_by_zoom() {
+        let mut pipeline = stable_pipeline(true);
+        let data: Vec<ComputeData> = (1..=16).map(escaped).collect();
+        let reference_pixels = pipeline.colorize_final(&data, 4, 4);
+
+        // One full gradient length of offset maps every pixel back onto itself
+        let full_cycle_octaves = 1.0 / ZOOM_OFFSET_PER_OCTAVE;
+        pipeline.set_pixel_size_log2(-full_cycle_octaves);
+        assert_eq!(pipeline.colorize_final(&data, 4, 4), reference_pixels);
+
+        pipeline.set_pixel_size_log2(-full_cycle_octaves / 2.0);
+        assert_ne!(pipeline.colorize_final(&data, 4, 4), reference_pixels);
+        // Progressive chunks pick up the new offset before the final frame
+        assert_eq!(
+            pipeline.colorize_chunk(&data),
+            pipeline.colorize_final(&data, 4, 4)
+        );
+    }
+
+    #[test]
+    fn zoom_stable_normalizes_by_global_iteration_reference() {
+        let with_max = |max_iterations| {
+            ComputeData::Mandelbrot(MandelbrotData {
+                iterations: 50,
+                max_iterations,
+                escaped: true,
+                final_z_norm_sq: 100000.0,
+                ..MandelbrotData::default()
+            })
+        };
+
+        let mut pipeline = stable_pipeline(false);
+        pipeline.set_iteration_reference(Some(400));
+        let shallow = pipeline.colorize_final(&[with_max(100)], 1, 1);
+        let deep = pipeline.colorize_final(&[with_max(300)], 1, 1);
+        assert_eq!(shallow, deep);
+
+        // Per-frame normalization colors the same escape differently
+        let mut unstable = ColorPipeline::new(Palette::default(), RenderSettings::default());
+        assert_ne!(
+            unstable.colorize_final(&[with_max(100)], 1, 1),
+            unstable.colorize_final(&[with_max(300)], 1, 1)
+        );
+    }
+
+    #[test]
+    fn pixel_size_reaches_distance_layers() {
+        let palette = Palette {
+            layers: vec![ColorLayer {
+                source: LayerSource::Distance,
+                opacity: 1.0,
+                blend_mode: BlendMode::Normal,
+                ..ColorLayer::default()
+            }],
+            ..Palette::default()
+        };
+        let mut pipeline = ColorPipeline::new(palette, RenderSettings::default());
+        let ComputeData::Mandelbrot(pixel) = escaped(30);
+        let data = [ComputeData::Mandelbrot(MandelbrotData {
+            distance_log2: -8.0,
+            ..pixel
+        })];
+
+        let coarse = pipeline.colorize_chunk(&data);
+        pipeline.set_pixel_size_log2(-12.0);
+        // Changing settings keeps the renderer's pixel size
+        pipeline.set_render_settings(RenderSettings::default());
+        let fine = pipeline.colorize_chunk(&data);
+        assert_ne!(coarse, fine);
+        assert_eq!(pipeline.colorize_final(&data, 1, 1), fine);
+    }
+
+    #[test]
+    fn set_render_settings_keeps_cycle_phase() {
+        let mut pipeline = ColorPipeline::new(Palette::default(), RenderSettings::default());
+        pipeline.set_cycle_phase(0.25);
+        pipeline.set_render_settings(RenderSettings {
+            cycle_count: 8,
+            ..RenderSettings::default()
+        });
+        assert_eq!(pipeline.cycle_phase(), 0.25);
+        assert_eq!(pipeline.render_settings().cycle_count, 8);
+    }
+}
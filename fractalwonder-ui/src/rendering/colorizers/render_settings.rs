@@ -14,6 +14,10 @@ pub struct RenderSettings {
     /// Force HDRFloat for all calculations (debug option)
     #[serde(default)]
     pub force_hdr_float: bool,
+    /// Zoom-stable coloring for animations: freezes the histogram from a reference
+    /// frame and shifts the gradient with zoom depth instead of re-equalizing.
+    #[serde(default)]
+    pub zoom_stable: bool,
 }
 
 fn default_use_gpu() -> bool {
@@ -27,6 +31,7 @@ impl Default for RenderSettings {
             use_gpu: true,
             xray_enabled: false,
             force_hdr_float: false,
+            zoom_stable: false,
         }
     }
 }
@@ -187,6 +187,11 @@ mod tests {
             final_z_norm_sq: 100000.0,
             surface_normal_re: 0.894, // Approximate unit vector
             surface_normal_im: 0.447,
+            orbit_trap: 0.0,
+            stripe_avg: 0.0,
+            distance_log2: 0.0,
+            atom_domain: 0,
+            period: 0,
         };
         let normal = compute_normal(&m);
         assert!(normal.is_some());
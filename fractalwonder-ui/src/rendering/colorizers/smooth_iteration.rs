@@ -1,6 +1,7 @@
 //! Smooth iteration colorizer using the formula μ = n + 1 - log₂(ln(|z|))
 //! to eliminate banding in exterior regions.
 
+use super::layer::composite_layers;
 use super::shading::apply_slope_shading;
 use super::{Colorizer, Palette, PaletteLut, RenderSettings};
 use fractalwonder_core::{ComputeData, MandelbrotData};
@@ -19,6 +20,16 @@ pub struct SmoothIterationContext {
     /// Sorted smooth values for rank-order histogram coloring. None if disabled.
     /// Used to find each pixel's percentile rank via binary search.
     pub sorted_smooth: Option<Vec<f64>>,
+    /// Iteration count for linear normalization, replacing each pixel's max_iterations.
+    /// Set by zoom-stable coloring so frames with different iteration limits agree.
+    pub iteration_reference: Option<f64>,
+    /// Gradient offset added after the transfer curve, from zoom-stable coloring.
+    pub palette_offset: f64,
+    /// Palette phase offset in [0, 1), driven by color cycling.
+    pub cycle_phase: f64,
+    /// log₂ of one pixel's size in fractal units.
+    /// Lets layers measure distance estimates in pixels.
+    pub pixel_size_log2: f64,
 }
 
 /// Compute smooth iteration count from MandelbrotData.
@@ -109,6 +120,7 @@ impl Colorizer for SmoothIterationColorizer {
         SmoothIterationContext {
             smooth_values,
             sorted_smooth,
+            ..SmoothIterationContext::default()
         }
     }
 
@@ -175,14 +187,66 @@ impl SmoothIterationColorizer {
         lut: &PaletteLut,
         render_settings: &RenderSettings,
     ) -> [u8; 4] {
-        if !data.escaped {
-            return [0, 0, 0, 255];
+        let base = self.colorize_base(data, smooth, context, palette, lut, render_settings);
+        composite_layers(
+            base,
+            data,
+            &palette.layers,
+            lut.layer_luts(),
+            context.pixel_size_log2,
+        )
+    }
+
+    /// Position in [0, 1) on the palette gradient an exterior pixel maps to.
+    /// None for interior pixels, which are colored by the interior mode.
+    pub fn palette_position(
+        &self,
+        data: &MandelbrotData,
+        context: &SmoothIterationContext,
+        palette: &Palette,
+        render_settings: &RenderSettings,
+    ) -> Option<f64> {
+        if data.max_iterations == 0 || !data.escaped {
+            return None;
         }
+        let smooth = compute_smooth_iteration(data);
+        Some(self.gradient_position(data, smooth, context, palette, render_settings))
+    }
 
+    /// Color from the palette's own gradient, before layers are composited.
+    fn colorize_base(
+        &self,
+        data: &MandelbrotData,
+        smooth: f64,
+        context: &SmoothIterationContext,
+        palette: &Palette,
+        lut: &PaletteLut,
+        render_settings: &RenderSettings,
+    ) -> [u8; 4] {
         if data.max_iterations == 0 {
             return [0, 0, 0, 255];
         }
 
+        if !data.escaped {
+            return palette
+                .interior
+                .colorize(data, lut, context.pixel_size_log2);
+        }
+
+        let t = self.gradient_position(data, smooth, context, palette, render_settings);
+        let [r, g, b] = lut.sample(t);
+        [r, g, b, 255]
+    }
+
+    /// Gradient position of an escaped pixel after histogram, transfer curve and cycling.
+    fn gradient_position(
+        &self,
+        data: &MandelbrotData,
+        smooth: f64,
+        context: &SmoothIterationContext,
+        palette: &Palette,
+        render_settings: &RenderSettings,
+    ) -> f64 {
         let normalized = if let Some(sorted) = &context.sorted_smooth {
             let lookup_value = if palette.smooth_enabled {
                 smooth
@@ -190,25 +254,28 @@ impl SmoothIterationColorizer {
                 data.iterations as f64
             };
             percentile_rank(sorted, lookup_value)
-        } else if palette.smooth_enabled {
-            smooth / data.max_iterations as f64
         } else {
-            data.iterations as f64 / data.max_iterations as f64
+            let max_iterations = context
+                .iteration_reference
+                .unwrap_or(data.max_iterations as f64);
+            if palette.smooth_enabled {
+                smooth / max_iterations
+            } else {
+                data.iterations as f64 / max_iterations
+            }
         };
 
         // Apply transfer curve (replaces transfer_bias)
         let transferred = palette.apply_transfer(normalized);
 
-        // Apply cycling
+        // Apply cycling (repetitions, animated phase and zoom offset)
         let cycle_count = render_settings.cycle_count as f64;
-        let t = if cycle_count > 1.0 {
-            (transferred * cycle_count).fract()
+        let phase = context.cycle_phase + context.palette_offset;
+        if cycle_count > 1.0 || phase != 0.0 {
+            (transferred * cycle_count + phase).rem_euclid(1.0)
         } else {
             (transferred * cycle_count).clamp(0.0, 1.0)
-        };
-
-        let [r, g, b] = lut.sample(t);
-        [r, g, b, 255]
+        }
     }
 }
 
@@ -227,6 +294,11 @@ mod tests {
             final_z_norm_sq: 4.0,
             surface_normal_re: 0.0,
             surface_normal_im: 0.0,
+            orbit_trap: 0.0,
+            stripe_avg: 0.0,
+            distance_log2: 0.0,
+            atom_domain: 0,
+            period: 0,
         };
         let smooth = compute_smooth_iteration(&data);
         assert_eq!(smooth, 1000.0);
@@ -242,6 +314,11 @@ mod tests {
             final_z_norm_sq: 100000.0,
             surface_normal_re: 0.0,
             surface_normal_im: 0.0,
+            orbit_trap: 0.0,
+            stripe_avg: 0.0,
+            distance_log2: 0.0,
+            atom_domain: 0,
+            period: 0,
         };
         let smooth = compute_smooth_iteration(&data);
         // Should be close to 10 but with fractional adjustment
@@ -298,6 +375,11 @@ mod tests {
                 final_z_norm_sq: 100000.0,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             }),
             ComputeData::Mandelbrot(MandelbrotData {
                 iterations: 10,
@@ -307,6 +389,11 @@ mod tests {
                 final_z_norm_sq: 0.0,
                 surface_normal_re: 0.0,
                 surface_normal_im: 0.0,
+                orbit_trap: 0.0,
+                stripe_avg: 0.0,
+                distance_log2: 0.0,
+                atom_domain: 0,
+                period: 0,
             }),
         ];
 
@@ -323,4 +410,38 @@ mod tests {
         // Only 1 exterior pixel
         assert_eq!(sorted.len(), 1);
     }
+
+    #[test]
+    fn cycle_phase_shifts_gradient_position() {
+        let palette = Palette::default();
+        let lut = PaletteLut::from_palette(&palette);
+        // ln|z| = 1 gives smooth = n + 1 = 3, so t = 3 / 12 = 0.25
+        let data = MandelbrotData {
+            iterations: 2,
+            max_iterations: 12,
+            escaped: true,
+            final_z_norm_sq: std::f32::consts::E.powi(2),
+            ..MandelbrotData::default()
+        };
+        let colorize = |phase: f64| {
+            let context = SmoothIterationContext {
+                cycle_phase: phase,
+                ..SmoothIterationContext::default()
+            };
+            SmoothIterationColorizer.colorize_base(
+                &data,
+                compute_smooth_iteration(&data),
+                &context,
+                &palette,
+                &lut,
+                &RenderSettings::default(),
+            )
+        };
+
+        let [r, g, b] = lut.sample(0.75);
+        assert_eq!(colorize(0.5), [r, g, b, 255]);
+        // Phase wraps around the end of the gradient
+        let [r, g, b] = lut.sample(0.125);
+        assert_eq!(colorize(0.875), [r, g, b, 255]);
+    }
 }
@@ -0,0 +1,74 @@
+//! Julia sets rendered on the main thread, bypassing the worker pool.
+//!
+//! The preview follows the cursor, so it has to be ready within a frame;
+//! the full-screen view yields to the browser between row slices instead.
+
+use crate::rendering::canvas_utils::{draw_full_frame, yield_to_browser};
+use crate::rendering::colorizers::ColorPipeline;
+use fractalwonder_compute::{render_julia_rows, JuliaView};
+use fractalwonder_core::ComputeData;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+/// Iteration limit of the preview window.
+pub const JULIA_PREVIEW_ITERATIONS: u32 = 300;
+
+/// Iteration limit of the full-screen Julia view.
+pub const JULIA_FULL_ITERATIONS: u32 = 2000;
+
+/// Rows computed between yields in `draw_julia_progressive`.
+const ROWS_PER_SLICE: u32 = 16;
+
+/// Colorize the finished data and draw it over the whole canvas.
+fn colorize_and_draw(
+    ctx: &CanvasRenderingContext2d,
+    pipeline: &mut ColorPipeline,
+    view: &JuliaView,
+    data: &[ComputeData],
+) -> Result<(), JsValue> {
+    let (width, height) = view.size;
+    pipeline.set_pixel_size_log2((view.width / width.max(1) as f64).log2());
+    let pixels = pipeline.colorize_final(data, width as usize, height as usize);
+    let pixel_bytes: Vec<u8> = pixels.into_iter().flatten().collect();
+    draw_full_frame(ctx, &pixel_bytes, width, height)
+}
+
+/// Draw the whole Julia set for `c` in one pass.
+pub fn draw_julia(
+    ctx: &CanvasRenderingContext2d,
+    pipeline: &mut ColorPipeline,
+    c: (f64, f64),
+    canvas_size: (u32, u32),
+    max_iterations: u32,
+) -> Result<(), JsValue> {
+    let view = JuliaView::whole_set(canvas_size);
+    let data = render_julia_rows(c, &view, 0..canvas_size.1, max_iterations);
+    colorize_and_draw(ctx, pipeline, &view, &data)
+}
+
+/// Draw the whole Julia set for `c`, yielding to the browser every few rows.
+///
+/// Gives up without drawing once `is_current` returns false, so a newer
+/// request can take over. Returns whether the image was drawn.
+pub async fn draw_julia_progressive(
+    ctx: CanvasRenderingContext2d,
+    mut pipeline: ColorPipeline,
+    c: (f64, f64),
+    canvas_size: (u32, u32),
+    max_iterations: u32,
+    is_current: impl Fn() -> bool,
+) -> bool {
+    let view = JuliaView::whole_set(canvas_size);
+    let mut data = Vec::with_capacity((canvas_size.0 * canvas_size.1) as usize);
+    let mut row = 0;
+    while row < canvas_size.1 {
+        let end = (row + ROWS_PER_SLICE).min(canvas_size.1);
+        data.extend(render_julia_rows(c, &view, row..end, max_iterations));
+        row = end;
+        yield_to_browser().await;
+        if !is_current() {
+            return false;
+        }
+    }
+    colorize_and_draw(&ctx, &mut pipeline, &view, &data).is_ok()
+}
@@ -1,14 +1,29 @@
 mod canvas_utils;
 pub mod colorizers;
+mod julia;
+mod orbit_overlay;
 mod parallel_renderer;
+mod pixel_inspector;
+mod render_estimator;
 mod render_progress;
+mod render_stats;
 mod test_pattern;
 mod tiles;
 
-pub use canvas_utils::{draw_pixels_to_canvas, get_2d_context, performance_now, yield_to_browser};
+pub use canvas_utils::{
+    capture_thumbnail, draw_pixels_to_canvas, get_2d_context, performance_now, yield_to_browser,
+};
 pub use colorizers::Colorizer;
+pub use julia::{
+    draw_julia, draw_julia_progressive, JULIA_FULL_ITERATIONS, JULIA_PREVIEW_ITERATIONS,
+};
+pub use orbit_overlay::{draw_orbit_overlay, OrbitOverlay, ScreenMapping};
+pub(crate) use parallel_renderer::assemble_tiles_to_buffer;
 pub use parallel_renderer::ParallelRenderer;
-pub use render_progress::RenderProgress;
+pub use pixel_inspector::{find_pixel_data, PixelInspection, PixelSource};
+pub use render_estimator::RenderEstimator;
+pub use render_progress::{format_duration, format_iteration_rate, RenderProgress};
+pub use render_stats::{draw_heatmap, precision_color, HeatmapMode, RenderStats, TileRecord};
 // Only export what's still needed for tests
 pub use test_pattern::{calculate_tick_params, calculate_tick_params_from_log2, TickParams};
 pub use tiles::{calculate_tile_size, generate_tiles, tile_to_viewport};
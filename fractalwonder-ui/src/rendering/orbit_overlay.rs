@@ -0,0 +1,299 @@
+//! Iteration path of a clicked pixel, drawn over the image.
+//!
+//! The path comes from the workers' perturbation code, so it shows what the
+//! tiles computed: where the pixel wanders relative to the reference orbit,
+//! where it rebases and when it escapes. Points are placed on screen from
+//! their offset to the reference point, which stays exact at any depth.
+
+use fractalwonder_core::{pixel_delta_step, pixel_to_delta, HDRFloat, OrbitPath, Viewport};
+use web_sys::CanvasRenderingContext2d;
+
+/// Side of the complex-plane inset in canvas pixels.
+const INSET_SIZE: f64 = 180.0;
+
+/// The inset shows [-INSET_RANGE, INSET_RANGE]² of the complex plane.
+const INSET_RANGE: f64 = 2.0;
+
+/// Screen coordinates are clamped to this far outside the canvas, as paths
+/// leaving the view jump by many orders of magnitude.
+const SCREEN_LIMIT: f64 = 1e5;
+
+/// Maps offsets from the viewport center to canvas pixel coordinates.
+#[derive(Clone, Debug)]
+pub struct ScreenMapping {
+    /// Offset of pixel (0, 0)
+    origin: (HDRFloat, HDRFloat),
+    /// Length the pixel steps are measured in
+    scale: HDRFloat,
+    /// Fractal-space step per pixel right and down, in units of `scale`
+    step_x: (f64, f64),
+    step_y: (f64, f64),
+}
+
+impl ScreenMapping {
+    pub fn new(viewport: &Viewport, canvas_size: (u32, u32)) -> Self {
+        let precision = viewport.width.precision_bits();
+        let origin = pixel_to_delta(0.0, 0.0, viewport, canvas_size, precision);
+        let step = pixel_delta_step(viewport, canvas_size);
+        let step_x = (
+            HDRFloat::from_bigfloat(&step.x.0),
+            HDRFloat::from_bigfloat(&step.x.1),
+        );
+        let step_y = (
+            HDRFloat::from_bigfloat(&step.y.0),
+            HDRFloat::from_bigfloat(&step.y.1),
+        );
+        let scale = HDRFloat::from_bigfloat(&viewport.width).div_f64(canvas_size.0.max(1) as f64);
+        let unit = |v: &HDRFloat| v.div(&scale).to_f64();
+        Self {
+            origin: (
+                HDRFloat::from_bigfloat(&origin.0),
+                HDRFloat::from_bigfloat(&origin.1),
+            ),
+            step_x: (unit(&step_x.0), unit(&step_x.1)),
+            step_y: (unit(&step_y.0), unit(&step_y.1)),
+            scale,
+        }
+    }
+
+    /// Canvas position of a point at `offset` from the viewport center.
+    pub fn to_screen(&self, offset: &(HDRFloat, HDRFloat)) -> (f64, f64) {
+        let re = offset.0.sub(&self.origin.0).div(&self.scale).to_f64();
+        let im = offset.1.sub(&self.origin.1).div(&self.scale).to_f64();
+        // Solve re + i·im = x·step_x + y·step_y
+        let (ax, ay) = self.step_x;
+        let (bx, by) = self.step_y;
+        let det = ax * by - bx * ay;
+        let x = (re * by - bx * im) / det;
+        let y = (ax * im - re * ay) / det;
+        (
+            x.clamp(-SCREEN_LIMIT, SCREEN_LIMIT),
+            y.clamp(-SCREEN_LIMIT, SCREEN_LIMIT),
+        )
+    }
+}
+
+/// A traced path placed on the canvas it was requested for.
+#[derive(Clone, Debug)]
+pub struct OrbitOverlay {
+    /// Pixel the path was traced for
+    pub pixel: (u32, u32),
+    pub path: OrbitPath,
+    /// Canvas positions of the path points
+    screen: Vec<(f64, f64)>,
+    /// Canvas positions of the reference orbit over the same iterations
+    reference_screen: Vec<(f64, f64)>,
+}
+
+impl OrbitOverlay {
+    pub fn new(pixel: (u32, u32), path: OrbitPath, mapping: &ScreenMapping) -> Self {
+        let screen = path
+            .points
+            .iter()
+            .map(|point| mapping.to_screen(&point.offset))
+            .collect();
+        let reference_screen = path
+            .reference
+            .iter()
+            .map(|z| {
+                mapping.to_screen(&(
+                    HDRFloat::from_f64(z.0 - path.c_ref.0),
+                    HDRFloat::from_f64(z.1 - path.c_ref.1),
+                ))
+            })
+            .collect();
+        Self {
+            pixel,
+            path,
+            screen,
+            reference_screen,
+        }
+    }
+
+    /// One-line description of how the pixel ended.
+    pub fn summary(&self) -> String {
+        let path = &self.path;
+        let outcome = match (path.escaped_at(), path.period()) {
+            (Some(n), _) => format!("Escaped at {n}"),
+            (None, Some(period)) => format!("Period {period}"),
+            (None, None) => format!("No escape in {} iterations", path.data.max_iterations),
+        };
+        let mut summary = format!("{outcome} · {} rebases", path.rebases);
+        let total = path.escaped_at().map_or(path.data.iterations, |n| n + 1) as usize;
+        if path.points.len() < total {
+            summary.push_str(&format!(" · first {} shown", path.points.len()));
+        }
+        summary
+    }
+}
+
+/// Stroke a polyline through canvas points.
+fn stroke_path(ctx: &CanvasRenderingContext2d, points: &[(f64, f64)], color: &str, width: f64) {
+    if points.len() < 2 {
+        return;
+    }
+    ctx.begin_path();
+    ctx.move_to(points[0].0, points[0].1);
+    for &(x, y) in &points[1..] {
+        ctx.line_to(x, y);
+    }
+    ctx.set_stroke_style_str(color);
+    ctx.set_line_width(width);
+    ctx.stroke();
+}
+
+fn fill_circle(ctx: &CanvasRenderingContext2d, (x, y): (f64, f64), radius: f64, color: &str) {
+    ctx.begin_path();
+    let _ = ctx.arc(x, y, radius, 0.0, std::f64::consts::TAU);
+    ctx.set_fill_style_str(color);
+    ctx.fill();
+}
+
+/// Draw the path and reference orbit over the image, with a complex-plane
+/// inset in the bottom-left corner.
+pub fn draw_orbit_overlay(
+    ctx: &CanvasRenderingContext2d,
+    overlay: &OrbitOverlay,
+    canvas_size: (u32, u32),
+) {
+    // Pixel centers rather than their top-left corners
+    let centered = |points: &[(f64, f64)]| -> Vec<(f64, f64)> {
+        points.iter().map(|&(x, y)| (x + 0.5, y + 0.5)).collect()
+    };
+    let screen = centered(&overlay.screen);
+    let reference = centered(&overlay.reference_screen);
+
+    ctx.save();
+    let _ = ctx.set_line_dash(&js_sys::Array::of2(&4.0.into(), &4.0.into()));
+    stroke_path(ctx, &reference, "rgba(160,160,160,0.8)", 1.0);
+    let _ = ctx.set_line_dash(&js_sys::Array::new());
+    stroke_path(ctx, &screen, "rgba(255,220,0,0.9)", 1.5);
+    for &point in &screen {
+        fill_circle(ctx, point, 2.0, "rgba(255,220,0,0.9)");
+    }
+    fill_circle(
+        ctx,
+        (overlay.pixel.0 as f64 + 0.5, overlay.pixel.1 as f64 + 0.5),
+        4.0,
+        "rgb(0,200,255)",
+    );
+    if overlay.path.escaped_at().is_some() {
+        if let Some(&last) = screen.last() {
+            fill_circle(ctx, last, 5.0, "rgb(255,60,60)");
+        }
+    }
+    draw_inset(ctx, overlay, canvas_size);
+    ctx.restore();
+}
+
+/// Path in the complex plane itself, where it is not too deep to see.
+fn draw_inset(ctx: &CanvasRenderingContext2d, overlay: &OrbitOverlay, canvas_size: (u32, u32)) {
+    const MARGIN: f64 = 12.0;
+    let left = MARGIN;
+    let top = canvas_size.1 as f64 - INSET_SIZE - MARGIN;
+    let center = (left + INSET_SIZE / 2.0, top + INSET_SIZE / 2.0);
+    let scale = INSET_SIZE / (2.0 * INSET_RANGE);
+    let to_inset = |&(re, im): &(f64, f64)| {
+        (
+            center.0 + re.clamp(-INSET_RANGE, INSET_RANGE) * scale,
+            center.1 - im.clamp(-INSET_RANGE, INSET_RANGE) * scale,
+        )
+    };
+
+    ctx.set_fill_style_str("rgba(0,0,0,0.75)");
+    ctx.fill_rect(left, top, INSET_SIZE, INSET_SIZE);
+    ctx.begin_path();
+    ctx.rect(left, top, INSET_SIZE, INSET_SIZE);
+    ctx.clip();
+
+    // Axes and the |z| = 2 circle
+    ctx.begin_path();
+    ctx.move_to(left, center.1);
+    ctx.line_to(left + INSET_SIZE, center.1);
+    ctx.move_to(center.0, top);
+    ctx.line_to(center.0, top + INSET_SIZE);
+    ctx.set_stroke_style_str("rgba(255,255,255,0.25)");
+    ctx.set_line_width(1.0);
+    ctx.stroke();
+    ctx.begin_path();
+    let _ = ctx.arc(center.0, center.1, 2.0 * scale, 0.0, std::f64::consts::TAU);
+    ctx.stroke();
+
+    let reference: Vec<(f64, f64)> = overlay.path.reference.iter().map(to_inset).collect();
+    let points: Vec<(f64, f64)> = overlay
+        .path
+        .points
+        .iter()
+        .map(|point| to_inset(&point.z))
+        .collect();
+    stroke_path(ctx, &reference, "rgba(160,160,160,0.8)", 1.0);
+    stroke_path(ctx, &points, "rgba(255,220,0,0.9)", 1.0);
+
+    ctx.set_fill_style_str("white");
+    ctx.set_font("11px monospace");
+    let _ = ctx.fill_text(&overlay.summary(), left + 6.0, top + 14.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fractalwonder_core::{MandelbrotData, OrbitPathPoint};
+
+    fn offset_of(viewport: &Viewport, size: (u32, u32), x: f64, y: f64) -> (HDRFloat, HDRFloat) {
+        let (re, im) = pixel_to_delta(x, y, viewport, size, viewport.width.precision_bits());
+        (HDRFloat::from_bigfloat(&re), HDRFloat::from_bigfloat(&im))
+    }
+
+    fn assert_maps_back(viewport: &Viewport) {
+        let size = (200, 100);
+        let mapping = ScreenMapping::new(viewport, size);
+        for (x, y) in [(0.0, 0.0), (37.0, 81.0), (150.0, 20.0)] {
+            let (sx, sy) = mapping.to_screen(&offset_of(viewport, size, x, y));
+            assert!(
+                (sx - x).abs() < 1e-3 && (sy - y).abs() < 1e-3,
+                "({x}, {y}) -> ({sx}, {sy})"
+            );
+        }
+    }
+
+    #[test]
+    fn offsets_map_back_to_their_pixels() {
+        assert_maps_back(&Viewport::from_f64(-0.5, 0.0, 4.0, 2.0, 64));
+    }
+
+    #[test]
+    fn mapping_handles_rotation_and_deep_zoom() {
+        let deep = Viewport::from_strings("-0.75", "0.1", "1e-200", "5e-201", 768).unwrap();
+        assert_maps_back(&deep);
+        assert_maps_back(&deep.with_rotation(0.7));
+    }
+
+    #[test]
+    fn summary_describes_outcome() {
+        let point = OrbitPathPoint {
+            z: (0.0, 0.0),
+            offset: (HDRFloat::ZERO, HDRFloat::ZERO),
+            reference_index: 0,
+        };
+        let mut path = OrbitPath {
+            points: vec![point; 4],
+            data: MandelbrotData {
+                iterations: 3,
+                max_iterations: 100,
+                escaped: true,
+                ..MandelbrotData::default()
+            },
+            rebases: 1,
+            ..OrbitPath::default()
+        };
+        let mapping = ScreenMapping::new(&Viewport::from_f64(0.0, 0.0, 4.0, 4.0, 64), (10, 10));
+        let overlay = OrbitOverlay::new((1, 2), path.clone(), &mapping);
+        assert_eq!(overlay.summary(), "Escaped at 3 · 1 rebases");
+
+        path.data.escaped = false;
+        path.data.iterations = 100;
+        path.data.period = 5;
+        let overlay = OrbitOverlay::new((1, 2), path, &mapping);
+        assert_eq!(overlay.summary(), "Period 5 · 1 rebases · first 4 shown");
+    }
+}
@@ -2,11 +2,19 @@ use crate::config::FractalConfig;
 use crate::rendering::canvas_utils::{
     draw_full_frame, draw_pixels_to_canvas, get_2d_context, performance_now,
 };
-use crate::rendering::colorizers::{ColorPipeline, Palette, RenderSettings};
+use crate::rendering::colorizers::{
+    compute_smooth_iteration, ColorPipeline, Palette, RenderSettings,
+};
 use crate::rendering::tiles::{calculate_tile_size, generate_tiles};
-use crate::rendering::RenderProgress;
+use crate::rendering::{
+    draw_heatmap, draw_orbit_overlay, find_pixel_data, HeatmapMode, OrbitOverlay, PixelInspection,
+    PixelSource, RenderEstimator, RenderProgress, RenderStats, ScreenMapping,
+};
 use crate::workers::{OrbitCompleteData, TileResult, WorkerPool};
-use fractalwonder_core::{ComputeData, HDRFloat, MandelbrotData, PixelRect, Viewport};
+use fractalwonder_core::{
+    pixel_delta_step, pixel_to_delta, pixel_to_fractal, unskew_from_field, BigFloat, ComputeData,
+    HDRFloat, MandelbrotData, PixelRect, Viewport,
+};
 use fractalwonder_gpu::{GpuAvailability, GpuContext, ProgressiveGpuRenderer};
 use leptos::*;
 use std::cell::{Cell, RefCell};
@@ -20,6 +28,12 @@ pub struct ParallelRenderer {
     config: &'static FractalConfig,
     worker_pool: Rc<RefCell<WorkerPool>>,
     progress: RwSignal<RenderProgress>,
+    /// Per-tile diagnostics of the current CPU render
+    stats: RwSignal<RenderStats>,
+    /// Tile property drawn over the finished image
+    heatmap_mode: Rc<Cell<HeatmapMode>>,
+    /// Traced path of a clicked pixel, drawn over the finished image
+    orbit_overlay: RwSignal<Option<OrbitOverlay>>,
     canvas_ctx: Rc<RefCell<Option<CanvasRenderingContext2d>>>,
     /// Stored tile results for re-colorizing without recompute
     tile_results: Rc<RefCell<Vec<TileResult>>>,
@@ -35,6 +49,8 @@ pub struct ParallelRenderer {
     render_generation: Rc<Cell<u32>>,
     /// Full-image ComputeData buffer for GPU tile accumulation
     gpu_result_buffer: Rc<RefCell<Vec<ComputeData>>>,
+    /// Whether the current image comes from the progressive GPU renderer
+    gpu_render: Cell<bool>,
     /// Current viewport for zoom calculation in recolorize
     current_viewport: Rc<RefCell<Option<Viewport>>>,
     /// Unified colorization pipeline
@@ -44,6 +60,9 @@ pub struct ParallelRenderer {
 impl ParallelRenderer {
     pub fn new(config: &'static FractalConfig) -> Result<Self, JsValue> {
         let progress = create_rw_signal(RenderProgress::default());
+        let stats = create_rw_signal(RenderStats::default());
+        let heatmap_mode = Rc::new(Cell::new(HeatmapMode::Off));
+        let orbit_overlay = create_rw_signal(None::<OrbitOverlay>);
         let canvas_ctx: Rc<RefCell<Option<CanvasRenderingContext2d>>> = Rc::new(RefCell::new(None));
         let tile_results: Rc<RefCell<Vec<TileResult>>> = Rc::new(RefCell::new(Vec::new()));
         let progressive_gpu_renderer: Rc<RefCell<Option<ProgressiveGpuRenderer>>> =
@@ -84,7 +103,7 @@ impl ParallelRenderer {
             }
         };
 
-        let worker_pool = WorkerPool::new(config.id, on_tile_complete, progress)?;
+        let worker_pool = WorkerPool::new(config.id, on_tile_complete, progress, stats)?;
 
         // Set up render complete callback to apply postprocessing (shading) when all tiles done
         let tile_results_complete = Rc::clone(&tile_results);
@@ -92,6 +111,7 @@ impl ParallelRenderer {
         let canvas_size_complete = Rc::clone(&canvas_size);
         let current_viewport: Rc<RefCell<Option<Viewport>>> = Rc::new(RefCell::new(None));
         let pipeline_complete = Rc::clone(&pipeline);
+        let heatmap_mode_complete = Rc::clone(&heatmap_mode);
         worker_pool.borrow().set_render_complete_callback(move || {
             let ctx_ref = canvas_ctx_complete.borrow();
             let Some(ctx) = ctx_ref.as_ref() else {
@@ -111,12 +131,21 @@ impl ParallelRenderer {
             // Draw full frame
             let pixel_bytes: Vec<u8> = final_pixels.into_iter().flatten().collect();
             let _ = draw_full_frame(ctx, &pixel_bytes, width, height);
+            stats.with_untracked(|stats| draw_heatmap(ctx, stats, heatmap_mode_complete.get()));
+            orbit_overlay.with_untracked(|overlay| {
+                if let Some(overlay) = overlay {
+                    draw_orbit_overlay(ctx, overlay, (width, height));
+                }
+            });
         });
 
         Ok(Self {
             config,
             worker_pool,
             progress,
+            stats,
+            heatmap_mode,
+            orbit_overlay,
             canvas_ctx,
             tile_results,
             progressive_gpu_renderer,
@@ -125,6 +154,7 @@ impl ParallelRenderer {
             canvas_size,
             render_generation,
             gpu_result_buffer,
+            gpu_render: Cell::new(false),
             current_viewport,
             pipeline,
         })
@@ -141,6 +171,48 @@ impl ParallelRenderer {
             });
     }
 
+    /// Set the heatmap drawn over the image; takes effect on the next colorization.
+    pub fn set_heatmap_mode(&self, mode: HeatmapMode) {
+        self.heatmap_mode.set(mode);
+    }
+
+    /// Draw the heatmap and orbit path, if any, over the finished image.
+    fn draw_overlays(&self, ctx: &CanvasRenderingContext2d) {
+        self.stats
+            .with_untracked(|stats| draw_heatmap(ctx, stats, self.heatmap_mode.get()));
+        self.orbit_overlay.with_untracked(|overlay| {
+            if let Some(overlay) = overlay {
+                draw_orbit_overlay(ctx, overlay, self.canvas_size.get());
+            }
+        });
+    }
+
+    /// Traced path of a clicked pixel; set when a trace arrives.
+    pub fn orbit_overlay(&self) -> RwSignal<Option<OrbitOverlay>> {
+        self.orbit_overlay
+    }
+
+    /// Trace the iteration path of a canvas pixel. The result lands in
+    /// `orbit_overlay`; recolorize to draw it.
+    pub fn trace_orbit(&self, x: u32, y: u32) {
+        let Some(viewport) = self.current_viewport.borrow().clone() else {
+            return;
+        };
+        let canvas_size = self.canvas_size.get();
+        let orbit_overlay = self.orbit_overlay;
+        self.worker_pool
+            .borrow_mut()
+            .trace_orbit_path(x, y, move |path| {
+                let mapping = ScreenMapping::new(&viewport, canvas_size);
+                orbit_overlay.set(Some(OrbitOverlay::new((x, y), path, &mapping)));
+            });
+    }
+
+    /// Remove the orbit path; recolorize to erase it.
+    pub fn clear_orbit_overlay(&self) {
+        self.orbit_overlay.set(None);
+    }
+
     /// Re-colorize all stored tiles using full pipeline (no recompute).
     pub fn recolorize(&self) {
         let ctx_ref = self.canvas_ctx.borrow();
@@ -160,16 +232,161 @@ impl ParallelRenderer {
         // Draw full frame
         let pixel_bytes: Vec<u8> = final_pixels.into_iter().flatten().collect();
         let _ = draw_full_frame(ctx, &pixel_bytes, width, height);
+        self.draw_overlays(ctx);
+    }
+
+    /// Set the palette color-cycling phase used by subsequent colorization.
+    pub fn set_cycle_phase(&self, phase: f64) {
+        self.pipeline.borrow_mut().set_cycle_phase(phase);
+    }
+
+    /// Re-colorize stored tiles for a color-cycling animation frame.
+    /// Reuses the histogram from the last full colorization so frames don't flicker.
+    pub fn recolorize_cycle(&self) {
+        // Partial frames would be repainted over in-flight tiles
+        if !self.progress.get_untracked().is_complete {
+            return;
+        }
+        let ctx_ref = self.canvas_ctx.borrow();
+        let Some(ctx) = ctx_ref.as_ref() else {
+            return;
+        };
+
+        let (width, height) = self.canvas_size.get();
+        let tiles = self.tile_results.borrow();
+        let full_buffer = assemble_tiles_to_buffer(&tiles, width as usize, height as usize);
+
+        let pixels = self.pipeline.borrow().colorize_cycle_frame(
+            &full_buffer,
+            width as usize,
+            height as usize,
+        );
+
+        let pixel_bytes: Vec<u8> = pixels.into_iter().flatten().collect();
+        let _ = draw_full_frame(ctx, &pixel_bytes, width, height);
+        self.draw_overlays(ctx);
+    }
+
+    /// Stretch that would make the features of the current image look
+    /// unflattened, estimated from its smooth iteration counts.
+    pub fn estimate_unskew(&self) -> Option<[[f64; 2]; 2]> {
+        let (width, height) = self.canvas_size.get();
+        let (width, height) = (width as usize, height as usize);
+        let smooth = |data: &ComputeData| {
+            let ComputeData::Mandelbrot(m) = data;
+            if m.escaped && !m.glitched {
+                compute_smooth_iteration(m)
+            } else {
+                f64::NAN
+            }
+        };
+        let field: Vec<f64> = if self.gpu_render.get() {
+            self.gpu_result_buffer.borrow().iter().map(smooth).collect()
+        } else {
+            let tiles = self.tile_results.borrow();
+            assemble_tiles_to_buffer(&tiles, width, height)
+                .iter()
+                .map(smooth)
+                .collect()
+        };
+        unskew_from_field(&field, width, height)
     }
 
     pub fn progress(&self) -> RwSignal<RenderProgress> {
         self.progress
     }
 
+    pub fn stats(&self) -> RwSignal<RenderStats> {
+        self.stats
+    }
+
     pub fn cancel(&self) {
         self.worker_pool.borrow_mut().cancel();
     }
 
+    /// Fractal coordinate of a canvas pixel of the current image, at the
+    /// viewport's full precision.
+    pub fn pixel_coordinate(&self, x: u32, y: u32) -> Option<(BigFloat, BigFloat)> {
+        let viewport = self.current_viewport.borrow();
+        let viewport = viewport.as_ref()?;
+        Some(pixel_to_fractal(
+            x as f64,
+            y as f64,
+            viewport,
+            self.canvas_size.get(),
+            viewport.precision_bits(),
+        ))
+    }
+
+    /// Fractal coordinate of a canvas pixel rounded to f64, cheap enough to
+    /// follow the cursor at any depth.
+    pub fn pixel_coordinate_f64(&self, x: u32, y: u32) -> Option<(f64, f64)> {
+        let viewport = self.current_viewport.borrow();
+        let viewport = viewport.as_ref()?;
+        let (re, im) = pixel_to_fractal(x as f64, y as f64, viewport, self.canvas_size.get(), 64);
+        Some((re.to_f64(), im.to_f64()))
+    }
+
+    /// Raw compute data, provenance and palette position of a canvas pixel.
+    ///
+    /// The fractal coordinate is only converted to decimal when
+    /// `with_coordinate` is set, as that is slow at deep zoom.
+    pub fn inspect_pixel(&self, x: u32, y: u32, with_coordinate: bool) -> Option<PixelInspection> {
+        let (width, height) = self.canvas_size.get();
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let data = {
+            let tiles = self.tile_results.borrow();
+            match find_pixel_data(&tiles, x, y) {
+                Some(data) => data.clone(),
+                None if self.gpu_render.get() => {
+                    let buffer = self.gpu_result_buffer.borrow();
+                    let ComputeData::Mandelbrot(m) = buffer.get((y * width + x) as usize)?;
+                    m.clone()
+                }
+                None => return None,
+            }
+        };
+
+        let source = if self.gpu_render.get() {
+            PixelSource::Gpu {
+                orbit_id: self.worker_pool.borrow().orbit_id(),
+            }
+        } else {
+            self.stats
+                .with_untracked(|stats| {
+                    stats.tile_at(x, y).map(|record| PixelSource::Perturbation {
+                        orbit_id: record.orbit_id,
+                        precision: record.precision,
+                    })
+                })
+                .unwrap_or(PixelSource::Unknown)
+        };
+
+        let palette_position = self
+            .pipeline
+            .borrow()
+            .palette_position(&ComputeData::Mandelbrot(data.clone()));
+
+        let coordinate = if with_coordinate {
+            self.pixel_coordinate(x, y)
+                .map(|(re, im)| (re.to_decimal_string(), im.to_decimal_string()))
+        } else {
+            None
+        };
+
+        Some(PixelInspection {
+            x,
+            y,
+            data,
+            source,
+            palette_position,
+            coordinate,
+        })
+    }
+
     /// Subdivide quadtree cells that contain glitched tiles.
     pub fn subdivide_glitched_cells(&self) {
         self.worker_pool.borrow_mut().subdivide_glitched_cells();
@@ -197,9 +414,17 @@ impl ParallelRenderer {
         // Clear stored tile results from previous render
         self.tile_results.borrow_mut().clear();
 
+        // The traced path belongs to the previous image, which is redrawn anyway
+        self.orbit_overlay.set_untracked(None);
+
         // Store viewport for zoom calculation in recolorize
         *self.current_viewport.borrow_mut() = Some(viewport.clone());
 
+        // Pixel size lets distance layers measure in pixels at any zoom depth
+        self.pipeline
+            .borrow_mut()
+            .set_pixel_size_log2(viewport.width.log2_approx() - (width as f64).log2());
+
         // Store canvas context for tile callbacks
         if let Ok(ctx) = get_2d_context(canvas) {
             *self.canvas_ctx.borrow_mut() = Some(ctx);
@@ -220,9 +445,16 @@ impl ParallelRenderer {
         // To re-enable: let tiles: Vec<_> = tiles.into_iter().take(4).collect();
 
         // Start render with GPU perturbation or CPU fallback
-        // Check runtime use_gpu option (user-controllable) AND config gpu_enabled (fractal type)
-        let use_gpu = self.config.gpu_enabled && self.pipeline.borrow().render_settings().use_gpu;
+        // Check runtime use_gpu option (user-controllable) AND config gpu_enabled (fractal type).
+        // Interior modes that need period detection also force the CPU path.
+        let use_gpu = {
+            let pipeline = self.pipeline.borrow();
+            self.config.gpu_enabled
+                && pipeline.render_settings().use_gpu
+                && !pipeline.palette().interior.mode.requires_cpu()
+        };
         let use_progressive = self.config.gpu_progressive_row_sets > 0;
+        self.gpu_render.set(use_gpu && use_progressive);
         if use_gpu && use_progressive {
             // Use progressive GPU rendering (row-sets / venetian blinds pattern)
             log::info!(
@@ -231,13 +463,22 @@ impl ParallelRenderer {
             );
             self.start_progressive_gpu_render(viewport, canvas);
         } else {
-            let force_hdr_float = self.pipeline.borrow().render_settings().force_hdr_float;
+            let (force_hdr_float, collect_orbit_stats, probe_interior) = {
+                let pipeline = self.pipeline.borrow();
+                (
+                    pipeline.render_settings().force_hdr_float,
+                    pipeline.palette().needs_orbit_stats(),
+                    pipeline.palette().interior.mode.requires_cpu(),
+                )
+            };
             log::info!("Using CPU renderer (zoom={zoom:.2e}, force_hdr={force_hdr_float})");
             self.worker_pool.borrow_mut().start_perturbation_render(
                 viewport.clone(),
                 (width, height),
                 tiles,
                 force_hdr_float,
+                collect_orbit_stats,
+                probe_interior,
             );
         }
     }
@@ -320,6 +561,8 @@ impl ParallelRenderer {
 
                     // Schedule first row-set
                     let render_start_time = performance_now();
+                    let mut estimator = RenderEstimator::uniform(row_set_count);
+                    estimator.start(render_start_time);
                     schedule_row_set(
                         0, // row_set_index
                         gen,
@@ -337,6 +580,7 @@ impl ParallelRenderer {
                         viewport,
                         orbit_data_clone,
                         render_start_time,
+                        Rc::new(RefCell::new(estimator)),
                         pipeline,
                     );
                 });
@@ -385,6 +629,7 @@ fn schedule_row_set(
     viewport: Viewport,
     orbit_data: Rc<OrbitCompleteData>,
     render_start_time: f64,
+    estimator: Rc<RefCell<RenderEstimator>>,
     pipeline: Rc<RefCell<ColorPipeline>>,
 ) {
     // Check generation - abort if stale
@@ -409,26 +654,17 @@ fn schedule_row_set(
     wasm_bindgen_futures::spawn_local(async move {
         // Convert viewport to HDRFloat format for delta computation
         let vp_width = HDRFloat::from_bigfloat(&viewport_spawn.width);
-        let vp_height = HDRFloat::from_bigfloat(&viewport_spawn.height);
-
-        let half = HDRFloat::from_f64(0.5);
-        let half_width = vp_width.mul(&half);
-        let half_height = vp_height.mul(&half);
-        let origin_re = half_width.neg();
-        let origin_im = half_height.neg();
-
-        // Use HDRFloat division to preserve extended exponent range at deep zoom
-        let step_re = vp_width.div_f64(width as f64);
-        let step_im = vp_height.div_f64(height as f64);
 
-        let dc_origin = (
-            (origin_re.head, origin_re.tail, origin_re.exp),
-            (origin_im.head, origin_im.tail, origin_im.exp),
-        );
-        let dc_step = (
-            (step_re.head, step_re.tail, step_re.exp),
-            (step_im.head, step_im.tail, step_im.exp),
-        );
+        // Top-left pixel and per-pixel steps, carrying any rotation and skew.
+        // Converting from BigFloat keeps the extended exponent range at deep zoom.
+        let hdr_parts = |value: &BigFloat| {
+            let hdr = HDRFloat::from_bigfloat(value);
+            (hdr.head, hdr.tail, hdr.exp)
+        };
+        let precision = viewport_spawn.precision_bits();
+        let origin = pixel_to_delta(0.0, 0.0, &viewport_spawn, (width, height), precision);
+        let dc_origin = (hdr_parts(&origin.0), hdr_parts(&origin.1));
+        let dc_step = pixel_delta_step(&viewport_spawn, (width, height)).map(hdr_parts);
 
         // Debug: log dc values for first row-set only
         if row_set_index == 0 {
@@ -444,8 +680,6 @@ fn schedule_row_set(
         }
 
         let tau_sq = config.tau_sq as f32;
-        let reference_escaped =
-            orbit_data_spawn.orbit.len() < orbit_data_spawn.max_iterations as usize;
 
         // Check if GPU is already in use
         if gpu_in_use_spawn.get() {
@@ -470,10 +704,11 @@ fn schedule_row_set(
         // Mark GPU in use
         gpu_in_use_spawn.set(true);
 
+        let collect_orbit_stats = pipeline_spawn.borrow().palette().needs_orbit_stats();
+
         let row_set_result = renderer
             .render_row_set(
                 &orbit_data_spawn.orbit,
-                &orbit_data_spawn.derivative,
                 orbit_data_spawn.orbit_id,
                 dc_origin,
                 dc_step,
@@ -484,7 +719,7 @@ fn schedule_row_set(
                 orbit_data_spawn.max_iterations,
                 config.gpu_iterations_per_dispatch,
                 tau_sq,
-                reference_escaped,
+                collect_orbit_stats,
                 orbit_data_spawn.bla_table.as_ref(),
             )
             .await;
@@ -561,10 +796,22 @@ fn schedule_row_set(
                 }
 
                 // Update progress
-                let elapsed_ms = performance_now() - render_start_time;
+                let now = performance_now();
+                let elapsed_ms = now - render_start_time;
+                let iterations = result
+                    .data
+                    .iter()
+                    .map(|ComputeData::Mandelbrot(m)| m.iterations as u64)
+                    .sum();
+                estimator.borrow_mut().record_step(
+                    row_set_index,
+                    result.compute_time_ms,
+                    iterations,
+                );
                 progress.update(|p| {
                     p.completed_steps += 1;
                     p.elapsed_ms = elapsed_ms;
+                    estimator.borrow().update_progress(now, p);
                     p.is_complete = is_final;
                 });
 
@@ -619,6 +866,7 @@ fn schedule_row_set(
                             viewport_spawn,
                             orbit_data_spawn,
                             render_start_time,
+                            estimator,
                             pipeline_spawn,
                         );
                     });
@@ -633,7 +881,12 @@ fn schedule_row_set(
 
 /// Assemble tile results into a single full-image buffer.
 /// Tiles may arrive out of order, so we place each tile's data at the correct position.
-fn assemble_tiles_to_buffer(tiles: &[TileResult], width: usize, height: usize) -> Vec<ComputeData> {
+/// A tile appearing more than once keeps its last result.
+pub(crate) fn assemble_tiles_to_buffer(
+    tiles: &[TileResult],
+    width: usize,
+    height: usize,
+) -> Vec<ComputeData> {
     // Initialize with default (interior) pixels
     let mut buffer = vec![ComputeData::Mandelbrot(MandelbrotData::default()); width * height];
 
@@ -0,0 +1,170 @@
+//! Raw compute data of a single pixel, for debugging palettes and glitches.
+
+use crate::rendering::colorizers::compute_smooth_iteration;
+use crate::workers::TileResult;
+use fractalwonder_core::{ComputeData, DeltaPrecision, MandelbrotData};
+
+/// Which render path computed a pixel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PixelSource {
+    /// CPU perturbation against a reference orbit
+    Perturbation {
+        orbit_id: u32,
+        precision: DeltaPrecision,
+    },
+    /// GPU perturbation against the render's reference orbit
+    Gpu { orbit_id: u32 },
+    /// Not recorded, e.g. while the tile is still in flight
+    Unknown,
+}
+
+/// Everything known about one pixel of the current image.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PixelInspection {
+    pub x: u32,
+    pub y: u32,
+    pub data: MandelbrotData,
+    pub source: PixelSource,
+    /// Position on the palette gradient, None for interior pixels
+    pub palette_position: Option<f64>,
+    /// Fractal coordinate as decimal strings (real, imaginary). Only filled in
+    /// for pinned pixels, as the conversion is slow at deep zoom.
+    pub coordinate: Option<(String, String)>,
+}
+
+impl PixelInspection {
+    pub fn smooth_iteration(&self) -> f64 {
+        compute_smooth_iteration(&self.data)
+    }
+
+    /// Label and value rows for display.
+    pub fn rows(&self) -> Vec<(&'static str, String)> {
+        let data = &self.data;
+        let mut rows = vec![("Pixel", format!("{}, {}", self.x, self.y))];
+        if let Some((re, im)) = &self.coordinate {
+            rows.push(("Re", re.clone()));
+            rows.push(("Im", im.clone()));
+        }
+        rows.push((
+            "Iterations",
+            format!("{} / {}", data.iterations, data.max_iterations),
+        ));
+        rows.push(("Smooth", format!("{:.4}", self.smooth_iteration())));
+        rows.push((
+            "Flags",
+            match (data.escaped, data.glitched) {
+                (true, true) => "escaped, glitched",
+                (true, false) => "escaped",
+                (false, true) => "interior, glitched",
+                (false, false) => "interior",
+            }
+            .to_string(),
+        ));
+        rows.push(("|z|²", format!("{:.6e}", data.final_z_norm_sq)));
+        rows.push((
+            "Normal",
+            format!(
+                "({:.4}, {:.4})",
+                data.surface_normal_re, data.surface_normal_im
+            ),
+        ));
+        if data.period > 0 {
+            rows.push(("Period", data.period.to_string()));
+        }
+        rows.push((
+            "Source",
+            match self.source {
+                PixelSource::Perturbation {
+                    orbit_id,
+                    precision,
+                } => format!("orbit #{orbit_id}, {}", precision.label()),
+                PixelSource::Gpu { orbit_id } => format!("orbit #{orbit_id}, GPU"),
+                PixelSource::Unknown => "unknown".to_string(),
+            },
+        ));
+        rows.push((
+            "Palette",
+            self.palette_position
+                .map_or("interior".to_string(), |t| format!("{t:.4}")),
+        ));
+        rows
+    }
+}
+
+/// Data of a pixel from stored tile results.
+///
+/// Tiles rendered again (longer orbit, glitch correction) are pushed after
+/// their first result, so the last tile containing the pixel wins.
+pub fn find_pixel_data(tiles: &[TileResult], x: u32, y: u32) -> Option<&MandelbrotData> {
+    let result = tiles.iter().rev().find(|t| t.tile.contains(x, y))?;
+    let index = (y - result.tile.y) * result.tile.width + (x - result.tile.x);
+    match result.data.get(index as usize)? {
+        ComputeData::Mandelbrot(m) => Some(m),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fractalwonder_core::PixelRect;
+
+    fn tile(x: u32, y: u32, iterations: u32) -> TileResult {
+        let data = (0..16)
+            .map(|i| {
+                ComputeData::Mandelbrot(MandelbrotData {
+                    iterations: iterations + i,
+                    max_iterations: 1000,
+                    escaped: true,
+                    ..MandelbrotData::default()
+                })
+            })
+            .collect();
+        TileResult {
+            tile: PixelRect::new(x, y, 4, 4),
+            data,
+            compute_time_ms: 1.0,
+        }
+    }
+
+    #[test]
+    fn finds_pixel_in_its_tile() {
+        let tiles = vec![tile(0, 0, 0), tile(4, 0, 100)];
+        // Row 2, column 1 of the second tile
+        assert_eq!(find_pixel_data(&tiles, 5, 2).unwrap().iterations, 109);
+        assert!(find_pixel_data(&tiles, 8, 0).is_none());
+    }
+
+    #[test]
+    fn rerendered_tile_wins() {
+        let tiles = vec![tile(0, 0, 0), tile(0, 0, 500)];
+        assert_eq!(find_pixel_data(&tiles, 0, 0).unwrap().iterations, 500);
+    }
+
+    #[test]
+    fn rows_show_coordinate_only_when_pinned() {
+        let mut inspection = PixelInspection {
+            x: 3,
+            y: 7,
+            data: MandelbrotData {
+                iterations: 1000,
+                max_iterations: 1000,
+                period: 3,
+                ..MandelbrotData::default()
+            },
+            source: PixelSource::Perturbation {
+                orbit_id: 2,
+                precision: DeltaPrecision::F64,
+            },
+            palette_position: None,
+            coordinate: None,
+        };
+        let labels: Vec<&str> = inspection.rows().iter().map(|(l, _)| *l).collect();
+        assert!(!labels.contains(&"Re"));
+        assert!(labels.contains(&"Period"));
+
+        inspection.coordinate = Some(("-0.75".to_string(), "0.1".to_string()));
+        let rows = inspection.rows();
+        assert_eq!(rows[1], ("Re", "-0.75".to_string()));
+        assert!(rows.contains(&("Palette", "interior".to_string())));
+    }
+}
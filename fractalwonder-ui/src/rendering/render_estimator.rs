@@ -0,0 +1,362 @@
+//! Remaining-time estimate for a render in progress.
+//!
+//! Tiles are dispatched center-out, and the center of a deep zoom usually
+//! holds the interior pixels that run to the iteration limit. So the tiles
+//! finished first are not a fair sample of the rest: their cost per pixel is
+//! fitted against distance from the canvas center and extrapolated to the
+//! tiles still to come. Row-sets of the progressive GPU renderer interleave
+//! rows across the whole image and are all alike, so for them the fit
+//! reduces to the mean.
+
+use crate::rendering::RenderProgress;
+use fractalwonder_core::PixelRect;
+use std::collections::HashMap;
+
+/// Part of the render whose cost is predicted.
+#[derive(Clone, Copy, Debug)]
+struct Step {
+    /// Distance from the canvas center, relative to the farthest step
+    distance: f64,
+    pixels: f64,
+}
+
+/// Running sums over finished steps of (distance, compute ms per pixel), so
+/// the cost fit is updated in constant time per step.
+#[derive(Clone, Copy, Debug)]
+struct CostSums {
+    n: f64,
+    d: f64,
+    c: f64,
+    dd: f64,
+    dc: f64,
+    min_c: f64,
+    max_c: f64,
+}
+
+impl Default for CostSums {
+    fn default() -> Self {
+        Self {
+            n: 0.0,
+            d: 0.0,
+            c: 0.0,
+            dd: 0.0,
+            dc: 0.0,
+            min_c: f64::INFINITY,
+            max_c: 0.0,
+        }
+    }
+}
+
+impl CostSums {
+    fn add(&mut self, distance: f64, cost: f64) {
+        self.n += 1.0;
+        self.d += distance;
+        self.c += cost;
+        self.dd += distance * distance;
+        self.dc += distance * cost;
+        self.min_c = self.min_c.min(cost);
+        self.max_c = self.max_c.max(cost);
+    }
+
+    /// Least-squares line through the samples, or None before the first.
+    fn fit(&self) -> Option<CostFit> {
+        if self.n == 0.0 {
+            return None;
+        }
+        let mean_d = self.d / self.n;
+        let mean_c = self.c / self.n;
+        let var = self.dd - self.n * mean_d * mean_d;
+        let cov = self.dc - self.n * mean_d * mean_c;
+        Some(CostFit {
+            mean_d,
+            mean_c,
+            slope: if var > 1e-9 { cov / var } else { 0.0 },
+            min: self.min_c,
+            max: self.max_c,
+        })
+    }
+}
+
+/// Compute time per pixel as a line in distance from the center.
+#[derive(Clone, Copy, Debug)]
+struct CostFit {
+    mean_d: f64,
+    mean_c: f64,
+    slope: f64,
+    min: f64,
+    max: f64,
+}
+
+impl CostFit {
+    /// Predicted cost at `distance`, kept within the range seen so far so
+    /// that a steep fit does not run away at the edges.
+    fn cost_per_pixel(&self, distance: f64) -> f64 {
+        (self.mean_c + self.slope * (distance - self.mean_d)).clamp(self.min, self.max)
+    }
+}
+
+/// Predicts how long a render has left from the steps finished so far.
+#[derive(Debug, Default)]
+pub struct RenderEstimator {
+    steps: Vec<Step>,
+    /// Step index of each tile, keyed by its top-left corner
+    tile_index: HashMap<(u32, u32), usize>,
+    /// Steps not finished yet
+    pending: Vec<bool>,
+    /// Finished steps as (distance, compute ms per pixel)
+    costs: CostSums,
+    /// Last report of each tile as (compute ms, iterations, BLA iterations)
+    reported: Vec<(f64, u64, u64)>,
+    /// Time the first step was started
+    started_at: Option<f64>,
+    compute_ms: f64,
+    iterations: u64,
+    bla_iterations: u64,
+}
+
+impl RenderEstimator {
+    /// Estimator for tiles of a canvas.
+    pub fn for_tiles(tiles: &[PixelRect], canvas_size: (u32, u32)) -> Self {
+        let center = (canvas_size.0 as f64 / 2.0, canvas_size.1 as f64 / 2.0);
+        let distances: Vec<f64> = tiles
+            .iter()
+            .map(|tile| {
+                let x = tile.x as f64 + tile.width as f64 / 2.0 - center.0;
+                let y = tile.y as f64 + tile.height as f64 / 2.0 - center.1;
+                x.hypot(y)
+            })
+            .collect();
+        let farthest = distances.iter().copied().fold(0.0, f64::max);
+
+        let steps = tiles
+            .iter()
+            .zip(&distances)
+            .map(|(tile, &distance)| Step {
+                distance: if farthest > 0.0 {
+                    distance / farthest
+                } else {
+                    0.0
+                },
+                pixels: (tile.width * tile.height) as f64,
+            })
+            .collect();
+        let tile_index = tiles
+            .iter()
+            .enumerate()
+            .map(|(index, tile)| ((tile.x, tile.y), index))
+            .collect();
+
+        Self {
+            steps,
+            tile_index,
+            pending: vec![true; tiles.len()],
+            reported: vec![(0.0, 0, 0); tiles.len()],
+            ..Self::default()
+        }
+    }
+
+    /// Estimator for `count` steps of equal cost, such as GPU row-sets.
+    pub fn uniform(count: u32) -> Self {
+        Self {
+            steps: vec![
+                Step {
+                    distance: 0.0,
+                    pixels: 1.0,
+                };
+                count as usize
+            ],
+            pending: vec![true; count as usize],
+            ..Self::default()
+        }
+    }
+
+    /// Note that work has started; only the first call counts.
+    pub fn start(&mut self, now: f64) {
+        self.started_at.get_or_insert(now);
+    }
+
+    /// Record work on a tile. A tile continued later, e.g. with a longer
+    /// orbit, reports its totals so far each time, replacing its earlier
+    /// report, and stays pending until it is finished.
+    pub fn record_tile(
+        &mut self,
+        tile: &PixelRect,
+        compute_time_ms: f64,
+        iterations: u64,
+        bla_iterations: u64,
+        finished: bool,
+    ) {
+        let Some(&index) = self.tile_index.get(&(tile.x, tile.y)) else {
+            self.add_work(compute_time_ms, iterations, bla_iterations);
+            return;
+        };
+        let (ms, its, bla) = std::mem::replace(
+            &mut self.reported[index],
+            (compute_time_ms, iterations, bla_iterations),
+        );
+        self.compute_ms -= ms;
+        self.iterations -= its;
+        self.bla_iterations -= bla;
+        self.add_work(compute_time_ms, iterations, bla_iterations);
+        if finished {
+            self.finish(index, compute_time_ms);
+        }
+    }
+
+    /// Drop a tile that will not be computed.
+    pub fn skip_tile(&mut self, tile: &PixelRect) {
+        if let Some(&index) = self.tile_index.get(&(tile.x, tile.y)) {
+            self.pending[index] = false;
+        }
+    }
+
+    /// Record a finished step of a uniform estimator.
+    pub fn record_step(&mut self, index: u32, compute_time_ms: f64, iterations: u64) {
+        self.add_work(compute_time_ms, iterations, 0);
+        if (index as usize) < self.steps.len() {
+            self.finish(index as usize, compute_time_ms);
+        }
+    }
+
+    fn add_work(&mut self, compute_time_ms: f64, iterations: u64, bla_iterations: u64) {
+        self.compute_ms += compute_time_ms;
+        self.iterations += iterations;
+        self.bla_iterations += bla_iterations;
+    }
+
+    fn finish(&mut self, index: usize, compute_time_ms: f64) {
+        let step = self.steps[index];
+        self.pending[index] = false;
+        if step.pixels > 0.0 {
+            self.costs.add(step.distance, compute_time_ms / step.pixels);
+        }
+    }
+
+    /// Wall-clock time the remaining steps should take, once a step has
+    /// finished. Compute time is spread over the workers in parallel, at the
+    /// rate observed so far.
+    pub fn remaining_ms(&self, now: f64) -> Option<f64> {
+        let elapsed = now - self.started_at?;
+        if elapsed <= 0.0 || self.compute_ms <= 0.0 {
+            return None;
+        }
+        let parallelism = self.compute_ms / elapsed;
+        let fit = self.costs.fit()?;
+
+        let remaining: f64 = self
+            .steps
+            .iter()
+            .zip(&self.pending)
+            .filter(|(_, &pending)| pending)
+            .map(|(step, _)| step.pixels * fit.cost_per_pixel(step.distance))
+            .sum();
+        Some(remaining / parallelism)
+    }
+
+    /// Copy the totals and the estimate into `progress`.
+    pub fn update_progress(&self, now: f64, progress: &mut RenderProgress) {
+        progress.iterations = self.iterations;
+        progress.bla_iterations = self.bla_iterations;
+        progress.remaining_ms = self.remaining_ms(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_estimate_before_a_step_finishes() {
+        let mut estimator = RenderEstimator::uniform(4);
+        assert_eq!(estimator.remaining_ms(1_000.0), None);
+        estimator.start(0.0);
+        assert_eq!(estimator.remaining_ms(1_000.0), None);
+    }
+
+    #[test]
+    fn uniform_steps_extrapolate_the_mean() {
+        let mut estimator = RenderEstimator::uniform(4);
+        estimator.start(0.0);
+        estimator.record_step(0, 100.0, 1_000);
+        estimator.record_step(1, 300.0, 3_000);
+
+        // Two steps of 200ms each remain, run one at a time
+        let remaining = estimator.remaining_ms(400.0).unwrap();
+        assert!((remaining - 400.0).abs() < 1e-9);
+
+        let mut progress = RenderProgress::new(4);
+        estimator.update_progress(400.0, &mut progress);
+        assert_eq!(progress.iterations, 4_000);
+        assert_eq!(progress.remaining_ms, Some(remaining));
+    }
+
+    #[test]
+    fn parallel_workers_shorten_the_estimate() {
+        let mut estimator = RenderEstimator::uniform(4);
+        estimator.start(0.0);
+        // Two workers finished 100ms steps side by side
+        estimator.record_step(0, 100.0, 0);
+        estimator.record_step(1, 100.0, 0);
+
+        let remaining = estimator.remaining_ms(100.0).unwrap();
+        assert!((remaining - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cost_is_extrapolated_from_center_out() {
+        // A row of five tiles; the dense center costs more per pixel
+        let tiles: Vec<PixelRect> = (0..5).map(|i| PixelRect::new(i * 10, 0, 10, 10)).collect();
+        let mut estimator = RenderEstimator::for_tiles(&tiles, (50, 10));
+        estimator.start(0.0);
+        estimator.record_tile(&tiles[2], 400.0, 0, 0, true);
+        estimator.record_tile(&tiles[1], 200.0, 0, 0, true);
+        estimator.record_tile(&tiles[3], 200.0, 0, 0, true);
+
+        // Fit: 4ms/px at the center, 2ms/px halfway; the edges would be 0
+        // but stay at the cheapest cost seen
+        let mean_cost = 800.0 / 3.0;
+        let remaining = estimator.remaining_ms(800.0).unwrap();
+        assert!((remaining - 400.0).abs() < 1e-9);
+        assert!(remaining < 2.0 * mean_cost);
+    }
+
+    #[test]
+    fn continued_tiles_stay_pending() {
+        let tiles = [PixelRect::new(0, 0, 10, 10), PixelRect::new(10, 0, 10, 10)];
+        let mut estimator = RenderEstimator::for_tiles(&tiles, (20, 10));
+        estimator.start(0.0);
+        estimator.record_tile(&tiles[0], 50.0, 500, 100, false);
+        assert_eq!(estimator.remaining_ms(50.0), None);
+
+        // The second report holds the tile's totals, including the first
+        estimator.record_tile(&tiles[0], 100.0, 1_000, 200, true);
+        let remaining = estimator.remaining_ms(100.0).unwrap();
+        assert!((remaining - 100.0).abs() < 1e-9);
+        assert_eq!(estimator.iterations, 1_000);
+        assert_eq!(estimator.bla_iterations, 200);
+
+        estimator.skip_tile(&tiles[1]);
+        assert_eq!(estimator.remaining_ms(100.0), Some(0.0));
+    }
+
+    #[test]
+    fn thousands_of_tiles_are_estimated_on_every_update() {
+        // 32px tiles over a HiDPI canvas, all of equal cost
+        let tiles: Vec<PixelRect> = (0..80)
+            .flat_map(|y| (0..100).map(move |x| PixelRect::new(x * 32, y * 32, 32, 32)))
+            .collect();
+        let mut estimator = RenderEstimator::for_tiles(&tiles, (3200, 2560));
+        estimator.start(0.0);
+
+        let mut remaining = None;
+        for (i, tile) in tiles.iter().enumerate() {
+            estimator.record_tile(tile, 1.0, 0, 0, true);
+            remaining = estimator.remaining_ms((i + 1) as f64);
+            if i == tiles.len() / 2 {
+                let pending = (tiles.len() - i - 1) as f64;
+                assert!((remaining.unwrap() - pending).abs() < 1e-6);
+            }
+        }
+        assert_eq!(remaining, Some(0.0));
+    }
+}
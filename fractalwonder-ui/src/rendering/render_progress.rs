@@ -7,6 +7,16 @@ pub struct RenderProgress {
     pub total_steps: u32,
     pub elapsed_ms: f64,
     pub is_complete: bool,
+    /// Reference orbit points computed so far (perturbation renders).
+    pub orbit_computed: u32,
+    /// Iteration limit of the reference orbit; zero when there is none.
+    pub orbit_max_iterations: u32,
+    /// Iterations computed so far, including those skipped by BLA.
+    pub iterations: u64,
+    /// Iterations skipped by bilinear approximation.
+    pub bla_iterations: u64,
+    /// Predicted time until the render completes, once there is a basis for it.
+    pub remaining_ms: Option<f64>,
 }
 
 impl RenderProgress {
@@ -17,6 +27,11 @@ impl RenderProgress {
             total_steps,
             elapsed_ms: 0.0,
             is_complete: false,
+            orbit_computed: 0,
+            orbit_max_iterations: 0,
+            iterations: 0,
+            bla_iterations: 0,
+            remaining_ms: None,
         }
     }
 
@@ -28,6 +43,58 @@ impl RenderProgress {
             (self.completed_steps as f32 / self.total_steps as f32) * 100.0
         }
     }
+
+    /// Reference orbit completion percentage, or None once the orbit is done
+    /// (or the render has no orbit).
+    pub fn orbit_percentage(&self) -> Option<f32> {
+        if self.orbit_max_iterations == 0 || self.orbit_computed >= self.orbit_max_iterations {
+            None
+        } else {
+            Some(self.orbit_computed as f32 / self.orbit_max_iterations as f32 * 100.0)
+        }
+    }
+
+    /// Iterations per second of wall-clock time since the render started.
+    pub fn iterations_per_second(&self) -> Option<f64> {
+        (self.iterations > 0 && self.elapsed_ms > 0.0)
+            .then(|| self.iterations as f64 / self.elapsed_ms * 1000.0)
+    }
+
+    /// Share of iterations skipped by BLA, in percent.
+    pub fn bla_percentage(&self) -> Option<f64> {
+        (self.iterations > 0).then(|| self.bla_iterations as f64 / self.iterations as f64 * 100.0)
+    }
+}
+
+/// Format a duration as e.g. "0.4s", "12s", "3m 05s" or "2h 07m".
+pub fn format_duration(ms: f64) -> String {
+    let seconds = ms.max(0.0) / 1000.0;
+    if seconds < 10.0 {
+        return format!("{:.1}s", seconds);
+    }
+    let seconds = seconds.round() as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds / 60 % 60),
+    }
+}
+
+/// Format a rate with an SI prefix, e.g. "812k it/s" or "3.2G it/s".
+pub fn format_iteration_rate(per_second: f64) -> String {
+    const PREFIXES: [(f64, &str); 4] = [(1e12, "T"), (1e9, "G"), (1e6, "M"), (1e3, "k")];
+    PREFIXES
+        .iter()
+        .find(|(scale, _)| per_second >= *scale)
+        .map(|(scale, prefix)| {
+            let scaled = per_second / scale;
+            if scaled < 10.0 {
+                format!("{:.1}{} it/s", scaled, prefix)
+            } else {
+                format!("{:.0}{} it/s", scaled, prefix)
+            }
+        })
+        .unwrap_or_else(|| format!("{:.0} it/s", per_second))
 }
 
 #[cfg(test)]
@@ -61,4 +128,41 @@ mod tests {
         progress.completed_steps = 64;
         assert!((progress.percentage() - 100.0).abs() < 0.001);
     }
+
+    #[test]
+    fn iteration_rate_needs_elapsed_time() {
+        let mut progress = RenderProgress::new(4);
+        progress.iterations = 3_000;
+        assert_eq!(progress.iterations_per_second(), None);
+
+        progress.elapsed_ms = 1_500.0;
+        progress.bla_iterations = 750;
+        assert_eq!(progress.iterations_per_second(), Some(2_000.0));
+        assert_eq!(progress.bla_percentage(), Some(25.0));
+    }
+
+    #[test]
+    fn durations_and_rates_are_formatted_compactly() {
+        assert_eq!(format_duration(420.0), "0.4s");
+        assert_eq!(format_duration(12_300.0), "12s");
+        assert_eq!(format_duration(185_000.0), "3m 05s");
+        assert_eq!(format_duration(7_650_000.0), "2h 07m");
+
+        assert_eq!(format_iteration_rate(950.0), "950 it/s");
+        assert_eq!(format_iteration_rate(812_000.0), "812k it/s");
+        assert_eq!(format_iteration_rate(3.24e9), "3.2G it/s");
+    }
+
+    #[test]
+    fn orbit_percentage_only_while_orbit_runs() {
+        let mut progress = RenderProgress::new(64);
+        assert_eq!(progress.orbit_percentage(), None);
+
+        progress.orbit_max_iterations = 1000;
+        progress.orbit_computed = 250;
+        assert!((progress.orbit_percentage().unwrap() - 25.0).abs() < 0.001);
+
+        progress.orbit_computed = 1000;
+        assert_eq!(progress.orbit_percentage(), None);
+    }
 }
@@ -0,0 +1,317 @@
+//! Per-tile render diagnostics and the heatmap overlay drawn from them.
+//!
+//! Workers report the cost of every CPU tile; collected here they show
+//! where a render spends its time and which precision path each part of
+//! the image took. The overlay extends x-ray mode, which marks glitched
+//! pixels, to whole tiles.
+
+use fractalwonder_core::{DeltaPrecision, PixelRect};
+use web_sys::CanvasRenderingContext2d;
+
+/// What a finished tile cost.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TileRecord {
+    pub tile: PixelRect,
+    pub compute_time_ms: f64,
+    pub total_iterations: u64,
+    pub bla_iterations: u64,
+    pub rebase_count: u64,
+    pub precision: DeltaPrecision,
+    pub glitched_pixels: u32,
+    /// Reference orbit the tile was rendered against
+    pub orbit_id: u32,
+}
+
+impl TileRecord {
+    pub fn pixels(&self) -> u64 {
+        self.tile.width as u64 * self.tile.height as u64
+    }
+
+    /// Compute time per pixel, which compares tiles of different sizes.
+    pub fn ms_per_pixel(&self) -> f64 {
+        self.compute_time_ms / self.pixels().max(1) as f64
+    }
+}
+
+/// Tile records of the current render.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    pub tiles: Vec<TileRecord>,
+}
+
+impl RenderStats {
+    /// Add a finished tile, replacing an earlier record of the same tile
+    /// when it is rendered again.
+    pub fn record(&mut self, record: TileRecord) {
+        match self.tiles.iter_mut().find(|t| t.tile == record.tile) {
+            Some(existing) => *existing = record,
+            None => self.tiles.push(record),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.tiles.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn pixels(&self) -> u64 {
+        self.tiles.iter().map(TileRecord::pixels).sum()
+    }
+
+    pub fn compute_time_ms(&self) -> f64 {
+        self.tiles.iter().map(|t| t.compute_time_ms).sum()
+    }
+
+    /// Share of iterations skipped by BLA, in [0, 1].
+    pub fn bla_ratio(&self) -> f64 {
+        let total: u64 = self.tiles.iter().map(|t| t.total_iterations).sum();
+        let bla: u64 = self.tiles.iter().map(|t| t.bla_iterations).sum();
+        if total == 0 {
+            0.0
+        } else {
+            bla as f64 / total as f64
+        }
+    }
+
+    pub fn rebases_per_pixel(&self) -> f64 {
+        let rebases: u64 = self.tiles.iter().map(|t| t.rebase_count).sum();
+        rebases as f64 / self.pixels().max(1) as f64
+    }
+
+    pub fn glitched_pixels(&self) -> u64 {
+        self.tiles.iter().map(|t| t.glitched_pixels as u64).sum()
+    }
+
+    /// Number of tiles per precision path, cheapest path first.
+    pub fn precision_mix(&self) -> Vec<(DeltaPrecision, usize)> {
+        [
+            DeltaPrecision::F64,
+            DeltaPrecision::ScaledF64,
+            DeltaPrecision::FloatExp,
+            DeltaPrecision::HdrFloat,
+        ]
+        .into_iter()
+        .map(|precision| {
+            let count = self
+                .tiles
+                .iter()
+                .filter(|t| t.precision == precision)
+                .count();
+            (precision, count)
+        })
+        .filter(|(_, count)| *count > 0)
+        .collect()
+    }
+
+    /// The `n` tiles with the longest compute time, slowest first.
+    pub fn slowest(&self, n: usize) -> Vec<TileRecord> {
+        let mut tiles = self.tiles.clone();
+        tiles.sort_by(|a, b| b.compute_time_ms.total_cmp(&a.compute_time_ms));
+        tiles.truncate(n);
+        tiles
+    }
+
+    /// Record of the tile that contains a pixel.
+    pub fn tile_at(&self, x: u32, y: u32) -> Option<&TileRecord> {
+        self.tiles.iter().find(|t| t.tile.contains(x, y))
+    }
+
+    /// Range of compute time per pixel, for scaling the cost heatmap.
+    fn cost_range(&self) -> (f64, f64) {
+        self.tiles
+            .iter()
+            .map(TileRecord::ms_per_pixel)
+            .fold((f64::INFINITY, 0.0f64), |(lo, hi), c| {
+                (lo.min(c), hi.max(c))
+            })
+    }
+}
+
+/// Tile property shown by the heatmap overlay.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapMode {
+    #[default]
+    Off,
+    /// Compute time per pixel, blue for cheap through red for expensive
+    Cost,
+    /// Delta arithmetic used for the tile
+    Precision,
+    /// Share of glitched pixels
+    Glitches,
+}
+
+impl HeatmapMode {
+    /// Mode that follows this one when cycling through them.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Cost,
+            Self::Cost => Self::Precision,
+            Self::Precision => Self::Glitches,
+            Self::Glitches => Self::Off,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Cost => "Tile Cost",
+            Self::Precision => "Precision Path",
+            Self::Glitches => "Glitches",
+        }
+    }
+}
+
+/// Fill color of the precision heatmap for each path.
+pub fn precision_color(precision: DeltaPrecision) -> [u8; 3] {
+    match precision {
+        DeltaPrecision::F64 => [64, 200, 96],
+        DeltaPrecision::ScaledF64 => [64, 160, 240],
+        DeltaPrecision::FloatExp => [240, 176, 48],
+        DeltaPrecision::HdrFloat => [224, 64, 208],
+    }
+}
+
+/// Overlay color for a tile as RGBA, or None to leave it uncovered.
+pub fn heatmap_color(
+    mode: HeatmapMode,
+    record: &TileRecord,
+    stats: &RenderStats,
+) -> Option<[u8; 4]> {
+    const ALPHA: u8 = 150;
+    match mode {
+        HeatmapMode::Off => None,
+        HeatmapMode::Cost => {
+            // Costs span orders of magnitude between exterior and interior tiles
+            let (lo, hi) = stats.cost_range();
+            let t = if hi > lo && lo > 0.0 {
+                ((record.ms_per_pixel().max(lo) / lo).ln() / (hi / lo).ln()).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let red = (t * 255.0).round() as u8;
+            Some([red, 40, 255 - red, ALPHA])
+        }
+        HeatmapMode::Precision => {
+            let [r, g, b] = precision_color(record.precision);
+            Some([r, g, b, ALPHA])
+        }
+        HeatmapMode::Glitches => (record.glitched_pixels > 0).then(|| {
+            let share = record.glitched_pixels as f64 / record.pixels().max(1) as f64;
+            [255, 0, 255, (64.0 + share * 191.0).round() as u8]
+        }),
+    }
+}
+
+/// Cover each recorded tile with its heatmap color.
+pub fn draw_heatmap(ctx: &CanvasRenderingContext2d, stats: &RenderStats, mode: HeatmapMode) {
+    if mode == HeatmapMode::Off {
+        return;
+    }
+    for record in &stats.tiles {
+        let Some([r, g, b, a]) = heatmap_color(mode, record, stats) else {
+            continue;
+        };
+        ctx.set_fill_style_str(&format!("rgba({r},{g},{b},{:.3})", a as f64 / 255.0));
+        ctx.fill_rect(
+            record.tile.x as f64,
+            record.tile.y as f64,
+            record.tile.width as f64,
+            record.tile.height as f64,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(x: u32, compute_time_ms: f64, precision: DeltaPrecision) -> TileRecord {
+        TileRecord {
+            tile: PixelRect::new(x, 0, 10, 10),
+            compute_time_ms,
+            total_iterations: 1_000,
+            bla_iterations: 250,
+            rebase_count: 50,
+            precision,
+            glitched_pixels: 0,
+            orbit_id: 1,
+        }
+    }
+
+    #[test]
+    fn aggregates_over_tiles() {
+        let mut stats = RenderStats::default();
+        stats.record(record(0, 10.0, DeltaPrecision::F64));
+        stats.record(record(10, 40.0, DeltaPrecision::ScaledF64));
+        stats.record(record(20, 20.0, DeltaPrecision::F64));
+
+        assert_eq!(stats.pixels(), 300);
+        assert!((stats.bla_ratio() - 0.25).abs() < 1e-12);
+        assert!((stats.rebases_per_pixel() - 0.5).abs() < 1e-12);
+        assert_eq!(
+            stats.precision_mix(),
+            vec![(DeltaPrecision::F64, 2), (DeltaPrecision::ScaledF64, 1)]
+        );
+        let slowest: Vec<u32> = stats.slowest(2).iter().map(|t| t.tile.x).collect();
+        assert_eq!(slowest, vec![10, 20]);
+    }
+
+    #[test]
+    fn rerendered_tile_replaces_its_record() {
+        let mut stats = RenderStats::default();
+        stats.record(record(0, 10.0, DeltaPrecision::F64));
+        stats.record(TileRecord {
+            glitched_pixels: 3,
+            ..record(0, 30.0, DeltaPrecision::F64)
+        });
+        assert_eq!(stats.tiles.len(), 1);
+        assert_eq!(stats.compute_time_ms(), 30.0);
+        assert_eq!(stats.glitched_pixels(), 3);
+    }
+
+    #[test]
+    fn cost_heatmap_runs_from_blue_to_red() {
+        let mut stats = RenderStats::default();
+        let cheap = record(0, 1.0, DeltaPrecision::F64);
+        let costly = record(10, 100.0, DeltaPrecision::F64);
+        stats.record(cheap);
+        stats.record(costly);
+
+        assert_eq!(
+            heatmap_color(HeatmapMode::Cost, &cheap, &stats),
+            Some([0, 40, 255, 150])
+        );
+        assert_eq!(
+            heatmap_color(HeatmapMode::Cost, &costly, &stats),
+            Some([255, 40, 0, 150])
+        );
+        assert_eq!(heatmap_color(HeatmapMode::Off, &cheap, &stats), None);
+    }
+
+    #[test]
+    fn glitch_heatmap_only_covers_glitched_tiles() {
+        let stats = RenderStats::default();
+        let clean = record(0, 1.0, DeltaPrecision::F64);
+        let glitched = TileRecord {
+            glitched_pixels: 100,
+            ..clean
+        };
+        assert_eq!(heatmap_color(HeatmapMode::Glitches, &clean, &stats), None);
+        assert_eq!(
+            heatmap_color(HeatmapMode::Glitches, &glitched, &stats),
+            Some([255, 0, 255, 255])
+        );
+    }
+
+    #[test]
+    fn heatmap_modes_cycle_back_to_off() {
+        let mut mode = HeatmapMode::Off;
+        for _ in 0..4 {
+            mode = mode.next();
+        }
+        assert_eq!(mode, HeatmapMode::Off);
+    }
+}
@@ -1,4 +1,4 @@
-use fractalwonder_core::{BigFloat, PixelRect, Viewport};
+use fractalwonder_core::{canvas_offset_to_delta, BigFloat, PixelRect, Viewport};
 
 /// Convert a pixel-space tile to its corresponding fractal-space viewport.
 pub fn tile_to_viewport(
@@ -28,8 +28,13 @@ pub fn tile_to_viewport(
     let offset_y = tile_center_y - canvas_center_y;
 
     // Convert pixel offsets to fractal-space offsets
-    let offset_x_bf = pixel_width.mul(&BigFloat::with_precision(offset_x, precision));
-    let offset_y_bf = pixel_height.mul(&BigFloat::with_precision(offset_y, precision));
+    let (offset_x_bf, offset_y_bf) = canvas_offset_to_delta(
+        (
+            pixel_width.mul(&BigFloat::with_precision(offset_x, precision)),
+            pixel_height.mul(&BigFloat::with_precision(offset_y, precision)),
+        ),
+        viewport,
+    );
 
     // Calculate tile center in fractal space
     let center_x = viewport.center.0.add(&offset_x_bf);
@@ -40,6 +45,7 @@ pub fn tile_to_viewport(
     let tile_height = pixel_height.mul(&BigFloat::with_precision(tile.height as f64, precision));
 
     Viewport::with_bigfloat(center_x, center_y, tile_width, tile_height)
+        .with_orientation_of(viewport)
 }
 
 /// Calculate tile size based on zoom level.
@@ -1,10 +1,13 @@
 mod perturbation;
 mod quadtree;
+mod shared_memory;
+mod worker_health;
 mod worker_pool;
 mod worker_pool_glitch;
 mod worker_pool_types;
 
 pub use perturbation::{calculate_dc_max, calculate_render_max_iterations, validate_viewport};
 pub use quadtree::{subdivide_to_depth, Bounds, QuadtreeCell, MAX_DEPTH, MIN_CELL_SIZE};
+pub use shared_memory::shared_memory_available;
 pub use worker_pool::WorkerPool;
 pub use worker_pool_types::{OrbitCompleteData, TileResult};
@@ -6,7 +6,10 @@
 use super::glitch_resolution::GlitchResolver;
 use super::helpers::{calculate_dc_max, calculate_render_max_iterations, validate_viewport};
 use crate::config::get_config;
-use fractalwonder_core::{BigFloat, HDRFloat, MainToWorker, PixelRect, Viewport};
+use fractalwonder_core::{
+    pixel_delta_step, pixel_to_delta, BigFloat, DeltaStep, HDRFloat, MainToWorker, PixelRect,
+    Viewport,
+};
 use std::collections::HashSet;
 
 /// Request to compute a reference orbit.
@@ -33,10 +36,16 @@ struct PerturbationState {
     orbit_id: u32,
     /// Workers that have confirmed storing the orbit
     workers_with_orbit: HashSet<usize>,
+    /// Orbit points already sent to every worker
+    streamed_orbit_len: u32,
+    /// Whether the last orbit chunk has been sent
+    orbit_complete: bool,
     /// Maximum iterations for perturbation tiles
     max_iterations: u32,
-    /// Delta step per pixel in fractal space
-    delta_step: (BigFloat, BigFloat),
+    /// Iterations the current reference orbit was requested for
+    orbit_iterations: u32,
+    /// Delta step per pixel in fractal space (rotated with the viewport)
+    delta_step: DeltaStep<BigFloat>,
     /// Glitch detection threshold squared
     tau_sq: f64,
     /// Maximum |delta_c| for BLA table construction.
@@ -46,6 +55,10 @@ struct PerturbationState {
     bla_enabled: bool,
     /// Force HDRFloat for all calculations (debug option)
     force_hdr_float: bool,
+    /// Record orbit statistics for the layered colorizers
+    collect_orbit_stats: bool,
+    /// Probe interior pixels for the period-based interior coloring modes
+    probe_interior: bool,
 }
 
 impl Default for PerturbationState {
@@ -53,12 +66,20 @@ impl Default for PerturbationState {
         Self {
             orbit_id: 0,
             workers_with_orbit: HashSet::new(),
+            streamed_orbit_len: 0,
+            orbit_complete: false,
             max_iterations: 0,
-            delta_step: (BigFloat::zero(64), BigFloat::zero(64)),
+            orbit_iterations: 0,
+            delta_step: DeltaStep {
+                x: (BigFloat::zero(64), BigFloat::zero(64)),
+                y: (BigFloat::zero(64), BigFloat::zero(64)),
+            },
             tau_sq: 1e-6,
             dc_max: HDRFloat::ZERO,
             bla_enabled: true,
             force_hdr_float: false,
+            collect_orbit_stats: false,
+            probe_interior: false,
         }
     }
 }
@@ -73,6 +94,9 @@ pub struct PerturbationCoordinator {
     canvas_size: (u32, u32),
     /// Renderer ID for config lookup
     renderer_id: String,
+    /// Orbit length to compute up front when reference orbits are kept
+    /// across renders at the same center; None computes one per render
+    orbit_reuse: Option<u32>,
 }
 
 impl PerturbationCoordinator {
@@ -83,6 +107,7 @@ impl PerturbationCoordinator {
             current_viewport: None,
             canvas_size: (0, 0),
             renderer_id: renderer_id.to_string(),
+            orbit_reuse: None,
         }
     }
 
@@ -111,11 +136,28 @@ impl PerturbationCoordinator {
         self.state.bla_enabled
     }
 
+    /// Keep the reference orbit across renders at the same center,
+    /// computing it at least `orbit_iterations` long. None computes one
+    /// orbit per render.
+    pub fn set_orbit_reuse(&mut self, orbit_iterations: Option<u32>) {
+        self.orbit_reuse = orbit_iterations;
+    }
+
     /// Set force_hdr_float flag.
     pub fn set_force_hdr_float(&mut self, force: bool) {
         self.state.force_hdr_float = force;
     }
 
+    /// Set collect_orbit_stats flag.
+    pub fn set_collect_orbit_stats(&mut self, collect: bool) {
+        self.state.collect_orbit_stats = collect;
+    }
+
+    /// Set probe_interior flag.
+    pub fn set_probe_interior(&mut self, probe: bool) {
+        self.state.probe_interior = probe;
+    }
+
     /// Access glitch resolver.
     pub fn glitch_resolver(&self) -> &GlitchResolver {
         &self.glitch_resolver
@@ -127,8 +169,28 @@ impl PerturbationCoordinator {
     }
 
     /// Check if a worker is ready for tile dispatch.
+    ///
+    /// Messages to a worker arrive in order, so once part of the orbit has
+    /// been broadcast any later tile is rendered against at least that prefix.
     pub fn worker_ready_for_tiles(&self, worker_id: usize) -> bool {
-        self.state.workers_with_orbit.contains(&worker_id)
+        self.state.streamed_orbit_len > 0 || self.state.workers_with_orbit.contains(&worker_id)
+    }
+
+    /// Number of orbit points already sent to every worker.
+    pub fn streamed_orbit_len(&self) -> u32 {
+        self.state.streamed_orbit_len
+    }
+
+    /// Whether the whole orbit has been sent to the workers.
+    pub fn orbit_complete(&self) -> bool {
+        self.state.orbit_complete
+    }
+
+    /// Record that orbit points up to `len` have been sent to every worker,
+    /// and whether that was the last chunk.
+    pub fn record_orbit_streamed(&mut self, len: u32, complete: bool) {
+        self.state.streamed_orbit_len = len;
+        self.state.orbit_complete = complete;
     }
 
     /// Record that a worker has stored the orbit.
@@ -136,6 +198,12 @@ impl PerturbationCoordinator {
         self.state.workers_with_orbit.insert(worker_id);
     }
 
+    /// Forget a worker that is being replaced; its successor starts without
+    /// the orbit.
+    pub fn forget_worker(&mut self, worker_id: usize) {
+        self.state.workers_with_orbit.remove(&worker_id);
+    }
+
     /// Check if all initialized workers have the orbit.
     pub fn all_workers_have_orbit(&self, initialized_workers: &HashSet<usize>) -> bool {
         initialized_workers
@@ -163,6 +231,51 @@ impl PerturbationCoordinator {
         // Reset state
         self.state.orbit_id = self.state.orbit_id.wrapping_add(1);
         self.state.workers_with_orbit.clear();
+        self.state.streamed_orbit_len = 0;
+        self.state.orbit_complete = false;
+
+        self.set_render_params(viewport, canvas_size);
+        self.state.dc_max = calculate_dc_max(viewport);
+        self.state.orbit_iterations = self.state.max_iterations.max(self.orbit_reuse.unwrap_or(0));
+
+        // Prepare orbit request
+        let (cx, cy) = &viewport.center;
+        let c_ref_json = serde_json::to_string(&(cx.compact(), cy.compact())).unwrap_or_default();
+
+        Ok(OrbitRequest {
+            render_id,
+            orbit_id: self.state.orbit_id,
+            c_ref_json,
+            max_iterations: self.state.orbit_iterations,
+        })
+    }
+
+    /// Whether a render of `viewport` can keep the current reference orbit.
+    ///
+    /// Needs orbit reuse turned on, a complete orbit at the same center that
+    /// is long enough, and BLA tables built for a viewport at least as wide.
+    pub fn can_reuse_orbit(&self, viewport: &Viewport) -> bool {
+        self.orbit_reuse.is_some()
+            && self.state.orbit_complete
+            && self
+                .current_viewport
+                .as_ref()
+                .is_some_and(|current| current.center == viewport.center)
+            && calculate_render_max_iterations(viewport, get_config(&self.renderer_id))
+                <= self.state.orbit_iterations
+            && calculate_dc_max(viewport).log2() <= self.state.dc_max.log2()
+    }
+
+    /// Prepare a render of `viewport` against the current reference orbit,
+    /// once `can_reuse_orbit` allows it. The workers keep the orbit and the
+    /// BLA tables built for its `dc_max`.
+    pub fn reuse_orbit(&mut self, viewport: &Viewport, canvas_size: (u32, u32)) {
+        self.set_render_params(viewport, canvas_size);
+    }
+
+    /// Set the parameters of a render of `viewport` that do not depend on
+    /// the reference orbit.
+    fn set_render_params(&mut self, viewport: &Viewport, canvas_size: (u32, u32)) {
         self.current_viewport = Some(viewport.clone());
         self.canvas_size = canvas_size;
 
@@ -175,27 +288,10 @@ impl PerturbationCoordinator {
         // Calculate render parameters
         self.state.max_iterations = calculate_render_max_iterations(viewport, config);
         self.state.tau_sq = config.map(|c| c.tau_sq).unwrap_or(1e-6);
-        self.state.dc_max = calculate_dc_max(viewport);
         self.state.bla_enabled = config.map(|c| c.bla_enabled).unwrap_or(true);
 
         // Calculate delta step per pixel
-        let precision = viewport.width.precision_bits();
-        let canvas_width_bf = BigFloat::with_precision(canvas_size.0 as f64, precision);
-        let canvas_height_bf = BigFloat::with_precision(canvas_size.1 as f64, precision);
-        self.state.delta_step = (
-            viewport.width.div(&canvas_width_bf),
-            viewport.height.div(&canvas_height_bf),
-        );
-
-        // Prepare orbit request
-        let c_ref_json = serde_json::to_string(&viewport.center).unwrap_or_default();
-
-        Ok(OrbitRequest {
-            render_id,
-            orbit_id: self.state.orbit_id,
-            c_ref_json,
-            max_iterations: self.state.max_iterations,
-        })
+        self.state.delta_step = pixel_delta_step(viewport, canvas_size);
     }
 
     /// Prepare for GPU-only orbit computation (no tiles).
@@ -211,6 +307,8 @@ impl PerturbationCoordinator {
         // Reset state (simplified for GPU - no glitch tracking)
         self.state.orbit_id = self.state.orbit_id.wrapping_add(1);
         self.state.workers_with_orbit.clear();
+        self.state.streamed_orbit_len = 0;
+        self.state.orbit_complete = false;
         self.current_viewport = Some(viewport.clone());
         self.canvas_size = canvas_size;
 
@@ -222,7 +320,8 @@ impl PerturbationCoordinator {
         self.state.tau_sq = config.map(|c| c.tau_sq).unwrap_or(1e-6);
 
         // Prepare orbit request
-        let c_ref_json = serde_json::to_string(&viewport.center).unwrap_or_default();
+        let (cx, cy) = &viewport.center;
+        let c_ref_json = serde_json::to_string(&(cx.compact(), cy.compact())).unwrap_or_default();
 
         Ok(OrbitRequest {
             render_id,
@@ -232,13 +331,24 @@ impl PerturbationCoordinator {
         })
     }
 
-    /// Build StoreReferenceOrbit messages for all workers.
-    pub fn build_orbit_broadcast(&self, orbit_data: &OrbitData) -> MainToWorker {
-        MainToWorker::StoreReferenceOrbit {
+    /// Build the AppendReferenceOrbit message carrying the orbit from `start` on.
+    ///
+    /// With `complete` set, workers finish the orbit, build their BLA tables
+    /// and reply `OrbitStored`.
+    pub fn build_orbit_chunk(
+        &self,
+        orbit_data: &OrbitData,
+        start: u32,
+        complete: bool,
+    ) -> MainToWorker {
+        let from = start as usize;
+        MainToWorker::AppendReferenceOrbit {
             orbit_id: self.state.orbit_id,
             c_ref: orbit_data.c_ref,
-            orbit: orbit_data.orbit.clone(),
-            derivative: orbit_data.derivative.clone(),
+            start,
+            orbit: orbit_data.orbit[from..].to_vec(),
+            derivative: orbit_data.derivative[from..].to_vec(),
+            complete,
             escaped_at: orbit_data.escaped_at,
             dc_max: self.state.dc_max,
             bla_enabled: self.state.bla_enabled,
@@ -251,18 +361,55 @@ impl PerturbationCoordinator {
         let precision = viewport.width.precision_bits();
 
         // Calculate delta_c_origin for this tile's top-left pixel
-        let norm_x = tile.x as f64 / self.canvas_size.0 as f64 - 0.5;
-        let norm_y = tile.y as f64 / self.canvas_size.1 as f64 - 0.5;
-
-        let norm_x_bf = BigFloat::with_precision(norm_x, precision);
-        let norm_y_bf = BigFloat::with_precision(norm_y, precision);
-        let delta_c_origin = (
-            norm_x_bf.mul(&viewport.width),
-            norm_y_bf.mul(&viewport.height),
+        let delta_c_origin = pixel_to_delta(
+            tile.x as f64,
+            tile.y as f64,
+            viewport,
+            self.canvas_size,
+            precision,
         );
 
-        let delta_c_origin_json = serde_json::to_string(&delta_c_origin).ok()?;
-        let delta_c_step_json = serde_json::to_string(&self.state.delta_step).ok()?;
+        self.tile_message(render_id, tile, self.state.orbit_id, delta_c_origin)
+    }
+
+    /// Build RenderTilePerturbation message re-rendering a glitched tile
+    /// against the orbit of its quadtree cell, or None if that orbit has
+    /// not been broadcast.
+    pub fn build_glitch_tile_message(
+        &self,
+        render_id: u32,
+        tile: PixelRect,
+    ) -> Option<MainToWorker> {
+        let (orbit_id, (cell_x, cell_y)) = self.glitch_resolver.cell_orbit_for(&tile)?;
+        let viewport = self.current_viewport.as_ref()?;
+        let precision = viewport.width.precision_bits();
+
+        // The tile's top-left pixel relative to the cell center instead of
+        // the viewport center
+        let pixel = pixel_to_delta(
+            tile.x as f64,
+            tile.y as f64,
+            viewport,
+            self.canvas_size,
+            precision,
+        );
+        let cell = pixel_to_delta(cell_x, cell_y, viewport, self.canvas_size, precision);
+        let delta_c_origin = (pixel.0.sub(&cell.0), pixel.1.sub(&cell.1));
+
+        self.tile_message(render_id, tile, orbit_id, delta_c_origin)
+    }
+
+    fn tile_message(
+        &self,
+        render_id: u32,
+        tile: PixelRect,
+        orbit_id: u32,
+        delta_c_origin: (BigFloat, BigFloat),
+    ) -> Option<MainToWorker> {
+        let delta_c_origin_json =
+            serde_json::to_string(&(delta_c_origin.0.compact(), delta_c_origin.1.compact()))
+                .ok()?;
+        let delta_c_step_json = serde_json::to_string(&self.state.delta_step.compact()).ok()?;
 
         let bigfloat_threshold_bits = get_config(&self.renderer_id)
             .map(|c| c.bigfloat_threshold_bits)
@@ -271,7 +418,7 @@ impl PerturbationCoordinator {
         Some(MainToWorker::RenderTilePerturbation {
             render_id,
             tile,
-            orbit_id: self.state.orbit_id,
+            orbit_id,
             delta_c_origin_json,
             delta_c_step_json,
             max_iterations: self.state.max_iterations,
@@ -279,12 +426,38 @@ impl PerturbationCoordinator {
             bigfloat_threshold_bits,
             bla_enabled: self.state.bla_enabled,
             force_hdr_float: self.state.force_hdr_float,
+            collect_orbit_stats: self.state.collect_orbit_stats,
+            probe_interior: self.state.probe_interior,
+        })
+    }
+
+    /// Build the TraceOrbitPath message for a pixel of the current render.
+    pub fn build_trace_message(&self, request_id: u32, x: u32, y: u32) -> Option<MainToWorker> {
+        let viewport = self.current_viewport.as_ref()?;
+        let precision = viewport.width.precision_bits();
+
+        // Same sample point as the pixel in `build_tile_message`
+        let delta_c = pixel_to_delta(x as f64, y as f64, viewport, self.canvas_size, precision);
+        let delta_c_json =
+            serde_json::to_string(&(delta_c.0.compact(), delta_c.1.compact())).ok()?;
+        let (cx, cy) = &viewport.center;
+        let c_ref_json = serde_json::to_string(&(cx.compact(), cy.compact())).ok()?;
+
+        Some(MainToWorker::TraceOrbitPath {
+            request_id,
+            orbit_id: self.state.orbit_id,
+            c_ref_json,
+            delta_c_json,
+            max_iterations: self.state.max_iterations,
+            tau_sq: self.state.tau_sq,
         })
     }
 
     /// Reset state for cancel or non-perturbation render.
     pub fn reset(&mut self) {
         self.state.workers_with_orbit.clear();
+        self.state.streamed_orbit_len = 0;
+        self.state.orbit_complete = false;
         self.glitch_resolver.clear();
     }
 }
@@ -301,6 +474,8 @@ mod tests {
             ),
             width: BigFloat::with_precision(4.0, 64),
             height: BigFloat::with_precision(4.0, 64),
+            rotation: 0.0,
+            skew: None,
         }
     }
 
@@ -332,6 +507,30 @@ mod tests {
         assert!(request.max_iterations > 0);
     }
 
+    #[test]
+    fn trace_message_targets_current_orbit() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        assert!(coord.build_trace_message(1, 10, 10).is_none());
+
+        let request = coord
+            .start_render(1, &create_test_viewport(), (800, 600))
+            .unwrap();
+        let Some(MainToWorker::TraceOrbitPath {
+            request_id,
+            orbit_id,
+            c_ref_json,
+            max_iterations,
+            ..
+        }) = coord.build_trace_message(7, 400, 300)
+        else {
+            panic!("expected TraceOrbitPath");
+        };
+        assert_eq!(request_id, 7);
+        assert_eq!(orbit_id, request.orbit_id);
+        assert_eq!(c_ref_json, request.c_ref_json);
+        assert_eq!(max_iterations, request.max_iterations);
+    }
+
     #[test]
     fn worker_ready_for_tiles_false_initially() {
         let coord = PerturbationCoordinator::new("mandelbrot");
@@ -345,6 +544,131 @@ mod tests {
         assert!(coord.worker_ready_for_tiles(0));
     }
 
+    #[test]
+    fn streamed_prefix_makes_every_worker_ready() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        let _ = coord.start_render(1, &create_test_viewport(), (800, 600));
+        assert!(!coord.worker_ready_for_tiles(3));
+        coord.record_orbit_streamed(65536, false);
+        assert!(coord.worker_ready_for_tiles(3));
+        assert!(!coord.orbit_complete());
+        coord.record_orbit_streamed(100_000, true);
+        assert!(coord.orbit_complete());
+
+        // A new render starts without an orbit again
+        let _ = coord.start_render(2, &create_test_viewport(), (800, 600));
+        assert_eq!(coord.streamed_orbit_len(), 0);
+        assert!(!coord.orbit_complete());
+        assert!(!coord.worker_ready_for_tiles(3));
+    }
+
+    #[test]
+    fn build_orbit_chunk_sends_points_from_start() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        let _ = coord.start_render(1, &create_test_viewport(), (800, 600));
+        let data = OrbitData {
+            c_ref: (-0.5, 0.0),
+            orbit: (0..10).map(|i| (i as f64, 0.0)).collect(),
+            derivative: vec![(1.0, 0.0); 10],
+            escaped_at: Some(10),
+        };
+        match coord.build_orbit_chunk(&data, 6, true) {
+            MainToWorker::AppendReferenceOrbit {
+                orbit_id,
+                start,
+                orbit,
+                derivative,
+                complete,
+                escaped_at,
+                ..
+            } => {
+                assert_eq!(orbit_id, coord.orbit_id());
+                assert_eq!(start, 6);
+                assert_eq!(orbit, vec![(6.0, 0.0), (7.0, 0.0), (8.0, 0.0), (9.0, 0.0)]);
+                assert_eq!(derivative.len(), 4);
+                assert!(complete);
+                assert_eq!(escaped_at, Some(10));
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn glitched_tile_renders_against_its_cell_orbit() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        let viewport = create_test_viewport();
+        let _ = coord.start_render(1, &viewport, (800, 600));
+        let tile = PixelRect::new(0, 0, 64, 64);
+        assert!(coord.build_glitch_tile_message(1, tile).is_none());
+
+        let resolver = coord.glitch_resolver_mut();
+        resolver.record_glitched_tile(tile);
+        resolver.subdivide_glitched_cells();
+        resolver.compute_cell_orbits(&viewport, (800, 600), 100);
+        let broadcasts = resolver.orbits_to_broadcast(HDRFloat::ZERO, false);
+
+        let Some(MainToWorker::RenderTilePerturbation {
+            orbit_id,
+            delta_c_origin_json,
+            ..
+        }) = coord.build_glitch_tile_message(1, tile)
+        else {
+            panic!("expected RenderTilePerturbation");
+        };
+        assert_eq!(orbit_id, broadcasts[0].0);
+
+        // The top-left pixel is 200x150 pixels, or 1.0 in each axis, from
+        // the center of the top-left quadrant
+        let (re, im): (BigFloat, BigFloat) = serde_json::from_str(&delta_c_origin_json).unwrap();
+        assert!((re.to_f64() + 1.0).abs() < 1e-12);
+        assert!((im.to_f64().abs() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn orbit_is_reused_only_when_enabled_and_centered() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        let viewport = create_test_viewport();
+        let _ = coord.start_render(1, &viewport, (800, 600));
+        coord.record_orbit_streamed(1000, true);
+        assert!(!coord.can_reuse_orbit(&viewport));
+
+        coord.set_orbit_reuse(Some(50_000));
+        let request = coord.start_render(2, &viewport, (800, 600)).unwrap();
+        assert_eq!(request.max_iterations, 50_000);
+        assert!(!coord.can_reuse_orbit(&viewport));
+        coord.record_orbit_streamed(50_000, true);
+        coord.record_worker_has_orbit(0);
+        assert!(coord.can_reuse_orbit(&viewport));
+
+        let mut zoomed_in = viewport.clone();
+        zoomed_in.width = BigFloat::with_precision(1.0, 64);
+        zoomed_in.height = BigFloat::with_precision(1.0, 64);
+        assert!(coord.can_reuse_orbit(&zoomed_in));
+
+        let mut zoomed_out = viewport.clone();
+        zoomed_out.width = BigFloat::with_precision(8.0, 64);
+        assert!(!coord.can_reuse_orbit(&zoomed_out));
+
+        let mut moved = viewport.clone();
+        moved.center.0 = BigFloat::with_precision(-0.75, 64);
+        assert!(!coord.can_reuse_orbit(&moved));
+
+        coord.reuse_orbit(&zoomed_in, (800, 600));
+        assert_eq!(coord.orbit_id(), request.orbit_id);
+        assert!(coord.orbit_complete());
+        assert!(coord.worker_ready_for_tiles(0));
+    }
+
+    #[test]
+    fn forget_worker_drops_its_orbit() {
+        let mut coord = PerturbationCoordinator::new("mandelbrot");
+        coord.record_worker_has_orbit(0);
+        coord.record_worker_has_orbit(1);
+        coord.forget_worker(0);
+        assert!(!coord.worker_ready_for_tiles(0));
+        assert!(coord.worker_ready_for_tiles(1));
+    }
+
     #[test]
     fn reset_clears_workers_with_orbit() {
         let mut coord = PerturbationCoordinator::new("mandelbrot");
@@ -81,12 +81,47 @@ impl GlitchResolver {
         self.glitched_tile_count
     }
 
+    /// Take the glitched tiles recorded so far, so that only tiles still
+    /// glitched after re-rendering are recorded again.
+    pub fn take_glitched_tiles(&mut self) -> Vec<PixelRect> {
+        self.glitched_tile_count = 0;
+        std::mem::take(&mut self.glitched_tiles)
+    }
+
+    /// Orbit id and pixel center of the leaf cell holding the center of
+    /// `tile`, if that cell's orbit has been broadcast.
+    pub fn cell_orbit_for(&self, tile: &PixelRect) -> Option<(u32, (f64, f64))> {
+        let quadtree = self.quadtree.as_ref()?;
+        let mut leaves = Vec::new();
+        quadtree.collect_leaves(&mut leaves);
+
+        let leaf = leaves
+            .into_iter()
+            .find(|leaf| leaf.contains(tile.x + tile.width / 2, tile.y + tile.height / 2))?;
+        let bounds = leaf.bounds;
+        let orbit_id =
+            *self
+                .cell_orbit_ids
+                .get(&(bounds.x, bounds.y, bounds.width, bounds.height))?;
+        // Same center as the orbit computed in `compute_cell_orbits`
+        let center = (
+            bounds.x as f64 + bounds.width as f64 / 2.0,
+            bounds.y as f64 + bounds.height as f64 / 2.0,
+        );
+        Some((orbit_id, center))
+    }
+
     /// Get quadtree reference for testing.
     #[allow(dead_code)]
     pub fn quadtree(&self) -> Option<&QuadtreeCell> {
         self.quadtree.as_ref()
     }
 
+    /// Ids of the cell orbits broadcast so far.
+    pub fn cell_orbit_ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.cell_orbit_ids.values().copied()
+    }
+
     /// Check if a cell orbit confirmation is being tracked.
     pub fn is_tracking_orbit(&self, orbit_id: u32) -> bool {
         self.cell_orbit_confirmations.contains_key(&orbit_id)
@@ -255,22 +290,48 @@ impl GlitchResolver {
             self.cell_orbit_confirmations
                 .insert(orbit_id, HashSet::new());
 
-            let msg = MainToWorker::StoreReferenceOrbit {
+            broadcasts.push((
                 orbit_id,
-                c_ref: orbit.c_ref,
-                orbit: orbit.orbit.clone(),
-                derivative: orbit.derivative.clone(),
-                escaped_at: orbit.escaped_at,
-                dc_max,
-                bla_enabled,
-            };
-
-            broadcasts.push((orbit_id, msg));
+                Self::store_message(orbit_id, orbit, dc_max, bla_enabled),
+            ));
         }
 
         broadcasts
     }
 
+    /// Messages storing every cell orbit already broadcast, for a worker
+    /// that replaces one that crashed.
+    pub fn broadcast_orbit_messages(
+        &self,
+        dc_max: HDRFloat,
+        bla_enabled: bool,
+    ) -> Vec<MainToWorker> {
+        self.cell_orbit_ids
+            .iter()
+            .filter_map(|(cell_key, &orbit_id)| {
+                let orbit = self.cell_orbits.get(cell_key)?;
+                Some(Self::store_message(orbit_id, orbit, dc_max, bla_enabled))
+            })
+            .collect()
+    }
+
+    fn store_message(
+        orbit_id: u32,
+        orbit: &ReferenceOrbit,
+        dc_max: HDRFloat,
+        bla_enabled: bool,
+    ) -> MainToWorker {
+        MainToWorker::StoreReferenceOrbit {
+            orbit_id,
+            c_ref: orbit.c_ref,
+            orbit: orbit.orbit.clone(),
+            derivative: orbit.derivative.clone(),
+            escaped_at: orbit.escaped_at,
+            dc_max,
+            bla_enabled,
+        }
+    }
+
     /// Get leaves with their glitch counts for logging.
     pub fn leaves_with_glitch_counts(&self) -> Vec<(Bounds, usize)> {
         let Some(quadtree) = &self.quadtree else {
@@ -342,6 +403,42 @@ mod tests {
         assert!(count > 0);
     }
 
+    #[test]
+    fn take_glitched_tiles_empties_the_list() {
+        let mut resolver = GlitchResolver::new();
+        resolver.init_for_render((128, 128));
+        resolver.record_glitched_tile(PixelRect::new(0, 0, 32, 32));
+        assert_eq!(
+            resolver.take_glitched_tiles(),
+            vec![PixelRect::new(0, 0, 32, 32)]
+        );
+        assert_eq!(resolver.glitched_tile_count(), 0);
+        assert!(resolver.take_glitched_tiles().is_empty());
+    }
+
+    #[test]
+    fn glitched_tile_maps_to_its_broadcast_cell_orbit() {
+        let viewport = Viewport::from_f64(-0.5, 0.0, 4.0, 4.0, 64);
+        let mut resolver = GlitchResolver::new();
+        resolver.init_for_render((128, 128));
+        let tile = PixelRect::new(0, 0, 32, 32);
+        resolver.record_glitched_tile(tile);
+        resolver.subdivide_glitched_cells();
+        assert_eq!(resolver.cell_orbit_for(&tile), None);
+
+        resolver.compute_cell_orbits(&viewport, (128, 128), 100);
+        let broadcasts = resolver.orbits_to_broadcast(HDRFloat::ZERO, false);
+        assert_eq!(broadcasts.len(), 1);
+
+        let (orbit_id, center) = resolver.cell_orbit_for(&tile).unwrap();
+        assert_eq!(orbit_id, broadcasts[0].0);
+        assert_eq!(center, (32.0, 32.0));
+        assert_eq!(
+            resolver.cell_orbit_for(&PixelRect::new(96, 96, 32, 32)),
+            None
+        );
+    }
+
     #[test]
     fn clear_removes_quadtree() {
         let mut resolver = GlitchResolver::new();
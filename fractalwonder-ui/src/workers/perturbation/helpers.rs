@@ -3,7 +3,7 @@
 //! These functions are stateless and easily testable.
 
 use crate::config::FractalConfig;
-use fractalwonder_core::{calculate_max_iterations, HDRFloat, Viewport};
+use fractalwonder_core::{calculate_max_iterations, BigFloat, HDRFloat, Viewport};
 
 /// Validate viewport dimensions for rendering.
 ///
@@ -24,15 +24,12 @@ pub fn validate_viewport(viewport: &Viewport) -> Result<(), String> {
 
 /// Calculate maximum iterations for a render based on zoom level.
 ///
-/// Uses log2_approx() instead of to_f64() to handle extreme zoom depths
-/// beyond f64 range (e.g., 10^308+).
+/// The zoom exponent comes from the exact binary exponent of the width, so the
+/// budget keeps growing at any depth instead of depending on f64 range.
 pub fn calculate_render_max_iterations(viewport: &Viewport, config: Option<&FractalConfig>) -> u32 {
-    // Calculate zoom exponent from viewport width using log2_approx to handle
-    // extreme values that overflow/underflow f64.
-    // zoom = 4 / width, so log10(zoom) = log10(4) - log10(width)
-    // log10(x) = log2(x) * log10(2)
-    let log2_width = viewport.width.log2_approx();
-    let log2_zoom = 2.0 - log2_width; // log2(4) = 2
+    // zoom = 4 / width, so log10(zoom) = log2(4 / width) * log10(2)
+    let reference_width = BigFloat::with_precision(4.0, 64);
+    let log2_zoom = reference_width.log2_ratio(&viewport.width);
     let zoom_exponent = if log2_zoom.is_finite() {
         log2_zoom * std::f64::consts::LOG10_2
     } else {
@@ -51,7 +48,8 @@ pub fn calculate_render_max_iterations(viewport: &Viewport, config: Option<&Frac
 /// used for BLA table construction.
 ///
 /// Returns HDRFloat to prevent underflow at deep zoom levels where
-/// viewport dimensions like 10^-270 would underflow in f64.
+/// viewport dimensions like 10^-270 would underflow in f64. The dimensions
+/// enter through their exact mantissa/exponent parts.
 pub fn calculate_dc_max(viewport: &Viewport) -> HDRFloat {
     // Convert BigFloat dimensions to HDRFloat to preserve extended exponent range
     let half_width = HDRFloat::from_bigfloat(&viewport.width).div_f64(2.0);
@@ -66,7 +64,6 @@ pub fn calculate_dc_max(viewport: &Viewport) -> HDRFloat {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use fractalwonder_core::BigFloat;
 
     fn create_test_viewport(width: f64, height: f64) -> Viewport {
         Viewport {
@@ -76,6 +73,8 @@ mod tests {
             ),
             width: BigFloat::with_precision(width, 64),
             height: BigFloat::with_precision(height, 64),
+            rotation: 0.0,
+            skew: None,
         }
     }
 
@@ -105,6 +104,25 @@ mod tests {
         assert!((dc_max.to_f64() - 2.828).abs() < 0.01);
     }
 
+    #[test]
+    fn calculate_dc_max_keeps_exact_exponent_at_extreme_depth() {
+        // Width 2^-4,000,000, well past 10^(10^6)
+        let precision = 4_000_128;
+        let width = BigFloat::with_precision(0.5, precision)
+            .powi(4_000_000)
+            .unwrap();
+        let viewport = Viewport::with_bigfloat(
+            BigFloat::with_precision(-0.5, precision),
+            BigFloat::zero(precision),
+            width.clone(),
+            width,
+        );
+
+        // sqrt(2) × width / 2
+        let dc_max = calculate_dc_max(&viewport);
+        assert!((dc_max.log2() - (-4_000_000.5)).abs() < 1e-6);
+    }
+
     #[test]
     fn calculate_max_iterations_increases_with_zoom() {
         let shallow = create_test_viewport(4.0, 4.0);
@@ -127,6 +145,8 @@ mod tests {
             ),
             width: BigFloat::from_string("1.5e-309", 2000).unwrap(),
             height: BigFloat::from_string("1.0e-309", 2000).unwrap(),
+            rotation: 0.0,
+            skew: None,
         };
 
         let iter = calculate_render_max_iterations(&extreme_viewport, None);
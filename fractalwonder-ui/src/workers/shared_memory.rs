@@ -0,0 +1,164 @@
+//! SharedArrayBuffers handed to the workers when shared memory is enabled.
+//!
+//! The orbit buffer is written once per finished orbit and read by every
+//! worker; the result buffer covers the whole frame and is written by the
+//! workers tile by tile. Messages only announce them.
+
+use crate::workers::perturbation::OrbitData;
+use fractalwonder_compute::shared_buffers::{
+    result_buffer_words, result_row_offset, tile_fits_frame, write_bla_entries, write_orbit_points,
+    SharedOrbitLayout, RESULT_HEADER_WORDS,
+};
+use fractalwonder_compute::{BlaTable, CompressedOrbit, ReferenceOrbit};
+use fractalwonder_core::{
+    ComputeData, HDRFloat, MainToWorker, MandelbrotData, PixelRect, SharedBlaLayout,
+};
+use js_sys::{Atomics, Float64Array, Int32Array, SharedArrayBuffer, Uint32Array};
+use wasm_bindgen::JsValue;
+
+/// Whether the page may create SharedArrayBuffers and post them to workers.
+pub fn shared_memory_available() -> bool {
+    js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("crossOriginIsolated"))
+        .map(|isolated| isolated.is_truthy())
+        .unwrap_or(false)
+}
+
+/// A finished reference orbit, its derivative and BLA table in one buffer.
+pub struct SharedOrbitBuffer {
+    pub buffer: SharedArrayBuffer,
+    /// `AttachSharedOrbit` message describing the buffer
+    pub message: MainToWorker,
+}
+
+impl SharedOrbitBuffer {
+    /// Fold and copy a finished orbit into a new shared buffer, with a BLA
+    /// table when enabled and deep enough to pay off.
+    ///
+    /// Returns None if the orbit is too large for one buffer.
+    pub fn new(
+        orbit_id: u32,
+        orbit_data: &OrbitData,
+        dc_max: &HDRFloat,
+        bla_enabled: bool,
+    ) -> Option<Self> {
+        let compressed = CompressedOrbit::compress(
+            &ReferenceOrbit {
+                c_ref: orbit_data.c_ref,
+                orbit: orbit_data.orbit.clone(),
+                derivative: orbit_data.derivative.clone(),
+                escaped_at: orbit_data.escaped_at,
+                period: None,
+            },
+            dc_max,
+        );
+        let bla_table = (bla_enabled && BlaTable::pays_off(dc_max))
+            .then(|| BlaTable::from_compressed(&compressed, dc_max));
+        let orbit = compressed.decompress();
+
+        let layout = SharedOrbitLayout {
+            len: orbit.orbit.len(),
+            bla_entries: bla_table.as_ref().map_or(0, |t| t.entries.len()),
+        };
+        let byte_len = u32::try_from(layout.byte_len()).ok()?;
+        let buffer = SharedArrayBuffer::new(byte_len);
+
+        let mut points = vec![0.0; layout.point_words()];
+        write_orbit_points(&orbit, &mut points);
+        Float64Array::new_with_byte_offset_and_length(&buffer, 0, points.len() as u32)
+            .copy_from(&points);
+
+        let bla = bla_table.map(|table| {
+            let mut words = vec![0; layout.bla_words()];
+            write_bla_entries(&table.entries, &mut words);
+            Uint32Array::new_with_byte_offset_and_length(
+                &buffer,
+                layout.bla_byte_offset() as u32,
+                words.len() as u32,
+            )
+            .copy_from(&words);
+            SharedBlaLayout {
+                entries: table.entries.len() as u32,
+                level_offsets: table.level_offsets,
+            }
+        });
+
+        Some(Self {
+            buffer,
+            message: MainToWorker::AttachSharedOrbit {
+                orbit_id,
+                c_ref: orbit.c_ref,
+                len: layout.len as u32,
+                escaped_at: orbit.escaped_at,
+                period: orbit.period,
+                dc_max: *dc_max,
+                bla,
+            },
+        })
+    }
+}
+
+/// Full-frame buffer the workers write tile results into.
+pub struct SharedResultBuffer {
+    pub buffer: SharedArrayBuffer,
+    width: u32,
+    height: u32,
+    header: Int32Array,
+    words: Uint32Array,
+}
+
+impl SharedResultBuffer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let words = result_buffer_words(width, height);
+        let buffer = SharedArrayBuffer::new((words * std::mem::size_of::<u32>()) as u32);
+        Self {
+            header: Int32Array::new_with_byte_offset_and_length(
+                &buffer,
+                0,
+                RESULT_HEADER_WORDS as u32,
+            ),
+            words: Uint32Array::new(&buffer),
+            buffer,
+            width,
+            height,
+        }
+    }
+
+    /// Frame size the buffer was allocated for.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// `AttachResultBuffer` message describing the buffer.
+    pub fn attach_message(&self) -> MainToWorker {
+        MainToWorker::AttachResultBuffer {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Accept results for `render_id` only. Workers still finishing a tile
+    /// of an earlier render see the change and send their results inline.
+    pub fn begin_render(&self, render_id: u32) {
+        let _ = Atomics::store(&self.header, 0, render_id as i32);
+    }
+
+    /// Read back the pixels of a tile a worker reported as written.
+    pub fn read_tile(&self, tile: &PixelRect) -> Option<Vec<ComputeData>> {
+        if !tile_fits_frame(tile, self.width, self.height) {
+            return None;
+        }
+        let mut row = vec![0; tile.width as usize * MandelbrotData::WORDS];
+        let mut data = Vec::with_capacity(tile.width as usize * tile.height as usize);
+        for y in 0..tile.height {
+            let offset = result_row_offset(self.width, tile, y) as u32;
+            self.words
+                .subarray(offset, offset + row.len() as u32)
+                .copy_to(&mut row);
+            data.extend(
+                row.chunks_exact(MandelbrotData::WORDS)
+                    .map(|words| ComputeData::Mandelbrot(MandelbrotData::from_words(words))),
+            );
+        }
+        Some(data)
+    }
+}
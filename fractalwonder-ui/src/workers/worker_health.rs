@@ -0,0 +1,240 @@
+//! Crash and hang detection for pool workers.
+//!
+//! Workers compute tiles and orbits in slices and send a heartbeat between
+//! them: orbit progress every 100ms, and `TileHeartbeat` about once a second
+//! while a tile is unfinished. So the pool records what each worker was
+//! handed and when it last heard from it, and treats a busy worker that
+//! stays silent for `SILENCE_TIMEOUT_MS` as hung, however long its work
+//! takes in total.
+
+use fractalwonder_core::PixelRect;
+use std::collections::HashMap;
+
+/// Interval between health checks.
+pub const HEALTH_CHECK_INTERVAL_MS: u32 = 1_000;
+
+/// Silence after which a worker computing a tile or an orbit counts as hung.
+const SILENCE_TIMEOUT_MS: f64 = 30_000.0;
+
+/// Crashes a tile may cause before it is given up.
+pub const MAX_TILE_FAILURES: u32 = 3;
+
+/// Crashes while computing the reference orbit before it is given up.
+pub const MAX_ORBIT_FAILURES: u32 = 3;
+
+/// Tile handed to a worker.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TileAssignment {
+    pub render_id: u32,
+    pub tile: PixelRect,
+}
+
+/// Work a worker held when it was released.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HeldWork {
+    pub tile: Option<TileAssignment>,
+    pub orbit: bool,
+}
+
+#[derive(Default)]
+struct WorkerStatus {
+    tile: Option<TileAssignment>,
+    orbit: bool,
+    last_heard: f64,
+}
+
+/// Tracks assignments and liveness of every worker in the pool.
+#[derive(Default)]
+pub struct WorkerHealth {
+    workers: HashMap<usize, WorkerStatus>,
+    /// Crashes per tile of the current render, keyed by (x, y, width, height)
+    tile_failures: HashMap<(u32, u32, u32, u32), u32>,
+    /// Crashes while computing the current orbit
+    orbit_failures: u32,
+}
+
+impl WorkerHealth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget the failures of the previous render.
+    pub fn begin_render(&mut self) {
+        self.tile_failures.clear();
+        self.orbit_failures = 0;
+    }
+
+    /// Forget everything, for when all workers are recreated.
+    pub fn clear(&mut self) {
+        self.workers.clear();
+        self.begin_render();
+    }
+
+    /// Record any message from a worker.
+    pub fn heard_from(&mut self, worker_id: usize, now: f64) {
+        self.workers.entry(worker_id).or_default().last_heard = now;
+    }
+
+    pub fn assign_tile(&mut self, worker_id: usize, render_id: u32, tile: PixelRect, now: f64) {
+        let status = self.workers.entry(worker_id).or_default();
+        status.tile = Some(TileAssignment { render_id, tile });
+        status.last_heard = now;
+    }
+
+    pub fn assign_orbit(&mut self, worker_id: usize, now: f64) {
+        let status = self.workers.entry(worker_id).or_default();
+        status.orbit = true;
+        status.last_heard = now;
+    }
+
+    pub fn tile_finished(&mut self, worker_id: usize) {
+        if let Some(status) = self.workers.get_mut(&worker_id) {
+            status.tile = None;
+        }
+    }
+
+    /// Forget the tile of a worker asking for more work, e.g. after it
+    /// abandoned a cancelled tile.
+    pub fn drop_tile(&mut self, worker_id: usize) {
+        if let Some(status) = self.workers.get_mut(&worker_id) {
+            status.tile = None;
+        }
+    }
+
+    pub fn orbit_finished(&mut self, worker_id: usize) {
+        if let Some(status) = self.workers.get_mut(&worker_id) {
+            status.orbit = false;
+        }
+    }
+
+    /// Whether the worker holds a tile or an orbit computation.
+    pub fn is_busy(&self, worker_id: usize) -> bool {
+        self.workers
+            .get(&worker_id)
+            .is_some_and(|status| status.tile.is_some() || status.orbit)
+    }
+
+    /// Forget a worker that is being replaced, returning the work it held.
+    pub fn release(&mut self, worker_id: usize) -> HeldWork {
+        self.workers
+            .remove(&worker_id)
+            .map(|status| HeldWork {
+                tile: status.tile,
+                orbit: status.orbit,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Count a crash on `tile`. Returns false once the tile should be given up.
+    pub fn retry_tile(&mut self, tile: &PixelRect) -> bool {
+        let failures = self
+            .tile_failures
+            .entry((tile.x, tile.y, tile.width, tile.height))
+            .or_default();
+        *failures += 1;
+        *failures < MAX_TILE_FAILURES
+    }
+
+    /// Count a crash during the orbit. Returns false once it should be given up.
+    pub fn retry_orbit(&mut self) -> bool {
+        self.orbit_failures += 1;
+        self.orbit_failures < MAX_ORBIT_FAILURES
+    }
+
+    /// Busy workers that have been silent for longer than
+    /// `SILENCE_TIMEOUT_MS`.
+    pub fn timed_out(&self, now: f64) -> Vec<usize> {
+        let mut hung: Vec<usize> = self
+            .workers
+            .iter()
+            .filter(|(_, status)| {
+                (status.tile.is_some() || status.orbit)
+                    && now - status.last_heard > SILENCE_TIMEOUT_MS
+            })
+            .map(|(&worker_id, _)| worker_id)
+            .collect();
+        hung.sort_unstable();
+        hung
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile() -> PixelRect {
+        PixelRect::new(64, 32, 32, 32)
+    }
+
+    #[test]
+    fn idle_workers_never_time_out() {
+        let mut health = WorkerHealth::new();
+        health.heard_from(0, 0.0);
+        assert!(health.timed_out(1e9).is_empty());
+        assert!(!health.is_busy(0));
+    }
+
+    #[test]
+    fn tile_worker_is_kept_alive_by_heartbeats() {
+        let mut health = WorkerHealth::new();
+        health.assign_tile(1, 7, tile(), 1_000.0);
+        assert!(health.timed_out(1_000.0 + SILENCE_TIMEOUT_MS).is_empty());
+        assert_eq!(health.timed_out(2_000.0 + SILENCE_TIMEOUT_MS), vec![1]);
+
+        // A tile far slower than the timeout survives while it keeps
+        // sending heartbeats
+        for second in 2..600 {
+            health.heard_from(1, second as f64 * 1_000.0);
+            assert!(health.timed_out(second as f64 * 1_000.0 + 500.0).is_empty());
+        }
+        assert_eq!(health.timed_out(600_000.0 + SILENCE_TIMEOUT_MS), vec![1]);
+
+        health.tile_finished(1);
+        assert!(!health.is_busy(1));
+        assert!(health.timed_out(1e9).is_empty());
+    }
+
+    #[test]
+    fn orbit_worker_is_kept_alive_by_progress() {
+        let mut health = WorkerHealth::new();
+        health.assign_orbit(2, 0.0);
+        health.heard_from(2, 25_000.0);
+        assert!(health.timed_out(50_000.0).is_empty());
+        assert_eq!(health.timed_out(60_000.0), vec![2]);
+
+        health.orbit_finished(2);
+        assert!(health.timed_out(60_000.0).is_empty());
+    }
+
+    #[test]
+    fn abandoned_tile_does_not_count_as_hung() {
+        let mut health = WorkerHealth::new();
+        health.assign_tile(4, 2, tile(), 0.0);
+        health.drop_tile(4);
+        assert!(!health.is_busy(4));
+        assert!(health.timed_out(1e9).is_empty());
+    }
+
+    #[test]
+    fn release_returns_held_work() {
+        let mut health = WorkerHealth::new();
+        health.assign_orbit(3, 0.0);
+        health.assign_tile(3, 9, tile(), 0.0);
+        let held = health.release(3);
+        assert!(held.orbit);
+        assert_eq!(held.tile.map(|t| (t.render_id, t.tile)), Some((9, tile())));
+        assert_eq!(health.release(3), HeldWork::default());
+    }
+
+    #[test]
+    fn tiles_are_given_up_after_repeated_crashes() {
+        let mut health = WorkerHealth::new();
+        for _ in 1..MAX_TILE_FAILURES {
+            assert!(health.retry_tile(&tile()));
+        }
+        assert!(!health.retry_tile(&tile()));
+
+        health.begin_render();
+        assert!(health.retry_tile(&tile()));
+    }
+}
@@ -1,12 +1,20 @@
-use crate::config::{get_config, get_cpu_threads};
-use crate::rendering::RenderProgress;
-use crate::workers::perturbation::{OrbitData, PerturbationCoordinator};
+use crate::config::{get_config, get_cpu_threads, get_shared_memory_enabled};
+use crate::rendering::{RenderEstimator, RenderProgress, RenderStats, TileRecord};
+use crate::workers::perturbation::{OrbitData, OrbitRequest, PerturbationCoordinator};
+use crate::workers::shared_memory::{
+    shared_memory_available, SharedOrbitBuffer, SharedResultBuffer,
+};
+use crate::workers::worker_health::{HeldWork, WorkerHealth, HEALTH_CHECK_INTERVAL_MS};
 use crate::workers::worker_pool_types::{
-    performance_now, OrbitCompleteCallback, OrbitCompleteData, PendingOrbitRequest,
-    RenderCompleteCallback, TileResult,
+    performance_now, OrbitCompleteCallback, OrbitCompleteData, OrbitPathCallback,
+    PendingOrbitRequest, RenderCompleteCallback, TileResult,
+};
+use fractalwonder_compute::{BlaTable, CompressedOrbit, ReferenceOrbit};
+use fractalwonder_core::{
+    ComputeData, DeltaPrecision, MainToWorker, MandelbrotData, OrbitPath, PixelRect, Viewport,
+    WorkerToMain,
 };
-use fractalwonder_compute::{BlaTable, ReferenceOrbit};
-use fractalwonder_core::{ComputeData, MainToWorker, PixelRect, Viewport, WorkerToMain};
+use gloo_timers::callback::Interval;
 use leptos::*;
 use std::cell::RefCell;
 use std::collections::{HashSet, VecDeque};
@@ -20,73 +28,130 @@ pub struct WorkerPool {
     pub(super) workers: Vec<Worker>,
     renderer_id: String,
     initialized_workers: HashSet<usize>,
-    pending_tiles: VecDeque<PixelRect>,
+    /// Initialized workers with nothing queued
+    idle_workers: HashSet<usize>,
+    pub(super) pending_tiles: VecDeque<PixelRect>,
+    /// Tiles waiting at the end of the streamed orbit prefix, with the
+    /// worker holding their unfinished pixels, sent on when more arrives
+    deferred_tiles: Vec<(PixelRect, usize)>,
+    /// Tiles whose unfinished pixels can continue on the orbit received so
+    /// far, with the worker holding them
+    resumable_tiles: Vec<(PixelRect, usize)>,
     current_render_id: u32,
     pub(super) current_viewport: Option<Viewport>,
     pub(super) canvas_size: (u32, u32),
     on_tile_complete: Rc<dyn Fn(TileResult)>,
     on_render_complete: RenderCompleteCallback,
     on_orbit_complete: OrbitCompleteCallback,
-    progress: RwSignal<RenderProgress>,
+    pub(super) progress: RwSignal<RenderProgress>,
+    /// Cost of each finished tile, for the diagnostics panel and heatmap
+    stats: RwSignal<RenderStats>,
     render_start_time: Option<f64>,
+    estimator: RenderEstimator,
     self_ref: Weak<RefCell<Self>>,
     /// Perturbation coordinator (handles state, glitch resolution, tile messages)
     pub(super) perturbation: PerturbationCoordinator,
     /// Whether current render is using perturbation mode
-    is_perturbation_render: bool,
+    pub(super) is_perturbation_render: bool,
+    /// Queued tiles are glitched ones, re-rendered against the orbits of
+    /// their quadtree cells
+    pub(super) glitch_pass: bool,
     /// GPU mode: orbit complete callback handles rendering, skip tile dispatch
     gpu_mode: bool,
     /// Pending orbit computation (waiting for worker to initialize)
     pending_orbit_request: Option<PendingOrbitRequest>,
-    /// Cached orbit data for callbacks
+    /// Worker computing the reference orbit; gets no tiles until it is done
+    orbit_worker: Option<usize>,
+    /// Cached orbit data for callbacks, accumulated as chunks stream in
     pending_orbit_data: Option<OrbitData>,
+    /// Whether the current render shares orbit and result buffers with the
+    /// workers instead of copying them through messages
+    shared_memory: bool,
+    /// Frame buffer the workers write tile results into
+    shared_results: Option<SharedResultBuffer>,
+    /// Finished orbit of the current render, for workers that start late
+    shared_orbit: Option<SharedOrbitBuffer>,
+    /// Bumped whenever a worker slot is respawned, so that events still
+    /// queued from the replaced worker are ignored
+    worker_generations: Vec<u32>,
+    /// What each worker holds and when it was last heard from
+    health: WorkerHealth,
+    /// Respawned workers that have not reported ready yet. Broadcasts skip
+    /// them; they get the current orbits once ready.
+    respawning: HashSet<usize>,
+    /// Orbit computation of the current render, repeated if its worker crashes
+    current_orbit_request: Option<OrbitRequest>,
+    /// Periodic check for hung workers
+    health_check: Option<Interval>,
+    /// Latest `trace_orbit_path` request; answers to earlier ones are dropped
+    orbit_trace: Option<(u32, OrbitPathCallback)>,
+    next_trace_id: u32,
 }
 
-fn create_workers(count: usize, pool: Rc<RefCell<WorkerPool>>) -> Result<Vec<Worker>, JsValue> {
-    web_sys::console::log_1(&format!("[WorkerPool] Creating {} workers", count).into());
-    let mut workers = Vec::with_capacity(count);
-
-    for worker_id in 0..count {
-        // Create worker as ES module to support import statements
-        let options = WorkerOptions::new();
-        options.set_type(WorkerType::Module);
-        let worker = Worker::new_with_options(WORKER_SCRIPT_PATH, &options)?;
+fn create_workers(
+    generations: &[u32],
+    pool: Rc<RefCell<WorkerPool>>,
+) -> Result<Vec<Worker>, JsValue> {
+    web_sys::console::log_1(&format!("[WorkerPool] Creating {} workers", generations.len()).into());
+    generations
+        .iter()
+        .enumerate()
+        .map(|(worker_id, &generation)| spawn_worker(worker_id, generation, Rc::clone(&pool)))
+        .collect()
+}
 
-        let pool_clone = Rc::clone(&pool);
-        let onmessage = Closure::wrap(Box::new(move |e: MessageEvent| {
-            let Some(msg_str) = e.data().as_string() else {
+fn spawn_worker(
+    worker_id: usize,
+    generation: u32,
+    pool: Rc<RefCell<WorkerPool>>,
+) -> Result<Worker, JsValue> {
+    // Create worker as ES module to support import statements
+    let options = WorkerOptions::new();
+    options.set_type(WorkerType::Module);
+    let worker = Worker::new_with_options(WORKER_SCRIPT_PATH, &options)?;
+
+    let pool_clone = Rc::clone(&pool);
+    let onmessage = Closure::wrap(Box::new(move |e: MessageEvent| {
+        let Some(msg_str) = e.data().as_string() else {
+            web_sys::console::error_1(
+                &format!("[WorkerPool] Worker {worker_id} non-string msg").into(),
+            );
+            return;
+        };
+        let mut pool = pool_clone.borrow_mut();
+        if !pool.is_current_worker(worker_id, generation) {
+            return;
+        }
+        match serde_json::from_str::<WorkerToMain>(&msg_str) {
+            Ok(msg) => pool.handle_message(worker_id, msg),
+            Err(err) => {
+                let preview: String = msg_str.chars().take(200).collect();
                 web_sys::console::error_1(
-                    &format!("[WorkerPool] Worker {worker_id} non-string msg").into(),
+                    &format!(
+                        "[WorkerPool] Parse error worker {worker_id}: {err}. Preview: {preview}"
+                    )
+                    .into(),
                 );
-                return;
-            };
-            match serde_json::from_str::<WorkerToMain>(&msg_str) {
-                Ok(msg) => pool_clone.borrow_mut().handle_message(worker_id, msg),
-                Err(err) => {
-                    let preview: String = msg_str.chars().take(200).collect();
-                    web_sys::console::error_1(&format!("[WorkerPool] Parse error worker {worker_id}: {err}. Preview: {preview}").into());
-                }
             }
-        }) as Box<dyn FnMut(_)>);
-
-        worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
-        onmessage.forget();
+        }
+    }) as Box<dyn FnMut(_)>);
 
-        let onerror = Closure::wrap(Box::new(move |e: web_sys::ErrorEvent| {
-            web_sys::console::error_1(&JsValue::from_str(&format!(
-                "Worker {} error: {}",
-                worker_id,
-                e.message()
-            )));
-        }) as Box<dyn FnMut(_)>);
+    worker.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    onmessage.forget();
 
-        worker.set_onerror(Some(onerror.as_ref().unchecked_ref()));
-        onerror.forget();
+    // Panics, including running out of memory, end up here; the worker's
+    // wasm instance is unusable afterwards
+    let onerror = Closure::wrap(Box::new(move |e: web_sys::ErrorEvent| {
+        let mut pool = pool.borrow_mut();
+        if pool.is_current_worker(worker_id, generation) {
+            pool.restart_worker(worker_id, &format!("crashed: {}", e.message()));
+        }
+    }) as Box<dyn FnMut(_)>);
 
-        workers.push(worker);
-    }
+    worker.set_onerror(Some(onerror.as_ref().unchecked_ref()));
+    onerror.forget();
 
-    Ok(workers)
+    Ok(worker)
 }
 
 impl WorkerPool {
@@ -94,6 +159,7 @@ impl WorkerPool {
         renderer_id: &str,
         on_tile_complete: F,
         progress: RwSignal<RenderProgress>,
+        stats: RwSignal<RenderStats>,
     ) -> Result<Rc<RefCell<Self>>, JsValue>
     where
         F: Fn(TileResult) + 'static,
@@ -118,7 +184,10 @@ impl WorkerPool {
             workers: Vec::new(),
             renderer_id: renderer_id.to_string(),
             initialized_workers: HashSet::new(),
+            idle_workers: HashSet::new(),
             pending_tiles: VecDeque::new(),
+            deferred_tiles: Vec::new(),
+            resumable_tiles: Vec::new(),
             current_render_id: 0,
             current_viewport: None,
             canvas_size: (0, 0),
@@ -126,20 +195,42 @@ impl WorkerPool {
             on_render_complete: Rc::new(RefCell::new(None)),
             on_orbit_complete: Rc::new(RefCell::new(None)),
             progress,
+            stats,
             render_start_time: None,
+            estimator: RenderEstimator::default(),
             self_ref: Weak::new(),
             perturbation: PerturbationCoordinator::new(renderer_id),
             is_perturbation_render: false,
+            glitch_pass: false,
             gpu_mode: false,
             pending_orbit_request: None,
+            orbit_worker: None,
             pending_orbit_data: None,
+            shared_memory: false,
+            shared_results: None,
+            shared_orbit: None,
+            worker_generations: vec![0; worker_count],
+            health: WorkerHealth::new(),
+            respawning: HashSet::new(),
+            current_orbit_request: None,
+            health_check: None,
+            orbit_trace: None,
+            next_trace_id: 0,
         }));
 
         pool.borrow_mut().self_ref = Rc::downgrade(&pool);
 
-        let workers = create_workers(worker_count, Rc::clone(&pool))?;
+        let workers = create_workers(&vec![0; worker_count], Rc::clone(&pool))?;
         pool.borrow_mut().workers = workers;
 
+        let weak = Rc::downgrade(&pool);
+        let health_check = Interval::new(HEALTH_CHECK_INTERVAL_MS, move || {
+            if let Some(pool) = weak.upgrade() {
+                pool.borrow_mut().check_worker_health();
+            }
+        });
+        pool.borrow_mut().health_check = Some(health_check);
+
         Ok(pool)
     }
 
@@ -149,25 +240,254 @@ impl WorkerPool {
         }
     }
 
+    /// Workers that take broadcasts; respawned ones catch up once ready.
+    pub(super) fn broadcast_targets(&self) -> Vec<usize> {
+        (0..self.workers.len())
+            .filter(|worker_id| !self.respawning.contains(worker_id))
+            .collect()
+    }
+
+    fn is_current_worker(&self, worker_id: usize, generation: u32) -> bool {
+        self.worker_generations.get(worker_id) == Some(&generation)
+    }
+
+    /// Post a message together with a SharedArrayBuffer as `[json, buffer]`.
+    fn send_with_buffer(&self, worker_id: usize, msg: &MainToWorker, buffer: &JsValue) {
+        if let Ok(json) = serde_json::to_string(msg) {
+            let parts = js_sys::Array::of2(&JsValue::from_str(&json), buffer);
+            let _ = self.workers[worker_id].post_message(&parts);
+        }
+    }
+
+    /// Hand the shared buffers of the current render to a worker.
+    fn attach_shared_buffers(&self, worker_id: usize) {
+        if let Some(results) = &self.shared_results {
+            self.send_with_buffer(worker_id, &results.attach_message(), &results.buffer);
+        }
+        if let Some(orbit) = &self.shared_orbit {
+            self.send_with_buffer(worker_id, &orbit.message, &orbit.buffer);
+        }
+    }
+
+    /// Decide whether this render uses shared memory and, if so, point the
+    /// workers at a result buffer for the frame.
+    fn prepare_shared_results(&mut self, canvas_size: (u32, u32)) {
+        self.shared_orbit = None;
+        self.shared_memory = get_shared_memory_enabled() && shared_memory_available();
+        if !self.shared_memory {
+            if get_shared_memory_enabled() {
+                web_sys::console::warn_1(
+                    &"[WorkerPool] Shared memory needs a cross-origin isolated page, using messages"
+                        .into(),
+                );
+            }
+            self.shared_results = None;
+            return;
+        }
+
+        if self
+            .shared_results
+            .as_ref()
+            .is_none_or(|results| results.size() != canvas_size)
+        {
+            self.shared_results = Some(SharedResultBuffer::new(canvas_size.0, canvas_size.1));
+        }
+        if let Some(results) = &self.shared_results {
+            results.begin_render(self.current_render_id);
+        }
+        for &worker_id in &self.initialized_workers {
+            self.attach_shared_buffers(worker_id);
+        }
+    }
+
     fn handle_ready(&mut self, worker_id: usize) {
         // Worker is ready - mark as initialized and check for pending work
-        let was_empty = self.initialized_workers.is_empty();
         self.initialized_workers.insert(worker_id);
-        if was_empty {
-            if let Some(pending) = self.pending_orbit_request.take() {
-                web_sys::console::log_1(
-                    &"[WorkerPool] First worker ready, dispatching queued orbit request".into(),
-                );
-                self.send_to_worker(
-                    worker_id,
-                    &MainToWorker::ComputeReferenceOrbit {
-                        render_id: pending.request.render_id,
-                        orbit_id: pending.request.orbit_id,
-                        c_ref_json: pending.request.c_ref_json,
-                        max_iterations: pending.request.max_iterations,
-                    },
+        self.idle_workers.insert(worker_id);
+        if self.shared_memory {
+            self.attach_shared_buffers(worker_id);
+        }
+        if self.respawning.remove(&worker_id) {
+            self.resend_orbits(worker_id);
+        }
+        if let Some(pending) = self.pending_orbit_request.take() {
+            web_sys::console::log_1(
+                &format!("[WorkerPool] Worker {worker_id} ready, dispatching queued orbit request")
+                    .into(),
+            );
+            self.request_orbit(worker_id, pending.request);
+        } else {
+            self.dispatch_to_idle_workers();
+        }
+    }
+
+    /// Send the orbits of the current render to a respawned worker.
+    ///
+    /// A finished shared orbit already went out with the shared buffers.
+    fn resend_orbits(&mut self, worker_id: usize) {
+        if !self.is_perturbation_render {
+            return;
+        }
+        if self.shared_orbit.is_none() && self.perturbation.streamed_orbit_len() > 0 {
+            if let Some(orbit_data) = &self.pending_orbit_data {
+                let msg = self.perturbation.build_orbit_chunk(
+                    orbit_data,
+                    0,
+                    self.perturbation.orbit_complete(),
                 );
+                self.send_to_worker(worker_id, &msg);
+            }
+        }
+        let cell_orbits = self
+            .perturbation
+            .glitch_resolver()
+            .broadcast_orbit_messages(self.perturbation.dc_max(), self.perturbation.bla_enabled());
+        for msg in &cell_orbits {
+            self.send_to_worker(worker_id, msg);
+        }
+    }
+
+    /// Terminate a crashed or hung worker, start a new one in its slot and
+    /// hand the work it held to the others.
+    fn restart_worker(&mut self, worker_id: usize, reason: &str) {
+        web_sys::console::error_1(
+            &format!("[WorkerPool] Worker {worker_id} {reason}, restarting it").into(),
+        );
+        self.workers[worker_id].terminate();
+        self.initialized_workers.remove(&worker_id);
+        self.idle_workers.remove(&worker_id);
+        self.perturbation.forget_worker(worker_id);
+        let held = self.health.release(worker_id);
+
+        let generation = self.worker_generations[worker_id].wrapping_add(1);
+        self.worker_generations[worker_id] = generation;
+        if let Some(pool_rc) = self.self_ref.upgrade() {
+            match spawn_worker(worker_id, generation, pool_rc) {
+                Ok(worker) => {
+                    self.workers[worker_id] = worker;
+                    self.respawning.insert(worker_id);
+                }
+                Err(err) => web_sys::console::error_1(
+                    &format!("[WorkerPool] Could not respawn worker {worker_id}: {err:?}").into(),
+                ),
+            }
+        }
+
+        if held.orbit && self.orbit_worker == Some(worker_id) {
+            self.orbit_worker = None;
+            self.retry_orbit();
+        }
+        self.requeue_held_tile(held);
+        self.dispatch_to_idle_workers();
+    }
+
+    /// Put the tile of a replaced worker back in the queue, or give it up
+    /// if it keeps taking workers down.
+    fn requeue_held_tile(&mut self, held: HeldWork) {
+        let Some(assignment) = held.tile else {
+            return;
+        };
+        if assignment.render_id != self.current_render_id {
+            return;
+        }
+        if self.health.retry_tile(&assignment.tile) {
+            self.pending_tiles.push_front(assignment.tile);
+        } else {
+            self.give_up_tile(assignment.tile);
+        }
+    }
+
+    /// Finish a tile that could not be computed with every pixel glitched,
+    /// so that the render still completes.
+    fn give_up_tile(&mut self, tile: PixelRect) {
+        web_sys::console::error_1(
+            &format!(
+                "[WorkerPool] Giving up on tile ({},{}) after repeated worker failures",
+                tile.x, tile.y
+            )
+            .into(),
+        );
+        let pixel = ComputeData::Mandelbrot(MandelbrotData {
+            max_iterations: self.perturbation.max_iterations(),
+            glitched: true,
+            ..MandelbrotData::default()
+        });
+        let data = vec![pixel; (tile.width * tile.height) as usize];
+        self.perturbation
+            .glitch_resolver_mut()
+            .record_glitched_tile(tile);
+        self.estimator.skip_tile(&tile);
+        self.finish_tile(tile, data, 0.0);
+    }
+
+    /// Ask another worker for the orbit of the current render after its
+    /// worker was lost.
+    fn retry_orbit(&mut self) {
+        let Some(request) = self.current_orbit_request.clone() else {
+            return;
+        };
+        if !self.health.retry_orbit() {
+            web_sys::console::error_1(
+                &format!(
+                    "[WorkerPool] Reference orbit of render #{} keeps failing, giving up",
+                    request.render_id
+                )
+                .into(),
+            );
+            self.current_orbit_request = None;
+            return;
+        }
+        match self.pick_orbit_worker() {
+            Some(worker_id) => self.request_orbit(worker_id, request),
+            None => self.pending_orbit_request = Some(PendingOrbitRequest { request }),
+        }
+    }
+
+    /// Restart workers that have been silent for longer than their work allows.
+    fn check_worker_health(&mut self) {
+        for worker_id in self.health.timed_out(performance_now()) {
+            self.restart_worker(worker_id, "stopped responding");
+        }
+    }
+
+    /// An initialized worker for the orbit, preferring one with nothing queued.
+    fn pick_orbit_worker(&self) -> Option<usize> {
+        self.initialized_workers
+            .iter()
+            .copied()
+            .min_by_key(|id| (!self.idle_workers.contains(id), *id))
+    }
+
+    /// Send a reference orbit computation to a worker.
+    fn request_orbit(&mut self, worker_id: usize, request: OrbitRequest) {
+        self.orbit_worker = Some(worker_id);
+        self.idle_workers.remove(&worker_id);
+        self.health.assign_orbit(worker_id, performance_now());
+        self.send_to_worker(
+            worker_id,
+            &MainToWorker::ComputeReferenceOrbit {
+                render_id: request.render_id,
+                orbit_id: request.orbit_id,
+                c_ref_json: request.c_ref_json,
+                max_iterations: request.max_iterations,
+            },
+        );
+    }
+
+    /// Hand pending tiles to idle workers, except the one computing the orbit.
+    pub(super) fn dispatch_to_idle_workers(&mut self) {
+        let mut idle: Vec<usize> = self
+            .idle_workers
+            .iter()
+            .copied()
+            .filter(|&id| Some(id) != self.orbit_worker)
+            .collect();
+        idle.sort_unstable();
+        for worker_id in idle {
+            if self.pending_tiles.is_empty() && self.resumable_tiles.is_empty() {
+                break;
             }
+            self.dispatch_work(worker_id);
         }
     }
 
@@ -175,6 +495,7 @@ impl WorkerPool {
         if render_id.is_none_or(|id| id == self.current_render_id) {
             self.dispatch_work(worker_id);
         } else {
+            self.idle_workers.insert(worker_id);
             self.send_to_worker(worker_id, &MainToWorker::NoWork);
         }
     }
@@ -182,6 +503,7 @@ impl WorkerPool {
     #[allow(clippy::too_many_arguments)]
     fn handle_tile_complete(
         &mut self,
+        worker_id: usize,
         render_id: u32,
         tile: PixelRect,
         data: Vec<ComputeData>,
@@ -189,7 +511,9 @@ impl WorkerPool {
         bla_iterations: u64,
         total_iterations: u64,
         rebase_count: u64,
-        used_f64: bool,
+        precision: DeltaPrecision,
+        needs_longer_orbit: bool,
+        in_shared_buffer: bool,
     ) {
         if render_id != self.current_render_id {
             web_sys::console::warn_1(
@@ -202,6 +526,54 @@ impl WorkerPool {
             return;
         }
 
+        let data = if in_shared_buffer {
+            match self
+                .shared_results
+                .as_ref()
+                .and_then(|results| results.read_tile(&tile))
+            {
+                Some(data) => data,
+                None => {
+                    web_sys::console::error_1(
+                        &format!(
+                            "[WorkerPool] Tile ({},{}) is not in the shared result buffer",
+                            tile.x, tile.y
+                        )
+                        .into(),
+                    );
+                    self.pending_tiles.push_back(tile);
+                    return;
+                }
+            }
+        } else {
+            data
+        };
+
+        self.estimator.record_tile(
+            &tile,
+            compute_time_ms,
+            total_iterations,
+            bla_iterations,
+            !needs_longer_orbit,
+        );
+
+        if needs_longer_orbit {
+            // Show what is final so far; the worker keeps the unfinished
+            // pixels and continues them once the orbit is longer, and the
+            // tile only counts as done then.
+            if self.perturbation.orbit_complete() {
+                self.resumable_tiles.push((tile, worker_id));
+            } else {
+                self.deferred_tiles.push((tile, worker_id));
+            }
+            (self.on_tile_complete)(TileResult {
+                tile,
+                data,
+                compute_time_ms,
+            });
+            return;
+        }
+
         if self.is_perturbation_render {
             let glitched_count = data
                 .iter()
@@ -212,7 +584,6 @@ impl WorkerPool {
             } else {
                 0.0
             };
-            let precision = if used_f64 { "f64" } else { "HDRFloat" };
             web_sys::console::log_1(
                 &format!(
                     "[WorkerPool] Tile ({},{}): {}/{} glitched, {:.1}% BLA ({}/{}), {} rebases, {}",
@@ -224,7 +595,7 @@ impl WorkerPool {
                     bla_iterations,
                     total_iterations,
                     rebase_count,
-                    precision
+                    precision.label()
                 )
                 .into(),
             );
@@ -233,17 +604,38 @@ impl WorkerPool {
                     .glitch_resolver_mut()
                     .record_glitched_tile(tile);
             }
+            self.stats.update(|stats| {
+                stats.record(TileRecord {
+                    tile,
+                    compute_time_ms,
+                    total_iterations,
+                    bla_iterations,
+                    rebase_count,
+                    precision,
+                    glitched_pixels: glitched_count as u32,
+                    orbit_id: self.perturbation.orbit_id(),
+                })
+            });
         }
 
+        self.finish_tile(tile, data, compute_time_ms);
+    }
+
+    /// Count a tile as done, hand it to the tile callback and report the end
+    /// of the render.
+    fn finish_tile(&mut self, tile: PixelRect, data: Vec<ComputeData>, compute_time_ms: f64) {
+        let now = performance_now();
         let elapsed = self
             .render_start_time
-            .map(|start| performance_now() - start)
+            .map(|start| now - start)
             .unwrap_or(0.0);
         let is_complete = {
             let mut complete = false;
+            let estimator = &self.estimator;
             self.progress.update(|p| {
                 p.completed_steps += 1;
                 p.elapsed_ms = elapsed;
+                estimator.update_progress(now, p);
                 p.is_complete = p.completed_steps >= p.total_steps;
                 complete = p.is_complete;
             });
@@ -275,58 +667,238 @@ impl WorkerPool {
         }
     }
 
-    fn handle_error(&self, worker_id: usize, message: String) {
+    fn handle_error(&mut self, worker_id: usize, message: String) {
         web_sys::console::error_1(&JsValue::from_str(&format!(
             "Worker {} error: {}",
             worker_id, message
         )));
+        // The worker dropped whatever it was doing and will not ask for more
+        if self.health.is_busy(worker_id) {
+            self.restart_worker(worker_id, "failed");
+        }
+    }
+
+    fn handle_orbit_progress(&mut self, render_id: u32, computed: u32, max_iterations: u32) {
+        if render_id != self.current_render_id || !self.is_perturbation_render {
+            return;
+        }
+        self.progress.update(|p| {
+            p.orbit_computed = computed;
+            p.orbit_max_iterations = max_iterations;
+        });
+    }
+
+    /// Append streamed orbit points to `pending_orbit_data`.
+    ///
+    /// Points already received are skipped, as a worker that takes over the
+    /// orbit from a crashed one streams it from the start again. Returns
+    /// false if the points leave a gap.
+    fn accumulate_orbit(
+        &mut self,
+        c_ref: (f64, f64),
+        start: u32,
+        orbit: Vec<(f64, f64)>,
+        derivative: Vec<(f64, f64)>,
+    ) -> bool {
+        let orbit_data = self.pending_orbit_data.get_or_insert_with(|| OrbitData {
+            c_ref,
+            orbit: Vec::new(),
+            derivative: Vec::new(),
+            escaped_at: None,
+        });
+        let received = orbit_data.orbit.len();
+        if received < start as usize {
+            web_sys::console::error_1(
+                &format!(
+                    "[WorkerPool] Orbit chunk starts at {} but {} points were received",
+                    start,
+                    orbit_data.orbit.len()
+                )
+                .into(),
+            );
+            return false;
+        }
+        let skip = received - start as usize;
+        orbit_data.orbit.extend(orbit.into_iter().skip(skip));
+        orbit_data
+            .derivative
+            .extend(derivative.into_iter().skip(skip));
+        true
+    }
+
+    /// Forward orbit points from `start` on to every worker and retry
+    /// deferred tiles against the longer prefix.
+    ///
+    /// With shared memory the finished orbit goes out as one shared buffer
+    /// instead of a last chunk.
+    fn broadcast_orbit_chunk(&mut self, start: u32, complete: bool) {
+        let Some(orbit_data) = self.pending_orbit_data.as_ref() else {
+            return;
+        };
+        let len = orbit_data.orbit.len() as u32;
+        self.shared_orbit = if complete && self.shared_memory {
+            self.share_orbit(orbit_data)
+        } else {
+            None
+        };
+        if let Some(shared) = &self.shared_orbit {
+            for worker_id in self.broadcast_targets() {
+                self.send_with_buffer(worker_id, &shared.message, &shared.buffer);
+            }
+        } else {
+            let msg = self
+                .perturbation
+                .build_orbit_chunk(orbit_data, start, complete);
+            for worker_id in self.broadcast_targets() {
+                self.send_to_worker(worker_id, &msg);
+            }
+        }
+        self.perturbation.record_orbit_streamed(len, complete);
+
+        self.resumable_tiles.append(&mut self.deferred_tiles);
+        self.dispatch_to_idle_workers();
+    }
+
+    /// Copy a finished orbit into a shared buffer, or None to fall back to
+    /// sending it in a message.
+    fn share_orbit(&self, orbit_data: &OrbitData) -> Option<SharedOrbitBuffer> {
+        let start = performance_now();
+        let shared = SharedOrbitBuffer::new(
+            self.perturbation.orbit_id(),
+            orbit_data,
+            &self.perturbation.dc_max(),
+            self.perturbation.bla_enabled(),
+        );
+        match &shared {
+            Some(shared) => web_sys::console::log_1(
+                &format!(
+                    "[WorkerPool] Orbit shared: {:.1} MB in {:.1}ms",
+                    shared.buffer.byte_length() as f64 / (1024.0 * 1024.0),
+                    performance_now() - start
+                )
+                .into(),
+            ),
+            None => web_sys::console::warn_1(
+                &"[WorkerPool] Orbit too large for a shared buffer, sending it in messages".into(),
+            ),
+        }
+        shared
     }
 
+    fn handle_orbit_chunk(
+        &mut self,
+        render_id: u32,
+        c_ref: (f64, f64),
+        start: u32,
+        orbit: Vec<(f64, f64)>,
+        derivative: Vec<(f64, f64)>,
+    ) {
+        if render_id != self.current_render_id {
+            return;
+        }
+        if !self.accumulate_orbit(c_ref, start, orbit, derivative) {
+            return;
+        }
+
+        // The GPU needs the whole orbit; CPU workers can start on the prefix
+        let streamed = self.perturbation.streamed_orbit_len();
+        let received = self
+            .pending_orbit_data
+            .as_ref()
+            .map_or(0, |o| o.orbit.len() as u32);
+        if self.gpu_mode || received == streamed {
+            return;
+        }
+
+        web_sys::console::log_1(
+            &format!(
+                "[WorkerPool] Streaming orbit prefix: {} points",
+                self.pending_orbit_data
+                    .as_ref()
+                    .map_or(0, |o| o.orbit.len())
+            )
+            .into(),
+        );
+        self.broadcast_orbit_chunk(streamed, false);
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn handle_orbit_complete(
         &mut self,
+        worker_id: usize,
         render_id: u32,
         orbit_id: u32,
         c_ref: (f64, f64),
+        start: u32,
         orbit: Vec<(f64, f64)>,
         derivative: Vec<(f64, f64)>,
         escaped_at: Option<u32>,
     ) {
+        // The orbit worker is free again, even if its orbit is stale
+        if self.orbit_worker == Some(worker_id) {
+            self.orbit_worker = None;
+        }
+        if self.initialized_workers.contains(&worker_id) {
+            self.idle_workers.insert(worker_id);
+        }
+
         if render_id != self.current_render_id {
+            self.dispatch_to_idle_workers();
             return;
         }
+        self.current_orbit_request = None;
+
+        if !self.accumulate_orbit(c_ref, start, orbit, derivative) {
+            return;
+        }
+        let Some(orbit_data) = self.pending_orbit_data.as_mut() else {
+            return;
+        };
+        orbit_data.escaped_at = escaped_at;
 
         web_sys::console::log_1(
             &format!(
                 "[WorkerPool] Reference orbit complete: {} points, escaped_at={:?}",
-                orbit.len(),
+                orbit_data.orbit.len(),
                 escaped_at
             )
             .into(),
         );
 
-        let orbit_data = OrbitData {
-            c_ref,
-            orbit: orbit.clone(),
-            derivative: derivative.clone(),
-            escaped_at,
-        };
-        self.pending_orbit_data = Some(orbit_data.clone());
-
         if self.gpu_mode {
             web_sys::console::log_1(&"[WorkerPool] GPU mode: triggering orbit callback".into());
+            let Some(orbit_data) = self.pending_orbit_data.take() else {
+                return;
+            };
 
-            // Compute BLA table for GPU acceleration
+            // The GPU upload regenerates points from the compressed orbit, so the
+            // full vectors can go as soon as it is built
             let dc_max = self.perturbation.dc_max();
-            let bla_table = if self.perturbation.bla_enabled() && !orbit.is_empty() {
-                let start = performance_now();
-                // Create ReferenceOrbit for BLA computation
-                let ref_orbit = ReferenceOrbit {
+            let start = performance_now();
+            let orbit = CompressedOrbit::compress(
+                &ReferenceOrbit {
                     c_ref,
-                    orbit: orbit.clone(),
-                    derivative: derivative.clone(),
+                    orbit: orbit_data.orbit,
+                    derivative: orbit_data.derivative,
                     escaped_at,
-                };
-                let table = BlaTable::compute(&ref_orbit, &dc_max);
+                    period: None,
+                },
+                &dc_max,
+            );
+            web_sys::console::log_1(
+                &format!(
+                    "[WorkerPool] Orbit compressed to {} waypoints (period {:?}) in {:.1}ms",
+                    orbit.waypoint_count(),
+                    orbit.period(),
+                    performance_now() - start
+                )
+                .into(),
+            );
+
+            // Compute BLA table for GPU acceleration
+            let bla_table = if self.perturbation.bla_enabled() && !orbit.is_empty() {
+                let start = performance_now();
+                let table = BlaTable::from_compressed(&orbit, &dc_max);
                 let elapsed = performance_now() - start;
                 web_sys::console::log_1(
                     &format!(
@@ -345,20 +917,18 @@ impl WorkerPool {
             if let Some(callback) = self.on_orbit_complete.borrow().as_ref() {
                 callback(OrbitCompleteData {
                     orbit,
-                    derivative,
                     orbit_id,
                     max_iterations: self.perturbation.max_iterations(),
-                    escaped_at,
                     bla_table,
                 });
             }
             return;
         }
 
-        let msg = self.perturbation.build_orbit_broadcast(&orbit_data);
-        for worker_id in 0..self.workers.len() {
-            self.send_to_worker(worker_id, &msg);
-        }
+        self.progress
+            .update(|p| p.orbit_computed = p.orbit_max_iterations);
+        let streamed = self.perturbation.streamed_orbit_len();
+        self.broadcast_orbit_chunk(streamed, true);
     }
 
     fn handle_orbit_stored(&mut self, worker_id: usize, orbit_id: u32) {
@@ -409,15 +979,19 @@ impl WorkerPool {
                 )
                 .into(),
             );
-            for worker_id in 0..self.workers.len() {
-                if self.initialized_workers.contains(&worker_id) {
-                    self.dispatch_work(worker_id);
-                }
-            }
+            self.dispatch_to_idle_workers();
         }
     }
 
     fn handle_message(&mut self, worker_id: usize, msg: WorkerToMain) {
+        self.health.heard_from(worker_id, performance_now());
+        match &msg {
+            WorkerToMain::TileComplete { .. } => self.health.tile_finished(worker_id),
+            WorkerToMain::ReferenceOrbitComplete { .. } => self.health.orbit_finished(worker_id),
+            WorkerToMain::RequestWork { .. } => self.health.drop_tile(worker_id),
+            _ => {}
+        }
+
         match msg {
             WorkerToMain::Ready => self.handle_ready(worker_id),
             WorkerToMain::RequestWork { render_id } => {
@@ -431,8 +1005,11 @@ impl WorkerPool {
                 bla_iterations,
                 total_iterations,
                 rebase_count,
-                used_f64,
+                precision,
+                needs_longer_orbit,
+                in_shared_buffer,
             } => self.handle_tile_complete(
+                worker_id,
                 render_id,
                 tile,
                 data,
@@ -440,19 +1017,42 @@ impl WorkerPool {
                 bla_iterations,
                 total_iterations,
                 rebase_count,
-                used_f64,
+                precision,
+                needs_longer_orbit,
+                in_shared_buffer,
             ),
             WorkerToMain::Error { message } => self.handle_error(worker_id, message),
             WorkerToMain::ReferenceOrbitComplete {
                 render_id,
                 orbit_id,
                 c_ref,
+                start,
                 orbit,
                 derivative,
                 escaped_at,
-            } => self
-                .handle_orbit_complete(render_id, orbit_id, c_ref, orbit, derivative, escaped_at),
+            } => self.handle_orbit_complete(
+                worker_id, render_id, orbit_id, c_ref, start, orbit, derivative, escaped_at,
+            ),
+            WorkerToMain::ReferenceOrbitProgress {
+                render_id,
+                computed,
+                max_iterations,
+                ..
+            } => self.handle_orbit_progress(render_id, computed, max_iterations),
+            WorkerToMain::ReferenceOrbitChunk {
+                render_id,
+                c_ref,
+                start,
+                orbit,
+                derivative,
+                ..
+            } => self.handle_orbit_chunk(render_id, c_ref, start, orbit, derivative),
             WorkerToMain::OrbitStored { orbit_id } => self.handle_orbit_stored(worker_id, orbit_id),
+            WorkerToMain::OrbitPath { request_id, path } => {
+                self.handle_orbit_path(request_id, path)
+            }
+            // Only keeps the worker's health record fresh
+            WorkerToMain::TileHeartbeat { .. } => {}
         }
     }
 
@@ -462,22 +1062,46 @@ impl WorkerPool {
         }
 
         if !self.perturbation.worker_ready_for_tiles(worker_id) {
+            self.idle_workers.insert(worker_id);
             self.send_to_worker(worker_id, &MainToWorker::NoWork);
             return;
         }
 
-        if let Some(tile) = self.pending_tiles.pop_front() {
-            if let Some(msg) = self
-                .perturbation
-                .build_tile_message(self.current_render_id, tile)
-            {
-                self.send_to_worker(worker_id, &msg);
+        if let Some(tile) = self.next_tile(worker_id) {
+            let msg = if self.glitch_pass {
+                self.perturbation
+                    .build_glitch_tile_message(self.current_render_id, tile)
             } else {
-                self.send_to_worker(worker_id, &MainToWorker::NoWork);
+                self.perturbation
+                    .build_tile_message(self.current_render_id, tile)
+            };
+            if let Some(msg) = msg {
+                let now = performance_now();
+                self.idle_workers.remove(&worker_id);
+                self.estimator.start(now);
+                self.health
+                    .assign_tile(worker_id, self.current_render_id, tile, now);
+                self.send_to_worker(worker_id, &msg);
+                return;
             }
-        } else {
-            self.send_to_worker(worker_id, &MainToWorker::NoWork);
         }
+        self.idle_workers.insert(worker_id);
+        self.send_to_worker(worker_id, &MainToWorker::NoWork);
+    }
+
+    /// The next tile for a worker: one it holds unfinished pixels of, then
+    /// a new one, then one held by another worker, which starts it over.
+    fn next_tile(&mut self, worker_id: usize) -> Option<PixelRect> {
+        let held = self
+            .resumable_tiles
+            .iter()
+            .position(|&(_, owner)| owner == worker_id);
+        if let Some(index) = held {
+            return Some(self.resumable_tiles.swap_remove(index).0);
+        }
+        self.pending_tiles
+            .pop_front()
+            .or_else(|| self.resumable_tiles.pop().map(|(tile, _)| tile))
     }
 
     pub fn start_perturbation_render(
@@ -486,50 +1110,84 @@ impl WorkerPool {
         canvas_size: (u32, u32),
         tiles: Vec<PixelRect>,
         force_hdr_float: bool,
+        collect_orbit_stats: bool,
+        probe_interior: bool,
     ) {
         self.is_perturbation_render = true;
         self.gpu_mode = false;
+        self.glitch_pass = false;
         self.current_render_id = self.current_render_id.wrapping_add(1);
+        let reuse_orbit = self.perturbation.can_reuse_orbit(&viewport);
+        self.cancel_stale_work(reuse_orbit);
 
         // Set force_hdr_float before starting render
         self.perturbation.set_force_hdr_float(force_hdr_float);
+        self.perturbation
+            .set_collect_orbit_stats(collect_orbit_stats);
+        self.perturbation.set_probe_interior(probe_interior);
 
-        let orbit_request =
+        let orbit_request = if reuse_orbit {
+            self.perturbation.reuse_orbit(&viewport, canvas_size);
+            None
+        } else {
             match self
                 .perturbation
                 .start_render(self.current_render_id, &viewport, canvas_size)
             {
-                Ok(req) => req,
+                Ok(req) => Some(req),
                 Err(e) => {
                     web_sys::console::error_1(&format!("[WorkerPool] {}", e).into());
                     return;
                 }
-            };
+            }
+        };
 
         let zoom_exponent = (4.0 / viewport.width.to_f64()).log10();
         web_sys::console::log_1(&format!(
-            "[WorkerPool] Starting perturbation render #{} with {} tiles, zoom=10^{:.1}, max_iter={}",
-            self.current_render_id, tiles.len(), zoom_exponent, orbit_request.max_iterations
+            "[WorkerPool] Starting perturbation render #{} with {} tiles, zoom=10^{:.1}, max_iter={}{}",
+            self.current_render_id,
+            tiles.len(),
+            zoom_exponent,
+            self.perturbation.max_iterations(),
+            if reuse_orbit { ", reusing orbit" } else { "" }
         ).into());
 
         self.current_viewport = Some(viewport);
         self.canvas_size = canvas_size;
+        self.estimator = RenderEstimator::for_tiles(&tiles, canvas_size);
         self.pending_tiles = tiles.into();
+        self.deferred_tiles.clear();
+        self.resumable_tiles.clear();
         self.render_start_time = Some(performance_now());
-        self.progress
-            .set(RenderProgress::new(self.pending_tiles.len() as u32));
+        self.stats.update(RenderStats::clear);
+        let orbit_max_iterations = orbit_request
+            .as_ref()
+            .map_or(self.perturbation.max_iterations(), |req| req.max_iterations);
+        self.progress.set(RenderProgress {
+            orbit_computed: if reuse_orbit { orbit_max_iterations } else { 0 },
+            orbit_max_iterations,
+            ..RenderProgress::new(self.pending_tiles.len() as u32)
+        });
+        // The workers keep a reused orbit, shared buffer included
+        let shared_orbit = self.shared_orbit.take().filter(|_| reuse_orbit);
+        self.prepare_shared_results(canvas_size);
+        if self.shared_memory {
+            self.shared_orbit = shared_orbit;
+        }
+        self.health.begin_render();
 
-        if let Some(&worker_id) = self.initialized_workers.iter().next() {
+        let Some(orbit_request) = orbit_request else {
+            self.current_orbit_request = None;
             self.pending_orbit_request = None;
-            self.send_to_worker(
-                worker_id,
-                &MainToWorker::ComputeReferenceOrbit {
-                    render_id: orbit_request.render_id,
-                    orbit_id: orbit_request.orbit_id,
-                    c_ref_json: orbit_request.c_ref_json,
-                    max_iterations: orbit_request.max_iterations,
-                },
-            );
+            self.dispatch_to_idle_workers();
+            return;
+        };
+        self.pending_orbit_data = None;
+        self.current_orbit_request = Some(orbit_request.clone());
+
+        if let Some(worker_id) = self.pick_orbit_worker() {
+            self.pending_orbit_request = None;
+            self.request_orbit(worker_id, orbit_request);
         } else {
             web_sys::console::log_1(
                 &"[WorkerPool] No workers initialized yet, queueing orbit request".into(),
@@ -548,22 +1206,59 @@ impl WorkerPool {
 
         web_sys::console::log_1(
             &format!(
-                "[WorkerPool] Cancelling render #{}, {} tiles pending - terminating workers",
+                "[WorkerPool] Cancelling render #{}, {} tiles pending",
                 self.current_render_id, pending_count
             )
             .into(),
         );
 
+        self.current_render_id = self.current_render_id.wrapping_add(1);
+        self.cancel_stale_work(false);
+
         self.is_perturbation_render = false;
+        self.glitch_pass = false;
         self.perturbation.reset();
-
-        self.recreate_workers();
+        self.current_orbit_request = None;
+        self.pending_orbit_request = None;
+        self.pending_tiles.clear();
+        self.deferred_tiles.clear();
+        self.resumable_tiles.clear();
 
         self.progress.update(|p| {
             p.is_complete = true;
         });
+    }
 
-        self.current_render_id = self.current_render_id.wrapping_add(1);
+    /// Stop the workers' tiles and orbits of earlier renders, once
+    /// `current_render_id` has moved on, and drop the orbits they cached,
+    /// except the main orbit if `keep_orbit` is set.
+    ///
+    /// With shared memory the result buffer header stops tiles at their next
+    /// pixel; otherwise workers see `CancelRender` between slices.
+    fn cancel_stale_work(&mut self, keep_orbit: bool) {
+        if let Some(results) = &self.shared_results {
+            results.begin_render(self.current_render_id);
+        }
+        if let Some(worker_id) = self.orbit_worker.take() {
+            self.health.orbit_finished(worker_id);
+        }
+        // A path traced on the previous image would be drawn on the wrong one
+        self.orbit_trace = None;
+
+        let mut messages = vec![MainToWorker::CancelRender {
+            render_id: self.current_render_id,
+        }];
+        let stale_orbits = (!keep_orbit)
+            .then(|| self.perturbation.orbit_id())
+            .into_iter()
+            .chain(self.perturbation.glitch_resolver().cell_orbit_ids());
+        messages.extend(stale_orbits.map(|orbit_id| MainToWorker::DiscardOrbit { orbit_id }));
+
+        for worker_id in self.broadcast_targets() {
+            for msg in &messages {
+                self.send_to_worker(worker_id, msg);
+            }
+        }
     }
 
     // Note: subdivide_glitched_cells and related methods are in worker_pool_glitch.rs
@@ -579,15 +1274,53 @@ impl WorkerPool {
         }
 
         self.pending_tiles.clear();
+        self.deferred_tiles.clear();
+        self.resumable_tiles.clear();
+        // The new workers hold no orbits
+        self.perturbation.reset();
         self.initialized_workers.clear();
+        self.idle_workers.clear();
+        self.orbit_worker = None;
+        self.respawning.clear();
+        self.health.clear();
+        for generation in &mut self.worker_generations {
+            *generation = generation.wrapping_add(1);
+        }
 
         if let Some(pool_rc) = self.self_ref.upgrade() {
-            if let Ok(new_workers) = create_workers(self.workers.len(), pool_rc) {
+            if let Ok(new_workers) = create_workers(&self.worker_generations, pool_rc) {
                 self.workers = new_workers;
             }
         }
     }
 
+    /// Terminate every worker for good, for a pool that is done.
+    ///
+    /// The workers' message handlers keep the pool alive, so dropping the
+    /// last outside reference to it does not stop them.
+    pub fn shut_down(&mut self) {
+        self.cancel();
+        self.health_check = None;
+        for worker in &self.workers {
+            worker.terminate();
+        }
+        self.workers.clear();
+        self.initialized_workers.clear();
+        self.idle_workers.clear();
+        self.respawning.clear();
+        self.health.clear();
+        self.pending_orbit_data = None;
+        self.shared_results = None;
+        self.shared_orbit = None;
+    }
+
+    /// Keep the reference orbit across renders at the same center,
+    /// computing it at least `orbit_iterations` long. None, the default,
+    /// computes one orbit per render.
+    pub fn set_orbit_reuse(&mut self, orbit_iterations: Option<u32>) {
+        self.perturbation.set_orbit_reuse(orbit_iterations);
+    }
+
     pub fn switch_renderer(&mut self, renderer_id: &str) {
         self.renderer_id = renderer_id.to_string();
         self.perturbation.set_renderer_id(renderer_id);
@@ -619,7 +1352,10 @@ impl WorkerPool {
     pub fn compute_orbit_for_gpu(&mut self, viewport: Viewport, canvas_size: (u32, u32)) {
         self.gpu_mode = true;
         self.is_perturbation_render = false;
+        self.glitch_pass = false;
+        self.shared_memory = false;
         self.current_render_id = self.current_render_id.wrapping_add(1);
+        self.cancel_stale_work(false);
 
         let orbit_request =
             match self
@@ -644,19 +1380,15 @@ impl WorkerPool {
 
         self.current_viewport = Some(viewport);
         self.canvas_size = canvas_size;
+        self.pending_orbit_data = None;
         self.render_start_time = Some(performance_now());
+        self.stats.update(RenderStats::clear);
+        self.health.begin_render();
+        self.current_orbit_request = Some(orbit_request.clone());
 
-        if let Some(&worker_id) = self.initialized_workers.iter().next() {
+        if let Some(worker_id) = self.pick_orbit_worker() {
             self.pending_orbit_request = None;
-            self.send_to_worker(
-                worker_id,
-                &MainToWorker::ComputeReferenceOrbit {
-                    render_id: orbit_request.render_id,
-                    orbit_id: orbit_request.orbit_id,
-                    c_ref_json: orbit_request.c_ref_json,
-                    max_iterations: orbit_request.max_iterations,
-                },
-            );
+            self.request_orbit(worker_id, orbit_request);
         } else {
             web_sys::console::log_1(
                 &"[WorkerPool] No workers initialized yet, queueing orbit request".into(),
@@ -673,6 +1405,49 @@ impl WorkerPool {
             .map(|o| (o.orbit.clone(), self.perturbation.orbit_id()))
     }
 
+    /// Trace the iteration path of a canvas pixel of the current render.
+    ///
+    /// A worker holding the render's orbit reuses it; others compute one
+    /// first. Returns false if there is no render or no worker to ask.
+    pub fn trace_orbit_path<F>(&mut self, x: u32, y: u32, callback: F) -> bool
+    where
+        F: FnOnce(OrbitPath) + 'static,
+    {
+        let Some(worker_id) = self.initialized_workers.iter().copied().min_by_key(|&id| {
+            (
+                !self.perturbation.worker_ready_for_tiles(id),
+                !self.idle_workers.contains(&id),
+                id,
+            )
+        }) else {
+            return false;
+        };
+        self.next_trace_id = self.next_trace_id.wrapping_add(1);
+        let Some(msg) = self
+            .perturbation
+            .build_trace_message(self.next_trace_id, x, y)
+        else {
+            return false;
+        };
+        self.orbit_trace = Some((self.next_trace_id, Box::new(callback)));
+        self.send_to_worker(worker_id, &msg);
+        true
+    }
+
+    fn handle_orbit_path(&mut self, request_id: u32, path: OrbitPath) {
+        if !matches!(self.orbit_trace, Some((id, _)) if id == request_id) {
+            return;
+        }
+        if let Some((_, callback)) = self.orbit_trace.take() {
+            callback(path);
+        }
+    }
+
+    /// Id of the reference orbit of the current render.
+    pub fn orbit_id(&self) -> u32 {
+        self.perturbation.orbit_id()
+    }
+
     pub fn get_max_iterations(&self) -> u32 {
         self.perturbation.max_iterations()
     }
@@ -2,6 +2,7 @@
 
 use super::worker_pool::WorkerPool;
 use super::worker_pool_types::performance_now;
+use leptos::*;
 
 impl WorkerPool {
     /// Subdivide cells that contain glitched tiles for re-rendering.
@@ -42,6 +43,55 @@ impl WorkerPool {
         self.compute_orbits_for_glitched_cells();
     }
 
+    /// Re-render the tiles that came out glitched, against reference orbits
+    /// at the centers of their quadtree cells one subdivision level deeper.
+    ///
+    /// Returns the number of tiles queued; the render completes again once
+    /// they are done, recording the tiles still glitched for another pass.
+    /// Zero when nothing is glitched or no cell can be subdivided further.
+    pub fn rerender_glitched_tiles(&mut self) -> usize {
+        if !self.is_perturbation_render
+            || self.perturbation.glitch_resolver().glitched_tile_count() == 0
+        {
+            return 0;
+        }
+        let subdivided = self
+            .perturbation
+            .glitch_resolver_mut()
+            .subdivide_glitched_cells();
+        if subdivided == 0 {
+            return 0;
+        }
+        self.compute_orbits_for_glitched_cells();
+
+        let resolver = self.perturbation.glitch_resolver_mut();
+        let tiles: Vec<_> = resolver
+            .take_glitched_tiles()
+            .into_iter()
+            .filter(|tile| resolver.cell_orbit_for(tile).is_some())
+            .collect();
+        web_sys::console::log_1(
+            &format!(
+                "[WorkerPool] Re-rendering {} glitched tiles against cell orbits",
+                tiles.len()
+            )
+            .into(),
+        );
+
+        let count = tiles.len();
+        if count == 0 {
+            return 0;
+        }
+        self.glitch_pass = true;
+        self.progress.update(|p| {
+            p.total_steps += count as u32;
+            p.is_complete = false;
+        });
+        self.pending_tiles.extend(tiles);
+        self.dispatch_to_idle_workers();
+        count
+    }
+
     pub(super) fn compute_orbits_for_glitched_cells(&mut self) {
         let Some(viewport) = self.current_viewport.clone() else {
             web_sys::console::log_1(
@@ -83,7 +133,7 @@ impl WorkerPool {
 
         let start_time = performance_now();
         for (orbit_id, msg) in &broadcasts {
-            for worker_id in 0..self.workers.len() {
+            for worker_id in self.broadcast_targets() {
                 self.send_to_worker(worker_id, msg);
             }
             web_sys::console::log_1(
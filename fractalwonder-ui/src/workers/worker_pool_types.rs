@@ -1,8 +1,8 @@
 //! Types for the worker pool.
 
 use crate::workers::perturbation::OrbitRequest;
-use fractalwonder_compute::BlaTable;
-use fractalwonder_core::{ComputeData, PixelRect};
+use fractalwonder_compute::{BlaTable, CompressedOrbit};
+use fractalwonder_core::{ComputeData, OrbitPath, PixelRect};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -17,11 +17,9 @@ pub struct TileResult {
 /// Orbit data passed to the orbit complete callback.
 #[derive(Clone)]
 pub struct OrbitCompleteData {
-    pub orbit: Vec<(f64, f64)>,
-    pub derivative: Vec<(f64, f64)>,
+    pub orbit: CompressedOrbit,
     pub orbit_id: u32,
     pub max_iterations: u32,
-    pub escaped_at: Option<u32>,
     pub bla_table: Option<BlaTable>,
 }
 
@@ -31,6 +29,9 @@ pub type OrbitCompleteCallback = Rc<RefCell<Option<Box<dyn Fn(OrbitCompleteData)
 /// Type alias for render complete callback.
 pub type RenderCompleteCallback = Rc<RefCell<Option<Rc<dyn Fn()>>>>;
 
+/// Callback receiving a traced pixel orbit.
+pub type OrbitPathCallback = Box<dyn FnOnce(OrbitPath)>;
+
 /// Pending reference orbit computation request.
 pub struct PendingOrbitRequest {
     pub request: OrbitRequest,